@@ -0,0 +1,37 @@
+/*!
+# Benchmark Demo: Attributes
+
+This works just like the `fn_fib` demo, but registers the benchmarks using
+attributes instead of the `benches!` macro. (It requires the `macros` crate
+feature.)
+*/
+
+use brunch::Bench;
+
+
+
+#[brunch::bench]
+/// # Checked Add.
+///
+/// Functions returning a `Bench` are registered as-is.
+fn checked_add() -> Bench<'static> {
+	Bench::new("u64::checked_add(2)")
+		.run(|| 2_u64.checked_add(2))
+}
+
+#[brunch::bench(name = "u64::MAX.checked_ilog10()", samples = 1000)]
+/// # Checked Log.
+///
+/// Anything else is benchmarked directly.
+fn checked_ilog10() -> Option<u32> { u64::MAX.checked_ilog10() }
+
+/// # Nested Benchmarks.
+///
+/// Registered benchmarks can live anywhere.
+mod nested {
+	#[brunch::bench]
+	/// # Checked Mul.
+	fn checked_mul() -> Option<u64> { 2_u64.checked_mul(2) }
+}
+
+brunch::main!();
@@ -0,0 +1,33 @@
+/*!
+# Benchmark: Stats
+*/
+
+use brunch::{
+	Bench,
+	benches,
+	Stats,
+};
+use std::time::Duration;
+
+
+
+/// # Sample Set.
+///
+/// Generate a million pseudo-random durations, clustered around one
+/// microsecond with the occasional spike.
+fn samples() -> Vec<Duration> {
+	let mut state = 0x2545_F491_4F6C_DD1D_u64;
+	(0..1_000_000_u64).map(|i| {
+		state = state.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+		let nanos = 900 + (state >> 57);
+		if i % 1000 == 0 { Duration::from_nanos(nanos * 50) }
+		else { Duration::from_nanos(nanos) }
+	}).collect()
+}
+
+benches!(
+	Bench::new("brunch::Stats::try_from(1M)")
+		.with_samples(150)
+		.with_timeout(Duration::from_secs(60))
+		.run_seeded(samples(), Stats::try_from),
+);
@@ -0,0 +1,47 @@
+/*!
+# Brunch: Build Script
+*/
+
+use std::process::Command;
+
+
+
+/// # Build!
+///
+/// This records the compiler version, target triple, and build profile so
+/// they can be stored alongside — or used to separate — the run-to-run
+/// history.
+fn main() {
+	println!("cargo:rerun-if-env-changed=RUSTC");
+
+	// The target triple.
+	let target = std::env::var("TARGET").unwrap_or_default();
+	println!("cargo:rustc-env=BRUNCH_TARGET={target}");
+
+	// The build profile, which Cargo doesn't expose directly for custom
+	// profiles, but can be teased from the output directory, e.g.
+	// target/<profile>/build/brunch-<hash>/out.
+	let profile = std::env::var_os("OUT_DIR")
+		.and_then(|p| {
+			let p = std::path::PathBuf::from(p);
+			p.ancestors().nth(3)?.file_name()?.to_str().map(String::from)
+		})
+		.or_else(|| std::env::var("PROFILE").ok())
+		.unwrap_or_default();
+	let opt = std::env::var("OPT_LEVEL").unwrap_or_default();
+	let key: String = format!("{target}-{profile}-{opt}").chars()
+		.map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') { c } else { '_' })
+		.collect();
+	println!("cargo:rustc-env=BRUNCH_PROFILE={key}");
+
+	// The compiler version.
+	let rustc = std::env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+	let version = Command::new(rustc)
+		.arg("--version")
+		.output()
+		.ok()
+		.and_then(|o| String::from_utf8(o.stdout).ok())
+		.map(|s| s.trim().to_owned())
+		.unwrap_or_default();
+	println!("cargo:rustc-env=BRUNCH_RUSTC={version}");
+}
@@ -0,0 +1,244 @@
+/*!
+# Brunch: Macros
+
+This crate provides the `#[bench]` attribute macro for [`Brunch`](https://crates.io/crates/brunch).
+
+It isn't meant to be used directly; enable Brunch's `macros` crate feature
+instead, and use it as `#[brunch::bench]`.
+*/
+
+#![deny(
+	clippy::allow_attributes_without_reason,
+	clippy::correctness,
+	unreachable_pub,
+	unsafe_code,
+)]
+
+#![warn(
+	clippy::complexity,
+	clippy::nursery,
+	clippy::pedantic,
+	clippy::perf,
+	clippy::style,
+
+	clippy::allow_attributes,
+	clippy::missing_docs_in_private_items,
+	clippy::str_to_string,
+	clippy::todo,
+
+	missing_docs,
+	trivial_casts,
+	trivial_numeric_casts,
+	unused_crate_dependencies,
+	unused_extern_crates,
+	unused_import_braces,
+)]
+
+use proc_macro::{
+	Delimiter,
+	TokenStream,
+	TokenTree,
+};
+
+
+
+/// # Linker Sections.
+///
+/// Registration piggybacks on the platform's "life before main" constructor
+/// arrays — the same trick used by crates like `ctor` and `inventory` — so
+/// that every annotated benchmark can add itself to the registry, no matter
+/// which module it lives in, before `main` is ever called.
+const SECTIONS: &str = r#"
+	#[cfg_attr(
+		any(
+			target_os = "android",
+			target_os = "dragonfly",
+			target_os = "freebsd",
+			target_os = "illumos",
+			target_os = "linux",
+			target_os = "netbsd",
+			target_os = "openbsd",
+		),
+		unsafe(link_section = ".init_array"),
+	)]
+	#[cfg_attr(target_vendor = "apple", unsafe(link_section = "__DATA,__mod_init_func"))]
+	#[cfg_attr(windows, unsafe(link_section = ".CRT$XCU"))]
+"#;
+
+
+
+#[proc_macro_attribute]
+/// # Register Benchmark.
+///
+/// This attribute registers a zero-argument function as a benchmark so that
+/// `brunch::main!()` can find and run it.
+///
+/// Functions returning a `Bench` are registered as-is:
+///
+/// ```ignore
+/// #[brunch::bench]
+/// fn checked_add() -> brunch::Bench<'static> {
+///     brunch::Bench::new("usize::checked_add(2)")
+///         .run(|| 2_usize.checked_add(2))
+/// }
+/// ```
+///
+/// Anything else is treated as the benchmark callback itself. The name
+/// defaults to the function's, but can be overridden — along with the sample
+/// limit — via arguments:
+///
+/// ```ignore
+/// #[brunch::bench(name = "usize::checked_mul(2)", samples = 1000)]
+/// fn checked_mul() -> Option<usize> { 2_usize.checked_mul(2) }
+/// ```
+pub fn bench(attr: TokenStream, item: TokenStream) -> TokenStream {
+	match expand(attr, item.clone()) {
+		Ok(out) => out,
+		// Keep the original item around so the error doesn't cascade.
+		Err(e) => {
+			let mut out = item;
+			out.extend(format!("compile_error!({e:?});").parse::<TokenStream>());
+			out
+		},
+	}
+}
+
+
+
+#[derive(Debug, Default)]
+/// # Attribute Arguments.
+struct Args {
+	/// # Name (String Literal).
+	name: Option<String>,
+
+	/// # Samples (Integer Literal).
+	samples: Option<String>,
+}
+
+impl Args {
+	/// # Parse.
+	///
+	/// Arguments take the form `key = value`, separated by commas.
+	fn parse(attr: TokenStream) -> Result<Self, String> {
+		let mut out = Self::default();
+		let mut iter = attr.into_iter().peekable();
+		while iter.peek().is_some() {
+			let Some(TokenTree::Ident(key)) = iter.next() else {
+				return Err("Expected `key = value`.".to_owned());
+			};
+			let key = key.to_string();
+			if ! matches!(iter.next(), Some(TokenTree::Punct(p)) if p.as_char() == '=') {
+				return Err(format!("Expected `{key} = value`."));
+			}
+			let Some(TokenTree::Literal(value)) = iter.next() else {
+				return Err(format!("Expected a literal value for `{key}`."));
+			};
+			let value = value.to_string();
+
+			match key.as_str() {
+				"name" if value.starts_with('"') || value.starts_with('r') => { out.name.replace(value); },
+				"name" => return Err("The name must be a string literal.".to_owned()),
+				"samples" if value.starts_with(|c: char| c.is_ascii_digit()) => { out.samples.replace(value); },
+				"samples" => return Err("The samples must be an integer literal.".to_owned()),
+				_ => return Err(format!("Unknown argument `{key}`; expected `name` or `samples`.")),
+			}
+
+			match iter.next() {
+				None => break,
+				Some(TokenTree::Punct(p)) if p.as_char() == ',' => {},
+				Some(_) => return Err("Expected a comma between arguments.".to_owned()),
+			}
+		}
+
+		Ok(out)
+	}
+
+	/// # Is Empty?
+	const fn is_empty(&self) -> bool { self.name.is_none() && self.samples.is_none() }
+}
+
+
+
+/// # Function Signature.
+struct Signature {
+	/// # Function Name.
+	ident: String,
+
+	/// # Returns a `Bench`?
+	builder: bool,
+}
+
+impl Signature {
+	/// # Parse.
+	///
+	/// Find the function name and return type, making sure there aren't any
+	/// arguments or generics to get in the way.
+	fn parse(item: TokenStream) -> Result<Self, String> {
+		let mut iter = item.into_iter()
+			.skip_while(|t| ! matches!(t, TokenTree::Ident(i) if i.to_string() == "fn"))
+			.skip(1);
+
+		let Some(TokenTree::Ident(ident)) = iter.next() else {
+			return Err("#[brunch::bench] can only be applied to functions.".to_owned());
+		};
+		let ident = ident.to_string();
+
+		match iter.next() {
+			Some(TokenTree::Group(g)) if g.delimiter() == Delimiter::Parenthesis => {
+				if ! g.stream().is_empty() {
+					return Err(format!("{ident} must not take any arguments."));
+				}
+			},
+			_ => return Err(format!("{ident} must not be generic.")),
+		}
+
+		// Anything between the arguments and the body is the return type
+		// (or a where clause, which would have failed above).
+		let builder = iter
+			.take_while(|t| ! matches!(t, TokenTree::Group(g) if g.delimiter() == Delimiter::Brace))
+			.any(|t| matches!(t, TokenTree::Ident(i) if i.to_string() == "Bench"));
+
+		Ok(Self { ident, builder })
+	}
+}
+
+
+
+/// # Expand.
+fn expand(attr: TokenStream, item: TokenStream) -> Result<TokenStream, String> {
+	let args = Args::parse(attr)?;
+	let Signature { ident, builder } = Signature::parse(item.clone())?;
+
+	// Figure out how to make the Bench.
+	let make =
+		if builder {
+			if ! args.is_empty() {
+				return Err(format!("{ident} returns a Bench; configure it there instead."));
+			}
+			format!("{ident}()")
+		}
+		else {
+			let name = args.name.unwrap_or_else(|| format!("\"{ident}()\""));
+			let samples = args.samples.map_or_else(String::new, |s| format!(".with_samples({s})"));
+			format!("::brunch::Bench::new({name}){samples}.run({ident})")
+		};
+
+	let register: TokenStream = format!(r#"
+		const _: () = {{
+			/// # Make Bench.
+			fn __brunch_make() -> ::brunch::Bench<'static> {{ {make} }}
+
+			/// # Register Bench.
+			extern "C" fn __brunch_register() {{ ::brunch::register_bench(__brunch_make); }}
+
+			#[used]
+			#[allow(unsafe_code, reason = "Needed for registration.")]
+			{SECTIONS}
+			static __BRUNCH_REGISTER: extern "C" fn() = __brunch_register;
+		}};
+	"#).parse().map_err(|e: proc_macro::LexError| e.to_string())?;
+
+	let mut out = item;
+	out.extend(register);
+	Ok(out)
+}
@@ -0,0 +1,119 @@
+/*!
+# Brunch: CPU Affinity
+*/
+
+use std::io::{
+	Error,
+	ErrorKind,
+};
+
+
+
+/// # Pin Current Thread.
+///
+/// Restrict the current thread to the given (zero-indexed) CPU core so the
+/// scheduler can't migrate it mid-run.
+///
+/// ## Errors
+///
+/// This will return an error if the core index is invalid, the OS refuses,
+/// or pinning isn't supported on the platform.
+pub(crate) fn pin(core: usize) -> Result<(), Error> { sys::pin(core) }
+
+
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+/// # Linux.
+mod sys {
+	use std::ffi::c_int;
+	use super::{
+		Error,
+		ErrorKind,
+	};
+
+	/// # CPU Set Size (in Words).
+	///
+	/// This matches glibc's fixed 1024-bit `cpu_set_t`.
+	const WORDS: usize = 1024 / 64;
+
+	extern "C" {
+		/// # Set Affinity.
+		fn sched_setaffinity(pid: c_int, size: usize, mask: *const u64) -> c_int;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Pin.
+	pub(super) fn pin(core: usize) -> Result<(), Error> {
+		let mut mask = [0_u64; WORDS];
+		let word = mask.get_mut(core / 64).ok_or(ErrorKind::InvalidInput)?;
+		*word = 1 << (core % 64);
+
+		// Safety: the mask is exactly as large as we say it is; a PID of zero
+		// means the calling thread.
+		let res = unsafe { sched_setaffinity(0, size_of_val(&mask), mask.as_ptr()) };
+		if res == 0 { Ok(()) }
+		else { Err(Error::last_os_error()) }
+	}
+}
+
+#[cfg(windows)]
+/// # Windows.
+mod sys {
+	use std::ffi::c_void;
+	use super::{
+		Error,
+		ErrorKind,
+	};
+
+	#[link(name = "kernel32")]
+	extern "system" {
+		/// # Current Thread (Pseudo) Handle.
+		fn GetCurrentThread() -> *mut c_void;
+
+		/// # Set Affinity.
+		fn SetThreadAffinityMask(thread: *mut c_void, mask: usize) -> usize;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Pin.
+	pub(super) fn pin(core: usize) -> Result<(), Error> {
+		let mask = u32::try_from(core).ok()
+			.and_then(|c| 1_usize.checked_shl(c))
+			.ok_or(ErrorKind::InvalidInput)?;
+
+		// Safety: the pseudo handle is always valid for the calling thread.
+		let res = unsafe { SetThreadAffinityMask(GetCurrentThread(), mask) };
+		if res == 0 { Err(Error::last_os_error()) }
+		else { Ok(()) }
+	}
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", windows)))]
+/// # Other.
+mod sys {
+	use super::{
+		Error,
+		ErrorKind,
+	};
+
+	/// # Pin (Unsupported).
+	pub(super) fn pin(_core: usize) -> Result<(), Error> {
+		Err(ErrorKind::Unsupported.into())
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_pin() {
+		// A silly core index should always fail, without crashing.
+		let err = std::thread::spawn(|| pin(usize::MAX)).join()
+			.expect("Thread panicked.")
+			.expect_err("Pinning should fail.");
+		assert!(matches!(err.kind(), ErrorKind::InvalidInput | ErrorKind::Unsupported));
+	}
+}
@@ -0,0 +1,259 @@
+/*!
+# Brunch: Allocation Tracking
+*/
+
+use dactyl::traits::SaturatingFrom;
+use std::{
+	alloc::{
+		GlobalAlloc,
+		Layout,
+		System,
+	},
+	sync::atomic::{
+		AtomicBool,
+		AtomicU64,
+		Ordering::Relaxed,
+	},
+};
+
+
+
+/// # Allocation Count.
+static ALLOCS: AtomicU64 = AtomicU64::new(0);
+
+/// # Reallocation Count.
+static REALLOCS: AtomicU64 = AtomicU64::new(0);
+
+/// # Deallocation Count.
+static DEALLOCS: AtomicU64 = AtomicU64::new(0);
+
+/// # Bytes Allocated.
+static BYTES: AtomicU64 = AtomicU64::new(0);
+
+/// # Tracker Installed?
+///
+/// This is set the first time the tracking allocator is used, which — if it
+/// is installed at all — will happen long before any benchmarks are run.
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Tracking Allocator.
+///
+/// This is a thin wrapper around the [`System`] allocator that counts the
+/// allocations, reallocations, and deallocations made, along with the total
+/// number of bytes requested.
+///
+/// When installed as the `#[global_allocator]`, the results summary will
+/// include the mean allocations and bytes allocated per iteration for each
+/// benchmark. When it isn't, those columns are simply omitted.
+///
+/// The counters are plain (relaxed) atomics, so the overhead is negligible,
+/// but they are process-wide; allocations made by other threads while a
+/// benchmark is running will be counted too.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use brunch::{Bench, TrackingAllocator};
+///
+/// #[global_allocator]
+/// static GLOBAL: TrackingAllocator = TrackingAllocator;
+///
+/// brunch::benches!(
+///     Bench::new("String::from")
+///         .run(|| String::from("Hello World")),
+/// );
+/// ```
+pub struct TrackingAllocator;
+
+#[expect(unsafe_code, reason = "Needed for GlobalAlloc.")]
+// Safety: everything is passed straight through to the system allocator.
+unsafe impl GlobalAlloc for TrackingAllocator {
+	#[inline]
+	unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+		track(layout.size());
+		// Safety: the caller upholds the contract.
+		unsafe { System.alloc(layout) }
+	}
+
+	#[inline]
+	unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+		track(layout.size());
+		// Safety: the caller upholds the contract.
+		unsafe { System.alloc_zeroed(layout) }
+	}
+
+	#[inline]
+	unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+		DEALLOCS.fetch_add(1, Relaxed);
+		// Safety: the caller upholds the contract.
+		unsafe { System.dealloc(ptr, layout) }
+	}
+
+	#[inline]
+	unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+		REALLOCS.fetch_add(1, Relaxed);
+		BYTES.fetch_add(u64::saturating_from(new_size.saturating_sub(layout.size())), Relaxed);
+		// Safety: the caller upholds the contract.
+		unsafe { System.realloc(ptr, layout, new_size) }
+	}
+}
+
+#[inline]
+/// # Track Allocation.
+fn track(size: usize) {
+	if ! ACTIVE.load(Relaxed) { ACTIVE.store(true, Relaxed); }
+	ALLOCS.fetch_add(1, Relaxed);
+	BYTES.fetch_add(u64::saturating_from(size), Relaxed);
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Counter Snapshot.
+///
+/// This holds the values of the allocation counters at a given moment, or
+/// the difference between two such moments.
+pub(crate) struct Snapshot {
+	/// # Allocations.
+	allocs: u64,
+
+	/// # Reallocations.
+	reallocs: u64,
+
+	/// # Deallocations.
+	deallocs: u64,
+
+	/// # Bytes Allocated.
+	bytes: u64,
+}
+
+impl Snapshot {
+	/// # Now.
+	///
+	/// Return the current counts, or `None` if the tracking allocator isn't
+	/// installed.
+	pub(crate) fn now() -> Option<Self> {
+		if ACTIVE.load(Relaxed) {
+			Some(Self {
+				allocs: ALLOCS.load(Relaxed),
+				reallocs: REALLOCS.load(Relaxed),
+				deallocs: DEALLOCS.load(Relaxed),
+				bytes: BYTES.load(Relaxed),
+			})
+		}
+		else { None }
+	}
+
+	/// # Since.
+	///
+	/// Return the difference between this snapshot and an earlier one.
+	pub(crate) const fn since(self, start: Self) -> Self {
+		Self {
+			allocs: self.allocs.saturating_sub(start.allocs),
+			reallocs: self.reallocs.saturating_sub(start.reallocs),
+			deallocs: self.deallocs.saturating_sub(start.deallocs),
+			bytes: self.bytes.saturating_sub(start.bytes),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Allocation Tally.
+///
+/// This adds up the per-sample snapshots collected over the course of a
+/// benchmark.
+pub(crate) struct Tally {
+	/// # Samples.
+	samples: u64,
+
+	/// # Totals.
+	total: Snapshot,
+}
+
+impl Tally {
+	/// # Push Sample.
+	pub(crate) const fn push(&mut self, v: Snapshot) {
+		self.samples = self.samples.saturating_add(1);
+		self.total.allocs = self.total.allocs.saturating_add(v.allocs);
+		self.total.reallocs = self.total.reallocs.saturating_add(v.reallocs);
+		self.total.deallocs = self.total.deallocs.saturating_add(v.deallocs);
+		self.total.bytes = self.total.bytes.saturating_add(v.bytes);
+	}
+
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	/// # Finish.
+	///
+	/// Return the per-sample averages, or `None` if nothing was tallied.
+	pub(crate) fn finish(self) -> Option<Allocs> {
+		if self.samples == 0 { return None; }
+		let samples = self.samples as f64;
+		Some(Allocs::new(
+			self.total.allocs.saturating_add(self.total.reallocs) as f64 / samples,
+			self.total.bytes as f64 / samples,
+		))
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// # Allocations Per Iteration.
+///
+/// This holds the mean number of (re)allocations and bytes allocated per
+/// benchmark iteration.
+pub(crate) struct Allocs {
+	/// # (Re)allocations.
+	allocs: f64,
+
+	/// # Bytes Allocated.
+	bytes: f64,
+}
+
+impl Allocs {
+	/// # New.
+	pub(crate) const fn new(allocs: f64, bytes: f64) -> Self {
+		Self { allocs, bytes }
+	}
+
+	/// # Allocations.
+	pub(crate) const fn allocs(self) -> f64 { self.allocs }
+
+	/// # Bytes.
+	pub(crate) const fn bytes(self) -> f64 { self.bytes }
+
+	/// # Nice Allocations.
+	pub(crate) fn nice_allocs(self) -> String { crate::util::nice_count(self.allocs) }
+
+	/// # Nice Bytes.
+	pub(crate) fn nice_bytes(self) -> String { crate::util::nice_bytes(self.bytes) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_tally() {
+		let mut tally = Tally::default();
+		assert!(tally.finish().is_none(), "Nothing tallied.");
+
+		tally.push(Snapshot { allocs: 2, reallocs: 1, deallocs: 3, bytes: 100 });
+		tally.push(Snapshot { allocs: 2, reallocs: 0, deallocs: 2, bytes: 50 });
+		let allocs = tally.finish().expect("Missing allocs.");
+		assert_eq!(allocs.nice_allocs(), "2.50");
+		assert_eq!(allocs.nice_bytes(), "75 B");
+		assert_eq!(Allocs::new(1.0, 1536.0).nice_bytes(), "1.50 KiB");
+
+		// Differences.
+		let a = Snapshot { allocs: 5, reallocs: 1, deallocs: 2, bytes: 64 };
+		let b = Snapshot { allocs: 7, reallocs: 1, deallocs: 4, bytes: 96 };
+		assert_eq!(b.since(a), Snapshot { allocs: 2, reallocs: 0, deallocs: 2, bytes: 32 });
+	}
+}
@@ -3,20 +3,99 @@
 */
 
 use crate::{
+	Abacus,
+	affinity,
+	Bencher,
+	alloc::{
+		Allocs,
+		Tally,
+	},
 	BrunchError,
+	checks,
+	clock::{
+		self,
+		Lap,
+		Stopwatch,
+	},
+	cold,
+	Config,
+	Context,
+	context::Contexts,
+	criterion,
 	History,
+	isolate,
+	Metric,
 	MIN_SAMPLES,
+	MIN_SAMPLES_FLOOR,
+	OutlierPolicy,
+	OutputTarget,
+	github,
+	perf::{
+		self,
+		Counters,
+	},
+	plot,
+	Priority,
+	priority,
+	Progress,
+	raw,
+	rss::{
+		self,
+		MaxRss,
+	},
+	runlog,
+	RunReport,
+	scaling::Fit,
+	signal,
 	Stats,
+	Stream,
+	term,
+	TimeUnit,
+	Timer,
+	timer::{
+		Tick,
+		TimerGuard,
+	},
+	Trend,
 	util,
 };
 use dactyl::{
+	NiceElapsed,
+	NiceFloat,
+	NicePercent,
 	NiceU32,
+	NiceU64,
+	total_cmp,
 	traits::SaturatingFrom,
 };
 use std::{
+	any::Any,
+	cell::RefCell,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+		VecDeque,
+	},
 	fmt,
 	hint::black_box,
-	num::NonZeroU32,
+	io::Write,
+	num::{
+		NonZeroU32,
+		NonZeroUsize,
+	},
+	panic::AssertUnwindSafe,
+	path::{
+		Path,
+		PathBuf,
+	},
+	rc::Rc,
+	sync::{
+		atomic::{
+			AtomicBool,
+			Ordering::SeqCst,
+		},
+		Once,
+	},
 	time::{
 		Duration,
 		Instant,
@@ -29,15 +108,202 @@ use std::{
 /// # Default Sample Count.
 const DEFAULT_SAMPLES: NonZeroU32 = unsafe { NonZeroU32::new_unchecked(2500) };
 
+/// # Default Sample Count (Cold).
+///
+/// Cold samples are much slower to collect, so fewer are taken by default.
+const DEFAULT_COLD_SAMPLES: NonZeroU32 = NonZeroU32::new(250).unwrap();
+
+/// # Maximum Sample Count.
+///
+/// This is the hard cap for "unlimited" benches — `with_samples(0)` — to keep
+/// memory usage in check.
+const MAX_SAMPLES: NonZeroU32 = NonZeroU32::new(16_777_216).unwrap();
+
+/// # Maximum Pre-Reservation.
+///
+/// Sample buffers are pre-allocated up to this many entries; anything beyond
+/// that is grown as needed.
+const MAX_RESERVE: u32 = 65_536;
+
+/// # Sampler.
+///
+/// Runners are boxed up as callbacks that execute and time a single sample,
+/// returning the wall time, and if requested, the CPU time (and allocations,
+/// if tracked).
+type Sampler<'a> = Box<dyn FnMut(bool) -> Lap + 'a>;
+
+/// # Verification Check.
+///
+/// This runs the callback once and hands the output to the verifier.
+type Check<'a> = Box<dyn FnOnce() -> bool + 'a>;
+
+/// # Setup.
+///
+/// This builds any shared context the runner needs before sampling begins.
+type Setup<'a> = Box<dyn Fn() + 'a>;
+
+/// # Chill.
+///
+/// This disturbs whatever state the runner depends on between cold samples.
+type Chill<'a> = Box<dyn FnMut() + 'a>;
+
 /// # Default Timeout.
 const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
 
-/// # Markup for No Change "Value".
-const NO_CHANGE: &str = "\x1b[2m---\x1b[0m";
+/// # Minimum Timeout.
+const MIN_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// # Wall-Clock Ceiling (Multiplier).
+///
+/// The timeout is measured against the time spent inside the timed region,
+/// but as a safety net against slow seeds, sampling will also stop once the
+/// wall-clock time reaches this many multiples of it.
+const WALL_CEILING: u32 = 10;
+
+/// # Exit Code: Panicked.
+///
+/// This matches the exit code Rust itself uses for panics.
+const EXIT_PANICKED: i32 = 101;
+
+/// # Exit Code: Over Budget.
+const EXIT_OVER_BUDGET: i32 = 1;
+
+/// # Quiet Panics?
+///
+/// While set, the panic hook installed by `catch_panic` stays silent.
+static QUIET_PANICS: AtomicBool = AtomicBool::new(false);
+
+/// # Streaming Threshold.
+///
+/// Benches collecting more than this many samples use streaming stats by
+/// default.
+const STREAMING_THRESHOLD: u32 = 100_000;
+
+/// # Default Significance Threshold.
+///
+/// Changes are only reported if Welch's t-test yields a p-value below this.
+const DEFAULT_SIGNIFICANCE: f64 = 0.05;
+
+/// # Material Difference.
+///
+/// When comparing against the best run, the change from the recent runs is
+/// shown too if the two differ by at least this much.
+const BEST_SPREAD: f64 = 0.01;
+
+/// # Default Pruning Thresholds.
+///
+/// Samples cells are highlighted when more than this fraction of samples were
+/// discarded as outliers (warning, alert).
+const DEFAULT_PRUNING: (f64, f64) = (0.10, 0.25);
+
+/// # Default Drift Threshold.
+///
+/// Benchmarks are flagged when the means of the first and second halves of
+/// their samples differ by more than this fraction.
+const DEFAULT_DRIFT: f64 = 0.05;
+
+/// # Low-Confidence Change Threshold.
+///
+/// Results with fewer than `MIN_SAMPLES` valid samples — on either side —
+/// must change by at least this much to be reported.
+const LOW_CONFIDENCE_CHANGE: f64 = 0.05;
+
+/// # Interleaved Slice.
+///
+/// When interleaved, each benchmark collects (at most) this many samples
+/// before passing the baton to the next.
+const INTERLEAVE_SLICE: u32 = 100;
+
+/// # Maximum Rounds.
+const MAX_RUNS: u32 = 100;
+
+/// # Minimum Measured Time.
+///
+/// Benches whose valid samples add up to less than this are too short to
+/// be trusted, and are noted beneath the table.
+const MIN_MEASURED: Duration = Duration::from_millis(5);
+
+/// # No Change "Value".
+const NO_CHANGE: &str = "---";
+
+/// # Minimum Name Width.
+///
+/// Names are never truncated below this many columns to make a table fit.
+const MIN_NAME_WIDTH: usize = 24;
+
+/// # Minimum Name Head.
+///
+/// When truncating a name, try to keep at least this much of the start.
+const MIN_NAME_HEAD: usize = 6;
+
+/// # Fast Band.
+///
+/// When slow thresholds are set, means below this are highlighted as fast.
+const FAST_BAND: Duration = Duration::from_micros(1);
+
+/// # Style: Dim.
+const STYLE_DIM: &str = "2";
+
+/// # Style: Drift Warning.
+const STYLE_DRIFT: &str = "93";
+
+/// # Style: Error.
+const STYLE_ERROR: &str = "1;38;5;208";
+
+/// # Style: Over Budget.
+const STYLE_OVER_BUDGET: &str = "1;91";
+
+/// # Style: Table Header.
+const STYLE_HEADER: &str = "1;95";
+
+/// # Style: Group Summary.
+const STYLE_GEOMEAN: &str = "1";
+
+/// # Style: Heavy Pruning (Warning).
+const STYLE_PRUNED_WARN: &str = "93";
+
+/// # Style: Heavy Pruning (Alert).
+const STYLE_PRUNED_ALERT: &str = "91";
+
+/// # Style: Faster.
+const STYLE_FASTER: &str = "92";
+
+/// # Style: Mean.
+const STYLE_MEAN: &str = "1";
+
+/// # Style: Mean (Fast).
+const STYLE_MEAN_FAST: &str = "1;92";
+
+/// # Style: Mean (Slow).
+const STYLE_MEAN_SLOW: &str = "1;93";
+
+/// # Style: Mean (Very Slow).
+const STYLE_MEAN_VERY_SLOW: &str = "1;91";
+
+/// # Style: Marker.
+///
+/// This isn't a real style; segments marked with it stand in for color, so
+/// are only printed when color is disabled. (They're blanked out otherwise
+/// to keep the alignment identical.)
+const STYLE_MARKER: &str = "!";
+
+/// # Style: Name.
+const STYLE_NAME: &str = "94";
+
+/// # Style: Name (Common Portion).
+const STYLE_NAME_DIM: &str = "34";
+
+/// # Style: Sample Divider.
+const STYLE_SLASH: &str = "35";
+
+/// # Style: Slower.
+const STYLE_SLOWER: &str = "91";
+
+/// # Style: Spacer.
+const STYLE_SPACER: &str = "35";
 
 
 
-#[derive(Debug, Default)]
 /// # Benchmarks.
 ///
 /// This holds a collection of benchmarks. You don't need to interact with this
@@ -48,7 +314,6 @@ const NO_CHANGE: &str = "\x1b[2m---\x1b[0m";
 ///
 /// ```no_run
 /// use brunch::{Bench, Benches};
-/// use std::time::Duration;
 ///
 /// fn main() {
 ///     // You can do set up, etc., here.
@@ -63,26 +328,311 @@ const NO_CHANGE: &str = "\x1b[2m---\x1b[0m";
 ///             .run(|| 2_usize.checked_add(2))
 ///     );
 ///
-///     // Maybe you want to pause between each benchmark to let the CPU cool?
-///     std::thread::sleep(Duration::from_secs(3));
-///
 ///     // Add another Bench.
 ///     benches.push(
 ///         Bench::new("200_usize.checked_mul(3)")
 ///             .run(|| 200_usize.checked_mul(3))
 ///     );
 ///
-///     // After the last Bench has been added, call `finish` to crunch the
-///     // stats and print a summary.
-///     benches.finish();
+///     // After the last Bench has been added, call `finish_or_print` to run
+///     // them, crunch the stats, and print a summary.
+///     benches.finish_or_print();
 ///
 ///     // You can do other stuff afterward if you want.
 ///     eprintln!("Done!");
 /// }
 /// ```
-pub struct Benches(Vec<Bench>);
+pub struct Benches<'a> {
+	/// # Benchmarks.
+	set: Vec<Bench<'a>>,
+
+	/// # Output Target.
+	output: Option<OutputTarget>,
+
+	/// # Color.
+	color: Option<bool>,
+
+	/// # Verbose.
+	verbose: Option<bool>,
+
+	/// # Significance Threshold.
+	significance: f64,
+
+	/// # Change Threshold.
+	threshold: Option<f64>,
+
+	/// # Compare Against Best.
+	compare_best: Option<bool>,
+
+	/// # Print libtest-Style Lines.
+	libtest: Option<bool>,
+
+	/// # Quiet.
+	quiet: Option<bool>,
+
+	/// # Run Each Bench in Its Own Process.
+	isolated: Option<bool>,
+
+	/// # Pin to CPU Core.
+	pin: Option<usize>,
+
+	/// # Thread Priority.
+	priority: Option<Priority>,
+
+	/// # Refuse Debug Build?
+	///
+	/// This is `true` for debug builds unless [`Benches::allow_debug`] says
+	/// otherwise.
+	debug: bool,
+
+	/// # Suite Time Budget.
+	budget: Option<Duration>,
+
+	/// # Retries.
+	///
+	/// The number of extra attempts allowed for benches that come back
+	/// `TooWild` or short.
+	retries: u8,
+
+	/// # Custom Timer.
+	///
+	/// If unspecified, `Instant` is used.
+	timer: Option<Rc<dyn Timer>>,
+
+	/// # Prune History.
+	prune: Option<bool>,
+
+	/// # History Path.
+	history: Option<PathBuf>,
+
+	/// # Plot Directory.
+	///
+	/// If unspecified, `BRUNCH_PLOTS` is used instead, if set.
+	plots: Option<PathBuf>,
+
+	/// # Run Log Path.
+	///
+	/// If unspecified, `BRUNCH_LOG` is used instead, if set.
+	run_log: Option<PathBuf>,
+
+	/// # Disable History.
+	no_history: bool,
+
+	/// # Save History Incrementally.
+	incremental: bool,
+
+	/// # Environmental Overrides.
+	overrides: Overrides,
+
+	/// # Default Sample Limit.
+	samples: Option<NonZeroU32>,
+
+	/// # Default Timeout Limit.
+	timeout: Option<Duration>,
+
+	/// # Display Unit.
+	unit: TimeUnit,
+
+	/// # Duplicate Name Policy.
+	dupes: DupePolicy,
+
+	/// # Name Style.
+	names: NameStyle,
+
+	/// # Show Operations Per Second.
+	ops: Option<bool>,
+
+	/// # Group Summaries.
+	geomean: Option<bool>,
+
+	/// # Ranking Table.
+	ranking: Option<bool>,
+
+	/// # Rounds.
+	runs: Option<u32>,
+
+	/// # Read-Only History.
+	history_ro: Option<bool>,
+
+	/// # Named Baseline.
+	///
+	/// If unspecified, `BRUNCH_BASELINE` is used instead, if set.
+	baseline: Option<String>,
+
+	/// # Saved Baseline.
+	///
+	/// If unspecified, `BRUNCH_SAVE_BASELINE` is used instead, if set.
+	save_baseline: Option<String>,
+
+	/// # Interleave Samples.
+	interleaved: Option<bool>,
+
+	/// # Parallel Jobs.
+	parallel: Option<usize>,
+
+	/// # Required Tags.
+	///
+	/// If unspecified, `BRUNCH_TAGS` is used instead, if set.
+	tags: Option<Vec<String>>,
+
+	/// # Excluded Tags.
+	///
+	/// If unspecified, `BRUNCH_SKIP_TAGS` is used instead, if set.
+	skip_tags: Option<Vec<String>>,
+
+	/// # Filtered-Out Benchmarks.
+	///
+	/// The names of any benchmarks left out because of their tags.
+	filtered: Vec<String>,
+
+	/// # Name Filters.
+	///
+	/// If unspecified, the (positional) command-line arguments are used
+	/// instead, if any.
+	name_filters: Option<Vec<String>>,
+
+	/// # Unmatched Benchmarks.
+	///
+	/// The names of any benchmarks left out by the name filters.
+	unmatched: Vec<String>,
 
-impl Extend<Bench> for Benches {
+	/// # List Only?
+	///
+	/// If unspecified, the `--list` command-line flag is used instead.
+	list: Option<bool>,
+
+	/// # Pruning Thresholds.
+	///
+	/// If `None`, heavy pruning goes unmentioned.
+	pruning: Option<(f64, f64)>,
+
+	/// # Slow Thresholds.
+	///
+	/// If `None`, means are never highlighted.
+	slow: Option<(Duration, Duration)>,
+
+	/// # Drift Threshold.
+	///
+	/// If `None`, drift goes unmentioned.
+	drift: Option<f64>,
+
+	/// # Shared Contexts.
+	contexts: Contexts<'a>,
+
+	/// # Start Time.
+	started: Instant,
+}
+
+impl Default for Benches<'_> {
+	fn default() -> Self {
+		Self {
+			set: Vec::new(),
+			output: None,
+			color: None,
+			verbose: None,
+			significance: DEFAULT_SIGNIFICANCE,
+			threshold: None,
+			compare_best: None,
+			libtest: None,
+			quiet: None,
+			isolated: None,
+			pin: None,
+			priority: None,
+			debug: cfg!(debug_assertions),
+			budget: None,
+			retries: 0,
+			timer: None,
+			prune: None,
+			history: None,
+			plots: None,
+			run_log: None,
+			no_history: false,
+			incremental: true,
+			overrides: Overrides::default(),
+			samples: None,
+			timeout: None,
+			unit: TimeUnit::Auto,
+			dupes: DupePolicy::Error,
+			names: NameStyle::Auto,
+			ops: None,
+			geomean: None,
+			ranking: None,
+			runs: None,
+			history_ro: None,
+			baseline: None,
+			save_baseline: None,
+			interleaved: None,
+			parallel: None,
+			tags: None,
+			skip_tags: None,
+			filtered: Vec::new(),
+			name_filters: None,
+			unmatched: Vec::new(),
+			list: None,
+			pruning: Some(DEFAULT_PRUNING),
+			slow: None,
+			drift: Some(DEFAULT_DRIFT),
+			contexts: Contexts::default(),
+			started: Instant::now(),
+		}
+	}
+}
+
+impl fmt::Debug for Benches<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		// Custom timers needn't be debuggable.
+		f.debug_struct("Benches")
+			.field("set", &self.set)
+			.field("output", &self.output)
+			.field("color", &self.color)
+			.field("verbose", &self.verbose)
+			.field("significance", &self.significance)
+			.field("threshold", &self.threshold)
+			.field("compare_best", &self.compare_best)
+			.field("libtest", &self.libtest)
+			.field("quiet", &self.quiet)
+			.field("isolated", &self.isolated)
+			.field("pin", &self.pin)
+			.field("priority", &self.priority)
+			.field("debug", &self.debug)
+			.field("budget", &self.budget)
+			.field("retries", &self.retries)
+			.field("prune", &self.prune)
+			.field("history", &self.history)
+			.field("plots", &self.plots)
+			.field("run_log", &self.run_log)
+			.field("no_history", &self.no_history)
+			.field("incremental", &self.incremental)
+			.field("overrides", &self.overrides)
+			.field("samples", &self.samples)
+			.field("timeout", &self.timeout)
+			.field("unit", &self.unit)
+			.field("dupes", &self.dupes)
+			.field("names", &self.names)
+			.field("ops", &self.ops)
+			.field("geomean", &self.geomean)
+			.field("ranking", &self.ranking)
+			.field("runs", &self.runs)
+			.field("history_ro", &self.history_ro)
+			.field("baseline", &self.baseline)
+			.field("interleaved", &self.interleaved)
+			.field("parallel", &self.parallel)
+			.field("tags", &self.tags)
+			.field("skip_tags", &self.skip_tags)
+			.field("filtered", &self.filtered)
+			.field("name_filters", &self.name_filters)
+			.field("unmatched", &self.unmatched)
+			.field("list", &self.list)
+			.field("pruning", &self.pruning)
+			.field("slow", &self.slow)
+			.field("drift", &self.drift)
+			.field("contexts", &self.contexts)
+			.field("started", &self.started)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a> Extend<Bench<'a>> for Benches<'a> {
 	/// # Extend.
 	///
 	/// Insert [`Bench`]es en-masse.
@@ -97,639 +647,9876 @@ impl Extend<Bench> for Benches {
 	///     Bench::new("String::len").run(|| "Hello World".len()),
 	///     Bench::spacer(),
 	/// ]);
-	/// benches.finish();
+	/// benches.finish_or_print();
 	/// ```
-	fn extend<T: IntoIterator<Item=Bench>>(&mut self, iter: T) {
+	fn extend<T: IntoIterator<Item=Bench<'a>>>(&mut self, iter: T) {
 		for b in iter { self.push(b); }
 	}
 }
 
-impl Benches {
-	/// # Add Benchmark.
+impl<'a> FromIterator<Bench<'a>> for Benches<'a> {
+	/// # From Iterator.
 	///
-	/// Use this method to push a benchmark to your `Benches` collection. Each
-	/// benchmark should be pushed before running [`Benches::finish`].
+	/// Collect [`Bench`]es into a new set. As with [`Benches::push`],
+	/// duplicate names are flagged.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
 	/// use brunch::{Benches, Bench};
 	///
-	/// let mut benches = Benches::default();
-	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
-	/// // Repeat push as needed.
-	/// benches.finish();
+	/// let mut benches: Benches = (1..=3_u8)
+	///     .map(|n|
+	///         Bench::new(format!("u8::checked_pow({n})"))
+	///             .run_seeded(n, |n| 2_u8.checked_pow(u32::from(n)))
+	///     )
+	///     .collect();
+	/// benches.finish_or_print();
 	/// ```
-	pub fn push(&mut self, mut b: Bench) {
-		if ! b.is_spacer() && self.has_name(&b.name) {
-			b.stats.replace(Err(BrunchError::DupeName));
-		}
-
-		self.0.push(b);
+	fn from_iter<T: IntoIterator<Item=Bench<'a>>>(iter: T) -> Self {
+		let mut out = Self::default();
+		out.extend(iter);
+		out
 	}
+}
 
-	/// # Finish.
-	///
-	/// Crunch and print the data!
-	///
-	/// This method should only be called after all benchmarks have been pushed
-	/// to the set.
-	///
-	/// ## Examples
+impl<'a, 'b> IntoIterator for &'b Benches<'a> {
+	type Item = &'b Bench<'a>;
+	type IntoIter = std::iter::Filter<std::slice::Iter<'b, Bench<'a>>, fn(&&Bench<'a>) -> bool>;
+
+	/// # Into Iterator.
 	///
-	/// ```no_run
-	/// use brunch::{Benches, Bench};
+	/// Iterate over the (non-spacer) benchmarks in the set.
+	fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
+
+
+/// # Benchmark Entry.
+///
+/// This is implemented for [`Bench`] as well as anything that can be turned
+/// into an iterator of them — `Option<Bench>`, `Vec<Bench>`, etc. — allowing
+/// single and dynamically-generated benchmarks to be mixed freely in the
+/// [`benches`](crate::benches) macro.
+pub trait BenchEntry<'a> {
+	/// # Push To Set.
+	fn push_to(self, set: &mut Benches<'a>);
+}
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Duplicate Name Policy.
+///
+/// This enum is used to specify what happens when a benchmark is pushed with
+/// the same name as one already in the set, via [`Benches::on_duplicate`].
+pub enum DupePolicy {
+	#[default]
+	/// # Error.
 	///
-	/// let mut benches = Benches::default();
-	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
-	/// benches.finish();
-	/// ```
-	pub fn finish(&self) {
-		// If there weren't any benchmarks, just print an error.
-		if self.0.is_empty() {
-			eprintln!("\x1b[1;91mError:\x1b[0m {}", BrunchError::NoBench);
-			return;
-		}
+	/// The duplicate is kept, but never run, and reported as an error.
+	Error,
 
-		// Build the summaries.
-		let mut history = History::default();
-		let mut summary = Table::default();
-		let names: Vec<Vec<char>> = self.0.iter()
-			.filter_map(|b|
-				if b.is_spacer() { None }
-				else { Some(b.name.chars().collect()) }
-			)
-			.collect();
-		for b in &self.0 {
-			summary.push(b, &names, &history);
-		}
+	/// # Replace.
+	///
+	/// The duplicate takes the place of the earlier benchmark, which is
+	/// dropped.
+	Replace,
 
-		// Update the history.
-		self.finish_history(&mut history);
+	/// # Rename.
+	///
+	/// The duplicate is renamed with a numeric suffix — ` (2)`, ` (3)`, etc.
+	/// — so both are run, reported, and saved to history separately.
+	Rename,
+}
 
-		eprintln!("{summary}");
-	}
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Name Style.
+///
+/// This enum is used to specify how benchmark names are highlighted in the
+/// summary table, via [`Benches::name_style`]. Either way, the text itself
+/// is unchanged.
+pub enum NameStyle {
+	#[default]
+	/// # Automatic.
+	///
+	/// Dim the portion of each name shared with the others — backing up to
+	/// the nearest `::` or `(` — and highlight the rest. (The namespaces of
+	/// benches created with [`Bench::new_in`] are dimmed instead.)
+	Auto,
 
-	/// # Finish: Update History.
-	fn finish_history(&self, history: &mut History) {
-		// Copy over the values.
-		for b in &self.0 {
-			if let Some(Ok(s)) = b.stats {
-				history.insert(&b.name, s);
-			}
-		}
+	/// # Plain.
+	///
+	/// Highlight the whole name.
+	Plain,
 
-		// Save it.
-		history.save();
-	}
+	/// # Split At.
+	///
+	/// Dim everything before the last occurrence (or run of occurrences) of
+	/// the delimiter, and highlight the rest, e.g. `':'` to split
+	/// `foo::bar()` into `foo` and `::bar()`. Names without the delimiter are
+	/// highlighted in full.
+	SplitAt(char),
 }
 
-impl Benches {
-	/// # Has Name.
-	fn has_name(&self, name: &str) -> bool {
-		self.0.iter().any(|b| b.name == name)
+impl NameStyle {
+	/// # Format Name.
+	///
+	/// Return the styled name cell.
+	fn format(self, name: &str, namespace: Option<&str>, names: &[Vec<char>]) -> Cell {
+		match self {
+			// No need to guess if the namespace is known.
+			Self::Auto => namespace.map_or_else(
+				|| format_name(name.chars().collect(), names),
+				|ns| Cell(vec![
+					(STYLE_NAME_DIM, format!("{ns}::")),
+					(STYLE_NAME, name[ns.len() + 2..].to_owned()),
+				]),
+			),
+			Self::Plain => Cell::new(STYLE_NAME, name),
+			Self::SplitAt(delim) => {
+				let Some(mut idx) = name.rfind(delim) else { return Cell::new(STYLE_NAME, name); };
+				while name[..idx].ends_with(delim) { idx -= delim.len_utf8(); }
+				if idx == 0 { Cell::new(STYLE_NAME, name) }
+				else {
+					Cell(vec![
+						(STYLE_NAME_DIM, name[..idx].to_owned()),
+						(STYLE_NAME, name[idx..].to_owned()),
+					])
+				}
+			},
+		}
 	}
 }
 
 
 
-#[derive(Debug)]
-/// # Benchmark.
-///
-/// This struct holds a single "bench" you wish to run. See the main crate
-/// documentation for more information.
-pub struct Bench {
-	/// # Benchmark Name.
-	name: String,
-
-	/// # Sample Limit.
-	samples: NonZeroU32,
-
-	/// # Timeout Limit.
-	timeout: Duration,
+impl<'a> BenchEntry<'a> for Bench<'a> {
+	#[inline]
+	fn push_to(self, set: &mut Benches<'a>) { set.push(self); }
+}
 
-	/// # Collected Stats.
-	stats: Option<Result<Stats, BrunchError>>,
+impl<'a, I> BenchEntry<'a> for I
+where I: IntoIterator<Item=Bench<'a>> {
+	#[inline]
+	fn push_to(self, set: &mut Benches<'a>) { set.extend(self); }
 }
 
-impl Bench {
+impl<'a> Benches<'a> {
 	#[must_use]
-	/// # New.
+	/// # With Output Target.
 	///
-	/// Instantiate a new benchmark with a name. The name can be anything, but
-	/// is intended to represent the method call itself, like `foo::bar(10)`.
+	/// By default, the results summary is printed to `STDERR`, or `STDOUT`
+	/// if the `BRUNCH_STDOUT=1` environmental variable is set.
 	///
-	/// Note: the names should be unique across all benchmarks, as they serve
-	/// as the key used when pulling "history". If you have two totally
-	/// different benchmarks named the same thing, the run-to-run change
-	/// reporting won't make any sense. ;)
+	/// This method can be used to set the target explicitly, overriding the
+	/// environment.
+	///
+	/// When printing to `STDOUT`, ANSI formatting is automatically disabled if
+	/// the stream is being piped or redirected.
+	///
+	/// Progress and other incidental messages are always printed to `STDERR`.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
-	/// use brunch::Bench;
-	/// use dactyl::{NiceU8, NiceU16};
+	/// use brunch::{Benches, Bench, OutputTarget};
 	///
-	/// brunch::benches!(
-    ///     Bench::new("dactyl::NiceU8::from(0)")
-    ///         .run(|| NiceU8::from(0_u8)),
-    /// );
-    /// ```
+	/// let mut benches = Benches::default()
+	///     .with_output(OutputTarget::Stdout);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn with_output(mut self, target: OutputTarget) -> Self {
+		self.output = Some(target);
+		self
+	}
+
+	#[must_use]
+	/// # With Color.
 	///
-	/// ## Panics
+	/// By default, the results summary is printed with ANSI formatting,
+	/// unless it is headed for a piped or redirected `STDOUT` (see
+	/// [`Benches::with_output`]).
 	///
-	/// This method will panic if the name is empty.
-	pub fn new<S>(name: S) -> Self
-	where S: AsRef<str> {
-		let name = name.as_ref().trim();
-		assert!(! name.is_empty(), "Name is required.");
-
-		// Compact and normalize whitespace, but otherwise pass whatever the
-		// name is on through.
-		let mut ws = false;
-		let name: String = name.chars()
-			.filter_map(|c|
-				if c.is_whitespace() {
-					if ws { None }
-					else {
-						ws = true;
-						Some(' ')
-					}
-				}
-				else {
-					ws = false;
-					Some(c)
-				}
-			)
-			.collect();
-
-		assert!(name.len() <= 65535, "Names cannot be longer than 65,535.");
-
-		Self {
-			name,
-			samples: DEFAULT_SAMPLES,
-			timeout: DEFAULT_TIMEOUT,
-			stats: None,
-		}
+	/// This method can be used to enable or disable the formatting
+	/// explicitly, overriding the default.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .with_color(false);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn with_color(mut self, color: bool) -> Self {
+		self.color = Some(color);
+		self
 	}
 
 	#[must_use]
-	/// # Spacer.
+	/// # With Verbosity.
 	///
-	/// This will render as a linebreak when printing results, useful if you
-	/// want to add visual separation between two different benchmarks.
+	/// By default, the results summary includes only the essentials, unless
+	/// the `BRUNCH_VERBOSE=1` environmental variable is set.
+	///
+	/// This method can be used to enable or disable verbose mode explicitly,
+	/// overriding the environment.
+	///
+	/// In verbose mode, the summary includes additional columns, like the
+	/// 95% confidence interval for each mean, the median, 95th percentile,
+	/// and range of the samples, and the total wall time spent running each
+	/// benchmark (including any seed generation).
+	///
+	/// The Change column is also stricter in verbose mode, only reporting
+	/// differences when the confidence intervals of the current and previous
+	/// runs do not overlap.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
-	/// use brunch::Bench;
-	/// use dactyl::{NiceU8, NiceU16};
+	/// use brunch::{Benches, Bench};
 	///
-	/// brunch::benches!(
-    ///     Bench::new("dactyl::NiceU8::from(0)")
-    ///         .run(|| NiceU8::from(0_u8)),
-    ///
-    ///     Bench::spacer(),
-    ///
-    ///     Bench::new("dactyl::NiceU16::from(0)")
-    ///         .run(|| NiceU16::from(0_u16)),
-    /// );
+	/// let mut benches = Benches::default()
+	///     .with_verbose(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
 	/// ```
-	pub const fn spacer() -> Self {
-		Self {
-			name: String::new(),
-			samples: DEFAULT_SAMPLES,
-			timeout: DEFAULT_TIMEOUT,
-			stats: None,
-		}
+	pub const fn with_verbose(mut self, verbose: bool) -> Self {
+		self.verbose = Some(verbose);
+		self
 	}
 
-	/// # Is Spacer?
-	fn is_spacer(&self) -> bool { self.name.is_empty() }
-
 	#[must_use]
-	/// # With Time Limit.
+	/// # With Significance Threshold.
 	///
-	/// By default, benches stop after reaching 2500 samples or 10 seconds,
-	/// whichever comes first.
+	/// Changes from previous runs are only reported if Welch's t-test finds
+	/// them statistically significant, i.e. the p-value is below this
+	/// threshold. The default is `0.05`.
 	///
-	/// This method can be used to override the time limit portion of that
-	/// equation.
+	/// Lower values reduce false alarms at the risk of missing real (but
+	/// small) changes; higher values do the opposite. Either way, changes of
+	/// less than half a percent are never reported.
 	///
-	/// Note: the minimum cutoff time is half a second.
+	/// Values outside `0.0..=1.0` (exclusive of zero) are ignored.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
-	/// use brunch::Bench;
-	/// use dactyl::NiceU8;
-	/// use std::time::Duration;
+	/// use brunch::{Benches, Bench};
 	///
-	/// brunch::benches!(
-    ///     Bench::new("dactyl::NiceU8::from(0)")
-    ///         .with_timeout(Duration::from_secs(1))
-    ///         .run(|| NiceU8::from(0_u8))
-    /// );
+	/// let mut benches = Benches::default()
+	///     .with_significance(0.01);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
 	/// ```
-	pub const fn with_timeout(mut self, timeout: Duration) -> Self {
-		if timeout.as_millis() < 500 {
-			self.timeout = Duration::from_millis(500);
-		}
-		else { self.timeout = timeout; }
+	pub fn with_significance(mut self, alpha: f64) -> Self {
+		if 0.0 < alpha && alpha <= 1.0 { self.significance = alpha; }
 		self
 	}
 
-	#[expect(unsafe_code, reason = "Ten is non-zero.")]
 	#[must_use]
-	/// # With Sample Limit.
-	///
-	/// By default, benches stop after reaching 2500 samples or 10 seconds,
-	/// whichever comes first.
+	/// # Change Threshold.
 	///
-	/// This method can be used to override the sample limit portion of that
-	/// equation.
+	/// On noisy machines, even statistically significant changes may not be
+	/// meaningful. This method can be used to set the minimum relative change
+	/// worth reporting, e.g. `0.03` for 3%, on top of the significance test.
+	/// Smaller changes are displayed as `---`.
 	///
-	/// Generally the default is a good sample size, but if your bench takes a
-	/// while to complete, you might want to use this method to shorten it up.
+	/// This can also be set using the `BRUNCH_THRESHOLD` environmental
+	/// variable. The default, zero, reports any significant change of at
+	/// least half a percent.
 	///
-	/// Note: the minimum number of samples is 100, but you should aim for at
-	/// least 150-200, because that minimum is applied _after_ outliers have
-	/// been removed from the set.
+	/// Negative and non-finite values are ignored.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
-	/// use brunch::Bench;
-	/// use dactyl::NiceU8;
+	/// use brunch::{Benches, Bench};
 	///
-	/// brunch::benches!(
-    ///     Bench::new("dactyl::NiceU8::from(0)")
-    ///         .with_samples(50_000)
-    ///         .run(|| NiceU8::from(0_u8))
-    /// );
+	/// let mut benches = Benches::default()
+	///     .change_threshold(0.03);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
 	/// ```
-	pub const fn with_samples(mut self, samples: u32) -> Self {
-		if samples < MIN_SAMPLES {
-			// Safety: ten is non-zero.
-			self.samples = unsafe { NonZeroU32::new_unchecked(MIN_SAMPLES) };
-		}
-		else {
-			// Safety: anything 10+ is also non-zero.
-			self.samples = unsafe { NonZeroU32::new_unchecked(samples) };
-		}
+	pub fn change_threshold(mut self, threshold: f64) -> Self {
+		if threshold.is_finite() && 0.0 <= threshold { self.threshold = Some(threshold); }
 		self
 	}
-}
 
-impl Bench {
 	#[must_use]
-	/// # Run Benchmark!
+	/// # Compare Against Best.
 	///
-	/// Use this method to execute a benchmark for a callback that does not
-	/// require any external arguments.
+	/// By default, changes are measured against the recent runs, which makes
+	/// it easy for a series of small regressions to creep in unnoticed. When
+	/// enabled, changes are instead measured against the fastest run ever
+	/// recorded for each benchmark.
+	///
+	/// If the change from the recent runs differs materially, both are shown,
+	/// e.g. `+0.30% (recent) / +9.80% (best)`.
+	///
+	/// This can also be enabled by setting the `BRUNCH_COMPARE_BEST=1`
+	/// environmental variable.
+	///
+	/// Note: noisy runs — those with a lot of outliers or a wide confidence
+	/// interval — are never crowned the best.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
-	/// use brunch::Bench;
-	/// use dactyl::NiceU8;
+	/// use brunch::{Benches, Bench};
 	///
-	/// brunch::benches!(
-    ///     Bench::new("dactyl::NiceU8::from(0)")
-    ///         .run(|| NiceU8::from(0_u8))
-    /// );
+	/// let mut benches = Benches::default()
+	///     .compare_best(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
 	/// ```
-	pub fn run<F, O>(mut self, mut cb: F) -> Self
-	where F: FnMut() -> O {
-		if self.is_spacer() { return self; }
-
-		let mut times: Vec<Duration> = Vec::with_capacity(usize::saturating_from(self.samples.get()));
-		let now = Instant::now();
-
-		for _ in 0..self.samples.get() {
-			let now2 = Instant::now();
-			let _res = black_box(cb());
-			times.push(now2.elapsed());
-
-			if self.timeout <= now.elapsed() { break; }
-		}
-
-		self.stats.replace(Stats::try_from(times));
-
+	pub const fn compare_best(mut self, best: bool) -> Self {
+		self.compare_best = Some(best);
 		self
 	}
 
 	#[must_use]
-	/// # Run Seeded Benchmark!
+	/// # libtest Format.
 	///
-	/// Use this method to execute a benchmark for a callback seeded with the
-	/// provided value.
+	/// Many tools — `cargo-benchcmp`, CI log parsers, etc. — know how to
+	/// scrape the classic libtest benchmark output. When enabled, a line in
+	/// that format is printed to `STDOUT` for each benchmark, in addition to
+	/// the usual summary, e.g.
 	///
-	/// For seeds that don't implement `Clone`, use [`Bench::run_seeded_with`]
-	/// instead.
+	/// ```text
+	/// test foo::bar() ... bench:       1,234 ns/iter (+/- 56)
+	/// test foo::baz() ... FAILED (Panicked: Oops.)
+	/// ```
+	///
+	/// The numbers are the mean and standard deviation, in whole
+	/// nanoseconds.
+	///
+	/// This can also be enabled by setting the `BRUNCH_BENCHFMT=libtest`
+	/// environmental variable.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
-	/// use brunch::Bench;
-	/// use dactyl::NiceU8;
+	/// use brunch::{Benches, Bench};
 	///
-	/// brunch::benches!(
-    ///     Bench::new("dactyl::NiceU8::from(13)")
-    ///         .run_seeded(13_u8, |v| NiceU8::from(v))
-    /// );
+	/// let mut benches = Benches::default()
+	///     .libtest_format(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
 	/// ```
-	pub fn run_seeded<F, I, O>(mut self, seed: I, mut cb: F) -> Self
-	where F: FnMut(I) -> O, I: Clone {
-		if self.is_spacer() { return self; }
-
-		let mut times: Vec<Duration> = Vec::with_capacity(usize::saturating_from(self.samples.get()));
-		let now = Instant::now();
-
-		for _ in 0..self.samples.get() {
-			let seed2 = seed.clone();
-			let now2 = Instant::now();
-			let _res = black_box(cb(seed2));
-			times.push(now2.elapsed());
-
-			if self.timeout <= now.elapsed() { break; }
-		}
-
-		self.stats.replace(Stats::try_from(times));
-
+	pub const fn libtest_format(mut self, libtest: bool) -> Self {
+		self.libtest = Some(libtest);
 		self
 	}
 
 	#[must_use]
-	/// # Run Callback-Seeded Benchmark!
+	/// # Quiet.
 	///
-	/// Use this method to execute a benchmark for a callback seeded with the
-	/// result of the provided method.
+	/// Suppress the decorative output — the "Starting" banner, progress
+	/// lines, environment notices, footer notes, etc. — leaving only the
+	/// results table. If the libtest format is enabled too, the table is
+	/// suppressed as well, leaving only the machine-readable lines.
 	///
-	/// For seeds that implement `Clone`, use [`Bench::run_seeded`] instead.
+	/// Warnings and errors are still printed, though the latter lose their
+	/// colors.
+	///
+	/// This can also be enabled by setting the `BRUNCH_QUIET=1`
+	/// environmental variable.
 	///
 	/// ## Examples
 	///
 	/// ```no_run
-	/// use brunch::Bench;
-	/// use dactyl::NiceU8;
-	///
-	/// fn make_num() -> u8 { 13_u8 }
+	/// use brunch::{Benches, Bench};
 	///
-	/// brunch::benches!(
-    ///     Bench::new("dactyl::NiceU8::from(13)")
-    ///         .run_seeded_with(make_num, |v| NiceU8::from(v))
-    /// );
+	/// let mut benches = Benches::default()
+	///     .quiet(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
 	/// ```
-	pub fn run_seeded_with<F1, F2, I, O>(mut self, mut seed: F1, mut cb: F2) -> Self
-	where F1: FnMut() -> I, F2: FnMut(I) -> O {
-		if self.is_spacer() { return self; }
-
-		let mut times: Vec<Duration> = Vec::with_capacity(usize::saturating_from(self.samples.get()));
-		let now = Instant::now();
-
-		for _ in 0..self.samples.get() {
-			let seed2 = seed();
-			let now2 = Instant::now();
-			let _res = black_box(cb(seed2));
-			times.push(now2.elapsed());
-
-			if self.timeout <= now.elapsed() { break; }
-		}
-
-		self.stats.replace(Stats::try_from(times));
-
+	pub const fn quiet(mut self, quiet: bool) -> Self {
+		self.quiet = Some(quiet);
 		self
 	}
-}
-
-
-
-#[derive(Debug, Clone)]
-/// # Benchmarking Results.
-///
-/// This table holds the results of all the benchmarks so they can be printed
-/// consistently.
-struct Table(Vec<TableRow>);
-
-impl Default for Table {
-	fn default() -> Self {
-		Self(vec![
-			TableRow::Normal(
-				"\x1b[1;95mMethod".to_owned(),
-				"Mean".to_owned(),
-				"Samples".to_owned(),
-				"Change\x1b[0m".to_owned(),
-			),
-			TableRow::Spacer,
-		])
-	}
-}
-
-impl fmt::Display for Table {
-	#[expect(clippy::many_single_char_names, reason = "Consistency is preferred.")]
-	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		// Maximum column widths.
-		let (w1, w2, w3, mut w4) = self.lens();
-		let changes = self.show_changes();
-		let width =
-			if changes { w1 + w2 + w3 + w4 + 12 }
-			else {
-				w4 = 0;
-				w1 + w2 + w3 + 8
-			};
-
-		// Pre-generate padding as we'll be slicing lots of things to fit.
-		let pad_len = w1.max(w2).max(w3).max(w4);
-		let mut pad = String::with_capacity(pad_len);
-		for _ in 0..pad_len { pad.push(' '); }
-
-		// Pre-generate the spacer too.
-		let mut spacer = String::with_capacity(10 + width);
-		spacer.push_str("\x1b[35m");
-		for _ in 0..width { spacer.push('-'); }
-		spacer.push_str("\x1b[0m\n");
-
-		// Print each line!
-		for v in &self.0 {
-			let (c1, c2, c3, c4) = v.lens();
-			match v {
-				TableRow::Normal(a, b, c, d) if changes => writeln!(
-					f, "{}{}    {}{}    {}{}    {}{}",
-					a, &pad[..w1 - c1],
-					&pad[..w2 - c2], b,
-					&pad[..w3 - c3], c,
-					&pad[..w4 - c4], d,
-				)?,
-				TableRow::Normal(a, b, c, _) => writeln!(
-					f, "{}{}    {}{}    {}{}",
-					a, &pad[..w1 - c1],
-					&pad[..w2 - c2], b,
-					&pad[..w3 - c3], c,
-				)?,
-				TableRow::Error(a, b) => writeln!(
-					f, "{}{}    \x1b[1;38;5;208m{}\x1b[0m",
-					a, &pad[..w1 - c1], b,
-				)?,
-				TableRow::Spacer => f.write_str(&spacer)?,
-			}
-		}
 
-		Ok(())
+	#[must_use]
+	/// # Isolated.
+	///
+	/// Allocator state, CPU frequency ramp-up, cache pollution, etc., can
+	/// carry over from one benchmark to the next, skewing the results of
+	/// later benchmarks in larger suites.
+	///
+	/// When enabled, the current binary is re-executed once for each
+	/// benchmark, giving each a fresh process of its own. The results are
+	/// collected and summarized (and saved to history) by the parent as
+	/// usual.
+	///
+	/// This can also be enabled by setting the `BRUNCH_ISOLATE=1`
+	/// environmental variable.
+	///
+	/// Note: because each child process re-runs `main`, any setup performed
+	/// there — as well as the construction of _every_ benchmark — is repeated
+	/// for each. Output from the children is suppressed.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .isolated(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn isolated(mut self, isolated: bool) -> Self {
+		self.isolated = Some(isolated);
+		self
 	}
-}
 
-impl Table {
-	/// # Add Row.
-	fn push(&mut self, src: &Bench, names: &[Vec<char>], history: &History) {
-		if src.is_spacer() { self.0.push(TableRow::Spacer); }
-		else {
-			let name = format_name(src.name.chars().collect(), names);
-			match src.stats.unwrap_or(Err(BrunchError::NoRun)) {
-				Ok(s) => {
-					let time = s.nice_mean();
-					let diff = history.get(&src.name)
-						.and_then(|h| s.is_deviant(h))
-						.unwrap_or_else(|| NO_CHANGE.to_owned());
-					let (valid, total) = s.samples();
-					let samples = format!(
-						"\x1b[2m{}\x1b[0;35m/\x1b[0;2m{}\x1b[0m",
-						NiceU32::from(valid),
-						NiceU32::from(total),
-					);
-
-					self.0.push(TableRow::Normal(name, time, samples, diff));
-				},
-				Err(e) => {
-					self.0.push(TableRow::Error(name, e));
-				}
-			}
-		}
+	#[must_use]
+	/// # Pin to CPU Core.
+	///
+	/// On machines with mixed core types — or just busy schedulers — the
+	/// benchmarking thread may be migrated from one core to another mid-run,
+	/// producing wildly inconsistent samples.
+	///
+	/// This method can be used to pin the thread to a specific (zero-indexed)
+	/// core for the duration of the run. It can also be set using the
+	/// `BRUNCH_PIN=<n>` environmental variable.
+	///
+	/// Pinning is currently supported on Linux and Windows. If it fails — or
+	/// isn't supported — a warning is printed and the benchmarks are run
+	/// unpinned.
+	///
+	/// When combined with [`Benches::isolated`], the child processes are
+	/// pinned instead of the parent, so that the parent's bookkeeping doesn't
+	/// compete for the same core.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .pin_to_core(2);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn pin_to_core(mut self, core: usize) -> Self {
+		self.pin = Some(core);
+		self
 	}
 
-	/// # Has Changes?
+	#[must_use]
+	/// # High Priority.
 	///
-	/// Returns true if any of the Change columns have a value.
-	fn show_changes(&self) -> bool {
-		self.0.iter().skip(2).any(|v|
-			if let TableRow::Normal(_, _, _, c) = v { c != NO_CHANGE }
-			else { false }
-		)
+	/// A shortcut for [`Benches::with_priority`] with [`Priority::High`] (or
+	/// [`Priority::Normal`] if `false`).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .high_priority(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn high_priority(self, high: bool) -> Self {
+		self.with_priority(if high { Priority::High } else { Priority::Normal })
 	}
 
-	/// # Widths.
-	fn lens(&self) -> (usize, usize, usize, usize) {
-		self.0.iter()
-			.fold((0, 0, 0, 0), |acc, v| {
-				let v = v.lens();
-				(
-					acc.0.max(v.0),
-					acc.1.max(v.1),
-					acc.2.max(v.2),
-					acc.3.max(v.3),
-				)
-			})
+	#[must_use]
+	/// # With Thread Priority.
+	///
+	/// Even on an otherwise idle machine, the benchmarking thread competes
+	/// with the desktop, the terminal, and assorted daemons for CPU time, and
+	/// every preemption shows up as noise.
+	///
+	/// This method can be used to raise the thread's scheduling priority for
+	/// the duration of the sampling, restoring the original afterward (even
+	/// if a benchmark panics). It can also be set using the
+	/// `BRUNCH_PRIORITY=high|realtime` environmental variable.
+	///
+	/// On Linux, [`Priority::High`] sets the thread's niceness to `-20`, and
+	/// [`Priority::Realtime`] switches it to `SCHED_FIFO`. Both require
+	/// elevated privileges: running as root, the `CAP_SYS_NICE` capability
+	/// (e.g. `sudo setcap cap_sys_nice+ep path/to/bench`), or suitable
+	/// `nice`/`rtprio` limits in `/etc/security/limits.conf`. On Windows,
+	/// they map to `THREAD_PRIORITY_HIGHEST` and
+	/// `THREAD_PRIORITY_TIME_CRITICAL` respectively.
+	///
+	/// If realtime scheduling is refused, high priority is tried instead; if
+	/// that's refused too — or isn't supported — a warning is printed and the
+	/// benchmarks are run at normal priority.
+	///
+	/// Note: a realtime thread that never yields can starve the rest of the
+	/// system, so keep an eye on long-running benchmarks. This pairs well
+	/// with [`Benches::pin_to_core`], but each works without the other.
+	/// When combined with [`Benches::isolated`], the child processes are
+	/// raised instead of the parent.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench, Priority};
+	///
+	/// let mut benches = Benches::default()
+	///     .with_priority(Priority::Realtime);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn with_priority(mut self, priority: Priority) -> Self {
+		self.priority = Some(priority);
+		self
 	}
-}
-
 
-
-#[derive(Debug, Clone)]
+	#[must_use]
+	/// # Allow Debug Builds.
+	///
+	/// Benchmarking an unoptimized build is almost always a mistake, and one
+	/// that tends to produce alarming — and entirely meaningless — results.
+	///
+	/// By default, when debug assertions are enabled, a big warning is
+	/// printed before the summary and the history is left alone: nothing is
+	/// loaded, nothing is saved, so a debug run can never poison the
+	/// baselines.
+	///
+	/// If you genuinely want to benchmark a debug build, pass `true` to
+	/// silence the warning and treat the run like any other.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .allow_debug(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn allow_debug(mut self, allow: bool) -> Self {
+		self.debug = cfg!(debug_assertions) && ! allow;
+		self
+	}
+
+	#[must_use]
+	/// # With Total Time Budget.
+	///
+	/// Limit the total amount of time spent running the benchmarks.
+	///
+	/// The budget is divided evenly among the benchmarks as they're run, so
+	/// any time left unused by quick benchmarks is redistributed to the ones
+	/// remaining. Each benchmark's own timeout — see [`Bench::with_timeout`]
+	/// — still applies as an upper bound.
+	///
+	/// Note: if the budget is too tight, benchmarks may fail to collect the
+	/// minimum number of samples required for analysis.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	/// use std::time::Duration;
+	///
+	/// let mut benches = Benches::default()
+	///     .with_total_budget(Duration::from_secs(60));
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn with_total_budget(mut self, budget: Duration) -> Self {
+		self.budget = Some(budget);
+		self
+	}
+
+	#[must_use]
+	/// # Retries.
+	///
+	/// On shared or noisy machines, a benchmark will occasionally come back
+	/// [`BrunchError::TooWild`] simply because something else spiked during
+	/// its window, or [`BrunchError::TooSmall`] because too many of the
+	/// samples it did take were pruned away. This method allows such
+	/// benchmarks to be re-sampled up to `retries` additional times, keeping
+	/// the first attempt that produces valid stats. The default is zero.
+	///
+	/// Retried benchmarks are noted in the results summary, e.g.
+	/// `(retried ×2)`, and only the successful attempt is recorded to
+	/// history. If every attempt fails, the error from the first is reported.
+	///
+	/// Retries count against the suite budget, if any — see
+	/// [`Benches::with_total_budget`] — and are abandoned once it has been
+	/// spent.
+	///
+	/// Note: when the suite is [repeated](Benches::repeat), a retry only
+	/// re-collects a single round's worth of samples.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .retries(2);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn retries(mut self, retries: u8) -> Self {
+		self.retries = retries;
+		self
+	}
+
+	#[must_use]
+	/// # With Timer.
+	///
+	/// Samples are timed using [`Instant`](std::time::Instant) by default.
+	/// This method can be used to swap in a different time source — see
+	/// [`Timer`] — for the samples, timeouts, and suite budget alike.
+	///
+	/// Note: the timer is read at the start and end of every sample, so
+	/// should be as cheap as possible; any overhead is counted against the
+	/// benchmarks.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench, Timer};
+	///
+	/// /// # A (Silly) Millisecond Timer.
+	/// struct MilliTimer(std::time::Instant);
+	///
+	/// impl Timer for MilliTimer {
+	///     fn now(&self) -> u64 {
+	///         u64::try_from(self.0.elapsed().as_millis()).unwrap_or(u64::MAX) * 1_000_000
+	///     }
+	/// }
+	///
+	/// let mut benches = Benches::default()
+	///     .with_timer(MilliTimer(std::time::Instant::now()));
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn with_timer<T: Timer + 'static>(mut self, timer: T) -> Self {
+		self.timer = Some(Rc::new(timer));
+		self
+	}
+
+	#[must_use]
+	/// # Prune History.
+	///
+	/// When enabled, any history entries that don't correspond to a
+	/// benchmark in the current suite — e.g. benchmarks that have since been
+	/// renamed or removed — are dropped when saving. This can also be enabled
+	/// by setting the `BRUNCH_PRUNE=1` environmental variable.
+	///
+	/// To keep partial runs from wiping out everyone else's baselines, pruning
+	/// only happens if _every_ benchmark completed successfully.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .prune_history(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn prune_history(mut self, prune: bool) -> Self {
+		self.prune = Some(prune);
+		self
+	}
+
+	#[must_use]
+	/// # With History Path.
+	///
+	/// Load and save the run-to-run history from/to a specific file. Missing
+	/// parent directories are created automatically.
+	///
+	/// This takes precedence over the `BRUNCH_HISTORY` environmental variable,
+	/// if both are set.
+	///
+	/// Note: if the path points to an existing directory, or the parent
+	/// directory cannot be created, history will be disabled.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .with_history_path("target/brunch/encode.hist");
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn with_history_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+		self.history = Some(path.into());
+		self
+	}
+
+	#[must_use]
+	/// # With Run Log.
+	///
+	/// Append one record per successful benchmark per run to this file,
+	/// building up a long-term log that can be plotted or otherwise analyzed
+	/// without any extra infrastructure. Unlike the history, nothing is ever
+	/// dropped or overwritten.
+	///
+	/// Files ending in `.csv` get CSV, with a header line; anything else
+	/// gets JSON Lines. Either way, each record has the following fields:
+	///
+	/// | Field | Description |
+	/// | ----- | ----------- |
+	/// | `time` | The Unix timestamp (in seconds) of the run. |
+	/// | `name` | The benchmark name. |
+	/// | `mean_ns` | The mean, in nanoseconds. |
+	/// | `deviation_ns` | The standard deviation, in nanoseconds. |
+	/// | `valid` | The number of samples, sans outliers. |
+	/// | `total` | The total number of samples. |
+	/// | `tag` | The value of `BRUNCH_TAG`, if any, e.g. a commit hash, or `null` (empty for CSV). |
+	///
+	/// Each record is appended as a single line with a single write, so
+	/// concurrent runs won't garble one another. Failures are reported as
+	/// warnings, but never fail the run. As with the history, nothing is
+	/// logged for unoptimized builds unless [`Benches::allow_debug`] is set.
+	///
+	/// This can also be enabled by setting the `BRUNCH_LOG=/path/to/file`
+	/// environmental variable.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .with_run_log("target/brunch.jsonl");
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn with_run_log<P: Into<PathBuf>>(mut self, path: P) -> Self {
+		self.run_log = Some(path.into());
+		self
+	}
+
+	#[must_use]
+	/// # Plot Directory.
+	///
+	/// Write a small, self-contained SVG plot of each benchmark's sample
+	/// distribution — after outlier pruning — to this directory: a histogram,
+	/// with solid and dotted lines marking the mean and ±σ, plus a dashed
+	/// line at the previous mean, if there's history, so regressions are
+	/// visually obvious.
+	///
+	/// The files are named after a sanitized version of the bench name, e.g.
+	/// `foo__bar_10_.svg` for `foo::bar(10)`, and are overwritten on each
+	/// run. Failures are reported as warnings, but never fail the run.
+	///
+	/// This can also be enabled by setting the `BRUNCH_PLOTS=/path/to/dir`
+	/// environmental variable.
+	///
+	/// Note: streaming benches don't keep their samples around, and isolated
+	/// ones keep them in another process, so have nothing to plot. See
+	/// [`Bench::streaming`] and [`Benches::isolated`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .plot_dir("target/brunch-plots");
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn plot_dir<P: AsRef<Path>>(mut self, dir: P) -> Self {
+		self.plots = Some(dir.as_ref().to_path_buf());
+		self
+	}
+
+	#[must_use]
+	/// # Outlier Thresholds.
+	///
+	/// Benchmarks that lose a large fraction of their samples to outlier
+	/// pruning are probably not to be trusted, even if the remainder passed
+	/// muster. By default, the _Samples_ cell is colored yellow when more than
+	/// 10% were pruned, and red when more than 25% were, and the offenders are
+	/// listed beneath the table.
+	///
+	/// Use this method to change those thresholds — `warn` and `alert`,
+	/// respectively — or [`Benches::without_outlier_warnings`] to disable
+	/// the highlighting altogether.
+	///
+	/// Values are clamped to `0.0..=1.0`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .outlier_thresholds(0.05, 0.15);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn outlier_thresholds(mut self, warn: f64, alert: f64) -> Self {
+		let warn = if warn.is_nan() { DEFAULT_PRUNING.0 } else { warn.clamp(0.0, 1.0) };
+		let alert = if alert.is_nan() { DEFAULT_PRUNING.1 } else { alert.clamp(warn, 1.0) };
+		self.pruning = Some((warn, alert));
+		self
+	}
+
+	#[must_use]
+	/// # Without Outlier Warnings.
+	///
+	/// Disable the heavy-pruning highlights and footnote described in
+	/// [`Benches::outlier_thresholds`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .without_outlier_warnings();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn without_outlier_warnings(mut self) -> Self {
+		self.pruning = None;
+		self
+	}
+
+	#[must_use]
+	/// # Drift Threshold.
+	///
+	/// Benchmarks whose samples steadily speed up or slow down over the
+	/// course of the run — e.g. because the CPU started throttling partway
+	/// through — have a mean that is quietly wrong, and that neither outlier
+	/// pruning nor the deviation will catch. To guard against this, the
+	/// means of the first and second halves of the samples (in the order
+	/// collected) are compared, and if they differ by more than 5%, the
+	/// benchmark is flagged with a `⚠` and a footnote, and any reported
+	/// change has to clear a higher bar. (See [`Stats::drift`].)
+	///
+	/// Use this method to change that fraction, or
+	/// [`Benches::without_drift_warnings`] to disable the check altogether.
+	///
+	/// Values are clamped to `0.0..=1.0`.
+	///
+	/// Note: [streamed](Bench::streaming) benchmarks don't keep the sample
+	/// order, so can't be checked.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .drift_threshold(0.1);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn drift_threshold(mut self, threshold: f64) -> Self {
+		self.drift = Some(
+			if threshold.is_nan() { DEFAULT_DRIFT }
+			else { threshold.clamp(0.0, 1.0) }
+		);
+		self
+	}
+
+	#[must_use]
+	/// # Without Drift Warnings.
+	///
+	/// Disable the drift check described in [`Benches::drift_threshold`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .without_drift_warnings();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn without_drift_warnings(mut self) -> Self {
+		self.drift = None;
+		self
+	}
+
+	#[must_use]
+	/// # Slow Thresholds.
+	///
+	/// Color-code the _Mean_ column: means above `slow` are rendered in
+	/// yellow, and means above `very_slow` in red. (Means under a microsecond
+	/// get a touch of green too.) When printing without color, the slow ones
+	/// are flagged with a `!` or `!!` after the name instead.
+	///
+	/// This is purely cosmetic; unlike [`Bench::expect_under`], it has no
+	/// effect on the exit code or history.
+	///
+	/// If `very_slow` is less than `slow`, it is raised to match.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	/// use std::time::Duration;
+	///
+	/// let mut benches = Benches::default()
+	///     .slow_thresholds(Duration::from_micros(50), Duration::from_millis(1));
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn slow_thresholds(mut self, slow: Duration, very_slow: Duration) -> Self {
+		self.slow = Some((slow, very_slow.max(slow)));
+		self
+	}
+
+	#[must_use]
+	/// # Without History.
+	///
+	/// Disable run-to-run history entirely: nothing will be loaded or saved,
+	/// so there will be no baselines to compare against.
+	///
+	/// This has the same effect as the `NO_BRUNCH_HISTORY=1` environmental
+	/// variable, and takes precedence over [`Benches::with_history_path`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .without_history();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn without_history(mut self) -> Self {
+		self.no_history = true;
+		self
+	}
+
+	#[must_use]
+	/// # Read-Only History.
+	///
+	/// Load the history and compare against it as usual, but never save it,
+	/// so that every run is measured against the same baseline — handy when
+	/// iterating on a change. The summary footer notes that the baseline was
+	/// not updated.
+	///
+	/// This can also be enabled by setting the `BRUNCH_HISTORY_RO=1`
+	/// environmental variable.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .history_readonly(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn history_readonly(mut self, readonly: bool) -> Self {
+		self.history_ro = Some(readonly);
+		self
+	}
+
+	#[must_use]
+	/// # Named Baseline.
+	///
+	/// Compare against the named baseline, e.g. `main`, rather than the
+	/// default "last run" one. The named baseline itself is left as-is; the
+	/// run is saved to the default baseline as usual, unless
+	/// [`Benches::save_baseline`] says otherwise.
+	///
+	/// This can also be set with the `BRUNCH_BASELINE` environmental
+	/// variable.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .baseline("main");
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn baseline<S: AsRef<str>>(mut self, name: S) -> Self {
+		self.baseline = Some(name.as_ref().trim().to_owned());
+		self
+	}
+
+	#[must_use]
+	/// # Save Named Baseline.
+	///
+	/// Save the run to the named baseline, e.g. `main`, rather than the
+	/// default "last run" one. Any number of baselines can coexist in the
+	/// same history file, each with its own runs.
+	///
+	/// Unless [`Benches::baseline`] is also set, the run is compared against
+	/// the previous runs of the same baseline.
+	///
+	/// This can also be set with the `BRUNCH_SAVE_BASELINE` environmental
+	/// variable. (Read-only histories are never saved, baseline or no.)
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .save_baseline("main");
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn save_baseline<S: AsRef<str>>(mut self, name: S) -> Self {
+		self.save_baseline = Some(name.as_ref().trim().to_owned());
+		self
+	}
+
+	#[must_use]
+	/// # Incremental History.
+	///
+	/// By default, the history is saved after _each_ benchmark completes, so
+	/// that results aren't lost if a later benchmark crashes or the run is
+	/// aborted.
+	///
+	/// If the extra I/O is a problem — e.g. on a painfully slow filesystem —
+	/// this method can be used to save only once, at the end.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .with_incremental_history(false);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn with_incremental_history(mut self, incremental: bool) -> Self {
+		self.incremental = incremental;
+		self
+	}
+
+	#[must_use]
+	/// # Default Sample Limit.
+	///
+	/// Set the sample limit for every benchmark that doesn't specify its own
+	/// via [`Bench::with_samples`]. (Explicit per-bench settings always win.)
+	///
+	/// The same minimum — and meaning of zero — applies here as for
+	/// [`Bench::with_samples`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .default_samples(50_000);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn default_samples(mut self, samples: u32) -> Self {
+		self.samples = Some(clamp_samples(samples));
+		self
+	}
+
+	#[must_use]
+	/// # Default Time Limit.
+	///
+	/// Set the time limit for every benchmark that doesn't specify its own
+	/// via [`Bench::with_timeout`]. (Explicit per-bench settings always win.)
+	///
+	/// The same minimum applies here as for [`Bench::with_timeout`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	/// use std::time::Duration;
+	///
+	/// let mut benches = Benches::default()
+	///     .default_timeout(Duration::from_secs(30));
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn default_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(clamp_timeout(timeout));
+		self
+	}
+
+	#[must_use]
+	/// # With Display Unit.
+	///
+	/// By default, each time in the results table is scaled to whatever unit
+	/// suits it best, which can make it hard to compare neighbors that fall
+	/// on either side of a boundary, like `980.00 ns` and `1.02 µs`.
+	///
+	/// Use this method to force every mean, confidence interval, etc., into
+	/// the same unit instead, e.g. `1,020.00 ns`. Benchmarks can override
+	/// this with [`Bench::with_unit`].
+	///
+	/// The relative changes are unaffected.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench, TimeUnit};
+	///
+	/// let mut benches = Benches::default()
+	///     .with_unit(TimeUnit::Nanos);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn with_unit(mut self, unit: TimeUnit) -> Self {
+		self.unit = unit;
+		self
+	}
+
+	#[must_use]
+	/// # On Duplicate.
+	///
+	/// By default, a benchmark pushed with the same name as an earlier one is
+	/// reported as an error (and never run). Use this method to have it
+	/// replace the earlier one or get renamed instead; see [`DupePolicy`] for
+	/// details.
+	///
+	/// The policy is applied as benchmarks are pushed, so this should be set
+	/// beforehand.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench, DupePolicy};
+	///
+	/// let mut benches = Benches::default()
+	///     .on_duplicate(DupePolicy::Rename);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	///
+	/// // This becomes "String::len (2)".
+	/// benches.push(Bench::new("String::len").run(|| "Hello".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn on_duplicate(mut self, policy: DupePolicy) -> Self {
+		self.dupes = policy;
+		self
+	}
+
+	#[must_use]
+	/// # Name Style.
+	///
+	/// By default, the summary table dims the portion of each benchmark name
+	/// it has in common with the others, and highlights the rest. If that
+	/// guesswork doesn't suit your naming scheme, use this method to pick a
+	/// different approach; see [`NameStyle`] for details.
+	///
+	/// This only affects the colors; plain-text output is the same either
+	/// way.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench, NameStyle};
+	///
+	/// let mut benches = Benches::default()
+	///     .name_style(NameStyle::SplitAt('('));
+	/// benches.push(Bench::new("String::len()").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn name_style(mut self, style: NameStyle) -> Self {
+		self.names = style;
+		self
+	}
+
+	#[must_use]
+	/// # Show Operations Per Second.
+	///
+	/// Add an _Ops/s_ column to the results table with the reciprocal of each
+	/// mean, e.g. `14.20 M ops/s` instead of (well, in addition to)
+	/// `70.42 ns`. Benchmarks can opt in or out individually with
+	/// [`Bench::show_ops`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .show_ops(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn show_ops(mut self, show: bool) -> Self {
+		self.ops = Some(show);
+		self
+	}
+
+	#[must_use]
+	/// # With Group Summaries.
+	///
+	/// Add a summary row to the end of each group of benchmarks — groups
+	/// being separated by [`Bench::spacer`]s — with the geometric mean of its
+	/// members' means, and, if every member has history, the geometric mean
+	/// of their changes, making it easy to track the overall performance of
+	/// an entire API surface from one release to the next.
+	///
+	/// Groups with fewer than two successful benchmarks are left alone.
+	///
+	/// Summary rows are for display only; they are not saved to history, and
+	/// are not included in [`Benches::results`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .with_geomean(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.push(Bench::new("str::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn with_geomean(mut self, geomean: bool) -> Self {
+		self.geomean = Some(geomean);
+		self
+	}
+
+	#[must_use]
+	/// # Show Ranking.
+	///
+	/// By default, only the main results summary is printed, unless the
+	/// `BRUNCH_RANK=1` environmental variable is set.
+	///
+	/// This method can be used to enable or disable the ranking table
+	/// explicitly, overriding the environment.
+	///
+	/// When enabled, a second, compact table follows the summary, ranking the
+	/// successful benchmarks from fastest to slowest, with the mean of each
+	/// relative to the fastest, e.g. `1.00x`, `2.30x`, `17.80x`.
+	///
+	/// Benchmarks are only ranked against the other members of their group
+	/// — groups being separated by [`Bench::spacer`]s — as comparing a
+	/// parser against a hasher is meaningless. Groups with fewer than two
+	/// successful benchmarks are left out.
+	///
+	/// Benchmarks whose means are within noise of one another — i.e. their
+	/// ±2σ ranges overlap — share a rank, marked with an `=`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .show_ranking(true);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.push(Bench::new("str::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn show_ranking(mut self, ranking: bool) -> Self {
+		self.ranking = Some(ranking);
+		self
+	}
+
+	#[must_use]
+	/// # Repeat.
+	///
+	/// Run the entire suite `runs` times — every benchmark once, then every
+	/// benchmark again, etc., to spread any thermal or background drift
+	/// evenly — pooling the samples from each round into a single result.
+	/// The sample and time limits apply to each round separately.
+	///
+	/// This can also be set with the `BRUNCH_RUNS` environmental variable.
+	/// (Max: 100.)
+	///
+	/// In verbose mode, a _Spread_ column is added showing how much the
+	/// per-round means differed, a rough measure of how stable the machine
+	/// was.
+	///
+	/// Note: repeats are not supported in [isolated](Benches::isolated) mode.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .repeat(3);
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn repeat(mut self, runs: u32) -> Self {
+		self.runs = Some(runs);
+		self
+	}
+
+	#[must_use]
+	/// # Interleave Samples.
+	///
+	/// Rather than running each benchmark start-to-finish before moving onto
+	/// the next, collect the samples in slices of a hundred or so, rotating
+	/// through the suite until every benchmark has reached its sample or
+	/// time limit. Any thermal throttling or background noise that comes and
+	/// goes during the run is thus spread evenly across the set instead of
+	/// landing on whichever benchmark happened to be running at the time,
+	/// making side-by-side comparisons more trustworthy.
+	///
+	/// The timeouts still apply to the time spent measuring each benchmark,
+	/// not the total wall time; the output and history are the same either
+	/// way.
+	///
+	/// This can also be enabled by setting the `BRUNCH_INTERLEAVE=1`
+	/// environmental variable.
+	///
+	/// Note: interleaving is not supported in [isolated](Benches::isolated)
+	/// mode. When combined with [`Benches::repeat`], each round is
+	/// interleaved separately.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .interleaved(true);
+	/// benches.extend([
+	///     Bench::new("String::len").run(|| "Hello World".len()),
+	///     Bench::new("str::len").run(|| "Hello World".len()),
+	/// ]);
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn interleaved(mut self, interleaved: bool) -> Self {
+		self.interleaved = Some(interleaved);
+		self
+	}
+
+	#[must_use]
+	/// # Parallel Runs.
+	///
+	/// Run up to `jobs` benchmarks at the same time, each in its own
+	/// [isolated](Benches::isolated) child process. Pass zero to use one job
+	/// per available CPU core; one (the default) runs everything
+	/// sequentially.
+	///
+	/// **This will skew the results.** Concurrent benchmarks compete for
+	/// cores, caches, memory bandwidth, and thermal headroom, so the timings
+	/// are only good for rough, relative comparisons — e.g. a quick smoke
+	/// test of a large suite — never for publication. The table header says
+	/// as much.
+	///
+	/// Because the numbers aren't comparable to sequential runs, the history
+	/// is consulted as usual but never updated. Results are still listed in
+	/// the order the benchmarks were declared.
+	///
+	/// This can also be set with the `BRUNCH_PARALLEL` environmental
+	/// variable.
+	///
+	/// Why processes rather than threads? Benchmark callbacks needn't be
+	/// `Send` — they're free to borrow locals, share [`Context`](crate::Context)s,
+	/// and so on — so they can't be handed off to other threads. Instead,
+	/// each job re-launches the bench binary to run just the one benchmark,
+	/// which reports back to the parent through a temporary file. That costs
+	/// a process launch per benchmark, but the startup isn't timed.
+	///
+	/// Note: parallel runs imply isolation, so [`Benches::repeat`] and
+	/// [`Benches::interleaved`] do not apply, and [pinning](Benches::pin_to_core) is
+	/// ignored.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .parallel(4);
+	/// benches.extend([
+	///     Bench::new("String::len").run(|| "Hello World".len()),
+	///     Bench::new("str::len").run(|| "Hello World".len()),
+	/// ]);
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn parallel(mut self, jobs: usize) -> Self {
+		self.parallel = Some(jobs);
+		self
+	}
+
+	#[must_use]
+	/// # Filter by Tags.
+	///
+	/// Only run the benchmarks carrying at least one of these
+	/// [tags](Bench::tag); the rest are left out of the run — and the
+	/// results — entirely, their history untouched.
+	///
+	/// This can also be set with the `BRUNCH_TAGS` environmental variable,
+	/// e.g. `BRUNCH_TAGS=slow,serde`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .filter_tags(&["slow"]);
+	/// benches.extend([
+	///     Bench::new("String::len").run(|| "Hello World".len()),
+	///     Bench::new("str::repeat").tag("slow").run(|| "Hello World".repeat(1000)),
+	/// ]);
+	/// benches.finish_or_print();
+	/// ```
+	pub fn filter_tags<S: AsRef<str>>(mut self, tags: &[S]) -> Self {
+		self.tags = Some(tags.iter().filter_map(|t| normalize_tag(t.as_ref())).collect());
+		self
+	}
+
+	#[must_use]
+	/// # Skip Tags.
+	///
+	/// The inverse of [`Benches::filter_tags`]: leave out any benchmark
+	/// carrying at least one of these [tags](Bench::tag). If both are set,
+	/// a benchmark has to pass both tests to be run.
+	///
+	/// This can also be set with the `BRUNCH_SKIP_TAGS` environmental
+	/// variable, e.g. `BRUNCH_SKIP_TAGS=slow`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .skip_tags(&["slow"]);
+	/// benches.extend([
+	///     Bench::new("String::len").run(|| "Hello World".len()),
+	///     Bench::new("str::repeat").tag("slow").run(|| "Hello World".repeat(1000)),
+	/// ]);
+	/// benches.finish_or_print();
+	/// ```
+	pub fn skip_tags<S: AsRef<str>>(mut self, tags: &[S]) -> Self {
+		self.skip_tags = Some(tags.iter().filter_map(|t| normalize_tag(t.as_ref())).collect());
+		self
+	}
+
+	#[must_use]
+	/// # Filter by Name.
+	///
+	/// Only run the benchmarks whose names contain at least one of these
+	/// strings; the rest are left out of the run — and the results —
+	/// entirely, their history untouched.
+	///
+	/// If unset, any positional command-line arguments are used instead,
+	/// just like `libtest`, e.g. `cargo bench -- nice_u8`. (Flags — and the
+	/// values of harness flags like `--format` — are ignored.)
+	///
+	/// A warning is printed if none of the benchmarks match.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default()
+	///     .filter_names(&["str::"]);
+	/// benches.extend([
+	///     Bench::new("String::len").run(|| "Hello World".len()),
+	///     Bench::new("str::len").run(|| "Hello World".len()),
+	/// ]);
+	/// benches.finish_or_print();
+	/// ```
+	pub fn filter_names<S: AsRef<str>>(mut self, names: &[S]) -> Self {
+		self.name_filters = Some(
+			names.iter()
+				.filter_map(|n| {
+					let n = n.as_ref().trim();
+					if n.is_empty() { None } else { Some(n.to_owned()) }
+				})
+				.collect()
+		);
+		self
+	}
+
+	#[must_use]
+	/// # List Only.
+	///
+	/// When `true`, [`Benches::finish`] prints the name of each benchmark —
+	/// followed by its [tags](Bench::tag), if any, e.g. `name [slow, serde]`
+	/// — and returns without running anything.
+	///
+	/// The tag and name filters still apply, so this is a handy way to check
+	/// what a given selection would run.
+	///
+	/// If unset, this is enabled by the `--list` command-line flag, just like
+	/// `libtest`, e.g. `cargo bench -- --list`.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default().list_only(true);
+	/// benches.extend([
+	///     Bench::new("String::len").run(|| "Hello World".len()),
+	///     Bench::new("str::repeat").tag("slow").run(|| "Hello World".repeat(1000)),
+	/// ]);
+	/// benches.finish_or_print();
+	/// ```
+	pub const fn list_only(mut self, list: bool) -> Self {
+		self.list = Some(list);
+		self
+	}
+
+	/// # Add Benchmark.
+	///
+	/// Use this method to push a benchmark to your `Benches` collection. Each
+	/// benchmark should be pushed before running [`Benches::finish`].
+	///
+	/// `Option<Bench>` is accepted too, making it easy to include benchmarks
+	/// conditionally; `None` is simply skipped.
+	///
+	/// Spacers are skipped if they would come first or immediately follow
+	/// another spacer, so groups can be left out without leaving gaps.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	///
+	/// // Only on Linux.
+	/// benches.push(cfg!(target_os = "linux").then(||
+	///     Bench::new("str::len").run(|| "Hello World".len())
+	/// ));
+	///
+	/// // Repeat push as needed.
+	/// benches.finish_or_print();
+	/// ```
+	pub fn push<B>(&mut self, b: B)
+	where B: Into<Option<Bench<'a>>> {
+		let Some(mut b) = b.into() else { return; };
+		if b.is_spacer() {
+			if self.set.last().is_none_or(Bench::is_spacer) { return; }
+		}
+		else if self.has_name(&b.name) {
+			match self.dupes {
+				DupePolicy::Error => { b.stats.replace(Err(BrunchError::DupeName)); },
+				DupePolicy::Replace => {
+					if let Some(old) = self.set.iter_mut().find(|o| o.name == b.name) {
+						*old = b;
+					}
+					return;
+				},
+				DupePolicy::Rename => {
+					let mut n = 2_u32;
+					let mut name = format!("{} ({n})", b.name);
+					while self.has_name(&name) {
+						n += 1;
+						name = format!("{} ({n})", b.name);
+					}
+					b.name = name;
+				},
+			}
+		}
+
+		self.set.push(b);
+	}
+
+	/// # Shared Context.
+	///
+	/// Create a [`Context`] — some (expensive) setup shared by any number of
+	/// benchmarks — for use with [`Bench::run_with_context`].
+	///
+	/// The value is built once, right before the first benchmark that needs
+	/// it is run, so the construction time is never attributed to any
+	/// benchmark's samples or timeout. It is dropped as soon as the
+	/// benchmarks have finished running.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// let data = benches.with_context(|| (0..1_000_000_u32).collect::<Vec<_>>());
+	///
+	/// benches.push(
+	///     Bench::new("Vec::iter().sum()")
+	///         .run_with_context(&data, |v| v.iter().copied().map(u64::from).sum::<u64>())
+	/// );
+	/// benches.push(
+	///     Bench::new("Vec::iter().max()")
+	///         .run_with_context(&data, |v| v.iter().max().copied())
+	/// );
+	/// benches.finish_or_print();
+	/// ```
+	pub fn with_context<T, F>(&mut self, init: F) -> Context<'a, T>
+	where T: 'a, F: FnOnce() -> T + 'a {
+		let ctx = Context::new(init);
+		self.contexts.push(&ctx);
+		ctx
+	}
+
+	/// # Retain.
+	///
+	/// Keep only the benchmarks — and spacers — for which the predicate
+	/// returns `true`, e.g. to drop entries that can't run on the current
+	/// system.
+	///
+	/// Duplicate names are re-checked afterward, so if the first of two
+	/// same-named benchmarks is removed, the second is no longer flagged.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.push(Bench::spacer());
+	/// benches.push(Bench::new("str::len").run(|| "Hello World".len()));
+	///
+	/// // Drop the spacers.
+	/// benches.retain(|b| ! b.is_spacer());
+	/// benches.finish_or_print();
+	/// ```
+	pub fn retain<F>(&mut self, cb: F)
+	where F: FnMut(&Bench<'a>) -> bool {
+		self.set.retain(cb);
+		self.recheck_names();
+	}
+
+	/// # Remove.
+	///
+	/// Remove and return the (first) benchmark with the given name, if any.
+	///
+	/// As with [`Benches::retain`], duplicate names are re-checked afterward,
+	/// so a replacement with the same name can be pushed without being
+	/// flagged.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	///
+	/// // Replace it!
+	/// assert!(benches.remove("String::len").is_some());
+	/// benches.push(Bench::new("String::len").run(|| String::new().len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn remove(&mut self, name: &str) -> Option<Bench<'a>> {
+		let idx = self.set.iter().position(|b| ! b.is_spacer() && b.name == name)?;
+		let out = self.set.remove(idx);
+		self.recheck_names();
+		Some(out)
+	}
+
+	#[doc(hidden)]
+	/// # Announce.
+	///
+	/// Print the "Starting" banner, unless quiet.
+	///
+	/// This is used by the [`benches`](crate::benches) macro to let folks
+	/// know something is happening; it is not intended to be called manually.
+	pub fn announce(&self) {
+		if ! self.is_quiet() {
+			eprint!("\x1b[1;38;5;199mStarting:\x1b[0m Running benchmark(s). Stand by!\n\n");
+		}
+	}
+
+	/// # Finish.
+	///
+	/// Run the benchmarks, then crunch and print the data, returning a
+	/// [`Summary`] of how things went.
+	///
+	/// This method should only be called after all benchmarks have been pushed
+	/// to the set.
+	///
+	/// Unlike [`Benches::finish_or_print`], this never exits the process, so
+	/// it's up to you to decide what — if anything — to do about failures.
+	/// See [`Summary::exit_code`] for the codes the latter would use.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	///
+	/// match benches.finish() {
+	///     Ok(summary) if summary.is_success() => eprintln!("All good!"),
+	///     Ok(summary) => eprintln!("{} benchmark(s) failed.", summary.errored()),
+	///     Err(e) => eprintln!("{e}"),
+	/// }
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will return an error if there were no benchmarks to run.
+	pub fn finish(&mut self) -> Result<Summary, BrunchError> {
+		let target = self.output.unwrap_or_else(OutputTarget::from_env);
+		let color = self.color.unwrap_or_else(|| target.color());
+		let width = term::width(target);
+		let mut out = String::new();
+		let res = self.finish_inner(&mut out, color, width);
+
+		// There isn't much to be done if the terminal can't be written to.
+		let _res = match target {
+			OutputTarget::Stderr => write_flush(&mut std::io::stderr().lock(), &out),
+			OutputTarget::Stdout => write_flush(&mut std::io::stdout().lock(), &out),
+		};
+
+		if res.is_ok() && ! self.is_list() { self.finish_extras(); }
+		res
+	}
+
+	/// # Finish or Print.
+	///
+	/// Run the benchmarks, then crunch and print the data, exactly like
+	/// [`Benches::finish`], but exit the process with a non-zero code — per
+	/// [`Summary::exit_code`] — if the run was interrupted, any benchmark
+	/// panicked, or any blew its time budget.
+	///
+	/// Panicking benchmarks are reported as errors in the summary rather than
+	/// aborting the entire run, but the process will still exit with a
+	/// non-zero code (`101`) at the end so the failure doesn't go unnoticed.
+	///
+	/// This is what the [`benches`](crate::benches) macro uses.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	/// ```
+	pub fn finish_or_print(&mut self) {
+		if let Some(code) = self.finish().ok().and_then(|s| s.exit_code()) {
+			std::process::exit(code);
+		}
+	}
+
+	/// # Finish To Writer.
+	///
+	/// This works exactly like [`Benches::finish`], except the summary (or
+	/// error) is written to the writer of your choosing instead of `STDERR`.
+	///
+	/// History is loaded, compared, and saved the same way in either case.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	///
+	/// let mut out = Vec::new();
+	/// let res = benches.finish_to(&mut out).unwrap();
+	/// assert!(res.is_ok_and(|s| s.is_success()));
+	/// ```
+	///
+	/// ## Errors
+	///
+	/// This will bubble up any errors encountered while writing to the writer.
+	/// The inner result is the same as [`Benches::finish`].
+	pub fn finish_to<W: Write>(&mut self, w: &mut W)
+	-> std::io::Result<Result<Summary, BrunchError>> {
+		let mut out = String::new();
+		let res = self.finish_inner(&mut out, self.color.unwrap_or(true), None);
+		write_flush(w, &out)?;
+		if res.is_ok() && ! self.is_list() { self.finish_extras(); }
+		Ok(res)
+	}
+
+	#[must_use]
+	/// # Render Plain.
+	///
+	/// Return the results table as a plain string, without any ANSI
+	/// formatting, suitable for e.g. pasting into commit messages.
+	///
+	/// The columns and alignment are otherwise the same as the summary
+	/// printed by [`Benches::finish`], which should generally be called first
+	/// so that the run-to-run changes (if any) can be included.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	///
+	/// let plain = benches.render_plain();
+	/// assert!(! plain.contains('\x1b'));
+	/// ```
+	pub fn render_plain(&self) -> String {
+		let mut out = String::new();
+		let _res = self.table().write_to(&mut out, false);
+		out
+	}
+
+	#[must_use]
+	/// # Results.
+	///
+	/// Return the outcome of each (non-spacer) benchmark in the set, in the
+	/// order they were pushed.
+	///
+	/// The baseline — the pooled stats from previous runs, if any — is only
+	/// populated once [`Benches::finish`] has been called, so this should
+	/// generally be called afterward.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.finish_or_print();
+	///
+	/// for res in benches.results() {
+	///     if let Ok(stats) = res.stats() {
+	///         println!("{}: {:?}", res.name(), stats.mean());
+	///     }
+	/// }
+	/// ```
+	pub fn results(&self) -> Vec<BenchResult> {
+		let gate = self.gate();
+		self.set.iter()
+			.filter_map(|b|
+				if b.is_spacer() { None }
+				else {
+					Some(BenchResult {
+						name: b.name.clone(),
+						stats: b.stats.clone().unwrap_or(Err(BrunchError::NoRun)),
+						baseline: b.baseline,
+						best: b.best,
+						cpu: b.cpu,
+						expect_under: b.expect_under,
+						skipped: if b.stats.is_none() { b.skip.clone() } else { None },
+						errors: b.errors.0,
+						drifted: matches!(b.stats, Some(Ok(s)) if self.drift.is_some_and(|t| s.is_drifting(t))),
+						change: match b.stats {
+							Some(Ok(s)) => b.baseline.and_then(|h| gate.change(s, h)),
+							_ => None,
+						},
+					})
+				}
+			)
+			.collect()
+	}
+
+	#[must_use]
+	/// # Report.
+	///
+	/// Return an owned, data-only [`RunReport`] of the results, suitable for
+	/// programmatic interchange. With the `serde` crate feature enabled, it
+	/// can be serialized to any `serde`-compatible format.
+	///
+	/// As with [`Benches::results`], this should generally be called after
+	/// [`Benches::finish`].
+	pub fn report(&self) -> RunReport { RunReport::from(self.results().as_slice()) }
+
+	#[must_use]
+	/// # Length.
+	///
+	/// Return the number of (non-spacer) benchmarks in the set.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::{Benches, Bench};
+	///
+	/// let benches: Benches = [
+	///     Bench::new("String::len").run(|| "Hello World".len()),
+	///     Bench::spacer(),
+	///     Bench::new("str::len").run(|| "Hello World".len()),
+	/// ].into_iter().collect();
+	/// assert_eq!(benches.len(), 2);
+	/// ```
+	pub fn len(&self) -> usize {
+		self.set.iter().filter(|b| ! b.is_spacer()).count()
+	}
+
+	#[must_use]
+	/// # Is Empty?
+	///
+	/// Returns `true` if there are no (non-spacer) benchmarks in the set.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// assert!(benches.is_empty());
+	///
+	/// benches.push(Bench::spacer());
+	/// assert!(benches.is_empty());
+	///
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// assert!(! benches.is_empty());
+	/// ```
+	pub fn is_empty(&self) -> bool { self.set.iter().all(Bench::is_spacer) }
+
+	#[must_use]
+	/// # Names.
+	///
+	/// Return the names of the (non-spacer) benchmarks in the set, in the
+	/// order they were pushed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// benches.push(Bench::new("str::len").run(|| "Hello World".len()));
+	/// assert_eq!(benches.names(), ["String::len", "str::len"]);
+	/// ```
+	pub fn names(&self) -> Vec<&str> {
+		self.iter().map(Bench::name).collect()
+	}
+
+	#[must_use]
+	/// # Iterator.
+	///
+	/// Iterate over the (non-spacer) benchmarks in the set.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::{Benches, Bench};
+	///
+	/// let mut benches = Benches::default();
+	/// benches.push(Bench::new("String::len").run(|| "Hello World".len()));
+	/// for b in benches.iter() {
+	///     println!("{}", b.name());
+	/// }
+	/// ```
+	pub fn iter(&self) -> <&Self as IntoIterator>::IntoIter {
+		self.set.iter().filter(|b| ! b.is_spacer())
+	}
+
+	/// # Finish: Update History.
+	fn finish_history(&self, history: &mut History) -> usize {
+		// Prune the rest, but only if everything ran successfully (or was
+		// skipped).
+		if
+			self.prune() &&
+			self.set.iter().all(|b|
+				b.is_spacer() ||
+				matches!(b.stats, Some(Ok(_))) ||
+				(b.stats.is_none() && b.is_skipped())
+			)
+		{
+			// Skipped and filtered benchmarks keep their entries.
+			for b in &self.set {
+				if b.is_skipped() { history.keep(&b.name); }
+			}
+			for name in self.filtered.iter().chain(&self.unmatched) { history.keep(name); }
+			history.prune();
+		}
+
+		// Save it (again).
+		history.save()
+	}
+}
+
+impl Benches<'_> {
+	/// # Finish (Inner).
+	///
+	/// This crunches the data and writes the summary to the buffer, with or
+	/// without ANSI formatting, squeezing it into the given width, if any.
+	#[expect(clippy::too_many_lines, reason = "It is what it is.")]
+	fn finish_inner(&mut self, out: &mut String, color: bool, width: Option<usize>)
+	-> Result<Summary, BrunchError> {
+		// Pin the thread, if requested. (When isolated, only the children
+		// are pinned; when parallel, nothing is, as they'd all pile onto the
+		// same core.)
+		let child = isolate::Child::from_env();
+		if self.jobs() == 1 && (child.is_some() || ! self.isolate()) { self.pin_thread(); }
+
+		// If we're a child process, just run the one bench and report back.
+		if let Some(child) = child { self.finish_child(child); }
+
+		// Leave out anything the tags or name filters rule out.
+		self.filter_set();
+
+		// If there weren't any benchmarks, just print an error.
+		let quiet = self.is_quiet();
+		if self.set.is_empty() {
+			out.push_str(if color && ! quiet { "\x1b[1;91mError:\x1b[0m " } else { "Error: " });
+			out.push_str(&BrunchError::NoBench.to_string());
+			out.push('\n');
+			return Err(BrunchError::NoBench);
+		}
+
+		// If we're only listing, that's that.
+		if self.is_list() {
+			self.write_list(out);
+			return Ok(Summary {
+				results: Vec::new(),
+				skipped: 0,
+				interrupted: false,
+				history_loaded: false,
+				history_saved: false,
+			});
+		}
+
+		// Mention anything about the environment that might skew the
+		// results.
+		if ! quiet { checks::print(); }
+
+		// Debug builds get a much louder warning — or at least some warning —
+		// and no history.
+		if self.debug {
+			if quiet {
+				eprintln!("\x1b[1;93mWarning:\x1b[0m This is an unoptimized (debug) build; the results should not be trusted.");
+			}
+			else { print_debug_banner(); }
+		}
+
+		// Pull the previous runs, if any.
+		let mut history =
+			if self.no_history || self.debug { History::disabled() }
+			else { History::new(self.history.as_deref()) };
+		if history.is_corrupt() {
+			eprintln!("\x1b[1;93mWarning:\x1b[0m The history file is corrupt; starting fresh.");
+		}
+
+		// Pick the baseline, and leave it be, if read-only.
+		let save = self.save_baseline_name();
+		if let Some(name) = self.baseline_name().or_else(|| save.clone()) { history.select(&name); }
+		if let Some(name) = save { history.save_to(&name); }
+		if self.is_history_readonly() { history.freeze(); }
+		let best = self.use_best();
+		for b in &mut self.set {
+			if ! b.is_spacer() {
+				b.baseline = history.get(&b.name);
+				b.baseline_allocs = history.allocs(&b.name);
+				b.baseline_perf = history.perf(&b.name);
+				if best { b.best = history.best(&b.name); }
+			}
+		}
+
+		// Warn (once) if the baselines were recorded under different
+		// conditions.
+		if let Some(meta) = self.set.iter()
+			.filter(|b| b.baseline.is_some())
+			.find_map(|b| history.mismatch(&b.name))
+		{
+			eprintln!(
+				"\x1b[1;93mWarning:\x1b[0m The baseline was recorded {} ago with {} ({}) on host {}; changes may not be meaningful.",
+				NiceElapsed::from(meta.age()),
+				meta.rustc(),
+				meta.target(),
+				if meta.host().is_empty() { "unknown" } else { meta.host() },
+			);
+		}
+
+		// Run the benchmarks!
+		self.exec(&mut history);
+
+		// Free up any shared contexts.
+		self.contexts.reset();
+
+		// Mention any baselines dropped due to configuration changes.
+		for b in &self.set {
+			if ! b.reconfigured.is_empty() {
+				eprintln!(
+					"\x1b[1;93mWarning:\x1b[0m The settings for {} changed ({}); its baseline has been reset.",
+					b.name,
+					b.reconfigured.join(", "),
+				);
+			}
+
+			// And any Err samples left out.
+			if b.discard_errors && b.errors.0 != 0 && matches!(b.stats, Some(Ok(_))) {
+				eprintln!(
+					"\x1b[1;93mWarning:\x1b[0m The callback for {} returned Err on {}/{} samples; only the Ok samples were kept.",
+					b.name,
+					NiceU32::from(b.errors.0),
+					NiceU32::from(b.errors.1),
+				);
+			}
+		}
+
+		// Build the summaries.
+		let summary = self.table();
+
+		// Update the history.
+		let pruned = self.finish_history(&mut history);
+		if let Some(e) = history.save_error() {
+			eprintln!("\x1b[1;93mWarning:\x1b[0m Unable to save the history to {e}.");
+		}
+
+		// Leave a trail for Criterion tooling, if requested.
+		if criterion::enabled() { self.finish_criterion(&criterion::root()); }
+
+		// Log the results for posterity, if requested.
+		if ! self.debug {
+			if let Some(path) = self.run_log.clone().or_else(runlog::path) {
+				self.finish_run_log(&path);
+			}
+		}
+
+		// Squeeze the table into the terminal, if needed. (The GitHub summary
+		// gets the original.) In quiet mode, the libtest lines — if any —
+		// stand in for it entirely.
+		if ! quiet || ! self.libtest() {
+			let _res = match width {
+				Some(width) => summary.fitted(width).write_to(out, color),
+				None => summary.write_to(out, color),
+			};
+
+			// Follow up with the rankings, if requested.
+			if let Some(ranking) = self.ranking().then(|| self.ranking_table()).flatten() {
+				out.push('\n');
+				let _res = match width {
+					Some(width) => ranking.fitted(width).write_to(out, color),
+					None => ranking.write_to(out, color),
+				};
+			}
+		}
+
+		// Add a footer with the tally and total run time, followed by any
+		// notes.
+		let results = self.results();
+		if ! quiet {
+			if let Some(tally) = tally_line(&results, color) {
+				out.push_str(&tally);
+				out.push('\n');
+			}
+			for note in self.notes(&history, pruned) {
+				if color {
+					out.push_str("\x1b[2m");
+					out.push_str(&note);
+					out.push_str("\x1b[0m\n");
+				}
+				else {
+					out.push_str(&note);
+					out.push('\n');
+				}
+			}
+			out.push('\n');
+		}
+
+		Ok(Summary {
+			results,
+			skipped: self.set.iter()
+				.filter(|b| b.is_pending() || (b.stats.is_none() && b.is_skipped()))
+				.count(),
+			interrupted: signal::interrupted(),
+			history_loaded: history.is_loaded(),
+			history_saved: history.is_enabled() && ! history.is_frozen() && history.save_error().is_none(),
+		})
+	}
+
+	/// # Finish: Extras.
+	///
+	/// Print the libtest-style lines and GitHub annotations, if applicable,
+	/// once the summary is out of the way.
+	fn finish_extras(&self) {
+		// Print the libtest-style lines, if requested.
+		if self.libtest() { self.finish_libtest(); }
+
+		// Let GitHub know how things went, if applicable.
+		if github::enabled() { self.finish_github(&self.table()); }
+	}
+
+	/// # Finish: Criterion Output.
+	///
+	/// Write Criterion-compatible estimates for each successful benchmark,
+	/// warning (once) on failure.
+	fn finish_criterion(&self, root: &Path) {
+		for b in &self.set {
+			if let Some(Ok(s)) = b.stats {
+				if let Err(e) = criterion::write(root, &b.name, s) {
+					eprintln!(
+						"\x1b[1;93mWarning:\x1b[0m Unable to write Criterion-compatible output to {} ({e}).",
+						root.display(),
+					);
+					return;
+				}
+			}
+		}
+	}
+
+	/// # Finish: Run Log.
+	///
+	/// Append a record for each successful benchmark to the run log, warning
+	/// on failure.
+	fn finish_run_log(&self, path: &Path) {
+		let records: Vec<(&str, Stats)> = self.set.iter()
+			.filter_map(|b| match b.stats {
+				Some(Ok(s)) => Some((b.name.as_str(), s)),
+				_ => None,
+			})
+			.collect();
+		if records.is_empty() { return; }
+		if let Err(e) = runlog::append(path, records) {
+			eprintln!(
+				"\x1b[1;93mWarning:\x1b[0m Unable to append to the run log at {} ({e}).",
+				path.display(),
+			);
+		}
+	}
+
+	/// # Finish: libtest Lines.
+	///
+	/// Print a libtest-style line to `STDOUT` for each benchmark that ran.
+	fn finish_libtest(&self) {
+		let interrupted = signal::interrupted();
+		let mut out = std::io::stdout().lock();
+		for b in &self.set {
+			if b.is_spacer() || (interrupted && b.is_pending()) { continue; }
+			let _res =
+				if b.stats.is_none() && b.is_skipped() { writeln!(out, "test {} ... ignored", b.name) }
+				else {
+					writeln!(
+						out,
+						"{}",
+						libtest_line(&b.name, b.stats.as_ref().unwrap_or(&Err(BrunchError::NoRun))),
+					)
+				};
+		}
+		let _res = out.flush();
+	}
+
+	/// # Finish: GitHub.
+	///
+	/// Emit the annotations and step summary.
+	fn finish_github(&self, summary: &Table) {
+		let mut md = String::new();
+		let _res = summary.write_markdown(&mut md);
+		github::report(&self.annotations(), &md);
+	}
+
+	/// # GitHub Annotations.
+	///
+	/// Return a workflow command for each benchmark that regressed — i.e.
+	/// got slower by enough to be reported in the summary — or failed.
+	fn annotations(&self) -> Vec<String> {
+		let gate = self.gate();
+		self.set.iter()
+			.filter(|b| ! b.is_spacer())
+			.filter_map(|b| match b.stats.as_ref()? {
+				Ok(s) if b.over_budget() => Some(github::annotation(
+					github::Level::Error,
+					"Benchmark Over Budget",
+					&format!(
+						"{}: {} > {}",
+						b.name,
+						s.nice_mean(),
+						util::nice_time(b.expect_under.unwrap_or_default().as_secs_f64()),
+					),
+				)),
+				Ok(s) => {
+					let old = b.baseline?;
+					let diff = gate.change(*s, old).filter(|d| 0.0 < *d)?;
+					let mut change = String::new();
+					format_change(diff).write_to(&mut change, false).ok()?;
+					Some(github::annotation(
+						github::Level::Warning,
+						"Benchmark Regression",
+						&format!("{}: {} \u{2192} {} ({change})", b.name, old.nice_mean(), s.nice_mean()),
+					))
+				},
+				Err(e) => Some(github::annotation(
+					github::Level::Error,
+					"Benchmark Failure",
+					&format!("{}: {e}", b.name),
+				)),
+			})
+			.collect()
+	}
+
+	/// # Finish (Child).
+	///
+	/// Run the benchmark requested by the parent process, pass along the
+	/// results, and exit.
+	fn finish_child(&mut self, child: isolate::Child) -> ! {
+		// The parent has already complained about any priority problems, so
+		// this can be quiet.
+		let _priority = self.raise_priority(true);
+
+		let (res, cpu, allocs, perf, rss, elapsed, errors) = match self.set.iter_mut().find(|b| ! b.is_spacer() && b.name == child.name()) {
+			Some(b) => {
+				// The parent has already worked out the settings.
+				b.samples = Some(child.samples());
+				b.timeout = Some(child.timeout());
+				b.measure_cpu = child.measure_cpu();
+				b.measure_memory = child.measure_memory();
+				b.timer.clone_from(&self.timer);
+				b.exec(&mut Progress::new(1), true, None);
+				(b.stats.clone().unwrap_or(Err(BrunchError::NoRun)), b.cpu, b.allocs, b.perf, b.rss, b.elapsed, b.errors)
+			},
+			None => (Err(BrunchError::NoRun), None, None, None, None, Duration::ZERO, (0, 0)),
+		};
+		child.finish(&res, cpu, allocs, perf, rss, elapsed, errors)
+	}
+
+	/// # Execute.
+	///
+	/// Run each of the benchmarks in order, showing progress along the way,
+	/// and recording the results to the history.
+	fn exec(&mut self, history: &mut History) {
+		signal::install();
+		self.overrides = Overrides::from_env();
+		let jobs = self.jobs();
+		let isolated = self.isolate();
+
+		// Parallel numbers aren't comparable, so leave the history be.
+		if 1 < jobs { history.freeze(); }
+
+		// Make sure we can actually measure CPU time, if anyone wants it.
+		if self.set.iter().any(|b| b.measure_cpu) && clock::thread_cpu_time().is_none() {
+			eprintln!("\x1b[1;93mWarning:\x1b[0m CPU time is not supported on this platform.");
+			for b in &mut self.set { b.measure_cpu = false; }
+		}
+
+		// Samples can't be pooled across processes.
+		let mut runs = self.runs();
+		if isolated && 1 < runs {
+			eprintln!("\x1b[1;93mWarning:\x1b[0m Repeated runs are not supported in isolated mode; running once.");
+			runs = 1;
+		}
+
+		// Ditto for interleaving.
+		let mut interleave = self.interleave();
+		if isolated && interleave {
+			eprintln!("\x1b[1;93mWarning:\x1b[0m Interleaving is not supported in isolated mode; running sequentially.");
+			interleave = false;
+		}
+		let chunk = if interleave { Some(INTERLEAVE_SLICE) } else { None };
+
+		let plots = self.plots.clone().or_else(plot::dir);
+		let mut progress = Progress::new(self.len()).with_quiet(self.is_quiet());
+		let _timer = TimerGuard::new(self.timer.clone());
+
+		// Raise the priority, if requested. (When isolated, only the children
+		// are raised, but it's worth a try here to surface any warnings.)
+		let _priority = self.raise_priority(false).filter(|_| ! isolated);
+
+		// Parallel runs are their own thing.
+		if 1 < jobs {
+			self.exec_parallel(jobs, history, plots.as_deref(), &mut progress);
+			progress.finish();
+			return;
+		}
+
+		let now = Tick::now();
+		let mut warmed = Duration::ZERO;
+		let mut left = self.set.iter().filter(|b| b.is_pending()).count() * usize::saturating_from(runs);
+		for round in 1..=runs {
+			if signal::interrupted() { break; }
+			progress.round(round, runs);
+
+			// When interleaved, it takes several passes to get through each
+			// round; otherwise one will do.
+			let mut pass = 0_u32;
+			let mut busy = true;
+			while busy {
+				pass += 1;
+				busy = false;
+				for b in &mut self.set {
+					if b.is_spacer() { continue; }
+					if signal::interrupted() { break; }
+					if pass == 1 { progress.start(&b.name); }
+					else if b.is_mid_round() { progress.resume(&b.name); }
+					else { continue; }
+
+					if b.is_pending() {
+						if round == 1 && pass == 1 {
+							b.prepare(self.samples, self.timeout, self.overrides, self.timer.as_ref(), plots.is_some());
+						}
+
+						// Divvy up what's left of the budget, if any. (Warm-ups
+						// don't count against it.)
+						if pass == 1 {
+							if let Some(budget) = self.budget {
+								let spent = now.elapsed().saturating_sub(warmed);
+								b.fit_budget(budget.saturating_sub(spent) / u32::saturating_from(left));
+							}
+							left = left.saturating_sub(1);
+						}
+						if isolated { b.exec_isolated(&mut progress); }
+						else {
+							b.exec(&mut progress, round == runs, chunk);
+							warmed += b.warmed;
+						}
+
+						// Come back to it on the next pass if it isn't done yet.
+						if b.is_mid_round() {
+							busy = true;
+							continue;
+						}
+
+						// Give wild or short results another go, if allowed.
+						if round == runs && b.is_retryable() {
+							let first = b.stats.take();
+							while b.retried < self.retries && ! signal::interrupted() {
+								// The budget applies to retries too.
+								if let Some(budget) = self.budget {
+									let spent = now.elapsed().saturating_sub(warmed);
+									let slice = budget.saturating_sub(spent) / u32::saturating_from(left + 1);
+									if slice.is_zero() { break; }
+									b.fit_budget(slice);
+								}
+
+								b.retried += 1;
+								b.stats = None;
+								if isolated { b.exec_isolated(&mut progress); }
+								else {
+									b.exec(&mut progress, true, None);
+									warmed += b.warmed;
+								}
+								if matches!(b.stats, Some(Ok(_))) { break; }
+							}
+
+							// Report the original error if nothing worked.
+							if ! matches!(b.stats, Some(Ok(_))) {
+								b.stats = first;
+								b.sampler = None;
+							}
+						}
+
+						// Record it once it's done, one way or another.
+						if b.stats.is_some() {
+							b.record(history);
+							if self.incremental { history.save(); }
+							if let Some(dir) = plots.as_deref() { b.write_plot(dir); }
+						}
+					}
+				}
+			}
+		}
+		progress.finish();
+	}
+
+	/// # Execute (Parallel).
+	///
+	/// Run up to `jobs` benchmarks at a time, each in its own child process,
+	/// checking in on them periodically. Results are stored in place, so the
+	/// declaration order is unaffected.
+	fn exec_parallel(
+		&mut self,
+		jobs: usize,
+		history: &mut History,
+		plots: Option<&Path>,
+		progress: &mut Progress,
+	) {
+		let mut queue: VecDeque<usize> = self.set.iter()
+			.enumerate()
+			.filter_map(|(idx, b)| b.is_pending().then_some(idx))
+			.collect();
+		for &idx in &queue {
+			self.set[idx].prepare(self.samples, self.timeout, self.overrides, self.timer.as_ref(), plots.is_some());
+		}
+
+		let now = Tick::now();
+		let mut running: Vec<(usize, isolate::Running)> = Vec::with_capacity(jobs);
+		let mut first: BTreeMap<usize, Result<Stats, BrunchError>> = BTreeMap::new();
+		loop {
+			// Take everything down with us.
+			if signal::interrupted() {
+				for (idx, child) in running {
+					self.set[idx].finish_isolated(child.kill());
+				}
+				break;
+			}
+
+			// Keep the slots full.
+			while running.len() < jobs {
+				let Some(idx) = queue.pop_front() else { break; };
+
+				// Divvy up what's left of the budget, if any.
+				let slice = self.parallel_slice(&now, queue.len() + running.len() + 1, jobs);
+				let b = &mut self.set[idx];
+				if let Some(slice) = slice { b.fit_budget(slice); }
+
+				if b.retried == 0 { progress.start(&b.name); }
+				else { progress.resume(&b.name); }
+				running.push((idx, isolate::launch(
+					&b.name,
+					b.samples(),
+					b.timeout(),
+					b.measure_cpu,
+					b.measure_memory,
+				)));
+			}
+			if running.is_empty() { break; }
+
+			// Collect whatever has finished.
+			let mut i = 0;
+			while i < running.len() {
+				let Some(res) = running[i].1.poll() else {
+					i += 1;
+					continue;
+				};
+				let idx = running.remove(i).0;
+				self.set[idx].finish_isolated(res);
+
+				// Give wild or short results another go, if allowed and there's
+				// still time for it.
+				let retry =
+					self.set[idx].is_retryable() &&
+					self.set[idx].retried < self.retries &&
+					self.parallel_slice(&now, queue.len() + running.len() + 1, jobs)
+						.is_none_or(|slice| ! slice.is_zero());
+
+				let b = &mut self.set[idx];
+				if retry {
+					// Hang onto the original result in case nothing works.
+					if let Some(res) = b.stats.take() { first.entry(idx).or_insert(res); }
+					b.retried += 1;
+					queue.push_back(idx);
+				}
+				else {
+					// Report the original error if nothing worked.
+					if ! matches!(b.stats, Some(Ok(_))) {
+						if let Some(res) = first.remove(&idx) { b.stats = Some(res); }
+					}
+					b.record(history);
+					if let Some(dir) = plots { b.write_plot(dir); }
+				}
+			}
+
+			if let Some((_, child)) = running.last() { progress.tick(child.elapsed()); }
+			std::thread::sleep(isolate::POLL);
+		}
+	}
+
+	/// # Parallel Budget Slice.
+	///
+	/// Return the share of the suite budget, if any, for the next benchmark
+	/// when `left` remain, bearing in mind `jobs` share it at once.
+	fn parallel_slice(&self, now: &Tick, left: usize, jobs: usize) -> Option<Duration> {
+		let budget = self.budget?;
+		Some(
+			budget.saturating_sub(now.elapsed()).saturating_mul(u32::saturating_from(jobs)) /
+			u32::saturating_from(left)
+		)
+	}
+
+	/// # Notes.
+	///
+	/// Return the footer lines: the total run time, followed by anything
+	/// else worth mentioning.
+	fn notes(&self, history: &History, pruned: usize) -> Vec<String> {
+		let mut notes = Vec::new();
+		notes.push(
+			if signal::interrupted() {
+				format!("Interrupted after {}.", NiceElapsed::from(self.started.elapsed()))
+			}
+			else {
+				format!(
+					"Finished {} in {}.",
+					if self.len() == 1 { "1 benchmark".to_owned() }
+					else { format!("{} benchmarks", NiceU32::from(u32::saturating_from(self.len()))) },
+					NiceElapsed::from(self.started.elapsed()),
+				)
+			}
+		);
+
+		// In verbose mode, spell out the change criteria so pasted results are
+		// self-describing.
+		if self.verbose() {
+			notes.push(self.gate().note());
+			notes.extend(self.warmup_note());
+		}
+
+		// Mention any overrides so abbreviated runs are obvious.
+		notes.extend(self.overrides.note());
+
+		// Mention which profile's baseline was used.
+		if let Some(profile) = history.profile() {
+			notes.push(format!("Baselines are from the {profile} profile."));
+		}
+
+		// And which named baseline, if any.
+		if let Some(name) = history.baseline() {
+			notes.push(format!("Compared against the \"{name}\" baseline."));
+		}
+		if let Some(name) = history.target().filter(|_| ! history.is_frozen()) {
+			notes.push(format!("Saved to the \"{name}\" baseline."));
+		}
+
+		// Mention anything the tags ruled out.
+		if ! self.filtered.is_empty() {
+			let len = self.filtered.len();
+			notes.push(format!(
+				"Left out {} by tag.",
+				if len == 1 { "1 benchmark".to_owned() }
+				else { format!("{} benchmarks", NiceU32::from(u32::saturating_from(len))) },
+			));
+		}
+
+		// Or the name filters.
+		if ! self.unmatched.is_empty() {
+			let len = self.unmatched.len();
+			notes.push(format!(
+				"Left out {} by name.",
+				if len == 1 { "1 benchmark".to_owned() }
+				else { format!("{} benchmarks", NiceU32::from(u32::saturating_from(len))) },
+			));
+		}
+
+		// Mention if the history was left alone.
+		if history.is_enabled() && history.is_frozen() {
+			notes.push(
+				if self.is_history_readonly() { "Read-only history (baseline not updated).".to_owned() }
+				else { "Parallel run (baseline not updated).".to_owned() }
+			);
+		}
+
+		// Mention the fresh start if the old shared history was passed over.
+		if history.is_split() {
+			notes.push("History is now kept separately for each bench binary; starting fresh.".to_owned());
+		}
+
+		// Call out any benchmarks that lost too many samples.
+		notes.extend(self.pruning_note());
+
+		// Or drifted.
+		notes.extend(self.drift_notes());
+
+		// Or were barely measured at all.
+		notes.extend(self.measured_note());
+
+		// Or were run with fewer samples than usual.
+		notes.extend(self.low_confidence_note());
+
+		// Mention any pruning so it isn't silent.
+		if pruned != 0 {
+			notes.push(format!(
+				"Pruned {} stale history {}.",
+				NiceU32::from(u32::saturating_from(pruned)),
+				if pruned == 1 { "entry" } else { "entries" },
+			));
+		}
+
+		notes
+	}
+
+	/// # Pruning Note.
+	///
+	/// List the benchmarks that lost more than the warning threshold's worth
+	/// of samples to outlier pruning, if any.
+	fn pruning_note(&self) -> Option<String> {
+		let (warn, _) = self.pruning?;
+		let heavy: Vec<String> = self.set.iter()
+			.filter_map(|b| match b.stats {
+				Some(Ok(s)) if warn < s.pruned() => Some(format!("{} ({})", b.name, NicePercent::from(s.pruned()))),
+				_ => None,
+			})
+			.collect();
+		if heavy.is_empty() { return None; }
+
+		Some(format!(
+			"Heavy outlier pruning: {}. Try increasing the timeout or batching more work into each sample.",
+			heavy.join(", "),
+		))
+	}
+
+	/// # Drift Notes.
+	///
+	/// Return a line for each benchmark whose samples drifted by more than
+	/// the threshold, if any.
+	fn drift_notes(&self) -> Vec<String> {
+		let Some(threshold) = self.drift else { return Vec::new(); };
+		self.set.iter()
+			.filter_map(|b| match b.stats {
+				Some(Ok(s)) if s.is_drifting(threshold) => Some(format!(
+					"\u{26a0} {}: samples drifted {}{} during collection (thermal throttling?)",
+					b.name,
+					if s.drift().is_sign_negative() { '-' } else { '+' },
+					NicePercent::from(s.drift().abs()),
+				)),
+				_ => None,
+			})
+			.collect()
+	}
+
+	/// # Measured Note.
+	///
+	/// List the benchmarks whose valid samples add up to less than
+	/// `MIN_MEASURED`, if any.
+	fn measured_note(&self) -> Option<String> {
+		let short: Vec<String> = self.set.iter()
+			.filter_map(|b| match b.stats {
+				Some(Ok(s)) if s.measured() < MIN_MEASURED => Some(format!("{} ({})", b.name, s.nice_measured())),
+				_ => None,
+			})
+			.collect();
+		if short.is_empty() { return None; }
+
+		Some(format!(
+			"Too little time measured: {}. Try raising the sample count with Bench::with_samples or batching more work into each sample.",
+			short.join(", "),
+		))
+	}
+
+	/// # Warm-Up Note.
+	///
+	/// List the benchmarks that were warmed up before sampling, if any, and
+	/// for how long.
+	fn warmup_note(&self) -> Option<String> {
+		let warm: Vec<String> = self.set.iter()
+			.filter_map(|b| match b.stats {
+				Some(Ok(_)) if ! b.warmup.is_zero() => Some(format!(
+					"{} ({})",
+					b.name,
+					util::nice_time(b.warmup.as_secs_f64()),
+				)),
+				_ => None,
+			})
+			.collect();
+		if warm.is_empty() { return None; }
+
+		Some(format!("Warmed up before sampling (untimed): {}.", warm.join(", ")))
+	}
+
+	/// # Low-Confidence Note.
+	///
+	/// List the benchmarks with fewer than `MIN_SAMPLES` valid samples, if
+	/// any. (These can only come about via [`Bench::with_min_samples`].)
+	fn low_confidence_note(&self) -> Option<String> {
+		let low: Vec<&str> = self.set.iter()
+			.filter_map(|b| match b.stats {
+				Some(Ok(s)) if s.is_low_confidence() => Some(b.name.as_str()),
+				_ => None,
+			})
+			.collect();
+		if low.is_empty() { return None; }
+
+		Some(format!(
+			"Low confidence (fewer than {MIN_SAMPLES} valid samples): {}.",
+			low.join(", "),
+		))
+	}
+
+	/// # Rounds.
+	///
+	/// Return the number of times the suite should be run, from
+	/// [`Benches::repeat`] or `BRUNCH_RUNS`, defaulting to one.
+	fn runs(&self) -> u32 {
+		self.runs
+			.or_else(|| std::env::var("BRUNCH_RUNS").ok()?.trim().parse::<u32>().ok())
+			.unwrap_or(1)
+			.clamp(1, MAX_RUNS)
+	}
+
+	/// # Parallel Jobs.
+	///
+	/// Return the number of benchmarks to run at once, from
+	/// [`Benches::parallel`] or `BRUNCH_PARALLEL`, defaulting to one. Zero
+	/// means one per available core.
+	fn jobs(&self) -> usize {
+		let jobs = self.parallel
+			.or_else(|| std::env::var("BRUNCH_PARALLEL").ok()?.trim().parse::<usize>().ok())
+			.unwrap_or(1);
+		if jobs == 0 { std::thread::available_parallelism().map_or(1, NonZeroUsize::get) }
+		else { jobs }
+	}
+
+	/// # Named Baseline.
+	fn baseline_name(&self) -> Option<String> {
+		self.baseline.clone()
+			.or_else(|| std::env::var("BRUNCH_BASELINE").ok())
+			.filter(|s| ! s.trim().is_empty())
+	}
+
+	/// # Saved Baseline.
+	fn save_baseline_name(&self) -> Option<String> {
+		self.save_baseline.clone()
+			.or_else(|| std::env::var("BRUNCH_SAVE_BASELINE").ok())
+			.filter(|s| ! s.trim().is_empty())
+	}
+
+	/// # Read-Only History?
+	fn is_history_readonly(&self) -> bool {
+		self.history_ro.unwrap_or_else(||
+			std::env::var("BRUNCH_HISTORY_RO").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Interleave?
+	fn interleave(&self) -> bool {
+		self.interleaved.unwrap_or_else(||
+			std::env::var("BRUNCH_INTERLEAVE").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Show Ranking?
+	fn ranking(&self) -> bool {
+		self.ranking.unwrap_or_else(||
+			std::env::var("BRUNCH_RANK").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Verbose?
+	fn verbose(&self) -> bool {
+		self.verbose.unwrap_or_else(||
+			std::env::var("BRUNCH_VERBOSE").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Change Criteria.
+	fn gate(&self) -> Gate {
+		Gate {
+			alpha: self.significance,
+			threshold: self.threshold(),
+			strict: self.verbose(),
+			drift: self.drift,
+		}
+	}
+
+	/// # Change Threshold.
+	fn threshold(&self) -> f64 {
+		self.threshold
+			.or_else(||
+				std::env::var("BRUNCH_THRESHOLD").ok()
+					.and_then(|s| s.trim().parse::<f64>().ok())
+					.filter(|t| t.is_finite() && 0.0 <= *t)
+			)
+			.unwrap_or(0.0)
+	}
+
+	/// # Isolate?
+	///
+	/// Parallel runs are always isolated.
+	fn isolate(&self) -> bool {
+		1 < self.jobs() || self.isolated.unwrap_or_else(||
+			std::env::var("BRUNCH_ISOLATE").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Pin Thread.
+	///
+	/// Pin the current thread to the requested core, if any, warning on
+	/// failure.
+	fn pin_thread(&self) {
+		let core = self.pin.or_else(||
+			std::env::var("BRUNCH_PIN").ok().and_then(|s| s.trim().parse::<usize>().ok())
+		);
+		if let Some(core) = core {
+			if let Err(e) = affinity::pin(core) {
+				eprintln!(
+					"\x1b[1;93mWarning:\x1b[0m Unable to pin the benchmarks to CPU core {core} ({e}); continuing unpinned.",
+				);
+			}
+		}
+	}
+
+	/// # Raise Priority.
+	///
+	/// Raise the current thread's priority as requested, if at all, falling
+	/// back from realtime to high if need be. The returned guard restores the
+	/// original when dropped.
+	fn raise_priority(&self, quiet: bool) -> Option<priority::Guard> {
+		let want = self.priority.or_else(Priority::from_env).unwrap_or_default();
+		if want == Priority::Normal { return None; }
+
+		let err = match priority::raise(want) {
+			Ok(guard) => return Some(guard),
+			Err(e) => e,
+		};
+
+		// Settle for high priority?
+		if want == Priority::Realtime {
+			if let Ok(guard) = priority::raise(Priority::High) {
+				if ! quiet {
+					eprintln!(
+						"\x1b[1;93mWarning:\x1b[0m Unable to use realtime scheduling ({err}); using high priority instead.",
+					);
+				}
+				return Some(guard);
+			}
+		}
+
+		if ! quiet {
+			eprintln!(
+				"\x1b[1;93mWarning:\x1b[0m Unable to raise the thread priority ({err}); continuing at normal priority.",
+			);
+		}
+		None
+	}
+
+	/// # libtest Format?
+	fn libtest(&self) -> bool {
+		self.libtest.unwrap_or_else(||
+			std::env::var("BRUNCH_BENCHFMT").is_ok_and(|s| s.trim() == "libtest")
+		)
+	}
+
+	/// # Quiet?
+	fn is_quiet(&self) -> bool {
+		self.quiet.unwrap_or_else(||
+			std::env::var("BRUNCH_QUIET").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Compare Against Best?
+	fn use_best(&self) -> bool {
+		self.compare_best.unwrap_or_else(||
+			std::env::var("BRUNCH_COMPARE_BEST").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Prune History?
+	fn prune(&self) -> bool {
+		self.prune.unwrap_or_else(||
+			std::env::var("BRUNCH_PRUNE").is_ok_and(|s| s.trim() == "1")
+		)
+	}
+
+	/// # Build Table.
+	fn table(&self) -> Table {
+		let verbose = self.verbose();
+		let mut cols = vec![Column::Mean, Column::Ops];
+		if verbose {
+			cols.push(Column::Interval);
+			cols.push(Column::Median);
+			cols.push(Column::P95);
+			cols.push(Column::Range);
+		}
+		cols.push(Column::Samples);
+		if verbose {
+			cols.push(Column::Total);
+			cols.push(Column::Wall);
+			cols.push(Column::Spread);
+			cols.push(Column::Cpu);
+		}
+		cols.push(Column::Allocs);
+		cols.push(Column::Bytes);
+		cols.push(Column::Instructions);
+		cols.push(Column::Ipc);
+		cols.push(Column::MaxRss);
+		cols.push(Column::Change);
+		cols.push(Column::Trend);
+		cols.push(Column::Budget);
+
+		let mut summary = Table::new(cols);
+		summary.gate = self.gate();
+		summary.unit = self.unit;
+		summary.ops = self.ops.unwrap_or(false);
+		summary.pruning = self.pruning;
+		summary.slow = self.slow;
+		summary.names = self.names;
+		let names: Vec<Vec<char>> = self.set.iter()
+			.filter_map(|b|
+				if b.is_spacer() { None }
+				else { Some(b.name.chars().collect()) }
+			)
+			.collect();
+
+		// Minimums aren't means, so relabel the column if need be.
+		let (any_min, all_min) = self.iter().fold((false, true), |(any, all), b| {
+			let min = matches!(b.metric.sanitize(), Metric::Min);
+			(any || min, all && min)
+		});
+		if all_min && any_min { summary.relabel(Column::Mean, "Min"); }
+		else if any_min { summary.relabel(Column::Mean, "Mean/Min"); }
+
+		// Make sure nobody mistakes parallel numbers for the real thing.
+		if 1 < self.jobs() {
+			summary.annotate(" (parallel run \u{2014} treat numbers as approximate)");
+		}
+
+		let interrupted = signal::interrupted();
+		let geomean = self.geomean.unwrap_or(false);
+		let mut group = Vec::new();
+		let mut series = Vec::new();
+		for b in &self.set {
+			// Skip anything that never got the chance to run.
+			if interrupted && b.is_pending() { continue; }
+
+			// Summarize each scaling series once it ends.
+			let base = series.first().copied().and_then(Bench::scale_base);
+			if base.is_some() && base != b.scale_base() {
+				summary.push_scaling(&series);
+				series.truncate(0);
+			}
+			if b.scale.is_some() { series.push(b); }
+
+			// Summarize each group before moving on to the next.
+			if b.is_spacer() {
+				if geomean { summary.push_geomean(&group); }
+				group.truncate(0);
+			}
+			else { group.push(b); }
+
+			summary.push(b, &names);
+		}
+		summary.push_scaling(&series);
+		if geomean { summary.push_geomean(&group); }
+		summary
+	}
+
+	/// # Build Ranking Table.
+	///
+	/// Return a table ranking the successful benchmarks in each group from
+	/// fastest to slowest, or `None` if there's nothing worth ranking.
+	fn ranking_table(&self) -> Option<Table> {
+		let mut ranking = Table::new(vec![Column::Rank, Column::Mean, Column::Relative]);
+		ranking.unit = self.unit;
+		ranking.names = self.names;
+		let names: Vec<Vec<char>> = self.set.iter()
+			.filter_map(|b|
+				if b.is_spacer() { None }
+				else { Some(b.name.chars().collect()) }
+			)
+			.collect();
+
+		let mut any = false;
+		for group in self.set.split(Bench::is_spacer) {
+			let group: Vec<&Bench<'_>> = group.iter().collect();
+			if ranking.push_ranking(&group, &names) { any = true; }
+		}
+
+		if any {
+			// Drop the trailing spacer.
+			ranking.rows.pop();
+			Some(ranking)
+		}
+		else { None }
+	}
+
+	/// # Filter Set.
+	///
+	/// Remove any benchmarks ruled out by the tag filters — from
+	/// [`Benches::filter_tags`] and [`Benches::skip_tags`], or the
+	/// environment — or the name filters — from [`Benches::filter_names`] or
+	/// the command line — along with any spacers left dangling as a result.
+	fn filter_set(&mut self) {
+		let want = self.tags.clone().or_else(|| tags_from_env("BRUNCH_TAGS")).unwrap_or_default();
+		let skip = self.skip_tags.clone().or_else(|| tags_from_env("BRUNCH_SKIP_TAGS")).unwrap_or_default();
+		let names = self.name_filters.clone().unwrap_or_else(names_from_args);
+		if want.is_empty() && skip.is_empty() && names.is_empty() { return; }
+
+		let mut matched = false;
+		let mut set = Vec::with_capacity(self.set.len());
+		for b in std::mem::take(&mut self.set) {
+			if b.is_spacer() {
+				if set.last().is_some_and(|l: &Bench<'_>| ! l.is_spacer()) { set.push(b); }
+			}
+			else if
+				(want.is_empty() || b.tags.iter().any(|t| want.contains(t))) &&
+				! b.tags.iter().any(|t| skip.contains(t))
+			{
+				if names.is_empty() || names.iter().any(|n| b.name.contains(n.as_str())) {
+					matched = true;
+					set.push(b);
+				}
+				else { self.unmatched.push(b.name); }
+			}
+			else { self.filtered.push(b.name); }
+		}
+		if set.last().is_some_and(Bench::is_spacer) { set.pop(); }
+		self.set = set;
+
+		// Don't let a typo go unnoticed.
+		if ! names.is_empty() && ! matched && ! self.unmatched.is_empty() {
+			eprintln!(
+				"\x1b[1;93mWarning:\x1b[0m No benchmarks matched the name filter{} {}.",
+				if names.len() == 1 { "" } else { "s" },
+				names.iter().map(|n| format!("\"{n}\"")).collect::<Vec<_>>().join(", "),
+			);
+		}
+	}
+
+	/// # List Only?
+	fn is_list(&self) -> bool {
+		self.list.unwrap_or_else(list_from_args)
+	}
+
+	/// # Write List.
+	///
+	/// Write the name of each benchmark, along with its tags, if any, one per
+	/// line.
+	fn write_list(&self, out: &mut String) {
+		for b in &self.set {
+			if b.is_spacer() { continue; }
+			out.push_str(&b.name);
+			if ! b.tags.is_empty() {
+				out.push_str(" [");
+				out.push_str(&b.tags.join(", "));
+				out.push(']');
+			}
+			out.push('\n');
+		}
+	}
+
+	/// # Has Name.
+	fn has_name(&self, name: &str) -> bool {
+		self.set.iter().any(|b| b.name == name)
+	}
+
+	/// # Recheck Names.
+	///
+	/// Re-flag duplicate names after removals, clearing the flag from any
+	/// benchmark that is now the first of its name.
+	fn recheck_names(&mut self) {
+		let mut seen = BTreeSet::new();
+		for b in &mut self.set {
+			if b.is_spacer() { continue; }
+			if ! seen.insert(b.name.as_str()) {
+				b.stats.replace(Err(BrunchError::DupeName));
+			}
+			else if matches!(b.stats, Some(Err(BrunchError::DupeName))) {
+				b.stats = None;
+			}
+		}
+	}
+}
+
+
+
+/// # Benchmark.
+///
+/// This struct holds a single "bench" you wish to run. See the main crate
+/// documentation for more information.
+///
+/// Note: benchmarks are not actually run until [`Benches::finish`] is
+/// called; the runner methods — [`Bench::run`], etc. — merely queue up the
+/// callback for later.
+#[expect(clippy::struct_excessive_bools, reason = "It is what it is.")]
+pub struct Bench<'a> {
+	/// # Benchmark Name.
+	name: String,
+
+	/// # Namespace Length.
+	///
+	/// For benches created with [`Bench::new_in`], this is the length (in
+	/// bytes) of the namespace at the start of the name, sans `::`. It is
+	/// zero otherwise.
+	namespace: usize,
+
+	/// # Sample Limit.
+	///
+	/// If unspecified, the suite default, if any, or `DEFAULT_SAMPLES` is
+	/// used instead.
+	samples: Option<NonZeroU32>,
+
+	/// # Minimum Samples.
+	///
+	/// If unspecified, `MIN_SAMPLES` is used instead.
+	min_samples: Option<u32>,
+
+	/// # Timeout Limit.
+	///
+	/// If unspecified, the suite default, if any, or `DEFAULT_TIMEOUT` is
+	/// used instead.
+	timeout: Option<Duration>,
+
+	/// # Streaming Stats.
+	///
+	/// If unspecified, streaming is used only when the sample limit exceeds
+	/// `STREAMING_THRESHOLD`.
+	streaming: Option<bool>,
+
+	/// # Outlier Policy.
+	outliers: OutlierPolicy,
+
+	/// # Metric.
+	metric: Metric,
+
+	/// # Time Budget.
+	///
+	/// If set, the mean must come in under this.
+	expect_under: Option<Duration>,
+
+	/// # Display Unit.
+	///
+	/// If unspecified, the suite's unit is used instead.
+	unit: Option<TimeUnit>,
+
+	/// # Show Operations Per Second.
+	///
+	/// If unspecified, the suite's setting is used instead.
+	ops: Option<bool>,
+
+	/// # Collected Stats.
+	stats: Option<Result<Stats, BrunchError>>,
+
+	/// # Previous Stats.
+	baseline: Option<Stats>,
+
+	/// # Best Previous Stats.
+	///
+	/// This is only populated when comparing against the best run.
+	best: Option<Stats>,
+
+	/// # Measure CPU Time?
+	measure_cpu: bool,
+
+	/// # CPU Time Stats.
+	cpu: Option<Stats>,
+
+	/// # Allocations.
+	///
+	/// This is only populated when the tracking allocator is installed.
+	allocs: Option<Allocs>,
+
+	/// # Measure Peak Memory?
+	measure_memory: bool,
+
+	/// # Peak Memory.
+	rss: Option<MaxRss>,
+
+	/// # Previous Allocations.
+	baseline_allocs: Option<Allocs>,
+
+	/// # Performance Counters.
+	///
+	/// This is only populated when the `perf` feature is enabled and the
+	/// hardware counters are available.
+	perf: Option<Counters>,
+
+	/// # Previous Performance Counters.
+	baseline_perf: Option<Counters>,
+
+	/// # Configuration.
+	///
+	/// The effective settings, populated once the suite defaults and
+	/// overrides have been applied.
+	config: Option<Config>,
+
+	/// # Configuration Changes.
+	///
+	/// If the settings differ from those the baseline was recorded with, this
+	/// describes each difference. (The baseline is dropped in such cases.)
+	reconfigured: Vec<String>,
+
+	/// # Trend.
+	trend: Option<Trend>,
+
+	/// # Wall Time.
+	///
+	/// The total time spent running the benchmark, including any seed
+	/// generation.
+	elapsed: Duration,
+
+	/// # Sampler.
+	///
+	/// This runs and times a single sample.
+	sampler: Option<Sampler<'a>>,
+
+	/// # Verification Check.
+	///
+	/// If present, this is run once before sampling begins.
+	check: Option<Check<'a>>,
+
+	/// # Setup.
+	///
+	/// If present, this is run before the check and sampling.
+	setup: Option<Setup<'a>>,
+
+	/// # Cold Caches?
+	///
+	/// See [`Bench::cold`].
+	cold: bool,
+
+	/// # Chill.
+	///
+	/// If present, this is run (untimed) before each cold sample. See
+	/// [`Bench::cold_with`].
+	chill: Option<Chill<'a>>,
+
+	/// # Warm-Up.
+	///
+	/// See [`Bench::with_warmup`].
+	warmup: Duration,
+
+	/// # Time Spent Warming Up.
+	///
+	/// This covers the most recent call to `Bench::exec`, and is kept apart
+	/// so the suite budget can leave it out.
+	warmed: Duration,
+
+	/// # Timeout Limited by Suite Budget?
+	budgeted: bool,
+
+	/// # Retries.
+	///
+	/// The number of times the benchmark was re-sampled after coming back
+	/// `TooWild` or short. See [`Benches::retries`].
+	retried: u8,
+
+	/// # Partial Results.
+	///
+	/// This holds the samples collected so far when the suite is repeated.
+	partial: Option<Box<Partial>>,
+
+	/// # Round Spread.
+	///
+	/// When the suite is repeated, this holds the relative spread of the
+	/// per-round means.
+	spread: Option<f64>,
+
+	/// # Skip Reason.
+	///
+	/// If set, the benchmark is not run at all.
+	skip: Option<String>,
+
+	/// # Tags.
+	tags: Vec<String>,
+
+	/// # Raw Sample Directory.
+	///
+	/// If unspecified, `BRUNCH_RAW` is used instead, if set.
+	raw: Option<PathBuf>,
+
+	/// # Plot?
+	///
+	/// If true, the pruned samples are kept for plotting.
+	plot: bool,
+
+	/// # Plot Samples.
+	///
+	/// The pruned (sorted) samples, in seconds, awaiting plotting.
+	plot_samples: Option<Vec<f64>>,
+
+	/// # Scaling Series.
+	///
+	/// For benches created with [`Bench::scaling`], this holds the length (in
+	/// bytes) of the base name at the start of the name, and the input size.
+	scale: Option<(usize, usize)>,
+
+	/// # Custom Timer.
+	///
+	/// This is copied from the suite before running. See
+	/// [`Benches::with_timer`].
+	timer: Option<Rc<dyn Timer>>,
+
+	/// # Discard `Err` Samples?
+	///
+	/// See [`Bench::discard_errors`].
+	discard_errors: bool,
+
+	/// # `Err` Samples (and Total).
+	///
+	/// For fallible runners, the number of samples for which the callback
+	/// returned `Err`, and the total number taken.
+	errors: (u32, u32),
+}
+
+impl fmt::Debug for Bench<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Bench")
+			.field("name", &self.name)
+			.field("namespace", &self.namespace())
+			.field("samples", &self.samples)
+			.field("min_samples", &self.min_samples)
+			.field("timeout", &self.timeout)
+			.field("streaming", &self.streaming)
+			.field("outliers", &self.outliers)
+			.field("metric", &self.metric)
+			.field("expect_under", &self.expect_under)
+			.field("unit", &self.unit)
+			.field("ops", &self.ops)
+			.field("stats", &self.stats)
+			.field("baseline", &self.baseline)
+			.field("best", &self.best)
+			.field("measure_cpu", &self.measure_cpu)
+			.field("cpu", &self.cpu)
+			.field("allocs", &self.allocs)
+			.field("baseline_allocs", &self.baseline_allocs)
+			.field("perf", &self.perf)
+			.field("baseline_perf", &self.baseline_perf)
+			.field("measure_memory", &self.measure_memory)
+			.field("rss", &self.rss)
+			.field("trend", &self.trend)
+			.field("elapsed", &self.elapsed)
+			.field("budgeted", &self.budgeted)
+			.field("retried", &self.retried)
+			.field("spread", &self.spread)
+			.field("skip", &self.skip)
+			.field("tags", &self.tags)
+			.field("raw", &self.raw)
+			.field("plot", &self.plot)
+			.field("scale", &self.scale)
+			.field("discard_errors", &self.discard_errors)
+			.field("errors", &self.errors)
+			.field("cold", &self.cold)
+			.field("warmup", &self.warmup)
+			.field("warmed", &self.warmed)
+			.finish_non_exhaustive()
+	}
+}
+
+impl Bench<'_> {
+	#[must_use]
+	/// # New.
+	///
+	/// Instantiate a new benchmark with a name. The name can be anything, but
+	/// is intended to represent the method call itself, like `foo::bar(10)`.
+	///
+	/// Note: the names should be unique across all benchmarks, as they serve
+	/// as the key used when pulling "history". If you have two totally
+	/// different benchmarks named the same thing, the run-to-run change
+	/// reporting won't make any sense. ;)
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::{NiceU8, NiceU16};
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .run(|| NiceU8::from(0_u8)),
+    /// );
+    /// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the name is empty.
+	pub fn new<S>(name: S) -> Self
+	where S: AsRef<str> {
+		let name = normalize_name(name.as_ref());
+		assert!(! name.is_empty(), "Name is required.");
+		assert!(name.len() <= 65535, "Names cannot be longer than 65,535.");
+
+		Self {
+			name,
+			namespace: 0,
+			samples: None,
+			min_samples: None,
+			timeout: None,
+			streaming: None,
+			outliers: OutlierPolicy::Default,
+			metric: Metric::Mean,
+			expect_under: None,
+			unit: None,
+			ops: None,
+			stats: None,
+			baseline: None,
+			best: None,
+			measure_cpu: false,
+			cpu: None,
+			allocs: None,
+			baseline_allocs: None,
+			perf: None,
+			baseline_perf: None,
+			config: None,
+			reconfigured: Vec::new(),
+			measure_memory: false,
+			rss: None,
+			trend: None,
+			elapsed: Duration::ZERO,
+			sampler: None,
+			check: None,
+			setup: None,
+			cold: false,
+			chill: None,
+			warmup: Duration::ZERO,
+			warmed: Duration::ZERO,
+			budgeted: false,
+			retried: 0,
+			partial: None,
+			spread: None,
+			skip: None,
+			tags: Vec::new(),
+			raw: None,
+			plot: false,
+			plot_samples: None,
+			scale: None,
+			timer: None,
+			discard_errors: false,
+			errors: (0, 0),
+		}
+	}
+
+	#[must_use]
+	/// # New (Namespaced).
+	///
+	/// Instantiate a new benchmark with a namespace and name, like
+	/// `dactyl::NiceU8` and `from(0)`.
+	///
+	/// The two are joined with `::` to form the full name — used for history,
+	/// duplicate detection, etc. — but are kept separate for display
+	/// purposes: the namespace is dimmed and the name highlighted, rather
+	/// than leaving it to [`Bench::new`]'s guesswork. Benches sharing a
+	/// namespace are also labeled accordingly in the group summaries.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+	///     Bench::new_in("dactyl::NiceU8", "from(0)")
+	///         .run(|| NiceU8::from(0_u8)),
+	/// );
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if either part is empty, or the combined name
+	/// is too long.
+	pub fn new_in<S>(namespace: S, name: S) -> Self
+	where S: AsRef<str> {
+		let namespace = normalize_name(namespace.as_ref());
+		let name = normalize_name(name.as_ref());
+		assert!(! namespace.is_empty(), "Namespace is required.");
+		assert!(! name.is_empty(), "Name is required.");
+
+		let mut out = Self::new(format!("{namespace}::{name}"));
+		out.namespace = namespace.len();
+		out
+	}
+
+	#[must_use]
+	/// # Spacer.
+	///
+	/// This will render as a linebreak when printing results, useful if you
+	/// want to add visual separation between two different benchmarks.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::{NiceU8, NiceU16};
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .run(|| NiceU8::from(0_u8)),
+    ///
+    ///     Bench::spacer(),
+    ///
+    ///     Bench::new("dactyl::NiceU16::from(0)")
+    ///         .run(|| NiceU16::from(0_u16)),
+    /// );
+	/// ```
+	pub const fn spacer() -> Self {
+		Self {
+			name: String::new(),
+			namespace: 0,
+			samples: None,
+			min_samples: None,
+			timeout: None,
+			streaming: None,
+			outliers: OutlierPolicy::Default,
+			metric: Metric::Mean,
+			expect_under: None,
+			unit: None,
+			ops: None,
+			stats: None,
+			baseline: None,
+			best: None,
+			measure_cpu: false,
+			cpu: None,
+			allocs: None,
+			baseline_allocs: None,
+			perf: None,
+			baseline_perf: None,
+			config: None,
+			reconfigured: Vec::new(),
+			measure_memory: false,
+			rss: None,
+			trend: None,
+			elapsed: Duration::ZERO,
+			sampler: None,
+			check: None,
+			setup: None,
+			cold: false,
+			chill: None,
+			warmup: Duration::ZERO,
+			warmed: Duration::ZERO,
+			budgeted: false,
+			retried: 0,
+			partial: None,
+			spread: None,
+			skip: None,
+			tags: Vec::new(),
+			raw: None,
+			plot: false,
+			plot_samples: None,
+			scale: None,
+			timer: None,
+			discard_errors: false,
+			errors: (0, 0),
+		}
+	}
+
+	/// # Over Budget?
+	///
+	/// Returns `true` if the benchmark has a time budget and either exceeded
+	/// it or failed to produce stats.
+	fn over_budget(&self) -> bool {
+		self.skip.is_none() &&
+		self.expect_under.is_some_and(|max| match &self.stats {
+			Some(Ok(s)) => max < s.mean(),
+			_ => true,
+		})
+	}
+
+	#[must_use]
+	/// # Is Spacer?
+	///
+	/// Returns `true` if this is a [`Bench::spacer`] rather than an actual
+	/// benchmark.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::Bench;
+	///
+	/// assert!(Bench::spacer().is_spacer());
+	/// assert!(! Bench::new("String::len").is_spacer());
+	/// ```
+	pub fn is_spacer(&self) -> bool { self.name.is_empty() }
+
+	/// # Scaling Base Name.
+	///
+	/// Return the base name — sans `(n=…)` — for benches created with
+	/// [`Bench::scaling`].
+	fn scale_base(&self) -> Option<&str> {
+		self.scale.and_then(|(len, _)| self.name.get(..len))
+	}
+
+	#[must_use]
+	/// # Name.
+	///
+	/// Return the benchmark's name.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::Bench;
+	///
+	/// let bench = Bench::new("String::len").run(|| "Hello World".len());
+	/// assert_eq!(bench.name(), "String::len");
+	/// ```
+	pub fn name(&self) -> &str { &self.name }
+
+	/// # Is Pending?
+	///
+	/// Returns true if the benchmark has a runner but has not yet been run.
+	const fn is_pending(&self) -> bool {
+		self.stats.is_none() && self.sampler.is_some()
+	}
+
+	/// # Is Mid-Round?
+	///
+	/// Returns true if the benchmark is partway through an interleaved
+	/// round.
+	fn is_mid_round(&self) -> bool {
+		self.is_pending() && self.partial.as_ref().is_some_and(|p| p.round.taken != 0)
+	}
+
+	/// # Is Retryable?
+	///
+	/// Returns true if the benchmark came back wild, or short despite having
+	/// taken enough samples. See [`Benches::retries`].
+	fn is_retryable(&self) -> bool {
+		self.stats.as_ref().is_some_and(|res| retryable(res, self.errors.1, self.min_samples()))
+	}
+
+	#[must_use]
+	/// # With Time Limit.
+	///
+	/// By default, benches stop after reaching 2500 samples or 10 seconds,
+	/// whichever comes first.
+	///
+	/// This method can be used to override the time limit portion of that
+	/// equation.
+	///
+	/// The limit applies to the _measured_ time — the sum of the samples —
+	/// so slow seed generation won't eat into it. (As a safety net, sampling
+	/// will also stop if the overall wall time reaches ten times the limit.)
+	///
+	/// Note: the minimum cutoff time is half a second.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	/// use std::time::Duration;
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .with_timeout(Duration::from_secs(1))
+    ///         .run(|| NiceU8::from(0_u8))
+    /// );
+	/// ```
+	pub const fn with_timeout(mut self, timeout: Duration) -> Self {
+		self.timeout = Some(clamp_timeout(timeout));
+		self
+	}
+
+	#[must_use]
+	/// # With Sample Limit.
+	///
+	/// By default, benches stop after reaching 2500 samples or 10 seconds,
+	/// whichever comes first.
+	///
+	/// This method can be used to override the sample limit portion of that
+	/// equation.
+	///
+	/// Generally the default is a good sample size, but if your bench takes a
+	/// while to complete, you might want to use this method to shorten it up.
+	///
+	/// Conversely, for very fast benches, you can pass zero to collect as
+	/// many samples as fit within the time limit (up to a hard cap of
+	/// 16,777,216).
+	///
+	/// Note: the minimum number of samples is 100, but you should aim for at
+	/// least 150-200, because that minimum is applied _after_ outliers have
+	/// been removed from the set. (Genuinely slow benches can lower it with
+	/// [`Bench::with_min_samples`].)
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .with_samples(50_000)
+    ///         .run(|| NiceU8::from(0_u8)),
+    ///     Bench::new("dactyl::NiceU8::from(1)")
+    ///         .with_samples(0) // As many as fit in the timeout.
+    ///         .run(|| NiceU8::from(1_u8)),
+    /// );
+	/// ```
+	pub const fn with_samples(mut self, samples: u32) -> Self {
+		self.samples = Some(clamp_samples(samples));
+		self
+	}
+
+	#[must_use]
+	/// # With Minimum Samples.
+	///
+	/// By default, a benchmark needs at least 100 valid samples to produce
+	/// stats. For benchmarks that take seconds per iteration, that can mean
+	/// waiting around for many minutes, so this method can be used to lower
+	/// the bar, to as few as 10.
+	///
+	/// Fewer samples mean less confidence, though, so results below the usual
+	/// minimum are dimmed in the table and listed beneath it, and any change
+	/// has to be at least 5% — with non-overlapping confidence intervals —
+	/// to be reported.
+	///
+	/// Note: this only lowers the floor; use [`Bench::with_samples`] to
+	/// actually collect fewer samples.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("std::thread::sleep(2s)")
+	///         .with_samples(20)
+	///         .with_min_samples(20)
+	///         .with_timeout(std::time::Duration::from_secs(60))
+	///         .run(|| std::thread::sleep(std::time::Duration::from_secs(2))),
+	/// );
+	/// ```
+	pub const fn with_min_samples(mut self, min: u32) -> Self {
+		self.min_samples = Some(
+			if min < MIN_SAMPLES_FLOOR { MIN_SAMPLES_FLOOR }
+			else if MIN_SAMPLES < min { MIN_SAMPLES }
+			else { min }
+		);
+		self
+	}
+
+	#[must_use]
+	/// # Streaming Stats.
+	///
+	/// By default, every sample is kept in memory until the bench has
+	/// finished, at which point outliers are pruned and the stats crunched.
+	/// That's the most accurate approach, but for benches collecting more
+	/// than 100,000 samples, brunch switches to a streaming, constant-memory
+	/// approach instead, estimating the outlier boundaries from a random
+	/// subset of the samples.
+	///
+	/// This method can be used to force one approach or the other, regardless
+	/// of the sample limit.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .with_samples(50_000)
+    ///         .streaming(true)
+    ///         .run(|| NiceU8::from(0_u8))
+    /// );
+	/// ```
+	pub const fn streaming(mut self, streaming: bool) -> Self {
+		self.streaming = Some(streaming);
+		self
+	}
+
+	#[must_use]
+	/// # Dump Raw Samples.
+	///
+	/// Write every collected sample — _before_ outlier pruning — to a file in
+	/// this directory for external analysis, one whole number of nanoseconds
+	/// per line, preceded by a `#` header line with the name and sample
+	/// count.
+	///
+	/// The file is named after a sanitized version of the bench name, e.g.
+	/// `foo__bar_10_.txt` for `foo::bar(10)`, and is overwritten on each run.
+	///
+	/// This can also be enabled for every bench by setting the
+	/// `BRUNCH_RAW=/path/to/dir` environmental variable.
+	///
+	/// Note: streaming benches don't keep their samples around, so have
+	/// nothing to dump. See [`Bench::streaming`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+	///     Bench::new("dactyl::NiceU8::from(0)")
+	///         .dump_raw("target/brunch-raw")
+	///         .run(|| NiceU8::from(0_u8))
+	/// );
+	/// ```
+	pub fn dump_raw<P: AsRef<Path>>(mut self, dir: P) -> Self {
+		self.raw = Some(dir.as_ref().to_path_buf());
+		self
+	}
+
+	#[must_use]
+	/// # Measure CPU Time.
+	///
+	/// For benchmarks that do I/O or sleep, wall time conflates waiting with
+	/// computing. When enabled, the CPU time consumed by the sampling thread
+	/// is measured too, and displayed as an extra column in verbose mode.
+	///
+	/// Wall time remains the primary metric, and is the only thing saved to
+	/// history.
+	///
+	/// CPU time is currently supported on Linux, macOS, and Windows. (Note
+	/// that Windows' clock is rather coarse, so it is only really useful for
+	/// slower benchmarks there.) On other platforms, a warning is printed and
+	/// the column is omitted.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use std::time::Duration;
+	///
+	/// brunch::benches!(
+	///     Bench::new("std::thread::sleep(1ms)")
+	///         .measure_cpu(true)
+	///         .run(|| std::thread::sleep(Duration::from_millis(1)))
+	/// );
+	/// ```
+	pub const fn measure_cpu(mut self, measure: bool) -> Self {
+		self.measure_cpu = measure;
+		self
+	}
+
+	#[must_use]
+	/// # Measure Peak Memory.
+	///
+	/// For benchmarks that build large intermediate structures, the memory
+	/// high-water mark can matter as much as the time. When enabled, the
+	/// process's peak resident set size (max RSS) is checked before and after
+	/// the sampling loop, and displayed as an extra column along with how
+	/// much it grew during the run.
+	///
+	/// Note that the peak is process-wide and can only ever go up, so once
+	/// one benchmark has pushed it high, later ones may not register at all.
+	/// For accurate per-bench numbers, combine this with
+	/// [`Benches::isolated`], in which case each child process measures its
+	/// own peak instead.
+	///
+	/// Peak memory is currently supported on Linux, macOS, and Windows. On
+	/// other platforms, the column is simply omitted.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("vec![0_u8; 1_000_000]")
+	///         .measure_memory(true)
+	///         .run(|| vec![0_u8; 1_000_000])
+	/// );
+	/// ```
+	pub const fn measure_memory(mut self, measure: bool) -> Self {
+		self.measure_memory = measure;
+		self
+	}
+
+	#[must_use]
+	/// # With Outlier Policy.
+	///
+	/// By default, samples falling more than `1.5×` the range between the
+	/// 5th and 95th quantiles below or above it are pruned as outliers before
+	/// the stats are crunched.
+	///
+	/// This method can be used to loosen, tighten, or disable that pruning.
+	/// See [`OutlierPolicy`] for the options.
+	///
+	/// Either way, the number of samples kept versus collected is shown in
+	/// the Samples column.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Bench, OutlierPolicy};
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .with_outliers(OutlierPolicy::Iqr { low: 0.25, high: 0.75, mult: 3.0 })
+    ///         .run(|| NiceU8::from(0_u8))
+    /// );
+	/// ```
+	pub const fn with_outliers(mut self, policy: OutlierPolicy) -> Self {
+		self.outliers = policy;
+		self
+	}
+
+	#[must_use]
+	/// # With Metric.
+	///
+	/// By default, the plain mean of the valid samples is reported. This
+	/// method can be used to report a trimmed mean instead, which is less
+	/// sensitive to both the outlier policy and the occasional stall.
+	///
+	/// Combine with [`OutlierPolicy::None`] to skip pruning altogether and
+	/// rely on the trimming alone.
+	///
+	/// For short, CPU-bound benchmarks, [`Metric::Min`] can be used to
+	/// report the fastest valid sample instead. It takes the mean's place
+	/// throughout — the summary, the history, and the change — and the
+	/// column is relabeled accordingly.
+	///
+	/// Note: the metric is recorded in the history; if the baseline was
+	/// calculated using a different one, it is dropped as though the
+	/// configuration had changed.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Bench, Metric};
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .with_metric(Metric::TrimmedMean(0.1))
+    ///         .run(|| NiceU8::from(0_u8))
+    /// );
+	/// ```
+	pub const fn with_metric(mut self, metric: Metric) -> Self {
+		self.metric = metric;
+		self
+	}
+
+	#[must_use]
+	/// # Expect Under.
+	///
+	/// Set an absolute time budget for the benchmark. If the (pruned) mean
+	/// comes in over it — or the benchmark fails to produce any stats at all
+	/// — the row will be marked "BUDGET EXCEEDED" and the process will exit
+	/// with a non-zero code (`1`) after the summary has been printed.
+	///
+	/// Unlike the run-to-run changes, this doesn't depend on history, making
+	/// it suitable for hard latency requirements.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	/// use std::time::Duration;
+	///
+	/// brunch::benches!(
+	///     Bench::new("dactyl::NiceU8::from(0)")
+	///         .expect_under(Duration::from_nanos(500))
+	///         .run(|| NiceU8::from(0_u8))
+	/// );
+	/// ```
+	pub const fn expect_under(mut self, budget: Duration) -> Self {
+		self.expect_under = Some(budget);
+		self
+	}
+
+	#[must_use]
+	/// # With Display Unit.
+	///
+	/// Force this benchmark's times into a particular unit in the results
+	/// table, overriding the suite-wide [`Benches::with_unit`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Bench, TimeUnit};
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+	///     Bench::new("dactyl::NiceU8::from(0)")
+	///         .with_unit(TimeUnit::Nanos)
+	///         .run(|| NiceU8::from(0_u8))
+	/// );
+	/// ```
+	pub const fn with_unit(mut self, unit: TimeUnit) -> Self {
+		self.unit = Some(unit);
+		self
+	}
+
+	#[must_use]
+	/// # Show Operations Per Second.
+	///
+	/// Include (or exclude) this benchmark in the _Ops/s_ column, overriding
+	/// the suite-wide [`Benches::show_ops`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+	///     Bench::new("dactyl::NiceU8::from(0)")
+	///         .show_ops(true)
+	///         .run(|| NiceU8::from(0_u8))
+	/// );
+	/// ```
+	pub const fn show_ops(mut self, show: bool) -> Self {
+		self.ops = Some(show);
+		self
+	}
+
+	#[must_use]
+	/// # Discard `Err` Samples.
+	///
+	/// By default, if a fallible callback — see [`Bench::run_fallible`] —
+	/// returns `Err` for any of its samples, the whole benchmark is reported
+	/// as a [`BrunchError::Fallible`] error, and nothing is saved to history.
+	///
+	/// Pass `true` to instead drop the `Err` samples and crunch the rest,
+	/// with a warning noting how many were left out. (If every sample fails,
+	/// it is still an error.)
+	///
+	/// This has no effect on infallible runners.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("u8::from_str(\"13\")")
+	///         .discard_errors(true)
+	///         .run_fallible(|| "13".parse::<u8>())
+	/// );
+	/// ```
+	pub const fn discard_errors(mut self, discard: bool) -> Self {
+		self.discard_errors = discard;
+		self
+	}
+
+	#[must_use]
+	/// # Cold Caches.
+	///
+	/// By default, samples are taken back to back, so the numbers reflect
+	/// steady-state performance with warm caches. That's overly rosy for code
+	/// whose real-world callers never see it twice in a row — startup paths,
+	/// rarely-taken branches, etc.
+	///
+	/// Pass `true` to evict the data cache before each sample by touching a
+	/// large (64 MiB) scratch buffer. This happens outside the timed region,
+	/// but it does take a few milliseconds per sample, so unless otherwise
+	/// specified, only 250 samples are collected.
+	///
+	/// Cold runs aren't comparable to warm ones; switching modes resets the
+	/// benchmark's baseline (with a warning). See also [`Bench::cold_with`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("u64::from_str(\"12345\")")
+	///         .cold(true)
+	///         .run(|| "12345".parse::<u64>())
+	/// );
+	/// ```
+	pub const fn cold(mut self, cold: bool) -> Self {
+		self.cold = cold;
+		self
+	}
+
+	#[must_use]
+	/// # With Warm-Up.
+	///
+	/// Run the callback — untimed — for (at least) the given duration before
+	/// collecting any samples, giving the caches, allocator, branch
+	/// predictors, etc., a chance to settle so the first few samples aren't
+	/// any noisier than the rest.
+	///
+	/// The warm-up doesn't count toward the timeout — or the suite's total
+	/// budget — and only happens once, even if the suite is repeated or
+	/// interleaved. (It is skipped entirely if the budget leaves no time for
+	/// sampling.) It is noted beneath the results in verbose mode.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use std::time::Duration;
+	///
+	/// brunch::benches!(
+	///     Bench::new("Vec::with_capacity(1024)")
+	///         .with_warmup(Duration::from_millis(500))
+	///         .run(|| Vec::<u8>::with_capacity(1024))
+	/// );
+	/// ```
+	pub const fn with_warmup(mut self, warmup: Duration) -> Self {
+		self.warmup = warmup;
+		self
+	}
+
+	#[must_use]
+	/// # Skip.
+	///
+	/// Mark the benchmark as skipped — e.g. because a fixture is missing or
+	/// the CPU lacks the instructions it needs — with the reason shown in
+	/// the results table in its place.
+	///
+	/// Skipped benchmarks are never run — any runner method is ignored — and
+	/// leave their history untouched, neither updating nor pruning it. They
+	/// still occupy their name, though, so aren't a way around duplicates.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("usize::checked_add(2)")
+	///         .skip("not today")
+	///         .run(|| 2_usize.checked_add(2))
+	/// );
+	/// ```
+	pub fn skip<S: AsRef<str>>(mut self, reason: S) -> Self {
+		if ! self.is_spacer() {
+			self.skip = Some(reason.as_ref().trim().to_owned());
+			self.sampler = None;
+			self.check = None;
+			self.setup = None;
+			self.chill = None;
+		}
+		self
+	}
+
+	#[must_use]
+	/// # Is Skipped?
+	///
+	/// Returns `true` if the benchmark has been marked as skipped via
+	/// [`Bench::skip`].
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::Bench;
+	///
+	/// assert!(Bench::new("String::len").skip("Nope.").is_skipped());
+	/// assert!(! Bench::new("String::len").is_skipped());
+	/// ```
+	pub const fn is_skipped(&self) -> bool { self.skip.is_some() }
+
+	#[must_use]
+	/// # Tag.
+	///
+	/// Add a tag — e.g. `slow` or `serde` — to the benchmark for use with
+	/// [`Benches::filter_tags`] and [`Benches::skip_tags`] (or `BRUNCH_TAGS`
+	/// and `BRUNCH_SKIP_TAGS`). This can be called as many times as needed.
+	///
+	/// Tags are trimmed and lowercased, and have no bearing on the history.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("serde_json::to_string(&big)")
+	///         .tag("slow")
+	///         .tag("serde")
+	///         .run(|| "Hello World".repeat(1000))
+	/// );
+	/// ```
+	pub fn tag<S: AsRef<str>>(mut self, tag: S) -> Self {
+		if let Some(tag) = normalize_tag(tag.as_ref()) {
+			if ! self.is_spacer() && ! self.tags.contains(&tag) { self.tags.push(tag); }
+		}
+		self
+	}
+
+	#[must_use]
+	/// # Tags.
+	///
+	/// Return the benchmark's tags, if any, in the order they were added.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::Bench;
+	///
+	/// let bench = Bench::new("String::len").tag("Fast").tag(" strings ");
+	/// assert_eq!(bench.tags(), ["fast", "strings"]);
+	/// ```
+	pub fn tags(&self) -> &[String] { &self.tags }
+
+	/// # Sample Limit.
+	///
+	/// This is never less than the minimum.
+	const fn samples(&self) -> NonZeroU32 {
+		let samples = match self.samples {
+			Some(n) => n,
+			None if self.cold => DEFAULT_COLD_SAMPLES,
+			None => DEFAULT_SAMPLES,
+		};
+		match NonZeroU32::new(self.min_samples()) {
+			Some(min) if samples.get() < min.get() => min,
+			_ => samples,
+		}
+	}
+
+	/// # Minimum Samples.
+	const fn min_samples(&self) -> u32 {
+		match self.min_samples {
+			Some(n) => n,
+			None => MIN_SAMPLES,
+		}
+	}
+
+	/// # Namespace.
+	///
+	/// Return the namespace, if set via [`Bench::new_in`].
+	fn namespace(&self) -> Option<&str> {
+		if self.namespace == 0 { None }
+		else { self.name.get(..self.namespace) }
+	}
+
+	/// # Time Limit.
+	const fn timeout(&self) -> Duration {
+		match self.timeout {
+			Some(t) => t,
+			None => DEFAULT_TIMEOUT,
+		}
+	}
+
+	/// # Fit Budget.
+	///
+	/// Lower the timeout to the given slice of the suite budget, if needed.
+	const fn fit_budget(&mut self, slice: Duration) {
+		if slice.as_nanos() < self.timeout().as_nanos() {
+			self.timeout = Some(slice);
+			self.budgeted = true;
+		}
+	}
+
+	/// # Streaming?
+	const fn is_streaming(&self) -> bool {
+		match self.streaming {
+			Some(s) => s,
+			None => STREAMING_THRESHOLD < self.samples().get(),
+		}
+	}
+}
+
+impl<'a> Bench<'a> {
+	#[must_use]
+	/// # Cold Caches (Custom).
+	///
+	/// This works like [`Bench::cold`] — which it implies — but also runs the
+	/// callback before each sample, for any domain-specific cooling-off, like
+	/// dropping an mmap or clearing an internal cache.
+	///
+	/// As with the scratch buffer, the callback runs outside the timed
+	/// region.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use std::sync::Mutex;
+	///
+	/// static CACHE: Mutex<Vec<u64>> = Mutex::new(Vec::new());
+	///
+	/// brunch::benches!(
+	///     Bench::new("lookup()")
+	///         .cold_with(|| CACHE.lock().unwrap().clear())
+	///         .run(|| {
+	///             let mut cache = CACHE.lock().unwrap();
+	///             if cache.is_empty() { cache.extend(0..1000); }
+	///             cache.iter().sum::<u64>()
+	///         })
+	/// );
+	/// ```
+	pub fn cold_with<F>(mut self, cb: F) -> Self
+	where F: FnMut() + 'a {
+		if ! self.is_spacer() && self.skip.is_none() {
+			self.cold = true;
+			self.chill = Some(Box::new(cb));
+		}
+		self
+	}
+
+	#[must_use]
+	/// # Run Benchmark!
+	///
+	/// Use this method to execute a benchmark for a callback that does not
+	/// require any external arguments.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(0)")
+    ///         .run(|| NiceU8::from(0_u8))
+    /// );
+	/// ```
+	pub fn run<F, O>(mut self, mut cb: F) -> Self
+	where F: FnMut() -> O + 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		self.sampler.replace(Box::new(move |cpu| {
+			let watch = Stopwatch::start(cpu);
+			let out = black_box(cb());
+			let lap = watch.stop();
+
+			// Drop the output only after the clock has stopped; a String or
+			// Vec shouldn't be charged for its deallocation when a usize
+			// isn't.
+			drop(out);
+			lap
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Seeded Benchmark!
+	///
+	/// Use this method to execute a benchmark for a callback seeded with the
+	/// provided value.
+	///
+	/// For seeds that don't implement `Clone`, use [`Bench::run_seeded_with`]
+	/// instead.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(13)")
+    ///         .run_seeded(13_u8, |v| NiceU8::from(v))
+    /// );
+	/// ```
+	pub fn run_seeded<F, I, O>(self, seed: I, mut cb: F) -> Self
+	where F: FnMut(I) -> O + 'a, I: Clone + 'a {
+		self.bench(move |b| b.iter_batched(|| seed.clone(), &mut cb))
+	}
+
+	#[must_use]
+	/// # Run Callback-Seeded Benchmark!
+	///
+	/// Use this method to execute a benchmark for a callback seeded with the
+	/// result of the provided method.
+	///
+	/// For seeds that implement `Clone`, use [`Bench::run_seeded`] instead.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use dactyl::NiceU8;
+	///
+	/// fn make_num() -> u8 { 13_u8 }
+	///
+	/// brunch::benches!(
+    ///     Bench::new("dactyl::NiceU8::from(13)")
+    ///         .run_seeded_with(make_num, |v| NiceU8::from(v))
+    /// );
+	/// ```
+	pub fn run_seeded_with<F1, F2, I, O>(self, mut seed: F1, mut cb: F2) -> Self
+	where F1: FnMut() -> I + 'a, F2: FnMut(I) -> O + 'a {
+		self.bench(move |b| b.iter_batched(&mut seed, &mut cb))
+	}
+
+	#[must_use]
+	/// # Run Benchmark With a Bencher!
+	///
+	/// Use this method when none of the fixed `run*` shapes fit — e.g. to
+	/// amortize a mutation and its undo, or to time only part of the work.
+	///
+	/// The callback is called once per sample with a [`Bencher`], and must
+	/// in turn call exactly one of its methods — [`Bencher::iter`],
+	/// [`Bencher::iter_batched`], or [`Bencher::iter_custom`] — to take the
+	/// measurement. The results feed into the same stats, timeouts, and
+	/// history as any other runner.
+	///
+	/// (Coming from `criterion`? This should feel familiar, except each
+	/// sample is a single iteration.)
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("Vec::sort")
+	///         .bench(|b| b.iter_batched(
+	///             || vec![5_u8, 3, 1, 4, 2],
+	///             |mut v| { v.sort(); v },
+	///         ))
+	/// );
+	/// ```
+	pub fn bench<F>(mut self, mut cb: F) -> Self
+	where F: FnMut(&mut Bencher) + 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		self.sampler.replace(Box::new(move |cpu| {
+			let mut b = Bencher::new(cpu);
+			cb(&mut b);
+			b.finish()
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Fallible Benchmark!
+	///
+	/// Use this method to execute a benchmark for a callback that returns a
+	/// `Result`.
+	///
+	/// A bug that makes the callback bail early would otherwise look like a
+	/// thrilling speedup, so each sample's outcome is checked — outside the
+	/// timed region — and any `Err`s counted. If there are any, the
+	/// benchmark is reported as a [`BrunchError::Fallible`] error and its
+	/// history left alone, unless [`Bench::discard_errors`] says otherwise.
+	///
+	/// Either way, the count is available afterward via
+	/// [`BenchResult::errors`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("u8::from_str(\"13\")")
+	///         .run_fallible(|| "13".parse::<u8>())
+	/// );
+	/// ```
+	pub fn run_fallible<F, T, E>(mut self, mut cb: F) -> Self
+	where F: FnMut() -> Result<T, E> + 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		self.sampler.replace(Box::new(move |cpu| {
+			let watch = Stopwatch::start(cpu);
+			let res = black_box(cb());
+			let lap = watch.stop().with_err(res.is_err());
+			drop(res);
+			lap
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Seeded Fallible Benchmark!
+	///
+	/// This works like [`Bench::run_fallible`], except the callback is
+	/// seeded with the provided value, as with [`Bench::run_seeded`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("u8::from_str(\"13\")")
+	///         .run_fallible_seeded("13", |v| v.parse::<u8>())
+	/// );
+	/// ```
+	pub fn run_fallible_seeded<F, I, T, E>(mut self, seed: I, mut cb: F) -> Self
+	where F: FnMut(I) -> Result<T, E> + 'a, I: Clone + 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		self.sampler.replace(Box::new(move |cpu| {
+			let seed2 = seed.clone();
+			let watch = Stopwatch::start(cpu);
+			let res = black_box(cb(seed2));
+			let lap = watch.stop().with_err(res.is_err());
+			drop(res);
+			lap
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Callback-Seeded Fallible Benchmark!
+	///
+	/// This works like [`Bench::run_fallible`], except the callback is
+	/// seeded with the result of the provided method, as with
+	/// [`Bench::run_seeded_with`].
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// fn make_str() -> String { "13".to_owned() }
+	///
+	/// brunch::benches!(
+	///     Bench::new("u8::from_str(\"13\")")
+	///         .run_fallible_seeded_with(make_str, |v| v.parse::<u8>())
+	/// );
+	/// ```
+	pub fn run_fallible_seeded_with<F1, F2, I, T, E>(mut self, mut seed: F1, mut cb: F2) -> Self
+	where F1: FnMut() -> I + 'a, F2: FnMut(I) -> Result<T, E> + 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		self.sampler.replace(Box::new(move |cpu| {
+			let seed2 = seed();
+			let watch = Stopwatch::start(cpu);
+			let res = black_box(cb(seed2));
+			let lap = watch.stop().with_err(res.is_err());
+			drop(res);
+			lap
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Indexed Benchmark!
+	///
+	/// Use this method to execute a benchmark for a callback that takes the
+	/// (zero-based) sample index as its argument, e.g. to pick a distinct
+	/// input from a pre-built corpus for each sample.
+	///
+	/// The index is tracked outside the timed region, but passing it along
+	/// is — necessarily — inside; it's only a couple of instructions, but
+	/// worth keeping in mind for the very fastest of benchmarks.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("usize::checked_pow(i)")
+	///         .run_indexed(|i| 2_usize.checked_pow(u32::try_from(i).unwrap_or(0)))
+	/// );
+	/// ```
+	pub fn run_indexed<F, O>(mut self, mut cb: F) -> Self
+	where F: FnMut(usize) -> O + 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		let mut idx = 0_usize;
+		self.sampler.replace(Box::new(move |cpu| {
+			let i = idx;
+			idx = idx.wrapping_add(1);
+			let watch = Stopwatch::start(cpu);
+			let out = black_box(cb(i));
+			let lap = watch.stop();
+			drop(out);
+			lap
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Seeded Indexed Benchmark!
+	///
+	/// This works like [`Bench::run_indexed`], except the callback also
+	/// receives a reference to the seed, making it easy to index into a
+	/// corpus without cloning it.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// fn main() {
+	///     let corpus: Vec<String> = (0..100).map(|n| n.to_string()).collect();
+	///
+	///     brunch::benches!(
+	///         inline:
+	///
+	///         Bench::new("str::parse::<u8>()")
+	///             .run_seeded_indexed(corpus, |i, c| c[i % c.len()].parse::<u8>()),
+	///     );
+	/// }
+	/// ```
+	pub fn run_seeded_indexed<F, I, O>(mut self, seed: I, mut cb: F) -> Self
+	where F: FnMut(usize, &I) -> O + 'a, I: 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		let mut idx = 0_usize;
+		self.sampler.replace(Box::new(move |cpu| {
+			let i = idx;
+			idx = idx.wrapping_add(1);
+			let watch = Stopwatch::start(cpu);
+			let out = black_box(cb(i, &seed));
+			let lap = watch.stop();
+			drop(out);
+			lap
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Self-Timed Benchmark!
+	///
+	/// Use this method for work that can't be timed from the outside — GPU
+	/// kernels timed by device events, sections of a larger operation, etc.
+	/// Rather than bracketing each call with its own clock, `Brunch` records
+	/// the `Duration` the callback returns as the sample.
+	///
+	/// Everything else works as usual: the sample limit, outlier pruning,
+	/// stats, history, etc. The timeout, however, is measured against the
+	/// wall time around each call, so a callback that under- or over-reports
+	/// can't make the benchmark run forever (or not at all).
+	///
+	/// Note: the reported durations are taken at face value; a stray zero or
+	/// absurdly large value will usually be pruned as an outlier, but a
+	/// callback that _consistently_ reports nonsense will produce nonsense
+	/// results.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use std::time::Instant;
+	///
+	/// brunch::benches!(
+	///     Bench::new("Vec::with_capacity(1024)")
+	///         .run_timed(|| {
+	///             let now = Instant::now();
+	///             let v = Vec::<u8>::with_capacity(1024);
+	///             let elapsed = now.elapsed();
+	///             drop(v); // Don't count the deallocation.
+	///             elapsed
+	///         })
+	/// );
+	/// ```
+	pub fn run_timed<F>(mut self, mut cb: F) -> Self
+	where F: FnMut() -> Duration + 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		self.sampler.replace(Box::new(move |cpu| {
+			let watch = Stopwatch::start(cpu);
+			let reported = black_box(cb());
+			watch.stop().with_reported(reported)
+		}));
+
+		self
+	}
+
+	#[must_use]
+	/// # Run Benchmark With Shared Context!
+	///
+	/// Use this method to execute a benchmark for a callback that borrows a
+	/// shared [`Context`], such as a large dataset used by several
+	/// benchmarks.
+	///
+	/// The context is built — if it hasn't been already — before the
+	/// benchmark starts, so the construction time never counts against its
+	/// samples or timeout. See [`Benches::with_context`] and [`Context::new`]
+	/// for more details.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::{Bench, Context};
+	///
+	/// fn main() {
+	///     let data = Context::new(|| (0..1_000_000_u32).collect::<Vec<_>>());
+	///
+	///     brunch::benches!(
+	///         inline:
+	///
+	///         Bench::new("Vec::iter().sum()")
+	///             .run_with_context(&data, |v| v.iter().copied().map(u64::from).sum::<u64>()),
+	///     );
+	/// }
+	/// ```
+	pub fn run_with_context<F, T, O>(mut self, ctx: &Context<'a, T>, mut cb: F) -> Self
+	where F: FnMut(&T) -> O + 'a, T: 'a {
+		if self.is_spacer() || self.skip.is_some() { return self; }
+
+		let ctx2 = ctx.clone();
+		self.setup.replace(Box::new(move || ctx2.init()));
+
+		let ctx = ctx.clone();
+		self.sampler.replace(Box::new(move |cpu| ctx.with(|v| {
+			let watch = Stopwatch::start(cpu);
+			let out = black_box(cb(v));
+			let lap = watch.stop();
+			drop(out);
+			lap
+		})));
+
+		self
+	}
+
+	#[must_use]
+	/// # Scaling Series.
+	///
+	/// Build one benchmark per input size — named like `sort(n=10000)`, so
+	/// each gets its own history — to get a feel for how the callback
+	/// scales.
+	///
+	/// For every sample, the `seed` method is called with the size to
+	/// generate a fresh input — outside the timed region — which is then
+	/// passed to the callback.
+	///
+	/// Once the series has run, the means are fit against `n`, `n log n`,
+	/// and `n²` (least squares), and the best-fitting complexity class and
+	/// its R² are reported in a summary row beneath the per-size rows. Fits
+	/// with an R² below `0.9` are reported as inconclusive.
+	///
+	/// At least three distinct sizes are needed for a fit, but the more the
+	/// merrier; spreading them across a few orders of magnitude helps too.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// fn make_input(n: usize) -> Vec<u32> {
+	///     (0..n).map(|i| (i as u32).wrapping_mul(2_654_435_761)).collect()
+	/// }
+	///
+	/// brunch::benches!(
+	///     Bench::scaling(
+	///         "sort",
+	///         [1_000, 10_000, 100_000],
+	///         make_input,
+	///         |mut v| { v.sort_unstable(); v },
+	///     ),
+	/// );
+	/// ```
+	///
+	/// ## Panics
+	///
+	/// This method will panic if the name is empty.
+	pub fn scaling<S, N, F1, F2, I, O>(name: S, sizes: N, seed: F1, cb: F2) -> Vec<Self>
+	where
+		S: AsRef<str>,
+		N: IntoIterator<Item=usize>,
+		F1: FnMut(usize) -> I + 'a,
+		F2: FnMut(I) -> O + 'a,
+	{
+		let base = normalize_name(name.as_ref());
+		assert!(! base.is_empty(), "Name is required.");
+
+		let seed = Rc::new(RefCell::new(seed));
+		let cb = Rc::new(RefCell::new(cb));
+		sizes.into_iter()
+			.map(|n| {
+				let (seed, cb) = (Rc::clone(&seed), Rc::clone(&cb));
+				let mut out = Self::new(format!("{base}(n={n})"));
+				out.scale = Some((base.len(), n));
+				out.sampler.replace(Box::new(move |cpu| {
+					let seed2 = seed.borrow_mut()(n);
+					let mut cb2 = cb.borrow_mut();
+					let watch = Stopwatch::start(cpu);
+					let out = black_box(cb2(seed2));
+					let lap = watch.stop();
+					drop(out);
+					lap
+				}));
+				out
+			})
+			.collect()
+	}
+
+	#[must_use]
+	/// # Verify Output.
+	///
+	/// Guard against benchmarking the wrong answer by checking the output
+	/// first. The callback is run once — outside the timed loop, and not
+	/// counted as a sample — and its output passed to the verifier; if that
+	/// returns `false`, the benchmark is reported as an error instead of
+	/// being run, and nothing is saved to history.
+	///
+	/// This must come last, right before the runner method.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("usize::checked_add(2)")
+	///         .verify(|v: &Option<usize>| *v == Some(4))
+	///         .run(|| 2_usize.checked_add(2))
+	/// );
+	/// ```
+	pub fn verify<O, V>(self, verifier: V) -> Verified<'a, O>
+	where V: FnOnce(&O) -> bool + 'a {
+		Verified { bench: self, verifier: Box::new(verifier) }
+	}
+}
+
+
+
+/// # Verified Benchmark.
+///
+/// This is a [`Bench`] with a pending output verifier, returned by
+/// [`Bench::verify`]. Call one of the runner methods to finish it off.
+pub struct Verified<'a, O> {
+	/// # Benchmark.
+	bench: Bench<'a>,
+
+	/// # Verifier.
+	verifier: Box<dyn FnOnce(&O) -> bool + 'a>,
+}
+
+impl<O> fmt::Debug for Verified<'_, O> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Verified")
+			.field("bench", &self.bench)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, O: 'a> Verified<'a, O> {
+	#[must_use]
+	/// # Run Benchmark!
+	///
+	/// This works exactly like [`Bench::run`], except the output is verified
+	/// first.
+	pub fn run<F>(self, cb: F) -> Bench<'a>
+	where F: FnMut() -> O + 'a {
+		let Self { mut bench, verifier } = self;
+		if bench.is_spacer() || bench.skip.is_some() { return bench; }
+
+		// The callback is shared, but borrowed outside the timed region.
+		let cb = Rc::new(RefCell::new(cb));
+		let cb2 = Rc::clone(&cb);
+		bench.check.replace(Box::new(move || {
+			let out = (*cb2.borrow_mut())();
+			verifier(&out)
+		}));
+		bench.sampler.replace(Box::new(move |cpu| {
+			let mut cb = cb.borrow_mut();
+			let watch = Stopwatch::start(cpu);
+			let out = black_box((*cb)());
+			let lap = watch.stop();
+			drop(out);
+			lap
+		}));
+
+		bench
+	}
+
+	#[must_use]
+	/// # Run Seeded Benchmark!
+	///
+	/// This works exactly like [`Bench::run_seeded`], except the output is
+	/// verified first.
+	pub fn run_seeded<F, I>(self, seed: I, cb: F) -> Bench<'a>
+	where F: FnMut(I) -> O + 'a, I: Clone + 'a {
+		let Self { mut bench, verifier } = self;
+		if bench.is_spacer() || bench.skip.is_some() { return bench; }
+
+		let cb = Rc::new(RefCell::new(cb));
+		let cb2 = Rc::clone(&cb);
+		let seed2 = seed.clone();
+		bench.check.replace(Box::new(move || {
+			let out = (*cb2.borrow_mut())(seed2);
+			verifier(&out)
+		}));
+		bench.sampler.replace(Box::new(move |cpu| {
+			let mut cb = cb.borrow_mut();
+			let seed2 = seed.clone();
+			let watch = Stopwatch::start(cpu);
+			let out = black_box((*cb)(seed2));
+			let lap = watch.stop();
+			drop(out);
+			lap
+		}));
+
+		bench
+	}
+
+	#[must_use]
+	/// # Run Callback-Seeded Benchmark!
+	///
+	/// This works exactly like [`Bench::run_seeded_with`], except the output
+	/// is verified first.
+	pub fn run_seeded_with<F1, F2, I>(self, seed: F1, cb: F2) -> Bench<'a>
+	where F1: FnMut() -> I + 'a, F2: FnMut(I) -> O + 'a {
+		let Self { mut bench, verifier } = self;
+		if bench.is_spacer() || bench.skip.is_some() { return bench; }
+
+		let seed = Rc::new(RefCell::new(seed));
+		let seed2 = Rc::clone(&seed);
+		let cb = Rc::new(RefCell::new(cb));
+		let cb2 = Rc::clone(&cb);
+		bench.check.replace(Box::new(move || {
+			let out = (*cb2.borrow_mut())((*seed2.borrow_mut())());
+			verifier(&out)
+		}));
+		bench.sampler.replace(Box::new(move |cpu| {
+			let mut cb = cb.borrow_mut();
+			let seed2 = (*seed.borrow_mut())();
+			let watch = Stopwatch::start(cpu);
+			let out = black_box((*cb)(seed2));
+			let lap = watch.stop();
+			drop(out);
+			lap
+		}));
+
+		bench
+	}
+}
+
+impl Bench<'_> {
+	/// # Record.
+	///
+	/// Work out the trend and add the results to the history, if
+	/// successful.
+	fn record(&mut self, history: &mut History) {
+		if let Some(Ok(s)) = self.stats {
+			// Comparisons against a differently-configured (or averaged)
+			// baseline would be apples to oranges, so drop it.
+			if let Some((old, new)) = history.config(&self.name).zip(self.config) {
+				self.reconfigured = new.diff(&old);
+			}
+			let metric = self.metric.sanitize();
+			if let Some(old) = history.metric(&self.name).filter(|m| *m != metric) {
+				self.reconfigured.push(format!("metric {old} \u{2192} {metric}"));
+			}
+			if ! self.reconfigured.is_empty() {
+				self.baseline = None;
+				self.baseline_allocs = None;
+				self.baseline_perf = None;
+				self.best = None;
+			}
+
+			let mut runs =
+				if self.reconfigured.is_empty() { history.runs(&self.name) }
+				else { Vec::new() };
+			runs.push(s);
+			self.trend = Trend::new(&runs);
+			history.insert(&self.name, s, self.metric.sanitize(), self.allocs, self.perf, self.config);
+		}
+	}
+
+	/// # Execute.
+	///
+	/// Run the benchmark — if it has a runner and hasn't already been run or
+	/// failed — collecting samples until either the sample or time limit has
+	/// been reached, and crunch the results.
+	///
+	/// When the suite is repeated, this is called once per round, with the
+	/// samples set aside in between; they're only crunched after the `last`.
+	///
+	/// When interleaved, each round is further divided into slices of (at
+	/// most) `chunk` samples, with the state likewise set aside between
+	/// calls until the round is complete.
+	#[expect(clippy::too_many_lines, reason = "It is what it is.")]
+	fn exec(&mut self, progress: &mut Progress, last: bool, chunk: Option<u32>) {
+		self.warmed = Duration::ZERO;
+		if self.stats.is_some() { return; }
+		let Some(mut cb) = self.sampler.take() else { return; };
+
+		// Build any shared context first, well clear of the timer.
+		if let Some(setup) = self.setup.as_ref() {
+			if let Err(e) = catch_panic(setup) {
+				self.stats.replace(Err(BrunchError::Panicked(e)));
+				return;
+			}
+		}
+
+		// Check the output first, if required.
+		if let Some(check) = self.check.take() {
+			match catch_panic(check) {
+				Ok(true) => {},
+				Ok(false) => {
+					self.stats.replace(Err(BrunchError::Unverified));
+					return;
+				},
+				Err(e) => {
+					self.stats.replace(Err(BrunchError::Panicked(e)));
+					return;
+				},
+			}
+		}
+
+		// Get the scratch buffer in place before any memory is measured.
+		let is_cold = self.cold;
+		if is_cold { cold::chill(); }
+
+		let samples = self.samples().get();
+		let timeout = self.timeout();
+		let fresh = self.partial.is_none();
+		let Partial { mut times, mut cpu_times, mut allocs, mut perf, rss_before, mut means, mut errors, round } =
+			if let Some(p) = self.partial.take() { *p }
+			else {
+				let new_samples = ||
+					if self.is_streaming() { Samples::Stream(Stream::default()) }
+					else { Samples::Exact(Vec::with_capacity(usize::saturating_from(samples.min(MAX_RESERVE)))) };
+				Partial {
+					times: new_samples(),
+					cpu_times: if self.measure_cpu { Some(new_samples()) } else { None },
+					allocs: Tally::default(),
+					perf: perf::Tally::default(),
+					rss_before: if self.measure_memory { rss::max_rss() } else { None },
+					means: Vec::new(),
+					errors: (0, 0),
+					round: Round::default(),
+				}
+			};
+
+		let _timer = TimerGuard::new(self.timer.clone());
+
+		// Warm things up the first time through, before the clock starts, so
+		// long as there's time left to measure anything afterward.
+		if fresh && ! self.warmup.is_zero() && ! timeout.is_zero() {
+			let warmup = self.warmup;
+			let start = Tick::now();
+			let res = catch_panic(|| {
+				while start.elapsed() < warmup {
+					let _lap = cb(false);
+					if signal::interrupted() { return false; }
+				}
+				true
+			});
+			self.warmed = start.elapsed();
+			self.elapsed += self.warmed;
+			match res {
+				Ok(true) => {},
+				Ok(false) => {
+					self.stats.replace(Err(BrunchError::Interrupted));
+					return;
+				},
+				Err(e) => {
+					self.stats.replace(Err(BrunchError::Panicked(e)));
+					return;
+				},
+			}
+		}
+
+		let now = Tick::now();
+		let ceiling = timeout.saturating_mul(WALL_CEILING);
+		let discard = self.discard_errors;
+		let Round { mut taken, mut kept, mut spent, wall: before } = round;
+		let mut stalled = false;
+		let mut timed_out = false;
+		let mut chill = self.chill.take();
+
+		// A panic shouldn't take the whole suite down with it.
+		let res = catch_panic(|| {
+			for _ in 0..samples.saturating_sub(taken).min(chunk.unwrap_or(u32::MAX)) {
+				// Cool things off first, well clear of the timer.
+				if is_cold {
+					cold::chill();
+					if let Some(c) = chill.as_mut() { c(); }
+				}
+
+				let lap = cb(cpu_times.is_some());
+				let wall = lap.wall();
+				taken += 1;
+				spent = spent.saturating_add(lap.spent());
+				errors = (errors.0 + u32::from(lap.is_err()), errors.1 + 1);
+
+				// Keep it unless it's an Err we've been asked to drop.
+				if ! (discard && lap.is_err()) {
+					times.push(wall);
+					kept = (kept.0.saturating_add(wall), kept.1 + 1);
+					if let Some(c) = cpu_times.as_mut() {
+						// The clock was already tested, but just in case…
+						if let Some(cpu) = lap.cpu() { c.push(cpu); }
+						else { cpu_times = None; }
+					}
+					if let Some(a) = lap.allocs() { allocs.push(a); }
+					if let Some(p) = lap.perf() { perf.push(p); }
+				}
+
+				// The timeout applies to the measured time, but if the seeds
+				// are really slow, we can't wait forever.
+				if timeout <= spent {
+					timed_out = true;
+					break;
+				}
+				let elapsed = before.saturating_add(now.elapsed());
+				if ceiling <= elapsed {
+					stalled = true;
+					break;
+				}
+				if signal::interrupted() { return false; }
+				progress.tick(elapsed);
+			}
+			true
+		});
+
+		let elapsed = now.elapsed();
+		self.elapsed += elapsed;
+		self.errors = errors;
+		self.chill = chill;
+
+		// Pick up where we left off next time if the round isn't over yet.
+		if res == Ok(true) && ! timed_out && ! stalled && taken < samples {
+			let round = Round { taken, kept, spent, wall: before.saturating_add(elapsed) };
+			self.partial = Some(Box::new(Partial { times, cpu_times, allocs, perf, rss_before, means, errors, round }));
+			self.sampler = Some(cb);
+			return;
+		}
+
+		if res == Ok(true) && kept.1 != 0 {
+			means.push(kept.0.as_secs_f64() / f64::from(kept.1));
+		}
+
+		// Dump the samples, if requested, now that the timing is done.
+		if last && res == Ok(true) { self.dump_raw_samples(&times); }
+
+		self.stats.replace(match res {
+			// Save it for later.
+			Ok(true) if ! last => {
+				self.partial = Some(Box::new(Partial {
+					times, cpu_times, allocs, perf, rss_before, means, errors,
+					round: Round::default(),
+				}));
+				self.sampler = Some(cb);
+				return;
+			},
+			Ok(true) => {
+				let min = self.min_samples();
+				self.cpu = cpu_times.and_then(|c| c.crunch(self.outliers, self.metric, min).ok());
+				self.allocs = allocs.finish();
+				self.perf = perf.finish();
+				self.rss = rss_before.zip(rss::max_rss())
+					.map(|(before, after)| MaxRss::new(before, after));
+				self.spread = spread(&means);
+				if self.plot { self.plot_samples = self.pruned_samples(&times); }
+				let (failed, total) = errors;
+				let res = match times.crunch(self.outliers, self.metric, min) {
+					// Errors trump everything else, unless they were dropped.
+					_ if failed != 0 && (! discard || failed == total) =>
+						Err(BrunchError::Fallible { failed, total }),
+					// If the seeds were to blame, say so.
+					Err(BrunchError::TooSmall(n)) if stalled => Err(BrunchError::SlowSeed(n)),
+					// Likewise if the benchmark itself is just slow.
+					Err(BrunchError::TooSmall(n)) if timed_out => Err(BrunchError::TimedOut {
+						collected: n,
+						needed: min,
+						mean_iter: Duration::from_secs_f64(
+							means.iter().sum::<f64>() / f64::from(u32::saturating_from(means.len()).max(1))
+						),
+						timeout,
+					}),
+					r => r,
+				};
+
+				// Hang onto the sampler in case of a retry.
+				if retryable(&res, errors.1, min) { self.sampler = Some(cb); }
+				res
+			},
+			Ok(false) => Err(BrunchError::Interrupted),
+			Err(e) => Err(BrunchError::Panicked(e)),
+		});
+	}
+
+	/// # Dump Raw Samples.
+	///
+	/// Write the samples to the raw directory, if any, warning on failure.
+	fn dump_raw_samples(&self, times: &Samples) {
+		let Samples::Exact(times) = times else { return; };
+		let Some(dir) = self.raw.clone().or_else(raw::dir) else { return; };
+		if let Err(e) = raw::write(&dir, &self.name, times) {
+			eprintln!(
+				"\x1b[1;93mWarning:\x1b[0m Unable to write the raw samples for {} to {} ({e}).",
+				self.name,
+				dir.display(),
+			);
+		}
+	}
+
+	/// # Pruned Samples.
+	///
+	/// Return a sorted copy of the samples — in seconds — sans outliers, if
+	/// they were kept.
+	fn pruned_samples(&self, times: &Samples) -> Option<Vec<f64>> {
+		let Samples::Exact(times) = times else { return None; };
+		let mut calc = Abacus::from(times.clone());
+		calc.prune_outliers(self.outliers);
+		Some(calc.into_inner())
+	}
+
+	/// # Write Plot.
+	///
+	/// Plot the samples, if any, warning on failure.
+	fn write_plot(&mut self, dir: &Path) {
+		let Some(samples) = self.plot_samples.take() else { return; };
+		let Some(Ok(stats)) = &self.stats else { return; };
+		if samples.is_empty() { return; }
+		if let Err(e) = plot::write(dir, &self.name, &samples, *stats, self.baseline) {
+			eprintln!(
+				"\x1b[1;93mWarning:\x1b[0m Unable to write the plot for {} to {} ({e}).",
+				self.name,
+				dir.display(),
+			);
+		}
+	}
+
+	/// # Prepare.
+	///
+	/// Fill in any suite defaults and environmental overrides ahead of the
+	/// first run, and note the settings for posterity.
+	fn prepare(
+		&mut self,
+		samples: Option<NonZeroU32>,
+		timeout: Option<Duration>,
+		overrides: Overrides,
+		timer: Option<&Rc<dyn Timer>>,
+		plot: bool,
+	) {
+		// Fill in any suite defaults.
+		if self.samples.is_none() { self.samples = samples; }
+		if self.timeout.is_none() { self.timeout = timeout; }
+
+		// Apply any environmental overrides.
+		overrides.apply(self);
+
+		// Use the suite's timer, if any.
+		self.timer = timer.map(Rc::clone);
+
+		// Note the settings for posterity.
+		self.config = Some(Config::new(self.samples().get(), self.timeout(), self.outliers, self.cold));
+
+		// Hang onto the samples if they're to be plotted.
+		self.plot = plot;
+	}
+
+	/// # Execute (Isolated).
+	///
+	/// Same as `Bench::exec`, except the sampling is carried out in a fresh
+	/// child process.
+	fn exec_isolated(&mut self, progress: &mut Progress) {
+		if self.stats.is_some() || self.sampler.is_none() { return; }
+		self.finish_isolated(isolate::spawn(
+			&self.name,
+			self.samples(),
+			self.timeout(),
+			self.measure_cpu,
+			self.measure_memory,
+			progress,
+		));
+	}
+
+	/// # Finish (Isolated).
+	///
+	/// Store the outcome of a child run.
+	fn finish_isolated(&mut self, outcome: isolate::Outcome) {
+		let (res, cpu, allocs, perf, rss, elapsed, errors) = outcome;
+		self.elapsed = elapsed;
+		self.cpu = cpu;
+		self.allocs = allocs;
+		self.perf = perf;
+		self.rss = rss;
+		self.errors = errors;
+		self.stats.replace(res);
+	}
+}
+
+
+
+/// # Retryable Result?
+///
+/// Wild results are worth another go, as are short ones when at least `min`
+/// samples were `taken`, i.e. too many were pruned away after the fact.
+const fn retryable(res: &Result<Stats, BrunchError>, taken: u32, min: u32) -> bool {
+	match res {
+		Err(BrunchError::TooWild) => true,
+		Err(BrunchError::TooSmall(n)) => *n < taken && min <= taken,
+		_ => false,
+	}
+}
+
+/// # Sample Collector.
+///
+/// Samples are either kept in full or folded into a [`Stream`], depending
+/// on [`Bench::streaming`].
+enum Samples {
+	/// # Every Sample.
+	Exact(Vec<Duration>),
+
+	/// # Streaming Stats.
+	Stream(Stream),
+}
+
+impl Samples {
+	/// # Push Sample.
+	fn push(&mut self, sample: Duration) {
+		match self {
+			Self::Exact(v) => v.push(sample),
+			Self::Stream(s) => s.push(sample),
+		}
+	}
+
+	/// # Crunch.
+	fn crunch(self, outliers: OutlierPolicy, metric: Metric, min: u32)
+	-> Result<Stats, BrunchError> {
+		match self {
+			Self::Exact(v) => Stats::from_samples(v, outliers, metric, min),
+			Self::Stream(s) => Stats::from_stream(s, outliers, metric, min),
+		}
+	}
+}
+
+
+
+/// # Partial Results.
+///
+/// When the suite is repeated or interleaved, everything collected for a
+/// benchmark is set aside here between rounds (or slices).
+struct Partial {
+	/// # Wall Times.
+	times: Samples,
+
+	/// # CPU Times.
+	cpu_times: Option<Samples>,
+
+	/// # Allocations.
+	allocs: Tally,
+
+	/// # Performance Counters.
+	perf: perf::Tally,
+
+	/// # Peak Memory (Before).
+	rss_before: Option<u64>,
+
+	/// # Per-Round Means.
+	means: Vec<f64>,
+
+	/// # `Err` Samples (and Total).
+	errors: (u32, u32),
+
+	/// # Current Round.
+	round: Round,
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Round Progress.
+///
+/// When interleaved, this keeps track of how far along the current round is
+/// between slices.
+struct Round {
+	/// # Samples Taken.
+	taken: u32,
+
+	/// # Kept Samples (Total Time, Count).
+	kept: (Duration, u32),
+
+	/// # Measured Time.
+	spent: Duration,
+
+	/// # Wall Time.
+	wall: Duration,
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Benchmark Result.
+///
+/// This struct holds the outcome of a single [`Bench`], as returned by
+/// [`Benches::results`].
+pub struct BenchResult {
+	/// # Benchmark Name.
+	name: String,
+
+	/// # Collected Stats.
+	stats: Result<Stats, BrunchError>,
+
+	/// # Previous Stats.
+	baseline: Option<Stats>,
+
+	/// # Best Previous Stats.
+	best: Option<Stats>,
+
+	/// # CPU Time Stats.
+	cpu: Option<Stats>,
+
+	/// # Time Budget.
+	expect_under: Option<Duration>,
+
+	/// # Skip Reason.
+	skipped: Option<String>,
+
+	/// # `Err` Samples.
+	errors: u32,
+
+	/// # Drifted?
+	drifted: bool,
+
+	/// # Change.
+	change: Option<f64>,
+}
+
+impl BenchResult {
+	#[must_use]
+	/// # Name.
+	///
+	/// Return the benchmark name.
+	pub fn name(&self) -> &str { &self.name }
+
+	/// # Stats.
+	///
+	/// Return the stats collected for the benchmark, or the error that
+	/// prevented their collection.
+	///
+	/// ## Errors
+	///
+	/// This will return an error if the benchmark could not be run or
+	/// analyzed.
+	pub fn stats(&self) -> Result<Stats, BrunchError> { self.stats.clone() }
+
+	#[must_use]
+	/// # Baseline.
+	///
+	/// Return the pooled stats from the previous runs, if history was
+	/// available.
+	pub const fn baseline(&self) -> Option<Stats> { self.baseline }
+
+	#[must_use]
+	/// # Best.
+	///
+	/// Return the stats from the fastest run ever recorded, if comparing
+	/// against the best was enabled via [`Benches::compare_best`] and history
+	/// was available.
+	pub const fn best(&self) -> Option<Stats> { self.best }
+
+	#[must_use]
+	/// # CPU Time.
+	///
+	/// Return the CPU time stats, if measured. See [`Bench::measure_cpu`].
+	pub const fn cpu(&self) -> Option<Stats> { self.cpu }
+
+	#[must_use]
+	/// # Time Budget.
+	///
+	/// Return the time budget, if any. See [`Bench::expect_under`].
+	pub const fn budget(&self) -> Option<Duration> { self.expect_under }
+
+	#[must_use]
+	/// # Over Budget?
+	///
+	/// Returns `true` if the benchmark had a time budget and either exceeded
+	/// it or failed to produce stats. See [`Bench::expect_under`].
+	pub fn over_budget(&self) -> bool {
+		self.skipped.is_none() &&
+		self.expect_under.is_some_and(|max| self.stats.as_ref().map_or(true, |s| max < s.mean()))
+	}
+
+	#[must_use]
+	/// # Skipped.
+	///
+	/// Return the reason the benchmark was skipped, if it was. See
+	/// [`Bench::skip`].
+	pub fn skipped(&self) -> Option<&str> { self.skipped.as_deref() }
+
+	#[must_use]
+	/// # `Err` Samples.
+	///
+	/// Return the number of samples for which a fallible callback returned
+	/// `Err`, whether or not they were discarded. See
+	/// [`Bench::run_fallible`].
+	pub const fn errors(&self) -> u32 { self.errors }
+
+	#[must_use]
+	/// # Pruned Fraction.
+	///
+	/// Return the fraction of samples discarded as outliers, if the benchmark
+	/// produced stats. See [`Stats::pruned`].
+	pub fn pruned(&self) -> Option<f64> {
+		self.stats.as_ref().ok().map(|s| s.pruned())
+	}
+
+	#[must_use]
+	/// # Drifted?
+	///
+	/// Returns `true` if the samples drifted by more than the
+	/// [threshold](Benches::drift_threshold) over the course of the run. See
+	/// [`Stats::drift`].
+	pub const fn drifted(&self) -> bool { self.drifted }
+
+	#[must_use]
+	/// # Change.
+	///
+	/// Return the relative change from the baseline — e.g. `-0.05` for 5%
+	/// faster — if there was one and it passed muster, i.e. the same value
+	/// shown in the table's Change column.
+	pub const fn change(&self) -> Option<f64> { self.change }
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Run Summary.
+///
+/// This struct holds the overall outcome of a run, as returned by
+/// [`Benches::finish`].
+pub struct Summary {
+	/// # Results.
+	results: Vec<BenchResult>,
+
+	/// # Skipped (Never Run).
+	skipped: usize,
+
+	/// # Interrupted?
+	interrupted: bool,
+
+	/// # History Loaded?
+	history_loaded: bool,
+
+	/// # History Saved?
+	history_saved: bool,
+}
+
+impl Summary {
+	#[must_use]
+	/// # Results.
+	///
+	/// Return the outcome of each benchmark, in order. See
+	/// [`Benches::results`].
+	pub fn results(&self) -> &[BenchResult] { &self.results }
+
+	#[must_use]
+	/// # Report.
+	///
+	/// Return an owned, data-only [`RunReport`] of the results. See
+	/// [`Benches::report`].
+	pub fn report(&self) -> RunReport { RunReport::from(self.results.as_slice()) }
+
+	#[must_use]
+	/// # Passed.
+	///
+	/// Return the number of benchmarks that produced stats.
+	pub fn passed(&self) -> usize {
+		self.results.iter().filter(|r| r.stats.is_ok()).count()
+	}
+
+	#[must_use]
+	/// # Errored.
+	///
+	/// Return the number of benchmarks that ran (or tried to), but failed to
+	/// produce stats.
+	pub fn errored(&self) -> usize {
+		self.results.len().saturating_sub(self.passed() + self.skipped)
+	}
+
+	#[must_use]
+	/// # Faster.
+	///
+	/// Return the number of benchmarks that got faster than their baseline.
+	/// See [`BenchResult::change`].
+	pub fn faster(&self) -> usize {
+		self.results.iter().filter(|r| r.change.is_some_and(f64::is_sign_negative)).count()
+	}
+
+	#[must_use]
+	/// # Slower.
+	///
+	/// Return the number of benchmarks that got slower than their baseline.
+	/// See [`BenchResult::change`].
+	pub fn slower(&self) -> usize {
+		self.results.iter().filter(|r| r.change.is_some_and(f64::is_sign_positive)).count()
+	}
+
+	#[must_use]
+	/// # Unchanged.
+	///
+	/// Return the number of benchmarks with a baseline but no reportable
+	/// change. (Benchmarks without a baseline don't count either way.)
+	pub fn unchanged(&self) -> usize {
+		self.results.iter()
+			.filter(|r| r.stats.is_ok() && r.baseline.is_some() && r.change.is_none())
+			.count()
+	}
+
+	#[must_use]
+	/// # Skipped.
+	///
+	/// Return the number of benchmarks that never ran, either because they
+	/// were skipped on purpose (see [`Bench::skip`]), or the run was
+	/// interrupted.
+	pub const fn skipped(&self) -> usize { self.skipped }
+
+	#[must_use]
+	/// # Interrupted?
+	///
+	/// Returns `true` if the run was cut short (by Ctrl+C).
+	pub const fn interrupted(&self) -> bool { self.interrupted }
+
+	#[must_use]
+	/// # History Loaded?
+	///
+	/// Returns `true` if an existing history file was loaded.
+	pub const fn history_loaded(&self) -> bool { self.history_loaded }
+
+	#[must_use]
+	/// # History Saved?
+	///
+	/// Returns `true` if the history was saved successfully; `false` if it
+	/// failed, or was disabled.
+	pub const fn history_saved(&self) -> bool { self.history_saved }
+
+	#[must_use]
+	/// # Success?
+	///
+	/// Returns `true` if every benchmark — not deliberately skipped — ran
+	/// successfully and within its budget, if any.
+	pub fn is_success(&self) -> bool {
+		! self.interrupted &&
+		self.results.iter().all(|r|
+			r.skipped.is_some() || (r.stats.is_ok() && ! r.over_budget())
+		)
+	}
+
+	#[must_use]
+	/// # Exit Code.
+	///
+	/// Return the exit code [`Benches::finish_or_print`] uses when things
+	/// didn't go to plan, if any: `130` if the run was interrupted, `101` if
+	/// any benchmark panicked, or `1` if any blew its time budget.
+	///
+	/// Other errors are reported in the table, but don't affect the exit
+	/// code.
+	pub fn exit_code(&self) -> Option<i32> {
+		if self.interrupted { Some(signal::EXIT_INTERRUPTED) }
+		else if self.results.iter().any(|r| matches!(r.stats, Err(BrunchError::Panicked(_)))) {
+			Some(EXIT_PANICKED)
+		}
+		else if self.results.iter().any(BenchResult::over_budget) { Some(EXIT_OVER_BUDGET) }
+		else { None }
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Environmental Overrides.
+///
+/// The `BRUNCH_SAMPLES` and `BRUNCH_TIMEOUT` environmental variables can be
+/// used to override the sample and time limits of _every_ benchmark — taking
+/// precedence over [`Bench::with_samples`] and [`Bench::with_timeout`] — to
+/// allow for quick-and-dirty runs without editing any code.
+///
+/// The usual minimums still apply.
+struct Overrides {
+	/// # Samples.
+	samples: Option<NonZeroU32>,
+
+	/// # Timeout.
+	timeout: Option<Duration>,
+}
+
+impl Overrides {
+	/// # From Environment.
+	///
+	/// Parse the overrides from the environment, printing a warning for any
+	/// invalid values.
+	fn from_env() -> Self {
+		let samples = std::env::var("BRUNCH_SAMPLES").ok();
+		let timeout = std::env::var("BRUNCH_TIMEOUT").ok();
+		let (out, bad) = Self::parse(samples.as_deref(), timeout.as_deref());
+		for (k, v) in bad {
+			eprintln!("\x1b[1;93mWarning:\x1b[0m Ignoring invalid {k} value {v:?}.");
+		}
+		out
+	}
+
+	/// # Parse.
+	///
+	/// Parse the raw sample count and timeout (in seconds), returning the
+	/// overrides along with any invalid values.
+	fn parse<'a>(samples: Option<&'a str>, timeout: Option<&'a str>)
+	-> (Self, Vec<(&'static str, &'a str)>) {
+		let mut bad = Vec::new();
+
+		let samples = samples.and_then(|v| {
+			let out = v.trim().parse::<u32>().ok()
+				.map(clamp_samples);
+			if out.is_none() { bad.push(("BRUNCH_SAMPLES", v)); }
+			out
+		});
+
+		let timeout = timeout.and_then(|v| {
+			let out = v.trim().parse::<f64>().ok()
+				.and_then(|t| Duration::try_from_secs_f64(t).ok())
+				.map(clamp_timeout);
+			if out.is_none() { bad.push(("BRUNCH_TIMEOUT", v)); }
+			out
+		});
+
+		(Self { samples, timeout }, bad)
+	}
+
+	/// # Apply.
+	///
+	/// Apply the overrides, if any, to the benchmark.
+	const fn apply(self, b: &mut Bench<'_>) {
+		if let Some(samples) = self.samples { b.samples = Some(samples); }
+		if let Some(timeout) = self.timeout { b.timeout = Some(timeout); }
+	}
+
+	/// # Note.
+	///
+	/// Return a summary of the overrides, if any, for the table footer.
+	fn note(self) -> Option<String> {
+		let parts: Vec<String> = [
+			self.samples.map(|n| format!("{} samples", NiceU32::from(n.get()))),
+			self.timeout.map(|t| format!("{} timeout", util::nice_time(t.as_secs_f64()).trim_end())),
+		]
+			.into_iter()
+			.flatten()
+			.collect();
+
+		if parts.is_empty() { None }
+		else { Some(format!("(overridden: {})", parts.join(", "))) }
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Table Column.
+///
+/// This enum holds the different (non-name) columns that may be included in
+/// the results table.
+pub(crate) enum Column {
+	/// # Mean.
+	Mean,
+
+	/// # Operations Per Second.
+	Ops,
+
+	/// # Confidence Interval.
+	///
+	/// When present, changes are only reported if the confidence intervals
+	/// of the two runs do not overlap.
+	Interval,
+
+	/// # Median.
+	Median,
+
+	/// # 95th Percentile.
+	P95,
+
+	/// # Fastest/Slowest Samples.
+	Range,
+
+	/// # Samples.
+	Samples,
+
+	/// # Total Measured Time.
+	Total,
+
+	/// # Wall Time.
+	Wall,
+
+	/// # Round Spread.
+	Spread,
+
+	/// # CPU Time.
+	Cpu,
+
+	/// # Allocations.
+	Allocs,
+
+	/// # Bytes Allocated.
+	Bytes,
+
+	/// # Instructions.
+	Instructions,
+
+	/// # Instructions Per Cycle.
+	Ipc,
+
+	/// # Peak Memory.
+	MaxRss,
+
+	/// # Change.
+	Change,
+
+	/// # Trend.
+	Trend,
+
+	/// # Time Budget.
+	Budget,
+
+	/// # Mean (Comparison A).
+	MeanA,
+
+	/// # Mean (Comparison B).
+	MeanB,
+
+	/// # Rank.
+	Rank,
+
+	/// # Relative to Fastest.
+	Relative,
+}
+
+impl Column {
+	/// # Label.
+	const fn label(self) -> &'static str {
+		match self {
+			Self::Mean => "Mean",
+			Self::Ops => "Ops/s",
+			Self::Interval => "95% CI",
+			Self::Median => "Median",
+			Self::P95 => "p95",
+			Self::Range => "Range",
+			Self::Samples => "Samples",
+			Self::Total => "Total",
+			Self::Wall => "Wall Time",
+			Self::Spread => "Spread",
+			Self::Cpu => "CPU Time",
+			Self::Allocs => "Allocs",
+			Self::Bytes => "Bytes",
+			Self::Instructions => "Instr.",
+			Self::Ipc => "IPC",
+			Self::MaxRss => "Max RSS",
+			Self::Change => "Change",
+			Self::Trend => "Trend",
+			Self::Budget => "Budget",
+			Self::MeanA => "Mean (A)",
+			Self::MeanB => "Mean (B)",
+			Self::Rank => "Rank",
+			Self::Relative => "Relative",
+		}
+	}
+
+	/// # Decimal-Aligned?
+	///
+	/// Numeric columns with units are aligned on the decimal point rather
+	/// than merely right-aligned.
+	const fn decimal(self) -> bool {
+		matches!(
+			self,
+			Self::Mean | Self::Ops | Self::Median | Self::P95 | Self::Cpu |
+			Self::MeanA | Self::MeanB | Self::Relative
+		)
+	}
+
+	/// # Optional?
+	///
+	/// Optional columns are only displayed if at least one row has a value.
+	const fn optional(self) -> bool {
+		matches!(
+			self,
+			Self::Ops | Self::Median | Self::P95 | Self::Range | Self::Spread |
+			Self::Cpu | Self::Allocs | Self::Bytes |
+			Self::Instructions | Self::Ipc | Self::MaxRss | Self::Change | Self::Trend |
+			Self::Budget
+		)
+	}
+
+	/// # Cell.
+	///
+	/// Return the cell for a given benchmark, if any.
+	fn cell(self, src: &Bench<'_>, stats: Stats, gate: Gate, unit: TimeUnit) -> Option<Cell> {
+		match self {
+			Self::Mean => Some(Cell::new(STYLE_MEAN, stats.nice_mean_in(unit))),
+			Self::Ops => util::nice_ops(stats.mean().as_secs_f64())
+				.map(|s| Cell::new(STYLE_DIM, s)),
+			Self::Interval => Some(Cell::new(STYLE_DIM, stats.nice_interval(unit))),
+			Self::Median => stats.nice_median_in(unit).map(|s| Cell::new(STYLE_DIM, s)),
+			Self::P95 => stats.nice_p95_in(unit).map(|s| Cell::new(STYLE_DIM, s)),
+			Self::Range => stats.nice_range(unit).map(|s| Cell::new(STYLE_DIM, s)),
+			Self::Samples => {
+				let (valid, total) = stats.samples();
+				Some(Cell(vec![
+					(STYLE_DIM, NiceU32::from(valid).to_string()),
+					(STYLE_SLASH, "/".to_owned()),
+					(STYLE_DIM, NiceU32::from(total).to_string()),
+				]))
+			},
+			Self::Total => Some(Cell::new(STYLE_DIM, stats.nice_measured())),
+			Self::Wall => Some(Cell::new(STYLE_DIM, util::nice_time(src.elapsed.as_secs_f64()))),
+			Self::Spread => src.spread.map(|s| Cell::new(STYLE_DIM, format!("\u{b1}{}", NicePercent::from(s)))),
+			Self::Cpu => src.cpu.map(|s| Cell::new(STYLE_DIM, s.nice_mean_in(unit))),
+			Self::Allocs => src.allocs.map(|a| Cell::new(STYLE_DIM, a.nice_allocs())),
+			Self::Bytes => src.allocs.map(|a| Cell::new(STYLE_DIM, a.nice_bytes())),
+			Self::Instructions => src.perf.map(|p| Cell::new(STYLE_DIM, p.nice_instructions())),
+			Self::Ipc => src.perf.and_then(Counters::nice_ipc).map(|p| Cell::new(STYLE_DIM, p)),
+			Self::MaxRss => src.rss.map(|m| Cell::new(STYLE_DIM, m.nice())),
+			Self::Change => {
+				// There's nothing to compare if the settings changed.
+				if ! src.reconfigured.is_empty() {
+					return Some(Cell::new(STYLE_DIM, "--- (config changed)"));
+				}
+
+				let mut notes = Vec::new();
+
+				// Note any change in the number of instructions…
+				if let Some(diff) = src.baseline_perf.zip(src.perf).and_then(|(o, n)| n.change(o)) {
+					let mut note = format_change(diff).0;
+					note.push((STYLE_DIM, " instructions".to_owned()));
+					notes.push(note);
+				}
+
+				// …or allocations too.
+				if let Some((old, new)) = src.baseline_allocs.zip(src.allocs)
+					.map(|(o, n)| (o.nice_allocs(), n.nice_allocs()))
+					.filter(|(o, n)| o != n)
+				{
+					notes.push(vec![(STYLE_DIM, format!("allocs {old} \u{2192} {new}"))]);
+				}
+
+				let mut cell = Self::change(src, stats, gate);
+				for note in notes {
+					if let Some(c) = cell.as_mut() {
+						c.0.push((STYLE_DIM, ", ".to_owned()));
+						c.0.extend(note);
+					}
+					else { cell = Some(Cell(note)); }
+				}
+				cell
+			},
+			Self::Trend => src.trend.map(|t| Cell::new(
+				match t {
+					Trend::Slower => STYLE_SLOWER,
+					Trend::Faster => STYLE_FASTER,
+					Trend::Stable => STYLE_DIM,
+				},
+				t.arrow(),
+			)),
+			Self::Budget => src.expect_under.map(|max| {
+				let ratio = stats.mean().as_secs_f64() / max.as_secs_f64();
+				if 1.0 < ratio {
+					Cell::new(
+						STYLE_OVER_BUDGET,
+						format!("BUDGET EXCEEDED (+{})", NicePercent::from(ratio - 1.0)),
+					)
+				}
+				else { Cell::new(STYLE_FASTER, "\u{2713}") }
+			}),
+			// These are only used for history comparisons and rankings.
+			Self::MeanA | Self::MeanB | Self::Rank | Self::Relative => None,
+		}
+	}
+
+	/// # Change Cell.
+	///
+	/// Return the change(s) from the baseline and/or best, if any.
+	fn change(src: &Bench<'_>, stats: Stats, gate: Gate) -> Option<Cell> {
+		let recent = src.baseline.and_then(|h| gate.change(stats, h));
+		let Some(best) = src.best else { return recent.map(format_change); };
+		match (recent, gate.change(stats, best)) {
+			(Some(r), Some(b)) if (r - b).abs() < BEST_SPREAD => Some(format_change(b)),
+			(Some(r), Some(b)) => {
+				let mut out = format_change(r);
+				out.0.push((STYLE_DIM, " (recent) / ".to_owned()));
+				out.0.extend(format_change(b).0);
+				out.0.push((STYLE_DIM, " (best)".to_owned()));
+				Some(out)
+			},
+			(Some(r), None) => {
+				let mut out = format_change(r);
+				out.0.push((STYLE_DIM, " (recent)".to_owned()));
+				Some(out)
+			},
+			(None, Some(b)) => {
+				let mut out = format_change(b);
+				out.0.push((STYLE_DIM, " (best)".to_owned()));
+				Some(out)
+			},
+			(None, None) => None,
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Change Criteria.
+///
+/// This holds the criteria a change from the baseline must meet to be
+/// reported.
+struct Gate {
+	/// # Significance Threshold.
+	///
+	/// The p-value from Welch's t-test must be below this.
+	alpha: f64,
+
+	/// # Change Threshold.
+	///
+	/// The relative change must be at least this large.
+	threshold: f64,
+
+	/// # Strict?
+	///
+	/// If true, the confidence intervals must not overlap either.
+	strict: bool,
+
+	/// # Drift Threshold.
+	///
+	/// Runs that drifted by more than this are held to a higher standard.
+	drift: Option<f64>,
+}
+
+impl Default for Gate {
+	fn default() -> Self {
+		Self {
+			alpha: DEFAULT_SIGNIFICANCE,
+			threshold: 0.0,
+			strict: false,
+			drift: Some(DEFAULT_DRIFT),
+		}
+	}
+}
+
+impl Gate {
+	/// # Change.
+	///
+	/// Return the relative change between the stats and baseline, if it
+	/// passes muster.
+	fn change(self, stats: Stats, baseline: Stats) -> Option<f64> {
+		let diff = stats.is_deviant(baseline, self.alpha)?;
+		if diff.abs() < self.threshold { return None; }
+
+		// If the run drifted, the change has to outstrip the drift, and the
+		// intervals can't overlap.
+		let drifting = self.is_drifting(stats);
+		if drifting && diff.abs() <= stats.drift().abs() { return None; }
+		// Likewise if either side is short on samples.
+		let shaky = stats.is_low_confidence() || baseline.is_low_confidence();
+		if shaky && diff.abs() < LOW_CONFIDENCE_CHANGE { return None; }
+		if self.strict || drifting || shaky { stats.is_significant(baseline)?; }
+		Some(diff)
+	}
+
+	/// # Drifting?
+	///
+	/// Returns true if the run drifted by more than the threshold, if any.
+	fn is_drifting(self, stats: Stats) -> bool {
+		self.drift.is_some_and(|t| stats.is_drifting(t))
+	}
+
+	/// # Note.
+	///
+	/// Describe the criteria for the footer.
+	fn note(self) -> String {
+		let mut out = format!("Changes are reported at p < {}", self.alpha);
+		if 0.0 < self.threshold {
+			out.push_str(" and \u{2265} ");
+			out.push_str(NicePercent::from(self.threshold).as_str());
+		}
+		if self.strict { out.push_str(", without interval overlap"); }
+		out.push('.');
+		out
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Benchmarking Results.
+///
+/// This table holds the results of all the benchmarks so they can be printed
+/// consistently.
+pub(crate) struct Table {
+	/// # Columns.
+	cols: Vec<Column>,
+
+	/// # Rows.
+	rows: Vec<TableRow>,
+
+	/// # Change Criteria.
+	gate: Gate,
+
+	/// # Display Unit.
+	unit: TimeUnit,
+
+	/// # Show Operations Per Second.
+	ops: bool,
+
+	/// # Pruning Thresholds.
+	pruning: Option<(f64, f64)>,
+
+	/// # Slow Thresholds.
+	slow: Option<(Duration, Duration)>,
+
+	/// # Name Style.
+	names: NameStyle,
+}
+
+impl fmt::Display for Table {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.write_to(f, true)
+	}
+}
+
+impl Table {
+	/// # New.
+	///
+	/// Start a new table with the given columns (in addition to the name).
+	pub(crate) fn new(cols: Vec<Column>) -> Self {
+		let header = TableRow::Normal(
+			Cell::new(STYLE_HEADER, "Method"),
+			cols.iter().map(|c| Some(Cell::new(STYLE_HEADER, c.label()))).collect(),
+		);
+		Self {
+			cols,
+			rows: vec![header, TableRow::Spacer],
+			gate: Gate::default(),
+			unit: TimeUnit::Auto,
+			ops: false,
+			pruning: None,
+			slow: None,
+			names: NameStyle::Auto,
+		}
+	}
+
+	/// # Relabel Column.
+	///
+	/// Replace the header label for the column, if present.
+	fn relabel(&mut self, col: Column, label: &str) {
+		let Some(idx) = self.cols.iter().position(|c| *c == col) else { return; };
+		if let Some(TableRow::Normal(_, cells)) = self.rows.first_mut() {
+			if let Some(cell) = cells.get_mut(idx) {
+				*cell = Some(Cell::new(STYLE_HEADER, label));
+			}
+		}
+	}
+
+	/// # Annotate Header.
+	///
+	/// Append a (dim) note to the header's name cell.
+	fn annotate(&mut self, note: &str) {
+		if let Some(TableRow::Normal(name, _)) = self.rows.first_mut() {
+			name.0.push((STYLE_DIM, note.to_owned()));
+		}
+	}
+
+	/// # Add Row.
+	fn push(&mut self, src: &Bench<'_>, names: &[Vec<char>]) {
+		if src.is_spacer() { self.rows.push(TableRow::Spacer); }
+		else {
+			let mut name = self.names.format(&src.name, src.namespace(), names);
+			if src.cold { name.0.push((STYLE_DIM, " (cold)".to_owned())); }
+			if src.retried != 0 {
+				name.0.push((STYLE_DIM, format!(" (retried \u{d7}{})", src.retried)));
+			}
+
+			// Skipped benches just get a note.
+			if let (None, Some(reason)) = (&src.stats, &src.skip) {
+				let note =
+					if reason.is_empty() { "skipped".to_owned() }
+					else { format!("skipped \u{2014} {reason}") };
+				self.rows.push(TableRow::Error(name, Cell::new(STYLE_DIM, note)));
+				return;
+			}
+
+			match src.stats.clone().unwrap_or(Err(BrunchError::NoRun)) {
+				Ok(s) => {
+					let unit = src.unit.unwrap_or(self.unit);
+					let ops = src.ops.unwrap_or(self.ops);
+					let pruned = self.pruning.and_then(|(warn, alert)| {
+						let p = s.pruned();
+						if alert < p { Some(STYLE_PRUNED_ALERT) }
+						else if warn < p { Some(STYLE_PRUNED_WARN) }
+						else { None }
+					});
+					let band = self.slow.map(|(slow, very_slow)| {
+						let mean = s.mean();
+						if very_slow < mean { (STYLE_MEAN_VERY_SLOW, " !!") }
+						else if slow < mean { (STYLE_MEAN_SLOW, " !") }
+						else if mean < FAST_BAND { (STYLE_MEAN_FAST, "") }
+						else { (STYLE_MEAN, "") }
+					});
+					if let Some((_, marker)) = band.filter(|(_, m)| ! m.is_empty()) {
+						name.0.push((STYLE_MARKER, marker.to_owned()));
+					}
+
+					// Flag anything that drifted.
+					if self.gate.is_drifting(s) {
+						name.0.push((STYLE_DRIFT, " \u{26a0}".to_owned()));
+					}
+					let cells = self.cols.iter()
+						.map(|c| match c {
+							Column::Ops if ! ops => None,
+							// Color-code the mean.
+							Column::Mean => c.cell(src, s, self.gate, unit).map(|mut cell| {
+								if let Some((style, _)) = band {
+									for seg in &mut cell.0 { seg.0 = style; }
+								}
+								// Dim anything short on samples.
+								if s.is_low_confidence() {
+									for seg in &mut cell.0 { seg.0 = STYLE_DIM; }
+								}
+								cell
+							}),
+							// Highlight heavy pruning.
+							Column::Samples => c.cell(src, s, self.gate, unit).map(|mut cell| {
+								if let Some(style) = pruned {
+									for seg in &mut cell.0 {
+										if seg.0 == STYLE_DIM { seg.0 = style; }
+									}
+								}
+								cell
+							}),
+							_ => c.cell(src, s, self.gate, unit),
+						})
+						.collect();
+					self.rows.push(TableRow::Normal(name, cells));
+				},
+				Err(e) => {
+					let e =
+						// If the suite budget was to blame, say so.
+						if let (BrunchError::TooSmall(n) | BrunchError::TimedOut { collected: n, .. }, true) = (&e, src.budgeted) {
+							format!(
+								"Insufficient samples collected ({}); try increasing the suite budget.",
+								NiceU32::from(*n),
+							)
+						}
+						else { e.to_string() };
+					self.rows.push(TableRow::Error(name, Cell::new(STYLE_ERROR, e)));
+				}
+			}
+		}
+	}
+
+	/// # Add Group Summary Row.
+	///
+	/// Add a row with the geometric mean of the group's means and, if every
+	/// member has a baseline, of their changes too.
+	fn push_geomean(&mut self, group: &[&Bench<'_>]) {
+		let ok: Vec<(Stats, Option<Stats>)> = group.iter()
+			.filter_map(|b| match b.stats { Some(Ok(s)) => Some((s, b.baseline)), _ => None })
+			.collect();
+		if ok.len() < 2 { return; }
+
+		let Some(mean) = geomean(ok.iter().map(|(s, _)| s.mean().as_secs_f64())) else { return; };
+		let ratio = ok.iter()
+			.map(|(s, h)| h.map(|h| s.mean().as_secs_f64() / h.mean().as_secs_f64()))
+			.collect::<Option<Vec<f64>>>()
+			.and_then(geomean);
+
+		let cells = self.cols.iter().map(|c| match c {
+			Column::Mean => Some(Cell::new(STYLE_GEOMEAN, util::nice_time_in(mean, self.unit))),
+			Column::Change => ratio.map(|r| format_change(r - 1.0)),
+			_ => None,
+		}).collect();
+
+		// Explicit namespaces take priority.
+		let ns = group.first().and_then(|b| b.namespace())
+			.filter(|ns| group.iter().all(|b| b.namespace() == Some(ns)));
+		let label = ns.or_else(|| group_prefix(group.iter().map(|b| b.name.as_str())))
+			.map_or_else(|| "  \u{2b91} geomean".to_owned(), |p| format!("  \u{2b91} {p} geomean"));
+		self.rows.push(TableRow::Normal(Cell::new(STYLE_GEOMEAN, label), cells));
+	}
+
+	#[expect(clippy::cast_precision_loss, reason = "It is what it is.")]
+	/// # Add Scaling Summary Row.
+	///
+	/// Fit the means of a [`Bench::scaling`] series against their input
+	/// sizes, and add a row naming the best-fitting complexity class, if
+	/// there are enough successful members to say.
+	fn push_scaling(&mut self, series: &[&Bench<'_>]) {
+		let Some(base) = series.first().copied().and_then(Bench::scale_base) else { return; };
+		let points: Vec<(f64, f64)> = series.iter()
+			.filter_map(|b| match (b.scale, &b.stats) {
+				(Some((_, n)), Some(Ok(s))) => Some((n as f64, s.mean().as_secs_f64())),
+				_ => None,
+			})
+			.collect();
+
+		let note = match Fit::new(&points) {
+			Some(fit) if fit.is_conclusive() => Cell::new(STYLE_GEOMEAN, fit.to_string()),
+			Some(fit) => Cell::new(STYLE_DIM, fit.to_string()),
+			// Enough points, but no growth to speak of.
+			None if 3 <= points.len() => Cell::new(STYLE_DIM, "inconclusive"),
+			None => return,
+		};
+		let label = format!("  \u{2b91} {base} scaling");
+		self.rows.push(TableRow::Error(Cell::new(STYLE_GEOMEAN, label), note));
+	}
+
+	/// # Add Group Rankings.
+	///
+	/// Add a row for each successful member of the group, fastest first,
+	/// followed by a spacer. Returns `false` — adding nothing — if there are
+	/// fewer than two to rank.
+	fn push_ranking(&mut self, group: &[&Bench<'_>], names: &[Vec<char>]) -> bool {
+		let mut ok: Vec<(&Bench<'_>, Stats)> = group.iter()
+			.filter_map(|b| match b.stats { Some(Ok(s)) => Some((*b, s)), _ => None })
+			.collect();
+		if ok.len() < 2 { return false; }
+		ok.sort_by_key(|a| a.1.mean());
+
+		let fastest = ok[0].1.mean().as_secs_f64();
+		let ranks = ranks(ok.iter().map(|(_, s)| *s));
+		for ((b, s), (rank, tied)) in ok.into_iter().zip(ranks) {
+			let name = self.names.format(&b.name, b.namespace(), names);
+			let style = if rank == 1 { STYLE_FASTER } else { STYLE_DIM };
+			let rank =
+				if tied { format!("={rank}") }
+				else { rank.to_string() };
+			let relative = s.mean().as_secs_f64() / fastest;
+			let cells = self.cols.iter().map(|c| match c {
+				Column::Rank => Some(Cell::new(style, rank.clone())),
+				Column::Mean => Some(Cell::new(STYLE_MEAN, s.nice_mean_in(b.unit.unwrap_or(self.unit)))),
+				Column::Relative =>
+					if relative.is_finite() {
+						Some(Cell::new(STYLE_DIM, format!("{}x", NiceFloat::from(relative).precise_str(2))))
+					}
+					else { None },
+				_ => None,
+			}).collect();
+			self.rows.push(TableRow::Normal(name, cells));
+		}
+		self.rows.push(TableRow::Spacer);
+		true
+	}
+
+	/// # Add Comparison Row.
+	///
+	/// Add a row comparing two historical results, or flag the one that's
+	/// missing.
+	pub(crate) fn push_compare(&mut self, name: Cell, a: Option<Stats>, b: Option<Stats>) {
+		match (a, b) {
+			(Some(a), Some(b)) => {
+				let cells = self.cols.iter().map(|c| match c {
+					Column::MeanA => Some(Cell::new(STYLE_MEAN, a.nice_mean())),
+					Column::MeanB => Some(Cell::new(STYLE_MEAN, b.nice_mean())),
+					Column::Change => {
+						let (a, b) = (a.mean().as_secs_f64(), b.mean().as_secs_f64());
+						if a.is_normal() { Some(format_change((b - a) / a)) }
+						else { None }
+					},
+					_ => None,
+				}).collect();
+				self.rows.push(TableRow::Normal(name, cells));
+			},
+			(Some(_), None) => self.rows.push(
+				TableRow::Error(name, Cell::new(STYLE_ERROR, "Missing from B."))
+			),
+			(None, Some(_)) => self.rows.push(
+				TableRow::Error(name, Cell::new(STYLE_ERROR, "Missing from A."))
+			),
+			(None, None) => {},
+		}
+	}
+
+	/// # Visible Columns.
+	///
+	/// Return a mask indicating which columns should be displayed. Optional
+	/// columns are only shown if at least one row has a value.
+	fn visible(&self) -> Vec<bool> {
+		self.cols.iter()
+			.enumerate()
+			.map(|(idx, c)|
+				! c.optional() ||
+				self.rows.iter().skip(2).any(|r|
+					matches!(r, TableRow::Normal(_, cells) if cells[idx].is_some())
+				)
+			)
+			.collect()
+	}
+
+	/// # Fitted Copy.
+	///
+	/// Return a copy of the table squeezed into the given width, if possible.
+	///
+	/// Long names are truncated first, down to `MIN_NAME_WIDTH`, keeping the
+	/// start and (unique) end visible. If that isn't enough, the _Samples_
+	/// column is dropped, and then the _Change_ column.
+	fn fitted(&self, max: usize) -> Self {
+		let mut out = self.clone();
+		let (w1, _) = out.lens(&out.decimals());
+		let over = out.width().saturating_sub(max);
+		if over != 0 {
+			let w2 = w1.saturating_sub(over).max(MIN_NAME_WIDTH);
+			if w2 < w1 {
+				for r in &mut out.rows {
+					if let TableRow::Normal(a, _) | TableRow::Error(a, _) = r {
+						a.truncate_middle(w2);
+					}
+				}
+			}
+		}
+		for col in [Column::Samples, Column::Change] {
+			if out.width() <= max { break; }
+			if let Some(idx) = out.cols.iter().position(|c| *c == col) {
+				out.cols.remove(idx);
+				for r in &mut out.rows {
+					if let TableRow::Normal(_, cells) = r { cells.remove(idx); }
+				}
+			}
+		}
+
+		out
+	}
+
+	/// # Total Width.
+	///
+	/// Return the printable width of the table, i.e. the length of the
+	/// spacer.
+	fn width(&self) -> usize {
+		let (w1, lens) = self.lens(&self.decimals());
+		lens.iter()
+			.zip(self.visible())
+			.fold(w1, |acc, (len, v)| if v { acc + len + 4 } else { acc })
+	}
+
+	/// # Decimal Widths.
+	///
+	/// Return the maximum integer and fraction widths for each
+	/// decimal-aligned column, or `None` for the rest.
+	fn decimals(&self) -> Vec<Option<(usize, usize)>> {
+		let mut out: Vec<Option<(usize, usize)>> = self.cols.iter()
+			.map(|c| if c.decimal() { Some((0, 0)) } else { None })
+			.collect();
+		for r in &self.rows {
+			let TableRow::Normal(_, cells) = r else { continue; };
+			for (dec, cell) in out.iter_mut().zip(cells) {
+				if let Some((int, frac)) = dec {
+					if let Some((i, f, _)) = cell.as_ref().and_then(Cell::split_decimal) {
+						*int = (*int).max(util::width(i));
+						*frac = (*frac).max(util::width(f));
+					}
+				}
+			}
+		}
+		out
+	}
+
+	/// # Widths.
+	///
+	/// Return the maximum width of the name column, and each of the others.
+	///
+	/// Cells in decimal-aligned columns are measured as they'll be printed,
+	/// i.e. with the padding needed to line up their integer and fractional
+	/// parts.
+	fn lens(&self, decimals: &[Option<(usize, usize)>]) -> (usize, Vec<usize>) {
+		let mut lens = vec![0; self.cols.len()];
+		let mut name = 0;
+		for r in &self.rows {
+			match r {
+				TableRow::Normal(a, cells) => {
+					name = name.max(a.width());
+					for ((len, cell), dec) in lens.iter_mut().zip(cells).zip(decimals) {
+						let w = match (cell, dec) {
+							(Some(c), Some(dec)) => c.aligned(*dec).as_ref().unwrap_or(c).width(),
+							(Some(c), None) => c.width(),
+							(None, _) => NO_CHANGE.len(),
+						};
+						*len = (*len).max(w);
+					}
+				},
+				TableRow::Error(a, _) => { name = name.max(a.width()); },
+				TableRow::Spacer => {},
+			}
+		}
+		(name, lens)
+	}
+
+	/// # Write Markdown.
+	///
+	/// Write the table to the given writer as a (plain) Markdown table, e.g.
+	/// for a GitHub step summary. Spacers are omitted.
+	pub(crate) fn write_markdown<W: fmt::Write>(&self, f: &mut W) -> fmt::Result {
+		let visible = self.visible();
+		let cols = visible.iter().filter(|v| **v).count();
+		let plain = |cell: &Cell| -> Result<String, fmt::Error> {
+			let mut out = String::new();
+			cell.write_to(&mut out, false)?;
+			Ok(out.replace('|', "\\|"))
+		};
+
+		for (idx, v) in self.rows.iter().enumerate() {
+			match v {
+				TableRow::Normal(a, cells) => {
+					write!(f, "| {} |", plain(a)?)?;
+					for (cell, _) in cells.iter().zip(visible.iter()).filter(|(_, v)| **v) {
+						match cell {
+							Some(c) => write!(f, " {} |", plain(c)?)?,
+							None => write!(f, " {NO_CHANGE} |")?,
+						}
+					}
+					f.write_char('\n')?;
+
+					// The header needs a separator.
+					if idx == 0 {
+						f.write_str("| :--- |")?;
+						for _ in 0..cols { f.write_str(" ---: |")?; }
+						f.write_char('\n')?;
+					}
+				},
+				TableRow::Error(a, b) => {
+					write!(f, "| {} | {} |", plain(a)?, plain(b)?)?;
+					for _ in 1..cols { f.write_str("  |")?; }
+					f.write_char('\n')?;
+				},
+				TableRow::Spacer => {},
+			}
+		}
+
+		Ok(())
+	}
+
+	/// # Write Table.
+	///
+	/// Write the table to the given writer, with or without ANSI formatting.
+	///
+	/// Widths are calculated from the printable content of each cell, so the
+	/// alignment is identical either way.
+	pub(crate) fn write_to<W: fmt::Write>(&self, f: &mut W, color: bool) -> fmt::Result {
+		// Maximum column widths.
+		let visible = self.visible();
+		let decimals = self.decimals();
+		let (w1, mut lens) = self.lens(&decimals);
+		for (len, v) in lens.iter_mut().zip(visible.iter().copied()) {
+			if ! v { *len = 0; }
+		}
+		let width = self.width();
+
+		// Pre-generate padding as we'll be slicing lots of things to fit.
+		let pad_len = lens.iter().copied().fold(w1, usize::max);
+		let pad = " ".repeat(pad_len);
+
+		// Pre-generate the spacer too.
+		let spacer = Cell::new(STYLE_SPACER, "-".repeat(width));
+		let no_change = Cell::new(STYLE_DIM, NO_CHANGE);
+
+		// Print each line!
+		for v in &self.rows {
+			match v {
+				TableRow::Normal(a, cells) => {
+					a.write_to(f, color)?;
+					f.write_str(&pad[..w1 - a.width()])?;
+					for (((cell, len), dec), _) in cells.iter()
+						.zip(lens.iter().copied())
+						.zip(decimals.iter().copied())
+						.zip(visible.iter().copied())
+						.filter(|(_, v)| *v)
+					{
+						let cell = cell.as_ref().unwrap_or(&no_change);
+						let aligned = dec.and_then(|d| cell.aligned(d));
+						let cell = aligned.as_ref().unwrap_or(cell);
+						f.write_str("    ")?;
+						f.write_str(&pad[..len - cell.width()])?;
+						cell.write_to(f, color)?;
+					}
+				},
+				TableRow::Error(a, b) => {
+					a.write_to(f, color)?;
+					f.write_str(&pad[..w1 - a.width()])?;
+					f.write_str("    ")?;
+					b.write_to(f, color)?;
+				},
+				TableRow::Spacer => { spacer.write_to(f, color)?; },
+			}
+			f.write_char('\n')?;
+		}
+
+		Ok(())
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
 /// # Table Row.
 ///
-/// This holds the data for a single row. There are a few different variations,
-/// but it's pretty straight-forward.
-enum TableRow {
-	/// # Normal Row.
-	Normal(String, String, String, String),
+/// This holds the data for a single row. There are a few different variations,
+/// but it's pretty straight-forward.
+enum TableRow {
+	/// # Normal Row.
+	///
+	/// This holds the name and a cell for each column, if any.
+	Normal(Cell, Vec<Option<Cell>>),
+
+	/// # An Error.
+	Error(Cell, Cell),
+
+	/// # A Spacer.
+	Spacer,
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # Table Cell.
+///
+/// A cell is made up of one or more segments, each with its own (optional)
+/// ANSI style. Keeping the two separate allows the content to be rendered
+/// with or without formatting, and measured exactly either way.
+pub(crate) struct Cell(Vec<(&'static str, String)>);
+
+impl Cell {
+	/// # New (Single Segment).
+	fn new<S: Into<String>>(style: &'static str, text: S) -> Self {
+		Self(vec![(style, text.into())])
+	}
+
+	/// # Split Decimal.
+	///
+	/// Split a single-segment numeric cell into its integer, fraction, and
+	/// unit parts. See [`util::split_decimal`].
+	fn split_decimal(&self) -> Option<(&str, &str, &str)> {
+		let [(_, text)] = self.0.as_slice() else { return None; };
+		util::split_decimal(text)
+	}
+
+	/// # Decimal-Aligned Copy.
+	///
+	/// Return a copy of the cell padded to the given integer and fraction
+	/// widths, so that it lines up with its neighbors on the decimal point
+	/// while the unit stays put on the right. Cells that aren't numeric
+	/// return `None`.
+	fn aligned(&self, (int, frac): (usize, usize)) -> Option<Self> {
+		let (i, f, rest) = self.split_decimal()?;
+		let mut text = String::with_capacity(int + frac + rest.len() + 4);
+		for _ in util::width(i)..int { text.push(' '); }
+		text.push_str(i);
+		text.push_str(f);
+		for _ in util::width(f)..frac { text.push(' '); }
+		text.push_str(rest);
+		Some(Self::new(self.0[0].0, text))
+	}
+
+	/// # Width.
+	///
+	/// Return the printable width of the cell.
+	fn width(&self) -> usize {
+		self.0.iter().map(|(_, t)| util::width(t)).sum()
+	}
+
+	/// # Truncate (Middle).
+	///
+	/// Shorten the cell to the given printable width, if needed, by replacing
+	/// the middle with an ellipsis. The unique — undimmed — end of a name is
+	/// favored, but a little of the start is kept too.
+	fn truncate_middle(&mut self, max: usize) {
+		if max < 2 || self.width() <= max { return; }
+		let avail = max - 1;
+
+		// Skip past any trailing markers or notes.
+		let end = self.0.iter()
+			.rposition(|(style, _)| *style != STYLE_MARKER && *style != STYLE_DIM && *style != STYLE_DRIFT)
+			.unwrap_or(0);
+		let suffix = match self.0.get(..=end).unwrap_or_default() {
+			[_, .., (style, s)] if *style == STYLE_NAME => util::width(s),
+			_ => 0,
+		};
+		let tail = suffix.max(avail / 2).min(avail - MIN_NAME_HEAD.min(avail / 2));
+		let head = avail - tail;
+
+		// Take from the front…
+		let mut front: Vec<(&'static str, String)> = Vec::new();
+		let mut w = 0;
+		for (style, text) in &self.0 {
+			let mut seg = String::new();
+			let mut done = false;
+			for c in text.chars() {
+				let cw = util::width(c.encode_utf8(&mut [0; 4]));
+				if head < w + cw { done = true; break; }
+				w += cw;
+				seg.push(c);
+			}
+			front.push((*style, seg));
+			if done { break; }
+		}
+
+		// …and the back.
+		let mut back: Vec<(&'static str, String)> = Vec::new();
+		w = 0;
+		for (style, text) in self.0.iter().rev() {
+			let mut seg = Vec::new();
+			let mut done = false;
+			for c in text.chars().rev() {
+				let cw = util::width(c.encode_utf8(&mut [0; 4]));
+				if tail < w + cw { done = true; break; }
+				w += cw;
+				seg.push(c);
+			}
+			// Don't start with an orphaned combining mark.
+			while seg.last().is_some_and(|c| util::width(c.encode_utf8(&mut [0; 4])) == 0) {
+				seg.pop();
+			}
+			back.push((*style, seg.into_iter().rev().collect()));
+			if done { break; }
+		}
+
+		front.push((STYLE_NAME_DIM, "\u{2026}".to_owned()));
+		front.extend(back.into_iter().rev());
+		front.retain(|(_, t)| ! t.is_empty());
+		self.0 = front;
+	}
+
+	/// # Write.
+	///
+	/// Write the cell to the given writer, with or without ANSI formatting.
+	fn write_to<W: fmt::Write>(&self, f: &mut W, color: bool) -> fmt::Result {
+		for (style, text) in &self.0 {
+			// Markers are a stand-in for color.
+			if *style == STYLE_MARKER {
+				if color { for _ in 0..util::width(text) { f.write_char(' ')?; } }
+				else { f.write_str(text)?; }
+			}
+			else if ! color { f.write_str(&util::strip_ansi(text))?; }
+			else if style.is_empty() { f.write_str(text)?; }
+			else { write!(f, "\x1b[{style}m{text}\x1b[0m")?; }
+		}
+		Ok(())
+	}
+}
+
+
+
+/// # Write and Flush.
+///
+/// Write the (pre-rendered) output to the writer, and flush it.
+fn write_flush<W: Write>(w: &mut W, out: &str) -> std::io::Result<()> {
+	w.write_all(out.as_bytes())?;
+	w.flush()
+}
+
+/// # Print Debug Banner.
+///
+/// Print an unmissable warning for benchmarks built without optimizations.
+fn print_debug_banner() {
+	const BAR: &str = "\x1b[1;91m!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!!\x1b[0m";
+	eprintln!(
+		"{BAR}
+\x1b[1;91mWarning:\x1b[0m \x1b[1mThis is an unoptimized (debug) build!\x1b[0m
+
+The results will be wildly slower than normal and should not be trusted.
+History will be neither loaded nor saved for this run. Try again with
+\x1b[1m--release\x1b[0m, or use \x1b[1mBenches::allow_debug\x1b[0m if this is intentional.
+{BAR}"
+	);
+}
+
+/// # Catch Panic.
+///
+/// Run the callback, returning its result, or the message of the panic that
+/// cut it short.
+///
+/// The first call installs a panic hook that stays silent while callbacks are
+/// running — the message is reported in the summary instead — so the output
+/// isn't cluttered with backtraces. Other panics are passed through to the
+/// previous hook as usual.
+fn catch_panic<T, F: FnOnce() -> T>(cb: F) -> Result<T, String> {
+	/// # Once.
+	static ONCE: Once = Once::new();
+	ONCE.call_once(|| {
+		let prev = std::panic::take_hook();
+		std::panic::set_hook(Box::new(move |info|
+			if ! QUIET_PANICS.load(SeqCst) { prev(info); }
+		));
+	});
+
+	QUIET_PANICS.store(true, SeqCst);
+	let res = std::panic::catch_unwind(AssertUnwindSafe(cb));
+	QUIET_PANICS.store(false, SeqCst);
+	res.map_err(|e| panic_message(&*e))
+}
+
+/// # Panic Message.
+///
+/// Return the first line of the panic message, if it was a string.
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+	payload.downcast_ref::<&str>().copied()
+		.or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+		.and_then(|s| s.lines().next())
+		.map_or_else(String::new, |s| s.trim().to_owned())
+}
+
+/// # Normalize Name.
+///
+/// Trim the name and compact its whitespace, but otherwise pass it through
+/// as-is.
+fn normalize_name(name: &str) -> String {
+	let mut ws = false;
+	name.trim().chars()
+		.filter_map(|c|
+			if c.is_whitespace() {
+				if ws { None }
+				else {
+					ws = true;
+					Some(' ')
+				}
+			}
+			else {
+				ws = false;
+				Some(c)
+			}
+		)
+		.collect()
+}
+
+/// # Normalize Tag.
+///
+/// Trim and lowercase the tag, returning `None` if it's empty.
+fn normalize_tag(tag: &str) -> Option<String> {
+	let tag = tag.trim();
+	if tag.is_empty() { None }
+	else { Some(tag.to_lowercase()) }
+}
+
+/// # Tags From Environment.
+///
+/// Parse a comma-separated list of tags from the given environmental
+/// variable, if set.
+fn tags_from_env(key: &str) -> Option<Vec<String>> {
+	let raw = std::env::var(key).ok()?;
+	Some(raw.split(',').filter_map(normalize_tag).collect())
+}
+
+/// # Name Filters From Arguments.
+///
+/// Return the positional command-line arguments, if any, to use as name
+/// filters.
+///
+/// See `parse_name_args` for details.
+fn names_from_args() -> Vec<String> {
+	// The test harness's arguments are meant for it, not us.
+	if cfg!(test) { Vec::new() }
+	else { parse_name_args(std::env::args().skip(1)) }
+}
+
+/// # List From Arguments.
+///
+/// Returns true if the `--list` flag was passed on the command line.
+fn list_from_args() -> bool {
+	// The test harness's arguments are meant for it, not us.
+	! cfg!(test) && std::env::args().skip(1).any(|a| a == "--list")
+}
+
+/// # Parse Name Filters.
+///
+/// Return the positional arguments to use as name filters.
+///
+/// Flags — like the `--bench` Cargo tacks on — are ignored, along with the
+/// values of any `libtest` (or `criterion`) flags that take one, so that the
+/// usual `cargo bench` invocations don't accidentally filter everything out.
+fn parse_name_args<I>(mut args: I) -> Vec<String>
+where I: Iterator<Item=String> {
+	/// # Harness Flags With Values.
+	const VALUED: [&str; 21] = [
+		// libtest.
+		"--color", "--format", "--logfile", "--shuffle-seed", "--skip",
+		"--test-threads", "-Z",
+		// criterion.
+		"--baseline", "--baseline-lenient", "--confidence-level",
+		"--load-baseline", "--measurement-time", "--noise-threshold",
+		"--nresamples", "--output-format", "--plotting-backend",
+		"--profile-time", "--sample-size", "--save-baseline",
+		"--significance-level", "--warm-up-time",
+	];
+
+	let mut out = Vec::new();
+	while let Some(arg) = args.next() {
+		if VALUED.contains(&arg.as_str()) { let _res = args.next(); }
+		else if ! arg.starts_with('-') {
+			let arg = arg.trim();
+			if ! arg.is_empty() { out.push(arg.to_owned()); }
+		}
+	}
+	out
+}
+
+#[expect(unsafe_code, reason = "MIN_SAMPLES_FLOOR is non-zero.")]
+/// # Clamp Samples.
+///
+/// Return the sample limit, bumped up to the absolute floor if needed. Zero
+/// — and anything past the hard cap — means `MAX_SAMPLES`, i.e. time-bound
+/// only.
+///
+/// Note: each benchmark's own minimum — usually `MIN_SAMPLES` — is applied
+/// later, by `Bench::samples`.
+const fn clamp_samples(samples: u32) -> NonZeroU32 {
+	if samples == 0 || MAX_SAMPLES.get() < samples { MAX_SAMPLES }
+	else if samples < MIN_SAMPLES_FLOOR {
+		// Safety: MIN_SAMPLES_FLOOR is non-zero.
+		unsafe { NonZeroU32::new_unchecked(MIN_SAMPLES_FLOOR) }
+	}
+	else {
+		// Safety: anything MIN_SAMPLES_FLOOR+ is also non-zero.
+		unsafe { NonZeroU32::new_unchecked(samples) }
+	}
+}
+
+/// # Clamp Timeout.
+///
+/// Return the time limit, bumped up to the minimum if needed.
+const fn clamp_timeout(timeout: Duration) -> Duration {
+	if timeout.as_millis() < MIN_TIMEOUT.as_millis() { MIN_TIMEOUT }
+	else { timeout }
+}
+
+/// # Format Change.
+///
+/// Style up a relative change, green for faster, red for slower.
+fn format_change(diff: f64) -> Cell {
+	if diff.is_sign_negative() {
+		Cell::new(STYLE_FASTER, format!("-{}", NicePercent::from(-diff)))
+	}
+	else {
+		Cell::new(STYLE_SLOWER, format!("+{}", NicePercent::from(diff)))
+	}
+}
+
+/// # Tally Line.
+///
+/// Return a one-line count of the benchmarks that got faster, slower, stayed
+/// the same, or failed, e.g. `2 faster · 1 slower · 34 unchanged · 1 error`,
+/// leaving out the empty buckets.
+///
+/// If none of the (successful) benchmarks had a baseline to compare against,
+/// `None` is returned instead.
+fn tally_line(results: &[BenchResult], color: bool) -> Option<String> {
+	if ! results.iter().any(|r| r.stats.is_ok() && r.baseline.is_some()) { return None; }
+
+	let mut faster = 0_u32;
+	let mut slower = 0_u32;
+	let mut unchanged = 0_u32;
+	let mut errors = 0_u32;
+	for r in results {
+		match (&r.stats, r.change) {
+			(Ok(_), Some(c)) if c.is_sign_negative() => { faster += 1; },
+			(Ok(_), Some(_)) => { slower += 1; },
+			(Ok(_), None) => if r.baseline.is_some() { unchanged += 1; },
+			(Err(BrunchError::NoRun), _) => {},
+			(Err(_), _) => if r.skipped.is_none() { errors += 1; },
+		}
+	}
+
+	let parts: Vec<String> = [
+		(faster, STYLE_FASTER, "faster"),
+		(slower, STYLE_SLOWER, "slower"),
+		(unchanged, STYLE_DIM, "unchanged"),
+		(errors, STYLE_ERROR, if errors == 1 { "error" } else { "errors" }),
+	]
+		.into_iter()
+		.filter(|(n, _, _)| *n != 0)
+		.map(|(n, style, label)|
+			if color { format!("\x1b[{style}m{} {label}\x1b[0m", NiceU32::from(n)) }
+			else { format!("{} {label}", NiceU32::from(n)) }
+		)
+		.collect();
+	if parts.is_empty() { return None; }
+
+	Some(parts.join(if color { " \x1b[2m\u{b7}\x1b[0m " } else { " \u{b7} " }))
+}
+
+/// # Round Spread.
+///
+/// Return the half-range of the per-round means relative to their midpoint,
+/// e.g. `0.01` for ±1%, if there was more than one round.
+fn spread(means: &[f64]) -> Option<f64> {
+	if means.len() < 2 { return None; }
+	let (lo, hi) = means.iter().copied().fold(
+		(f64::INFINITY, f64::NEG_INFINITY),
+		|(lo, hi), m| (lo.min(m), hi.max(m)),
+	);
+	let out = (hi - lo) / (hi + lo);
+	if out.is_finite() { Some(out) } else { None }
+}
+
+/// # Geometric Mean.
+///
+/// Return the geometric mean of the (positive) values, or `None` if there
+/// aren't any, or any are zero, negative, or otherwise weird.
+fn geomean<I: IntoIterator<Item=f64>>(src: I) -> Option<f64> {
+	let mut sum = 0.0;
+	let mut len = 0_u32;
+	for v in src {
+		if ! v.is_normal() || v.is_sign_negative() { return None; }
+		sum += v.ln();
+		len += 1;
+	}
+	if len == 0 { None }
+	else {
+		let out = (sum / f64::from(len)).exp();
+		if out.is_finite() { Some(out) } else { None }
+	}
+}
+
+/// # Ranks.
+///
+/// Return the (one-based) rank of each of the stats — already sorted from
+/// fastest to slowest — along with whether or not it is tied with a
+/// neighbor, i.e. their ±2σ ranges overlap. Tied stats share the rank of
+/// the first in the run.
+fn ranks<I: IntoIterator<Item=Stats>>(src: I) -> Vec<(usize, bool)> {
+	let mut out: Vec<(usize, bool)> = Vec::new();
+	let mut last: Option<f64> = None;
+	for (idx, s) in src.into_iter().enumerate() {
+		let mean = s.mean().as_secs_f64();
+		let spread = s.deviation().as_secs_f64() * 2.0;
+		let tied = last.is_some_and(|hi| total_cmp!((mean - spread) <= hi));
+		if tied {
+			if let Some(prev) = out.last_mut() { prev.1 = true; }
+			let rank = out.last().map_or(1, |(r, _)| *r);
+			out.push((rank, true));
+		}
+		else { out.push((idx + 1, false)); }
+		last = Some(mean + spread);
+	}
+	out
+}
+
+/// # Group Prefix.
+///
+/// Return the namespace — everything up to the last `::` — shared by all of
+/// the names, if any, e.g. `json` for `json::parse(…)` and
+/// `json::to_string(…)`.
+fn group_prefix<'a, I: IntoIterator<Item=&'a str>>(names: I) -> Option<&'a str> {
+	let mut iter = names.into_iter();
+	let mut out = iter.next()?;
+	for name in iter {
+		let len = out.char_indices()
+			.zip(name.chars())
+			.take_while(|((_, a), b)| a == b)
+			.last()
+			.map_or(0, |((idx, c), _)| idx + c.len_utf8());
+		out = &out[..len];
+	}
+	out.rfind("::").map(|idx| &out[..idx]).filter(|p| ! p.is_empty())
+}
+
+/// # libtest Line.
+///
+/// Format the result the way libtest does, e.g.
+/// `test foo ... bench:       1,234 ns/iter (+/- 56)`.
+fn libtest_line(name: &str, res: &Result<Stats, BrunchError>) -> String {
+	match res {
+		Ok(s) => format!(
+			"test {name} ... bench: {:>11} ns/iter (+/- {})",
+			NiceU64::from(u64::saturating_from(s.mean().as_nanos())),
+			NiceU64::from(u64::saturating_from(s.deviation().as_nanos())),
+		),
+		Err(e) => format!("test {name} ... FAILED ({e})"),
+	}
+}
+
+/// # Format Name.
+///
+/// Style up a benchmark name by dimming common portions, and highlighting
+/// unique ones.
+///
+/// This approach won't scale well, but the bench count for any given set
+/// should be relatively low.
+pub(crate) fn format_name(mut name: Vec<char>, names: &[Vec<char>]) -> Cell {
+	let len = name.len();
+
+	// Find the first unique char occurrence.
+	let mut pos: usize = names.iter()
+		.filter_map(|other|
+			if name.eq(other) { None }
+			else {
+				name.iter()
+					.zip(other.iter())
+					.position(|(l, r)| l != r)
+					.or_else(|| Some(len.min(other.len())))
+			}
+		)
+		.max()
+		.unwrap_or_default();
+
+	if 0 < pos && pos < len && ! matches!(name[pos], ':' | '(') {
+		// Let's rewind the marker to the position before the last : or (.
+		if let Some(pos2) = name[..pos].iter().rposition(|c| matches!(c, ':' | '(')) {
+			pos = name[..pos2].iter()
+				.rposition(|c| ! matches!(c, ':' | '('))
+				.map_or(0, |p| p + 1);
+		}
+		// Before the last _ or space?
+		else if let Some(pos2) = name[..pos].iter().rposition(|c| matches!(c, '_' | ' ')) {
+			pos = name[..pos2].iter()
+				.rposition(|c| ! matches!(c, '_' | ' '))
+				.map_or(0, |p| p + 1);
+		}
+		// Remove the marker entirely.
+		else { pos = 0; }
+	}
+
+	if pos == 0 { Cell::new(STYLE_NAME, name.into_iter().collect::<String>()) }
+	else if pos == len { Cell::new(STYLE_NAME_DIM, name.into_iter().collect::<String>()) }
+	else {
+		let b = name.split_off(pos);
+		Cell(vec![
+			(STYLE_NAME_DIM, name.into_iter().collect()),
+			(STYLE_NAME, b.into_iter().collect()),
+		])
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_results() {
+		let mut benches = Benches::default();
+		benches.extend([
+			Bench::new("One").with_samples(100).run(|| 1_u8.checked_add(1)),
+			Bench::spacer(),
+			Bench::new("Two"),
+			Bench::new("One").with_samples(100).run(|| 1_u8.checked_add(2)),
+		]);
+
+		// Nothing has run yet.
+		assert!(
+			benches.results().iter().all(|r| matches!(r.stats(), Err(BrunchError::NoRun | BrunchError::DupeName))),
+			"Benches should not run until finish.",
+		);
+
+		benches.exec(&mut History::disabled());
+		let res = benches.results();
+		assert_eq!(res.len(), 3, "Spacers should be omitted from results.");
+
+		assert_eq!(res[0].name(), "One");
+		assert!(res[0].baseline().is_none(), "Unexpected baseline.");
+		if let Ok(stats) = res[0].stats() {
+			assert_eq!(stats.total(), 100, "Wrong sample total.");
+			assert!(stats.valid() <= stats.total(), "Valid exceeds total.");
+		}
+
+		assert_eq!(res[1].name(), "Two");
+		assert!(matches!(res[1].stats(), Err(BrunchError::NoRun)), "Expected NoRun.");
+
+		assert_eq!(res[2].name(), "One");
+		assert!(matches!(res[2].stats(), Err(BrunchError::DupeName)), "Expected DupeName.");
+	}
+
+	#[test]
+	fn t_push_opt() {
+		let mut benches = Benches::default();
+		benches.push(Bench::spacer());
+		benches.push(Bench::new("One"));
+		benches.push(Bench::spacer());
+		benches.push(None);
+		benches.push(Bench::spacer());
+		benches.push(Some(Bench::new("Two")));
+		benches.push(false.then(|| Bench::new("Three")));
+
+		// The Nones and redundant spacers should have been skipped.
+		assert_eq!(benches.set.len(), 3);
+		assert!(benches.set[1].is_spacer(), "Expected spacer.");
+		assert_eq!(benches.results().len(), 2);
+	}
+
+	#[test]
+	fn t_entries() {
+		let mut benches = Benches::default();
+		BenchEntry::push_to(Bench::new("One"), &mut benches);
+		BenchEntry::push_to(None, &mut benches);
+		BenchEntry::push_to(Some(Bench::new("Two")), &mut benches);
+		BenchEntry::push_to(vec![Bench::new("Three"), Bench::new("Four")], &mut benches);
+		BenchEntry::push_to((5..7).map(|n| Bench::new(format!("N{n}"))), &mut benches);
+
+		let names: Vec<&str> = benches.set.iter().map(|b| b.name.as_str()).collect();
+		assert_eq!(names, ["One", "Two", "Three", "Four", "N5", "N6"]);
+	}
+
+	#[test]
+	fn t_iter() {
+		let benches: Benches = [
+			Bench::new("One"),
+			Bench::spacer(),
+			Bench::new("Two"),
+			Bench::new("One"),
+		].into_iter().collect();
+
+		// Spacers don't count.
+		assert_eq!(benches.len(), 3);
+		assert!(! benches.is_empty());
+		assert_eq!(benches.names(), ["One", "Two", "One"]);
+		assert_eq!((&benches).into_iter().count(), 3);
+
+		// Dupes should still be caught.
+		assert!(
+			matches!(benches.results()[2].stats(), Err(BrunchError::DupeName)),
+			"Expected DupeName.",
+		);
+
+		assert!(Benches::default().is_empty());
+		assert!(std::iter::once(Bench::spacer()).collect::<Benches>().is_empty());
+	}
+
+	#[test]
+	fn t_dupe_policy() {
+		let set = || [
+			Bench::new("One").run(|| 1_u8),
+			Bench::new("Two").run(|| 2_u8),
+			Bench::new("One").run(|| 3_u8),
+			Bench::new("One").run(|| 4_u8),
+		];
+
+		// Errors, but no wasted samples.
+		let mut benches = Benches::default();
+		benches.extend(set());
+		assert_eq!(benches.names(), ["One", "Two", "One", "One"]);
+		assert!(! benches.set[2].is_pending() && ! benches.set[3].is_pending());
+
+		// Replace in place.
+		let mut benches = Benches::default().on_duplicate(DupePolicy::Replace);
+		benches.extend(set());
+		assert_eq!(benches.names(), ["One", "Two"]);
+		assert!(benches.iter().all(Bench::is_pending));
+
+		// Rename.
+		let mut benches = Benches::default().on_duplicate(DupePolicy::Rename);
+		benches.extend(set());
+		assert_eq!(benches.names(), ["One", "Two", "One (2)", "One (3)"]);
+		assert!(benches.iter().all(Bench::is_pending));
+		benches.push(Bench::new("One (2)"));
+		assert_eq!(benches.names().last(), Some(&"One (2) (2)"));
+	}
+
+	#[test]
+	fn t_remove() {
+		let mut benches: Benches = [
+			Bench::new("One"),
+			Bench::spacer(),
+			Bench::new("Two"),
+			Bench::new("One"),
+		].into_iter().collect();
+		assert!(matches!(benches.set[3].stats, Some(Err(BrunchError::DupeName))));
+
+		// Removing the first One should clear the flag from the second.
+		assert_eq!(benches.remove("One").map(|b| b.name), Some("One".to_owned()));
+		assert!(benches.remove("Three").is_none());
+		assert_eq!(benches.names(), ["Two", "One"]);
+		assert!(benches.set[2].stats.is_none(), "DupeName should be cleared.");
+
+		// A replacement can be pushed after removal too.
+		assert!(benches.remove("Two").is_some());
+		benches.push(Bench::new("Two"));
+		assert!(benches.set.last().is_some_and(|b| b.stats.is_none()), "Unexpected DupeName.");
+
+		// Retain can drop spacers.
+		assert!(benches.set[0].is_spacer());
+		benches.retain(|b| ! b.is_spacer());
+		assert_eq!(benches.set.len(), 2);
+		benches.retain(|b| b.name() != "One");
+		assert_eq!(benches.names(), ["Two"]);
+	}
+
+	#[test]
+	fn t_incremental() {
+		let path = std::env::temp_dir().join("__brunch-test-incremental.last");
+		for incremental in [true, false] {
+			let _res = std::fs::remove_file(&path);
+			let mut benches = Benches::default().with_incremental_history(incremental);
+			benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+
+			// Results should be saved as they happen, unless disabled.
+			let mut history = History::new(Some(&path));
+			benches.exec(&mut history);
+			assert_eq!(
+				History::open(&path).and_then(|h| h.latest("One")).is_some(),
+				incremental,
+				"Incremental saving mismatch.",
+			);
+
+			// Either way, they should be saved at the end.
+			benches.finish_history(&mut history);
+			assert!(
+				History::open(&path).and_then(|h| h.latest("One")).is_some(),
+				"History should have been saved.",
+			);
+		}
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_without_history() {
+		let path = std::env::temp_dir().join("__brunch-test-without.last");
+		let _res = std::fs::remove_file(&path);
+
+		let mut benches = Benches::default()
+			.with_history_path(&path)
+			.without_history();
+		benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+
+		let mut out = Vec::new();
+		benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+		assert!(! path.exists(), "History should not have been saved.");
+	}
+
+	#[test]
+	fn t_color() {
+		let run = |color: Option<bool>| {
+			let mut benches = Benches::default()
+				.without_history()
+				.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+			if let Some(color) = color { benches = benches.with_color(color); }
+			benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+			let mut out = Vec::new();
+			benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+			String::from_utf8(out).expect("Invalid UTF-8.")
+		};
+
+		// Writers get color by default, but it can be switched off.
+		assert!(run(None).contains("\x1b["), "Missing ANSI formatting.");
+		assert!(run(Some(true)).contains("\x1b["), "Missing ANSI formatting.");
+		assert!(! run(Some(false)).contains("\x1b["), "Unexpected ANSI formatting.");
+	}
+
+	#[test]
+	fn t_config_macro() {
+		// Every key should find its way to the right setting.
+		let mut b = Benches::default();
+		crate::benches!(
+			@config b,
+			budget_secs: 60,
+			color: false,
+			history: "target/brunch.hist",
+			history_readonly: true,
+			isolated: true,
+			no_history: true,
+			output: OutputTarget::Stdout,
+			parallel: 2,
+			quiet: true,
+			repeat: 3,
+			retries: 4,
+			samples: 10_000,
+			skip_tags: ["slow"],
+			tags: ["fast", "serde"],
+			timeout: Duration::from_secs(3),
+			unit: TimeUnit::Micros,
+			verbose: true,
+		);
+		assert_eq!(b.budget, Some(Duration::from_secs(60)));
+		assert_eq!(b.color, Some(false));
+		assert_eq!(b.history.as_deref(), Some(Path::new("target/brunch.hist")));
+		assert_eq!(b.history_ro, Some(true));
+		assert_eq!(b.isolated, Some(true));
+		assert!(b.no_history, "History should be disabled.");
+		assert_eq!(b.output, Some(OutputTarget::Stdout));
+		assert_eq!(b.parallel, Some(2));
+		assert_eq!(b.quiet, Some(true));
+		assert_eq!(b.runs, Some(3));
+		assert_eq!(b.retries, 4);
+		assert_eq!(b.samples, Some(clamp_samples(10_000)));
+		assert_eq!(b.skip_tags.as_deref(), Some(&["slow".to_owned()][..]));
+		assert_eq!(b.tags.as_deref(), Some(&["fast".to_owned(), "serde".to_owned()][..]));
+		assert_eq!(b.timeout, Some(clamp_timeout(Duration::from_secs(3))));
+		assert_eq!(b.unit, TimeUnit::Micros);
+		assert_eq!(b.verbose, Some(true));
+
+		// Seconds can be fractional, and later keys win.
+		let mut b = Benches::default();
+		crate::benches!(@config b, timeout: Duration::from_secs(1), timeout_secs: 2.5, no_history: false);
+		assert_eq!(b.timeout, Some(clamp_timeout(Duration::from_millis(2500))));
+		assert!(! b.no_history, "History should be enabled.");
+
+		// An empty block leaves the defaults be.
+		let b = Benches::default();
+		crate::benches!(@config b,);
+		assert_eq!(b.samples, None);
+		assert_eq!(b.color, None);
+		assert!(b.tags.is_none(), "Tags should be unset.");
+	}
+
+	#[test]
+	fn t_history_readonly() {
+		let path = std::env::temp_dir().join("__brunch-test-readonly.last");
+		let _res = std::fs::remove_file(&path);
+
+		// Record a baseline.
+		let mut out = Vec::new();
+		let mut benches = Benches::default().allow_debug(true).with_history_path(&path);
+		benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+		benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+		let before = std::fs::read(&path).expect("History should have been saved.");
+
+		// Read-only runs should compare against it, but leave it be.
+		for _ in 0..2 {
+			let mut out = Vec::new();
+			let mut benches = Benches::default()
+				.allow_debug(true)
+				.with_history_path(&path)
+				.history_readonly(true);
+			benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+			let summary = benches.finish_to(&mut out)
+				.expect("Finish failed.")
+				.expect("Missing summary.");
+			assert!(summary.history_loaded(), "History should have been loaded.");
+			assert!(! summary.history_saved(), "History should not have been saved.");
+			assert!(benches.set[0].baseline.is_some(), "Missing baseline.");
+
+			let out = String::from_utf8(out).expect("Invalid UTF-8.");
+			assert!(out.contains("Read-only history (baseline not updated)."), "{out}");
+			assert_eq!(std::fs::read(&path).ok(), Some(before.clone()), "History should be unchanged.");
+		}
+
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_summary() {
+		// Nothing to run.
+		let mut out = Vec::new();
+		let res = Benches::default().finish_to(&mut out).expect("Finish failed.");
+		assert!(matches!(res, Err(BrunchError::NoBench)));
+
+		let path = std::env::temp_dir().join("__brunch-test-summary.last");
+		let _res = std::fs::remove_file(&path);
+		for loaded in [false, true] {
+			let mut benches = Benches::default()
+				.allow_debug(true)
+				.with_history_path(&path);
+			benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+			benches.push(Bench::new("Two"));
+
+			let summary = benches.finish_to(&mut out)
+				.expect("Finish failed.")
+				.expect("Missing summary.");
+			assert_eq!(summary.results().len(), 2);
+			assert_eq!(summary.passed(), 1);
+			assert_eq!(summary.errored(), 1);
+			assert_eq!(summary.skipped(), 0);
+			assert_eq!(summary.history_loaded(), loaded);
+			assert!(summary.history_saved(), "History should have been saved.");
+			assert!(! summary.is_success(), "Two should have failed.");
+			assert_eq!(summary.exit_code(), None);
+		}
+		let _res = std::fs::remove_file(&path);
+
+		// No history, no saving.
+		let mut benches = Benches::default().allow_debug(true).without_history();
+		benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+		let summary = benches.finish_to(&mut out)
+			.expect("Finish failed.")
+			.expect("Missing summary.");
+		assert!(summary.is_success(), "One should have passed.");
+		assert!(! summary.history_loaded(), "History should not have been loaded.");
+		assert!(! summary.history_saved(), "History should not have been saved.");
+	}
+
+	#[test]
+	fn t_quiet() {
+		let mut out = Vec::new();
+		let mut benches = Benches::default().allow_debug(true).without_history().quiet(true);
+		benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+		benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+		let out = String::from_utf8(out).expect("Invalid UTF-8.");
+		assert!(out.contains("One"), "{out}");
+		assert!(! out.contains("Finished"), "{out}");
+
+		// With libtest lines, the table goes too.
+		let mut out = Vec::new();
+		let mut benches = Benches::default()
+			.allow_debug(true)
+			.without_history()
+			.libtest_format(true)
+			.quiet(true);
+		benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+		benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+		assert!(out.is_empty(), "The table should have been suppressed.");
+
+		// Errors are still printed, just plainly.
+		let mut out = Vec::new();
+		let res = Benches::default().quiet(true).finish_to(&mut out).expect("Finish failed.");
+		assert!(matches!(res, Err(BrunchError::NoBench)));
+		assert_eq!(out, b"Error: At least one benchmark is required.\n");
+	}
+
+	#[test]
+	fn t_tags() {
+		let bench = |name: &str, tags: &[&str]| {
+			let mut b = Bench::new(name);
+			for t in tags { b = b.tag(t); }
+			b.run(|| 1_u8.checked_add(1))
+		};
+
+		// Tags are cleaned up and deduplicated.
+		let b = bench("A", &[" Slow", "slow", "", "serde "]);
+		assert_eq!(b.tags(), ["slow", "serde"]);
+		assert!(Bench::spacer().tag("slow").tags().is_empty(), "Spacers can't be tagged.");
+
+		let mut benches = Benches::default()
+			.filter_tags(&["slow", "SERDE"])
+			.skip_tags(&["broken"]);
+		benches.extend([
+			bench("A", &[]),
+			Bench::spacer(),
+			bench("B", &["slow"]),
+			bench("C", &["serde", "broken"]),
+			Bench::spacer(),
+			bench("D", &["fast"]),
+			Bench::spacer(),
+			bench("E", &["serde"]),
+		]);
+		benches.filter_set();
+
+		// Only the matches should be left, without any dangling spacers.
+		let names: Vec<&str> = benches.set.iter().map(|b| b.name.as_str()).collect();
+		assert_eq!(names, ["B", "", "E"]);
+		assert_eq!(benches.filtered, ["A", "C", "D"]);
+		assert!(
+			benches.notes(&History::disabled(), 0).iter().any(|n| n == "Left out 3 benchmarks by tag."),
+			"Missing note.",
+		);
+
+		// No filters, no changes.
+		let mut benches = Benches::default();
+		benches.extend([bench("A", &[]), Bench::spacer(), bench("B", &["slow"])]);
+		benches.filter_set();
+		assert_eq!(benches.set.len(), 3);
+		assert!(benches.filtered.is_empty(), "Nothing should be filtered.");
+	}
+
+	#[test]
+	fn t_filter_names() {
+		let bench = |name: &str, tags: &[&str]| {
+			let mut b = Bench::new(name);
+			for t in tags { b = b.tag(t); }
+			b.run(|| 1_u8.checked_add(1))
+		};
+
+		let mut benches = Benches::default()
+			.filter_names(&["nice_u8", " ", "u16"])
+			.skip_tags(&["broken"]);
+		benches.extend([
+			bench("nice_u8(0)", &[]),
+			bench("nice_u8(1)", &["broken"]),
+			Bench::spacer(),
+			bench("nice_u32(0)", &[]),
+			Bench::spacer(),
+			bench("nice_u16(0)", &[]),
+		]);
+		benches.filter_set();
+
+		// Names can match any filter, but the tags still apply.
+		let names: Vec<&str> = benches.set.iter().map(|b| b.name.as_str()).collect();
+		assert_eq!(names, ["nice_u8(0)", "", "nice_u16(0)"]);
+		assert_eq!(benches.filtered, ["nice_u8(1)"]);
+		assert_eq!(benches.unmatched, ["nice_u32(0)"]);
+		let notes = benches.notes(&History::disabled(), 0);
+		assert!(notes.iter().any(|n| n == "Left out 1 benchmark by tag."), "Missing tag note.");
+		assert!(notes.iter().any(|n| n == "Left out 1 benchmark by name."), "Missing name note.");
+	}
+
+	#[test]
+	fn t_name_args() {
+		let parse = |args: &[&str]| parse_name_args(args.iter().map(|a| (*a).to_owned()));
+
+		// Only the positional arguments are filters.
+		assert_eq!(parse(&["--bench"]), Vec::<String>::new());
+		assert_eq!(parse(&["--bench", "nice_u8"]), ["nice_u8"]);
+		assert_eq!(parse(&[" nice_u8 ", "", "--nocapture", "u16"]), ["nice_u8", "u16"]);
+
+		// Harness flag values aren't.
+		assert_eq!(
+			parse(&["--bench", "--format", "terse", "--color=never", "--test-threads", "1", "nice_u8"]),
+			["nice_u8"],
+		);
+		assert_eq!(
+			parse(&["--save-baseline", "main", "--sample-size", "10", "--skip", "u16"]),
+			Vec::<String>::new(),
+		);
+	}
+
+	#[test]
+	fn t_list() {
+		let ran = std::cell::Cell::new(false);
+		let mut benches = Benches::default()
+			.list_only(true)
+			.skip_tags(&["broken"]);
+		benches.extend([
+			Bench::new("nice_u8(0)").tag("fast").run(|| ran.set(true)),
+			Bench::spacer(),
+			Bench::new("nice_u8(1)").tag("broken").run(|| ran.set(true)),
+			Bench::new("nice_u16(0)").tag("slow").tag("serde").run(|| ran.set(true)),
+			Bench::new("nice_u32(0)").run(|| ran.set(true)),
+		]);
+
+		// Just the names and tags, minus anything filtered out.
+		let mut out = Vec::new();
+		let summary = benches.finish_to(&mut out)
+			.expect("Finish failed.")
+			.expect("Missing summary.");
+		assert_eq!(
+			String::from_utf8(out).expect("Invalid UTF-8."),
+			"nice_u8(0) [fast]\nnice_u16(0) [slow, serde]\nnice_u32(0)\n",
+		);
+
+		// Without running anything.
+		assert!(! ran.get(), "Nothing should have run.");
+		assert!(summary.results().is_empty(), "Nothing should have run.");
+		assert_eq!(summary.exit_code(), None);
+
+		// The flag itself isn't a name filter.
+		assert_eq!(parse_name_args(["--list", "nice_u8"].into_iter().map(String::from)), ["nice_u8"]);
+	}
+
+	#[test]
+	fn t_skip() {
+		let bench = Bench::new("Two").skip(" no AVX-512 ").run(|| 1_u8.checked_add(1));
+		assert!(bench.is_skipped());
+		assert!(bench.sampler.is_none(), "Skipped benches shouldn't have a runner.");
+
+		// Seed the history with an entry for the skipped bench.
+		let path = std::env::temp_dir().join("__brunch-test-skip.last");
+		let _res = std::fs::remove_file(&path);
+		let mut history = History::new(Some(&path));
+		history.insert(
+			"Two",
+			Stats::try_from(vec![Duration::from_micros(1); 1000]).expect("Invalid stats."),
+			Metric::Mean,
+			None,
+			None,
+			None,
+		);
+		history.save();
+
+		let mut benches = Benches::default()
+			.allow_debug(true)
+			.prune_history(true)
+			.with_history_path(&path);
+		benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+		benches.push(bench);
+
+		// The name is still taken.
+		benches.push(Bench::new("Two").run(|| 1_u8.checked_add(1)));
+		assert!(matches!(benches.set[2].stats, Some(Err(BrunchError::DupeName))));
+		benches.set.pop();
+
+		let mut out = Vec::new();
+		let summary = benches.finish_to(&mut out)
+			.expect("Finish failed.")
+			.expect("Missing summary.");
+		assert_eq!(summary.passed(), 1);
+		assert_eq!(summary.errored(), 0);
+		assert_eq!(summary.skipped(), 1);
+		assert!(summary.is_success(), "Skipping isn't failing.");
+		assert_eq!(summary.results()[1].skipped(), Some("no AVX-512"));
+
+		let plain = benches.render_plain();
+		assert!(plain.contains("skipped \u{2014} no AVX-512"), "{plain}");
+
+		// The history should be untouched.
+		let history = History::open(&path).expect("Missing history.");
+		assert_eq!(history.runs("One").len(), 1);
+		assert_eq!(history.runs("Two").len(), 1, "Skipped history should be kept as-is.");
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_config() {
+		let path = std::env::temp_dir().join("__brunch-test-config.last");
+		let _res = std::fs::remove_file(&path);
+
+		let run_with = |samples: u32, metric: Metric| {
+			let mut benches = Benches::default()
+				.allow_debug(true)
+				.with_history_path(&path);
+			benches.push(
+				Bench::new("One")
+					.with_samples(samples)
+					.with_metric(metric)
+					.run(|| 1_u8.checked_add(1))
+			);
+			let mut out = Vec::new();
+			benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+			benches
+		};
+		let run = |samples: u32| run_with(samples, Metric::Mean);
+
+		// Same settings, same comparison.
+		run(200);
+		let benches = run(200);
+		assert!(benches.set[0].reconfigured.is_empty(), "Unexpected config change.");
+		assert!(benches.set[0].baseline.is_some(), "Missing baseline.");
+
+		// Different settings, no comparison.
+		let benches = run(300);
+		assert_eq!(benches.set[0].reconfigured, ["samples 200 \u{2192} 300"]);
+		assert!(benches.set[0].baseline.is_none(), "The baseline should have been dropped.");
+		let plain = benches.render_plain();
+		assert!(plain.contains("--- (config changed)"), "{plain}");
+
+		// And the old runs should be gone.
+		let history = History::open(&path).expect("Missing history.");
+		assert_eq!(history.runs("One").len(), 1);
+
+		// Switching metrics counts as a change too.
+		let benches = run_with(300, Metric::Min);
+		assert_eq!(benches.set[0].reconfigured, ["metric mean \u{2192} minimum"]);
+		assert!(benches.set[0].baseline.is_none(), "The baseline should have been dropped.");
+		let plain = benches.render_plain();
+		assert!(plain.contains("Min"), "Column not relabeled: {plain}");
+		assert!(! plain.contains("Mean"), "Column not relabeled: {plain}");
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_debug() {
+		let path = std::env::temp_dir().join("__brunch-test-debug.last");
+
+		// Tests are (usually) debug builds, so this gets flagged by default.
+		assert_eq!(Benches::default().debug, cfg!(debug_assertions));
+		assert!(! Benches::default().allow_debug(true).debug, "Debug should be allowed.");
+
+		for debug in [false, true] {
+			let _res = std::fs::remove_file(&path);
+
+			let mut benches = Benches::default().with_history_path(&path);
+			benches.debug = debug;
+			benches.push(Bench::new("One").with_samples(1000).run(|| 1_u8.checked_add(1)));
+
+			let mut out = Vec::new();
+			benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+			assert_eq!(path.exists(), ! debug, "History saving mismatch.");
+		}
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_overrides() {
+		let bench = || Bench::new("One")
+			.with_samples(5000)
+			.with_timeout(Duration::from_secs(20));
+
+		// Nothing set.
+		let (over, bad) = Overrides::parse(None, None);
+		assert!(bad.is_empty(), "Unexpected invalid values.");
+		assert!(over.note().is_none(), "Unexpected note.");
+		let mut b = bench();
+		over.apply(&mut b);
+		assert_eq!(b.samples().get(), 5000, "Samples should be unchanged.");
+		assert_eq!(b.timeout(), Duration::from_secs(20), "Timeout should be unchanged.");
+
+		// The environment takes precedence over the builder.
+		let (over, bad) = Overrides::parse(Some("300"), Some("2.5"));
+		assert!(bad.is_empty(), "Unexpected invalid values.");
+		let mut b = bench();
+		over.apply(&mut b);
+		assert_eq!(b.samples().get(), 300, "Samples should be overridden.");
+		assert_eq!(b.timeout(), Duration::from_millis(2500), "Timeout should be overridden.");
+		assert_eq!(over.note().as_deref(), Some("(overridden: 300 samples, 2.50 s timeout)"));
+
+		// Minimums still apply.
+		let (over, _) = Overrides::parse(Some("5"), Some("0"));
+		let mut b = bench();
+		over.apply(&mut b);
+		assert_eq!(b.samples().get(), MIN_SAMPLES, "Samples should be clamped.");
+		assert_eq!(b.timeout(), Duration::from_millis(500), "Timeout should be clamped.");
+
+		// Invalid values are ignored.
+		let (over, bad) = Overrides::parse(Some("lots"), Some("-3"));
+		assert_eq!(bad, [("BRUNCH_SAMPLES", "lots"), ("BRUNCH_TIMEOUT", "-3")]);
+		let mut b = bench();
+		over.apply(&mut b);
+		assert_eq!(b.samples().get(), 5000, "Samples should be unchanged.");
+		assert_eq!(b.timeout(), Duration::from_secs(20), "Timeout should be unchanged.");
+	}
+
+	#[test]
+	fn t_suite_defaults() {
+		let mut benches = Benches::default()
+			.default_samples(300)
+			.default_timeout(Duration::from_secs(2));
+		benches.extend([
+			Bench::new("Suite").run(|| 1_u8.checked_add(1)),
+			Bench::new("Explicit")
+				.with_samples(200)
+				.with_timeout(Duration::from_secs(3))
+				.run(|| 1_u8.checked_add(2)),
+		]);
+		benches.exec(&mut History::disabled());
+
+		// The suite defaults should apply to the first.
+		assert_eq!(benches.set[0].samples().get(), 300, "Suite samples not applied.");
+		assert_eq!(benches.set[0].timeout(), Duration::from_secs(2), "Suite timeout not applied.");
+
+		// But not the second.
+		assert_eq!(benches.set[1].samples().get(), 200, "Explicit samples should win.");
+		assert_eq!(benches.set[1].timeout(), Duration::from_secs(3), "Explicit timeout should win.");
+
+		// Without suite defaults, the global defaults apply.
+		let bench = Bench::new("Global");
+		assert_eq!(bench.samples(), DEFAULT_SAMPLES);
+		assert_eq!(bench.timeout(), DEFAULT_TIMEOUT);
+	}
+
+	#[test]
+	fn t_streaming() {
+		// Streaming is automatic above the threshold.
+		assert!(! Bench::new("Small").is_streaming());
+		assert!(Bench::new("Big").with_samples(STREAMING_THRESHOLD + 1).is_streaming());
+		assert!(! Bench::new("Big").with_samples(STREAMING_THRESHOLD + 1).streaming(false).is_streaming());
+
+		// The results should be about the same either way.
+		let mut benches = Benches::default();
+		benches.extend([
+			Bench::new("Exact")
+				.with_samples(2000)
+				.run(|| std::thread::sleep(Duration::from_micros(50))),
+			Bench::new("Streaming")
+				.with_samples(2000)
+				.streaming(true)
+				.run(|| std::thread::sleep(Duration::from_micros(50))),
+		]);
+		benches.exec(&mut History::disabled());
+
+		let Some(Ok(a)) = benches.set[0].stats else { panic!("Exact bench failed."); };
+		let Some(Ok(b)) = benches.set[1].stats else { panic!("Streaming bench failed."); };
+		assert_eq!(a.total(), b.total());
+		let (a, b) = (a.mean().as_secs_f64(), b.mean().as_secs_f64());
+		assert!((a - b).abs() / a < 0.5, "Means are too different: {a} vs {b}.");
+	}
+
+	#[test]
+	fn t_unlimited_samples() {
+		// Zero means as many as possible.
+		assert_eq!(Bench::new("Zero").with_samples(0).samples(), MAX_SAMPLES);
+		assert_eq!(Bench::new("Lots").with_samples(u32::MAX).samples(), MAX_SAMPLES);
+		assert!(Bench::new("Zero").with_samples(0).is_streaming());
+
+		// It should run until the timeout, not the default limit.
+		let mut bench = Bench::new("Unlimited")
+			.with_samples(0)
+			.with_timeout(MIN_TIMEOUT)
+			.run(|| std::thread::sleep(Duration::from_micros(50)));
+		bench.exec(&mut Progress::new(1), true, None);
+		let Some(Ok(s)) = bench.stats else { panic!("Unlimited bench failed."); };
+		assert!(DEFAULT_SAMPLES.get() < s.total(), "Too few samples: {}", s.total());
+		assert!(MIN_TIMEOUT <= bench.elapsed, "Stopped before the timeout.");
+	}
+
+	#[test]
+	fn t_dump_raw() {
+		let dir = std::env::temp_dir().join("__brunch-test-dump-raw");
+		let _res = std::fs::remove_dir_all(&dir);
+
+		let mut bench = Bench::new("foo::bar(10)")
+			.with_samples(100)
+			.dump_raw(&dir)
+			.run(|| 10_u8.checked_add(1));
+		bench.exec(&mut Progress::new(1), true, None);
+
+		// Every sample should be there, not just the valid ones.
+		let raw = std::fs::read_to_string(dir.join("foo__bar_10_.txt"))
+			.expect("Missing raw samples.");
+		let _res = std::fs::remove_dir_all(&dir);
+		let mut lines = raw.lines();
+		assert_eq!(lines.next(), Some("# foo::bar(10) (100 samples, ns)"));
+		assert_eq!(lines.filter(|l| l.parse::<u64>().is_ok()).count(), 100);
+
+		// Streaming benches have nothing to dump.
+		let mut bench = Bench::new("Streaming")
+			.with_samples(100)
+			.streaming(true)
+			.dump_raw(&dir)
+			.run(|| 10_u8.checked_add(1));
+		bench.exec(&mut Progress::new(1), true, None);
+		assert!(! dir.exists(), "Streaming samples shouldn't be dumped.");
+	}
+
+	#[test]
+	fn t_plot_dir() {
+		let dir = std::env::temp_dir().join("__brunch-test-plot-dir");
+		let _res = std::fs::remove_dir_all(&dir);
+
+		let mut benches = Benches::default().plot_dir(&dir);
+		let mut b = Bench::new("foo::bar(10)").with_samples(100);
+		b.sampler = Some(Box::new(|_| Lap::fake(Duration::from_micros(1))));
+		b.baseline = Some(Stats::try_from(vec![Duration::from_nanos(900); 1000]).expect("Invalid stats."));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		assert!(matches!(benches.set[0].stats, Some(Ok(_))), "Expected stats.");
+		assert!(benches.set[0].plot_samples.is_none(), "The samples should have been released.");
+
+		let svg = std::fs::read_to_string(dir.join("foo__bar_10_.svg"))
+			.expect("Missing plot.");
+		let _res = std::fs::remove_dir_all(&dir);
+		assert!(svg.contains("foo::bar(10)"), "Missing name.");
+		assert!(svg.contains("previous"), "Missing baseline.");
+
+		// Failing to plot shouldn't fail the bench.
+		let file = std::env::temp_dir().join("__brunch-test-plot-file");
+		std::fs::write(&file, b"").expect("Write failed.");
+		let mut benches = Benches::default().plot_dir(&file);
+		let mut b = Bench::new("foo::bar(10)").with_samples(100);
+		b.sampler = Some(Box::new(|_| Lap::fake(Duration::from_micros(1))));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		let _res = std::fs::remove_file(&file);
+		assert!(matches!(benches.set[0].stats, Some(Ok(_))), "Expected stats.");
+	}
+
+	#[test]
+	fn t_gate() {
+		let a = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(1000 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let b = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(1020 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+
+		// A 2% change gets through by default.
+		let gate = Gate::default();
+		let diff = gate.change(b, a).expect("Change expected.");
+		assert!((diff - 0.02).abs() < 0.001, "Wrong change.");
+		assert_eq!(gate.note(), "Changes are reported at p < 0.05.");
+
+		// But not with a 3% threshold.
+		let gate = Gate { threshold: 0.03, ..Gate::default() };
+		assert!(gate.change(b, a).is_none(), "Change should be ignored.");
+
+		// Zero restores the default.
+		let gate = Benches::default().change_threshold(0.0).gate();
+		assert!(gate.change(b, a).is_some(), "Change expected.");
+
+		// Bad values are ignored.
+		let benches = Benches::default().change_threshold(0.03).change_threshold(f64::NAN);
+		assert_eq!(benches.threshold, Some(0.03));
+	}
+
+	#[test]
+	fn t_compare_best() {
+		let stats = |base: u64| Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(base + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let change = |b: &Bench<'_>| Column::Change.cell(b, stats(1000), Gate::default(), TimeUnit::Auto)
+			.map(|c| {
+				let mut out = String::new();
+				c.write_to(&mut out, false).expect("Unable to write cell.");
+				out
+			});
+
+		// Without a best, it's business as usual.
+		let mut bench = Bench::new("Foo");
+		bench.baseline = Some(stats(1000));
+		assert_eq!(change(&bench), None);
+		bench.baseline = Some(stats(980));
+		let recent = change(&bench).expect("Missing change.");
+		assert!(! recent.contains('('), "Unexpected label.");
+
+		// Both should be shown if they differ materially.
+		bench.best = Some(stats(900));
+		let both = change(&bench).expect("Missing change.");
+		assert!(both.starts_with(&recent), "Wrong recent change.");
+		assert!(both.contains(" (recent) / +"), "Missing recent label.");
+		assert!(both.ends_with(" (best)"), "Missing best label.");
+
+		// But not if they're close.
+		bench.best = Some(stats(979));
+		let one = change(&bench).expect("Missing change.");
+		assert!(! one.contains('('), "Unexpected label.");
+
+		// And only the best if the recent change is insignificant.
+		bench.baseline = Some(stats(1000));
+		bench.best = Some(stats(900));
+		let best = change(&bench).expect("Missing change.");
+		assert!(best.starts_with('+') && best.ends_with(" (best)"), "Wrong best change.");
+
+		// The setting can be toggled either way.
+		assert!(Benches::default().compare_best(true).use_best());
+		assert!(! Benches::default().compare_best(false).use_best());
+	}
+
+	#[test]
+	fn t_libtest() {
+		let stats = |nanos: u64| Stats::try_from(vec![Duration::from_nanos(nanos); 1000])
+			.expect("Invalid stats.");
+
+		assert_eq!(
+			libtest_line("foo::bar()", &Ok(stats(1234))),
+			"test foo::bar() ... bench:       1,234 ns/iter (+/- 0)",
+		);
+		assert_eq!(
+			libtest_line("foo::bar()", &Ok(stats(12_345_678_901))),
+			"test foo::bar() ... bench: 12,345,678,901 ns/iter (+/- 0)",
+		);
+		assert_eq!(
+			libtest_line("foo::bar()", &Ok(stats(7))),
+			"test foo::bar() ... bench:           7 ns/iter (+/- 0)",
+		);
+		assert_eq!(
+			libtest_line("foo::bar()", &Err(BrunchError::Panicked("Oops.".to_owned()))),
+			"test foo::bar() ... FAILED (Panicked: Oops.)",
+		);
+
+		// The setting can be toggled either way.
+		assert!(Benches::default().libtest_format(true).libtest());
+		assert!(! Benches::default().libtest_format(false).libtest());
+	}
+
+	#[test]
+	fn t_github() {
+		let stats = |base: u64| Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(base + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+
+		let mut benches = Benches::default();
+		let mut slower = Bench::new("Slower");
+		slower.stats = Some(Ok(stats(1200)));
+		slower.baseline = Some(stats(1000));
+		let mut faster = Bench::new("Faster|Pipe");
+		faster.stats = Some(Ok(stats(800)));
+		faster.baseline = Some(stats(1000));
+		let mut broken = Bench::new("Broken");
+		broken.stats = Some(Err(BrunchError::Panicked("Oops.".to_owned())));
+		benches.push(slower);
+		benches.push(Bench::spacer());
+		benches.push(faster);
+		benches.push(broken);
+
+		// Only the regression and failure should be annotated.
+		let annotations = benches.annotations();
+		assert_eq!(annotations.len(), 2);
+		assert!(annotations[0].starts_with("::warning title=Benchmark Regression::Slower: "));
+		assert!(annotations[0].ends_with("%25)"), "Percent not escaped.");
+		assert_eq!(annotations[1], "::error title=Benchmark Failure::Broken: Panicked: Oops.");
+		assert!(annotations.iter().all(|a| ! a.contains('\x1b')), "Unexpected ANSI.");
+
+		// The Markdown should have a header, separator, and three rows, sans
+		// spacer.
+		let mut md = String::new();
+		benches.table().write_markdown(&mut md).expect("Markdown failed.");
+		let lines: Vec<&str> = md.lines().collect();
+		assert_eq!(lines.len(), 5, "Wrong line count:\n{md}");
+		assert!(lines[0].starts_with("| Method | Mean | Samples |"));
+		assert!(lines[1].starts_with("| :--- | ---: |"));
+		assert!(lines[3].starts_with("| Faster\\|Pipe |"), "Pipe not escaped.");
+		assert!(lines[4].starts_with("| Broken | Panicked: Oops. |"));
+		assert!(lines.iter().all(|l| l.matches(" |").count() == lines[0].matches(" |").count()));
+		assert!(! md.contains('\x1b'), "Unexpected ANSI.");
+	}
+
+	#[test]
+	fn t_allocs() {
+		let stats = |base: u64| Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(base + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let cell = |col: Column, b: &Bench<'_>| col.cell(b, stats(1000), Gate::default(), TimeUnit::Auto)
+			.map(|c| {
+				let mut out = String::new();
+				c.write_to(&mut out, false).expect("Unable to write cell.");
+				out
+			});
+
+		// Nothing tracked, nothing to show.
+		let mut bench = Bench::new("Foo");
+		bench.baseline = Some(stats(1000));
+		assert_eq!(cell(Column::Allocs, &bench), None);
+		assert_eq!(cell(Column::Bytes, &bench), None);
+		assert_eq!(cell(Column::Change, &bench), None);
+
+		// Tracked, but unchanged.
+		bench.allocs = Some(Allocs::new(3.0, 96.0));
+		bench.baseline_allocs = Some(Allocs::new(3.0, 96.0));
+		assert_eq!(cell(Column::Allocs, &bench).as_deref(), Some("3"));
+		assert_eq!(cell(Column::Bytes, &bench).as_deref(), Some("96 B"));
+		assert_eq!(cell(Column::Change, &bench), None);
+
+		// Changed allocations should be noted on their own…
+		bench.allocs = Some(Allocs::new(5.0, 160.0));
+		assert_eq!(cell(Column::Change, &bench).as_deref(), Some("allocs 3 \u{2192} 5"));
+
+		// …or alongside a time change.
+		bench.baseline = Some(stats(900));
+		let change = cell(Column::Change, &bench).expect("Missing change.");
+		assert!(change.starts_with('+'), "Missing time change.");
+		assert!(change.ends_with(", allocs 3 \u{2192} 5"), "Missing allocs change.");
+	}
+
+	#[test]
+	fn t_perf() {
+		let stats = |base: u64| Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(base + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let cell = |col: Column, b: &Bench<'_>| col.cell(b, stats(1000), Gate::default(), TimeUnit::Auto)
+			.map(|c| {
+				let mut out = String::new();
+				c.write_to(&mut out, false).expect("Unable to write cell.");
+				out
+			});
+
+		// Nothing counted, nothing to show.
+		let mut bench = Bench::new("Foo");
+		bench.baseline = Some(stats(1000));
+		assert_eq!(cell(Column::Instructions, &bench), None);
+		assert_eq!(cell(Column::Ipc, &bench), None);
+
+		// Counted, but unchanged.
+		bench.perf = Some(Counters::new(5000.0, 2000.0));
+		bench.baseline_perf = Some(Counters::new(5010.0, 2500.0));
+		assert_eq!(cell(Column::Instructions, &bench).as_deref(), Some("5,000"));
+		assert_eq!(cell(Column::Ipc, &bench).as_deref(), Some("2.50"));
+		assert_eq!(cell(Column::Change, &bench), None);
+
+		// Changed instructions should be noted on their own…
+		bench.perf = Some(Counters::new(5090.0, 2000.0));
+		bench.baseline_perf = Some(Counters::new(5000.0, 2000.0));
+		assert_eq!(cell(Column::Change, &bench).as_deref(), Some("+1.80% instructions"));
+
+		// …or alongside the other changes.
+		bench.allocs = Some(Allocs::new(5.0, 160.0));
+		bench.baseline_allocs = Some(Allocs::new(3.0, 96.0));
+		assert_eq!(
+			cell(Column::Change, &bench).as_deref(),
+			Some("+1.80% instructions, allocs 3 \u{2192} 5"),
+		);
+		bench.baseline = Some(stats(900));
+		let change = cell(Column::Change, &bench).expect("Missing change.");
+		assert!(change.starts_with('+'), "Missing time change.");
+		assert!(change.contains(", +1.80% instructions, "), "Missing instruction change.");
+	}
+
+	#[test]
+	fn t_context() {
+		/// # Drop Guard.
+		struct Guard<'a>(&'a std::cell::Cell<bool>);
+		impl Drop for Guard<'_> {
+			fn drop(&mut self) { self.0.set(true); }
+		}
+
+		let built = std::cell::Cell::new(0_u8);
+		let dropped = std::cell::Cell::new(false);
+		let mut benches = Benches::default().allow_debug(true).without_history();
+		let ctx = benches.with_context(|| {
+			built.set(built.get() + 1);
+			std::thread::sleep(Duration::from_millis(250));
+			(vec![1_u64, 2, 3], Guard(&dropped))
+		});
+		benches.push(Bench::new("One").with_samples(100).run_with_context(&ctx, |v| v.0.iter().sum::<u64>()));
+		benches.push(Bench::new("Two").with_samples(100).run_with_context(&ctx, |v| v.0.iter().max().copied()));
+		drop(ctx);
+
+		let mut out = Vec::new();
+		benches.finish_to(&mut out).expect("Finish failed.").expect("Missing summary.");
+
+		// Built once, and only once, and dropped at the end.
+		assert_eq!(built.get(), 1);
+		assert!(dropped.get(), "The context should have been dropped.");
+
+		// The construction time shouldn't count against either.
+		for b in &benches.set {
+			assert!(b.elapsed < Duration::from_millis(200), "Setup was timed: {:?}", b.elapsed);
+		}
+	}
+
+	#[test]
+	fn t_run_indexed() {
+		let seen = RefCell::new(Vec::new());
+		let mut bench = Bench::new("Indexed").with_samples(100).run_indexed(|i| seen.borrow_mut().push(i));
+		bench.exec(&mut Progress::new(1), true, None);
+		drop(bench);
+		let seen = seen.into_inner();
+		assert_eq!(seen.len(), 100, "Wrong sample count.");
+		assert!(seen.iter().copied().eq(0..100), "Indexes out of order.");
+
+		let seen = RefCell::new(Vec::new());
+		let mut bench = Bench::new("Seeded")
+			.with_samples(100)
+			.run_seeded_indexed(vec![10_usize; 5], |i, v| seen.borrow_mut().push(v[i % 5] + i));
+		bench.exec(&mut Progress::new(1), true, None);
+		drop(bench);
+		assert!(seen.into_inner().into_iter().eq(10..110), "Seeded indexes out of order.");
+	}
+
+	#[test]
+	fn t_slow_seed() {
+		/// # Shared Clock.
+		///
+		/// Each reading advances a microsecond, but anyone holding the clock
+		/// can push it further along.
+		struct Clock(Rc<std::cell::Cell<u64>>);
+
+		impl crate::Timer for Clock {
+			fn now(&self) -> u64 {
+				self.0.set(self.0.get() + 1000);
+				self.0.get()
+			}
+		}
+
+		// The seeds alone take longer than the timeout, but the timed part is
+		// trivial, so all of the samples should still get collected.
+		let clock = Rc::new(std::cell::Cell::new(0_u64));
+		let seed_clock = Rc::clone(&clock);
+		let mut bench = Bench::new("Slow Seed")
+			.with_samples(100)
+			.with_timeout(MIN_TIMEOUT)
+			.run_seeded_with(
+				move || {
+					seed_clock.set(seed_clock.get() + 8_000_000);
+					13_u8
+				},
+				|v| v.checked_mul(2),
+			);
+		bench.timer = Some(Rc::new(Clock(clock)));
+		bench.exec(&mut Progress::new(1), true, None);
+		assert!(MIN_TIMEOUT < bench.elapsed, "The seeds should have outlasted the timeout.");
+		match bench.stats {
+			Some(Ok(s)) => assert_eq!(s.samples().1, 100, "Wrong sample count."),
+			ref other => panic!("Unexpected result: {other:?}"),
+		}
+	}
+
+	#[test]
+	fn t_timer() {
+		/// # Mock Bench.
+		fn mock(steps: &[Duration]) -> Bench<'static> {
+			let mut bench = Bench::new("Mock")
+				.with_samples(1000)
+				.with_timeout(MIN_TIMEOUT)
+				.run(|| 1_u8.checked_add(1));
+			bench.timer = Some(Rc::new(crate::MockTimer::new(steps.iter().copied())));
+			bench
+		}
+
+		// Each sample takes two readings, and the ceiling check another, so
+		// constant steps make for constant samples.
+		let mut bench = mock(&[Duration::from_millis(10)]);
+		bench.exec(&mut Progress::new(1), true, None);
+		match bench.stats {
+			Some(Err(BrunchError::TimedOut { collected, needed, mean_iter, timeout })) => {
+				assert_eq!(collected, 50, "The timeout should hit after fifty samples.");
+				assert_eq!(needed, MIN_SAMPLES);
+				assert_eq!(timeout, MIN_TIMEOUT);
+				assert!(
+					mean_iter.abs_diff(Duration::from_millis(10)) < Duration::from_micros(1),
+					"Wrong mean iteration: {mean_iter:?}",
+				);
+			},
+			ref other => panic!("Unexpected result: {other:?}"),
+		}
+
+		// Big jumps outside the samples are slow seeds.
+		let mut bench = mock(&[Duration::from_secs(1), Duration::from_millis(1), Duration::from_millis(1)]);
+		bench.exec(&mut Progress::new(1), true, None);
+		assert!(
+			matches!(bench.stats, Some(Err(BrunchError::SlowSeed(5)))),
+			"Expected SlowSeed: {:?}",
+			bench.stats,
+		);
+
+		// And suite timers should be passed along.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.push(
+			Bench::new("Mock")
+				.with_samples(100)
+				.run(|| 1_u8.checked_add(1))
+		);
+		benches.exec(&mut History::disabled());
+		let stats = benches.set[0].stats.clone().and_then(Result::ok).expect("Missing stats.");
+		assert!(stats.mean().abs_diff(Duration::from_micros(1)) <= Duration::from_nanos(1), "Wrong mean.");
+		assert_eq!(stats.samples(), (100, 100));
+	}
+
+	#[test]
+	fn t_slow() {
+		// Three-minute samples should be humanized throughout.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_millis(187_430)]));
+		benches.push(
+			Bench::new("Glacial")
+				.with_samples(100)
+				.with_timeout(Duration::from_secs(30_000))
+				.run(|| 1_u8.checked_add(1))
+		);
+		benches.exec(&mut History::disabled());
+		let stats = benches.set[0].stats.clone().and_then(Result::ok).expect("Missing stats.");
+		assert_eq!(stats.mean(), Duration::from_millis(187_430));
+		assert_eq!(stats.measured(), Duration::from_secs(18_743), "The total should be exact.");
+
+		let out = benches.render_plain();
+		assert!(out.contains("3 min 07 s"), "Missing mean:\n{out}");
+		assert!(! out.contains("187.43"), "Unscaled mean:\n{out}");
+
+		// Timing out should suggest a timeout that would actually work.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_millis(187_430)]));
+		benches.push(Bench::new("Glacial").with_samples(100).run(|| 1_u8.checked_add(1)));
+		benches.exec(&mut History::disabled());
+		match benches.set[0].stats {
+			Some(Err(ref e @ BrunchError::TimedOut { collected: 1, .. })) => {
+				let msg = e.to_string();
+				assert!(msg.contains("at least 5 h 44 min"), "Wrong suggestion: {msg}");
+			},
+			ref other => panic!("Unexpected result: {other:?}"),
+		}
+	}
 
-	/// # An Error.
-	Error(String, BrunchError),
+	#[test]
+	fn t_timed_out() {
+		// Each sample takes long enough that the timeout is reached first.
+		let mut bench = Bench::new("Slow")
+			.with_samples(100)
+			.with_timeout(MIN_TIMEOUT)
+			.run(|| std::thread::sleep(Duration::from_millis(20)));
+		bench.exec(&mut Progress::new(1), true, None);
+		match bench.stats {
+			Some(Err(BrunchError::TimedOut { collected, needed, mean_iter, timeout })) => {
+				assert!(collected < needed, "Too many samples collected.");
+				assert_eq!(needed, MIN_SAMPLES);
+				assert_eq!(timeout, MIN_TIMEOUT);
+				assert!(Duration::from_millis(20) <= mean_iter, "Mean iteration too short.");
+			},
+			ref other => panic!("Unexpected result: {other:?}"),
+		}
 
-	/// # A Spacer.
-	Spacer,
-}
+		// The message should say how far off we were and what to do.
+		let msg = bench.stats.and_then(Result::err).as_ref().map(ToString::to_string).unwrap_or_default();
+		assert!(msg.contains("/100 samples"), "Missing sample counts: {msg}");
+		assert!(msg.contains("raise the timeout to at least"), "Missing suggestion: {msg}");
 
-impl TableRow {
-	/// # Lengths (Widths).
-	///
-	/// Return the (approximate) printable widths for each column.
-	fn lens(&self) -> (usize, usize, usize, usize) {
-		match self {
-			Self::Normal(a, b, c, d) => (
-				util::width(a),
-				util::width(b),
-				util::width(c),
-				util::width(d),
+		// The seeds being slow is a different problem entirely.
+		let mut bench = Bench::new("Slow Seed")
+			.with_samples(100)
+			.with_timeout(MIN_TIMEOUT)
+			.run_seeded_with(
+				|| {
+					std::thread::sleep(Duration::from_millis(60));
+					13_u8
+				},
+				|v| v.checked_mul(2),
+			);
+		bench.exec(&mut Progress::new(1), true, None);
+		assert!(
+			matches!(bench.stats, Some(Err(BrunchError::SlowSeed(_)))),
+			"Expected SlowSeed.",
+		);
+	}
+
+	#[test]
+	fn t_verify() {
+		let good = std::cell::Cell::new(0_u32);
+		let bad = std::cell::Cell::new(0_u32);
+		let mut benches = Benches::default();
+		benches.extend([
+			Bench::new("Good")
+				.with_samples(100)
+				.verify(|v: &Option<u8>| *v == Some(2))
+				.run(|| {
+					good.set(good.get() + 1);
+					1_u8.checked_add(1)
+				}),
+			Bench::new("Bad")
+				.with_samples(100)
+				.verify(|v: &u8| *v == 3)
+				.run_seeded(1_u8, |v| {
+					bad.set(bad.get() + 1);
+					v + 1
+				}),
+			Bench::spacer().verify(|_: &u8| false).run(|| 1_u8),
+		]);
+
+		let mut history = History::disabled();
+		benches.exec(&mut history);
+		let res = benches.results();
+		assert_eq!(res.len(), 2);
+
+		// The good one should have run once for verification, plus once per
+		// sample.
+		assert!(! matches!(res[0].stats(), Err(BrunchError::Unverified)), "Unexpected Unverified.");
+		assert_eq!(good.get(), 101, "Wrong call count.");
+
+		// The bad one should not have been sampled at all.
+		assert!(matches!(res[1].stats(), Err(BrunchError::Unverified)), "Expected Unverified.");
+		assert_eq!(bad.get(), 1, "Wrong call count.");
+		assert!(history.latest("Bad").is_none(), "Failures should not be recorded.");
+	}
+
+	#[test]
+	fn t_drop_untimed() {
+		/// # Noisy Drop.
+		///
+		/// This reads the timer when dropped, advancing the mock, so any
+		/// drop inside the timed region would show up in the samples.
+		#[derive(Clone)]
+		struct Noisy;
+		impl Drop for Noisy {
+			fn drop(&mut self) { let _res = Tick::now(); }
+		}
+
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.extend([
+			Bench::new("run").with_samples(100).run(|| Noisy),
+			Bench::new("run_seeded").with_samples(100).run_seeded(Noisy, |n| vec![n; 8]),
+			Bench::new("run_fallible").with_samples(100).run_fallible(|| Ok::<Noisy, ()>(Noisy)),
+			Bench::new("run_indexed").with_samples(100).run_indexed(|_| Noisy),
+			Bench::new("verify").with_samples(100).verify(|_: &Noisy| true).run(|| Noisy),
+		]);
+		benches.exec(&mut History::disabled());
+
+		// Each sample should be a single step.
+		for b in &benches.set {
+			match b.stats {
+				Some(Ok(s)) => assert!(
+					s.mean() < Duration::from_nanos(1500),
+					"{} was charged for the drop: {:?}",
+					b.name,
+					s.mean(),
+				),
+				ref other => panic!("Unexpected result for {}: {other:?}", b.name),
+			}
+		}
+	}
+
+	#[test]
+	fn t_timed() {
+		// The reported times are what count, even if they add up to more
+		// than the timeout.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.push(
+			Bench::new("Self-Timed")
+				.with_samples(300)
+				.with_timeout(MIN_TIMEOUT)
+				.run_timed(|| Duration::from_millis(2))
+		);
+		benches.exec(&mut History::disabled());
+		let stats = benches.set[0].stats.clone().and_then(Result::ok).expect("Missing stats.");
+		assert_eq!(stats.samples().1, 300, "Wrong sample count.");
+		assert!(stats.mean().abs_diff(Duration::from_millis(2)) <= Duration::from_nanos(1), "Wrong mean.");
+
+		// The timeout goes by the time around the calls.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_millis(10)]));
+		benches.push(
+			Bench::new("Self-Timed")
+				.with_samples(1000)
+				.with_timeout(MIN_TIMEOUT)
+				.run_timed(|| Duration::from_nanos(1))
+		);
+		benches.exec(&mut History::disabled());
+		assert!(
+			matches!(benches.set[0].stats, Some(Err(BrunchError::TimedOut { collected: 50, .. }))),
+			"Expected TimedOut.",
+		);
+	}
+
+	#[test]
+	fn t_fallible() {
+		/// # Every Eighth Call Fails.
+		fn flaky(calls: &std::cell::Cell<u32>) -> Result<u32, ()> {
+			let n = calls.get() + 1;
+			calls.set(n);
+			if n % 8 == 0 { Err(()) } else { Ok(n) }
+		}
+
+		let (a, b) = (std::cell::Cell::new(0_u32), std::cell::Cell::new(0_u32));
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.extend([
+			Bench::new("Fine")
+				.with_samples(200)
+				.run_fallible(|| "13".parse::<u8>()),
+			Bench::new("Flaky")
+				.with_samples(200)
+				.run_fallible_seeded(&a, flaky),
+			Bench::new("Flaky (Discarded)")
+				.with_samples(200)
+				.discard_errors(true)
+				.run_fallible_seeded_with(|| &b, flaky),
+			Bench::new("Broken")
+				.with_samples(200)
+				.discard_errors(true)
+				.run_fallible(|| "nope".parse::<u8>()),
+		]);
+
+		let mut history = History::disabled();
+		benches.exec(&mut history);
+		let res = benches.results();
+
+		// No errors, no problem.
+		assert!(res[0].stats().is_ok(), "Expected stats.");
+		assert_eq!(res[0].errors(), 0);
+
+		// Errors fail the whole thing by default.
+		match res[1].stats() {
+			Err(e @ BrunchError::Fallible { failed: 25, total: 200 }) => assert!(
+				e.to_string().contains("returned Err on 25/200 samples"),
+				"Wrong message: {e}",
 			),
-			Self::Error(a, _) => (util::width(a), 0, 0, 0),
-			Self::Spacer => (0, 0, 0, 0),
+			other => panic!("Unexpected result: {other:?}"),
+		}
+		assert_eq!(res[1].errors(), 25);
+		assert!(history.latest("Flaky").is_none(), "Failures should not be recorded.");
+
+		// Unless they're discarded.
+		let stats = res[2].stats().expect("Expected stats.");
+		assert_eq!(stats.samples().1, 175, "The Err samples should have been dropped.");
+		assert_eq!(res[2].errors(), 25);
+		assert!(history.latest("Flaky (Discarded)").is_some(), "Missing history.");
+
+		// But there has to be something left.
+		assert!(
+			matches!(res[3].stats(), Err(BrunchError::Fallible { failed: 200, total: 200 })),
+			"Expected Fallible.",
+		);
+
+		// The count should make it into the report too.
+		assert_eq!(benches.report().benches()[1].errors(), 25);
+	}
+
+	#[test]
+	fn t_ranking() {
+		let stats = |ns: u64| Stats::try_from(vec![Duration::from_nanos(ns); 1000]);
+		let mut benches = Benches::default();
+		for (name, res) in [
+			("a()", stats(3000)),
+			("b()", stats(1000)),
+			("c()", Err(BrunchError::TooWild)),
+			("d()", stats(1000)),
+		] {
+			let mut b = Bench::new(name);
+			b.stats = Some(res);
+			benches.push(b);
 		}
+
+		// A lone bench has nothing to be ranked against.
+		benches.push(Bench::spacer());
+		let mut b = Bench::new("e()");
+		b.stats = Some(stats(50));
+		benches.push(b);
+
+		let table = benches.ranking_table().expect("Missing ranking.");
+		let rows: Vec<(String, String, String)> = table.rows.iter()
+			.skip(2)
+			.filter_map(|r| {
+				let TableRow::Normal(name, cells) = r else { return None; };
+				let text = |c: Option<&Cell>| {
+					let mut out = String::new();
+					if let Some(c) = c { c.write_to(&mut out, false).ok()?; }
+					Some(out)
+				};
+				Some((text(Some(name))?, text(cells[0].as_ref())?, text(cells[2].as_ref())?))
+			})
+			.collect();
+		assert_eq!(rows, [
+			("b()".to_owned(), "=1".to_owned(), "1.00x".to_owned()),
+			("d()".to_owned(), "=1".to_owned(), "1.00x".to_owned()),
+			("a()".to_owned(), "3".to_owned(), "3.00x".to_owned()),
+		]);
+		assert!(! matches!(table.rows.last(), Some(TableRow::Spacer)), "Trailing spacer.");
+
+		// Overlap is what makes a tie.
+		assert_eq!(
+			ranks([stats(1000), stats(1000), stats(2000)].into_iter().map(Result::unwrap)),
+			[(1, true), (1, true), (3, false)],
+		);
+		assert_eq!(
+			ranks([stats(1000), stats(2000), stats(3000)].into_iter().map(Result::unwrap)),
+			[(1, false), (2, false), (3, false)],
+		);
+
+		// Nothing to rank, no table.
+		let mut benches = Benches::default();
+		let mut b = Bench::new("a()");
+		b.stats = Some(stats(1000));
+		benches.push(b);
+		assert!(benches.ranking_table().is_none(), "Unexpected ranking.");
 	}
-}
 
+	#[test]
+	fn t_scaling() {
+		let sizes = [1_000_usize, 10_000, 100_000, 1_000_000];
+		let mut benches = Benches::default();
+		benches.extend(Bench::scaling("sort", sizes, |n| vec![0_u8; n], |v| v.len()));
+		assert_eq!(
+			benches.names(),
+			["sort(n=1000)", "sort(n=10000)", "sort(n=100000)", "sort(n=1000000)"],
+		);
 
+		// Fake a linear series, followed by a noisy one.
+		benches.push(Bench::spacer());
+		benches.extend(Bench::scaling("noise", sizes, |n| n, |n| n));
+		let noise = [500, 100, 600, 200].into_iter();
+		for (b, ns) in benches.set.iter_mut().filter(|b| ! b.is_spacer()).zip(
+			sizes.into_iter().map(|n| u64::saturating_from(n) * 3 + 100).chain(noise)
+		) {
+			b.stats = Some(Stats::try_from(vec![Duration::from_nanos(ns); 1000]));
+		}
 
-/// # Format Name.
-///
-/// Style up a benchmark name by dimming common portions, and highlighting
-/// unique ones.
-///
-/// This approach won't scale well, but the bench count for any given set
-/// should be relatively low.
-fn format_name(mut name: Vec<char>, names: &[Vec<char>]) -> String {
-	let len = name.len();
+		let table = benches.table();
+		let notes: Vec<(usize, String, String)> = table.rows.iter()
+			.enumerate()
+			.filter_map(|(idx, r)| {
+				let TableRow::Error(name, note) = r else { return None; };
+				let mut a = String::new();
+				let mut b = String::new();
+				name.write_to(&mut a, false).ok()?;
+				note.write_to(&mut b, false).ok()?;
+				Some((idx, a, b))
+			})
+			.collect();
+		assert_eq!(notes.len(), 2, "Expected two scaling rows.");
 
-	// Find the first unique char occurrence.
-	let mut pos: usize = names.iter()
-		.filter_map(|other|
-			if name.eq(other) { None }
-			else {
-				name.iter()
-					.zip(other.iter())
-					.position(|(l, r)| l != r)
-					.or_else(|| Some(len.min(other.len())))
+		// The summaries should follow their series.
+		assert_eq!(notes[0].0, 2 + sizes.len());
+		assert_eq!(notes[0].1.trim(), "\u{2b91} sort scaling");
+		assert!(notes[0].2.starts_with("O(n) (R\u{b2} = "), "{}", notes[0].2);
+		assert_eq!(notes[1].1.trim(), "\u{2b91} noise scaling");
+		assert!(notes[1].2.starts_with("inconclusive"), "{}", notes[1].2);
+
+		// And the samplers should work. (A real clock could leave too few
+		// samples after pruning, so use a steady one.)
+		let seeds = std::cell::Cell::new(0_usize);
+		let mut bench = Bench::scaling("len", [3], |n| { seeds.set(seeds.get() + 1); vec![0_u8; n] }, |v| v.len())
+			.remove(0)
+			.with_samples(100);
+		bench.timer = Some(Rc::new(crate::MockTimer::new([Duration::from_nanos(50)])));
+		bench.exec(&mut Progress::new(1), true, None);
+		let stats = bench.stats.and_then(Result::ok).expect("Expected stats.");
+		assert_eq!(stats.samples(), (100, 100));
+		assert!(stats.mean().abs_diff(Duration::from_nanos(50)) <= Duration::from_nanos(1), "Wrong mean.");
+		assert_eq!(seeds.get(), 100, "Each sample should be seeded.");
+	}
+
+	#[test]
+	fn t_retries() {
+		// One spike in the first hundred samples is enough to leave too few
+		// after pruning.
+		fn sampler(calls: &std::cell::Cell<u32>, every: u32) -> Sampler<'_> {
+			Box::new(move |_| {
+				let n = calls.get();
+				calls.set(n + 1);
+				let wild = n % every == 50;
+				// The tame samples need some spread, or the spike would land
+				// on the 95th percentile and escape pruning.
+				Lap::fake(
+					if wild { Duration::from_millis(1) }
+					else { Duration::from_nanos(1000 + u64::from(n % 100)) }
+				)
+			})
+		}
+		let calls = std::cell::Cell::new(0_u32);
+
+		// A single retry should do the trick.
+		let mut benches = Benches::default().retries(2);
+		let mut b = Bench::new("Wild").with_samples(100);
+		b.sampler = Some(sampler(&calls, u32::MAX));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		let b = &benches.set[0];
+		assert!(matches!(b.stats, Some(Ok(_))), "Expected stats.");
+		assert_eq!(b.retried, 1);
+		assert!(benches.render_plain().contains("Wild (retried \u{d7}1)"), "Missing note.");
+
+		// Without retries, it just fails.
+		calls.set(0);
+		let mut benches = Benches::default();
+		let mut b = Bench::new("Wild").with_samples(100);
+		b.sampler = Some(sampler(&calls, u32::MAX));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		let b = &benches.set[0];
+		assert!(matches!(b.stats, Some(Err(BrunchError::TooWild))), "Expected TooWild.");
+		assert_eq!(b.retried, 0);
+
+		// Persistent wildness exhausts the retries.
+		calls.set(0);
+		let mut benches = Benches::default().retries(2);
+		let mut b = Bench::new("Wild").with_samples(100);
+		b.sampler = Some(sampler(&calls, 100));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		let b = &benches.set[0];
+		assert!(matches!(b.stats, Some(Err(BrunchError::TooWild))), "Expected TooWild.");
+		assert_eq!(b.retried, 2);
+		assert!(b.sampler.is_none(), "The sampler should have been dropped.");
+		assert_eq!(calls.get(), 300);
+
+		// The original error is the one reported.
+		calls.set(0);
+		let mut benches = Benches::default().retries(2);
+		let mut b = Bench::new("Wild").with_samples(100);
+		let mut wild = sampler(&calls, 100);
+		let calls = &calls;
+		b.sampler = Some(Box::new(move |cpu| {
+			assert!(calls.get() < 200, "Last try.");
+			wild(cpu)
+		}));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		let b = &benches.set[0];
+		assert!(matches!(b.stats, Some(Err(BrunchError::TooWild))), "Expected the original error.");
+		assert_eq!(b.retried, 2);
+
+		// Short results are retried too, if enough samples were taken.
+		calls.set(0);
+		let mut benches = Benches::default().retries(2);
+		let mut b = Bench::new("Short").with_samples(100).discard_errors(true);
+		b.sampler = Some(Box::new(|_| {
+			let n = calls.get();
+			calls.set(n + 1);
+			Lap::fake(Duration::from_nanos(1000 + u64::from(n % 100))).with_err(n < 50)
+		}));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		let b = &benches.set[0];
+		assert!(matches!(b.stats, Some(Ok(_))), "Expected stats.");
+		assert_eq!(b.retried, 1);
+		assert_eq!(calls.get(), 200);
+
+		// And they likewise report the original error if nothing works.
+		calls.set(0);
+		let mut benches = Benches::default().retries(2);
+		let mut b = Bench::new("Short").with_samples(100).discard_errors(true);
+		b.sampler = Some(Box::new(|_| {
+			let n = calls.get();
+			calls.set(n + 1);
+			Lap::fake(Duration::from_nanos(1000 + u64::from(n % 100))).with_err(n % 100 < 50 + n / 100)
+		}));
+		benches.push(b);
+		benches.exec(&mut History::disabled());
+		let b = &benches.set[0];
+		assert!(matches!(b.stats, Some(Err(BrunchError::TooSmall(50)))), "Expected the original error.");
+		assert_eq!(b.retried, 2);
+		assert_eq!(calls.get(), 300);
+	}
+
+	#[test]
+	fn t_retryable() {
+		let stats = Stats::try_from(vec![Duration::from_micros(1); 200]).expect("Invalid stats.");
+		assert!(! retryable(&Ok(stats), 200, 100));
+		assert!(retryable(&Err(BrunchError::TooWild), 200, 100));
+		assert!(retryable(&Err(BrunchError::TooSmall(80)), 200, 100));
+		assert!(! retryable(&Err(BrunchError::TooSmall(80)), 80, 100));
+		assert!(! retryable(&Err(BrunchError::TooSmall(80)), 90, 100));
+		assert!(! retryable(&Err(BrunchError::TooFast), 200, 100));
+	}
+
+	#[test]
+	fn t_repeat() {
+		let calls = RefCell::new(Vec::new());
+		let mut benches = Benches::default().repeat(3);
+		benches.extend([
+			Bench::new("A").with_samples(100).run(|| calls.borrow_mut().push('A')),
+			Bench::new("B").with_samples(100).run(|| calls.borrow_mut().push('B')),
+		]);
+		assert_eq!(benches.runs(), 3);
+
+		let mut history = History::disabled();
+		benches.exec(&mut history);
+
+		// Each round should run everything once, in order.
+		let mut rounds = calls.borrow().clone();
+		assert_eq!(rounds.len(), 600);
+		rounds.dedup();
+		assert_eq!(rounds, ['A', 'B', 'A', 'B', 'A', 'B']);
+
+		// Everything should be wrapped up.
+		for b in &benches.set {
+			assert!(b.stats.is_some(), "Missing stats.");
+			assert!(b.partial.is_none(), "Leftover partial.");
+			assert!(b.spread.is_some(), "Missing spread.");
+		}
+
+		// Sanity.
+		assert_eq!(Benches::default().repeat(0).runs(), 1);
+		assert_eq!(Benches::default().repeat(1000).runs(), MAX_RUNS);
+		assert_eq!(spread(&[1.0]), None);
+		assert_eq!(spread(&[1.0, 3.0, 2.0]), Some(0.5));
+	}
+
+	#[test]
+	fn t_interleaved() {
+		// (A steady clock keeps the short runs from coming back too wild.)
+		let calls = RefCell::new(Vec::new());
+		let mut benches = Benches::default()
+			.interleaved(true)
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.extend([
+			Bench::new("A").with_samples(250).run(|| calls.borrow_mut().push('A')),
+			Bench::new("B").with_samples(250).run(|| calls.borrow_mut().push('B')),
+			Bench::new("C").with_samples(100).run(|| calls.borrow_mut().push('C')),
+		]);
+		assert!(benches.interleave());
+		benches.exec(&mut History::disabled());
+
+		// The samples should be collected in slices, round-robin.
+		let mut slices: Vec<(char, usize)> = Vec::new();
+		for c in calls.borrow().iter().copied() {
+			match slices.last_mut() {
+				Some((last, n)) if *last == c => { *n += 1; },
+				_ => { slices.push((c, 1)); },
 			}
-		)
-		.max()
-		.unwrap_or_default();
+		}
+		assert_eq!(
+			slices,
+			[('A', 100), ('B', 100), ('C', 100), ('A', 100), ('B', 100), ('A', 50), ('B', 50)],
+		);
 
-	if 0 < pos && pos < len && ! matches!(name[pos], ':' | '(') {
-		// Let's rewind the marker to the position before the last : or (.
-		if let Some(pos2) = name[..pos].iter().rposition(|c| matches!(c, ':' | '(')) {
-			pos = name[..pos2].iter()
-				.rposition(|c| ! matches!(c, ':' | '('))
-				.map_or(0, |p| p + 1);
+		// But the results should be the same as ever.
+		for (b, expected) in benches.set.iter().zip([250, 250, 100]) {
+			match b.stats {
+				Some(Ok(s)) => assert_eq!(s.samples().1, expected, "Wrong sample count."),
+				ref other => panic!("Unexpected result: {other:?}"),
+			}
+			assert!(b.partial.is_none(), "Leftover partial.");
 		}
-		// Before the last _ or space?
-		else if let Some(pos2) = name[..pos].iter().rposition(|c| matches!(c, '_' | ' ')) {
-			pos = name[..pos2].iter()
-				.rposition(|c| ! matches!(c, '_' | ' '))
-				.map_or(0, |p| p + 1);
+
+		// The timeouts apply to the measured time, summed across slices.
+		let mut benches = Benches::default()
+			.interleaved(true)
+			.with_timer(crate::MockTimer::new([Duration::from_millis(10)]));
+		benches.extend([
+			Bench::new("A").with_samples(1000).with_timeout(Duration::from_secs(5)).run(|| 1_u8.checked_add(1)),
+			Bench::new("B").with_samples(1000).with_timeout(Duration::from_secs(5)).run(|| 1_u8.checked_add(1)),
+		]);
+		benches.exec(&mut History::disabled());
+		for b in &benches.set {
+			match b.stats {
+				Some(Ok(s)) => assert_eq!(s.samples().1, 500, "The timeout should hit after five hundred samples."),
+				ref other => panic!("Unexpected result: {other:?}"),
+			}
 		}
-		// Remove the marker entirely.
-		else { pos = 0; }
 	}
 
-	if pos == 0 {
-		"\x1b[94m".chars()
-			.chain(name)
-			.chain("\x1b[0m".chars())
-			.collect()
+	#[test]
+	fn t_drift() {
+		let steady = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(1000 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let drifting = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(if i < 500 { 1000 } else { 1100 } + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		assert!(steady.drift().abs() < 0.001, "Unexpected drift.");
+		assert!((drifting.drift() - 0.0996).abs() < 0.001, "Wrong drift.");
+
+		let mut benches = Benches::default();
+		benches.extend([Bench::new("a()"), Bench::new("b()")]);
+		for (b, s) in benches.set.iter_mut().zip([steady, drifting]) {
+			b.stats = Some(Ok(s));
+			b.baseline = Some(steady);
+		}
+
+		// The drifter should be flagged.
+		let results = benches.results();
+		assert!(! results[0].drifted(), "Unexpected drift.");
+		assert!(results[1].drifted(), "Missing drift.");
+		let flagged: Vec<bool> = benches.table().rows.iter()
+			.filter_map(|r| match r {
+				TableRow::Normal(name, _) => Some(name.0.iter().any(|seg| seg.0 == STYLE_DRIFT)),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(flagged, [false, false, true]); // Header, a, b.
+		assert_eq!(
+			benches.drift_notes(),
+			["\u{26a0} b(): samples drifted +9.96% during collection (thermal throttling?)"],
+		);
+
+		// And its ~5% change held to a higher standard.
+		let gate = benches.gate();
+		assert!(gate.change(drifting, steady).is_none(), "Drift should swallow the change.");
+		let gate = Gate { drift: None, ..gate };
+		assert!(gate.change(drifting, steady).is_some(), "Change expected.");
+
+		// The threshold can be raised…
+		let benches = benches.drift_threshold(0.2);
+		assert!(! benches.results()[1].drifted(), "Unexpected drift.");
+		assert!(benches.drift_notes().is_empty(), "Unexpected drift note.");
+
+		// …or the check disabled.
+		let benches = benches.drift_threshold(0.01).without_drift_warnings();
+		assert!(! benches.results()[1].drifted(), "Unexpected drift.");
+		assert!(benches.drift_notes().is_empty(), "Unexpected drift note.");
 	}
-	else if pos == len {
-		"\x1b[34m".chars()
-			.chain(name)
-			.chain("\x1b[0m".chars())
-			.collect()
+
+	#[test]
+	fn t_min_samples() {
+		// The minimum is clamped to the floor, and the samples follow suit.
+		let b = Bench::new("a()").with_min_samples(3).with_samples(5);
+		assert_eq!(b.min_samples(), MIN_SAMPLES_FLOOR);
+		assert_eq!(b.samples().get(), MIN_SAMPLES_FLOOR);
+		let b = Bench::new("a()").with_min_samples(500);
+		assert_eq!(b.min_samples(), MIN_SAMPLES);
+		let b = Bench::new("a()").with_samples(20);
+		assert_eq!(b.samples().get(), MIN_SAMPLES, "The default minimum should still apply.");
+
+		// A 20-sample run should work end to end.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_secs(2)]));
+		benches.push(
+			Bench::new("slow()")
+				.with_min_samples(20)
+				.with_samples(20)
+				.with_timeout(Duration::from_secs(60))
+				.run(|| 1_u8.checked_add(1))
+		);
+		benches.exec(&mut History::disabled());
+		let stats = benches.set[0].stats.clone().and_then(Result::ok).expect("Missing stats.");
+		assert_eq!(stats.samples(), (20, 20));
+		assert!(stats.is_low_confidence(), "Expected low confidence.");
+
+		// The mean should be dimmed and the bench called out.
+		let table = benches.table();
+		let idx = table.cols.iter().position(|c| *c == Column::Mean).expect("Missing mean.");
+		let styles: Vec<&'static str> = table.rows.iter()
+			.skip(1) // Header.
+			.filter_map(|r| match r {
+				TableRow::Normal(_, cells) => cells[idx].as_ref().map(|c| c.0[0].0),
+				_ => None,
+			})
+			.collect();
+		assert_eq!(styles, [STYLE_DIM]);
+		assert_eq!(
+			benches.low_confidence_note().as_deref(),
+			Some("Low confidence (fewer than 100 valid samples): slow()."),
+		);
+
+		// History should take it.
+		let path = std::env::temp_dir().join("__brunch-test-min-samples.last");
+		let _res = std::fs::remove_file(&path);
+		let mut history = History::new(Some(&path));
+		history.insert("slow()", stats, Metric::Mean, None, None, None);
+		history.save();
+		let history = History::new(Some(&path));
+		assert!(history.get("slow()").is_some(), "History should load low-sample runs.");
+		let _res = std::fs::remove_file(&path);
+
+		// Small changes shouldn't be reported.
+		let base = |ns: u64| Stats::try_from(
+			(0..200_u64).map(|i| Duration::from_nanos(ns + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let low = |ns: u64| Stats::from_samples(
+			(0..20_u64).map(|i| Duration::from_nanos(ns + i % 10)).collect::<Vec<_>>(),
+			OutlierPolicy::Default,
+			Metric::Mean,
+			20,
+		).expect("Invalid stats.");
+		let gate = benches.gate();
+		assert!(gate.change(base(1030), base(1000)).is_some(), "Change expected.");
+		assert!(gate.change(low(1030), base(1000)).is_none(), "Low-confidence change should need more.");
+		assert!(gate.change(base(1030), low(1000)).is_none(), "Low-confidence baseline should need more.");
+		assert!(gate.change(low(1200), base(1000)).is_some(), "Big changes should still count.");
 	}
-	else {
-		let b = name.split_off(pos);
-		"\x1b[34m".chars()
-			.chain(name)
-			.chain("\x1b[94m".chars())
-			.chain(b)
-			.chain("\x1b[0m".chars())
-			.collect()
+
+	#[test]
+	fn t_tally() {
+		let stats = |ns: u64| Stats::try_from(
+			(0..200_u64).map(|i| Duration::from_nanos(ns + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+
+		let mut benches = Benches::default();
+		benches.extend([
+			Bench::new("faster()"),
+			Bench::new("slower()"),
+			Bench::new("same()"),
+			Bench::new("new()"),
+			Bench::new("broken()"),
+			Bench::new("skipped()").skip("Not today."),
+		]);
+		for (b, (now, then)) in benches.set.iter_mut().zip([
+			(Some(900), Some(1000)),
+			(Some(1100), Some(1000)),
+			(Some(1000), Some(1000)),
+			(Some(1000), None),
+			(None, Some(1000)),
+		]) {
+			b.stats = Some(now.map_or(Err(BrunchError::Overflow), |ns| Ok(stats(ns))));
+			b.baseline = then.map(stats);
+		}
+
+		// The changes should match the table.
+		let results = benches.results();
+		assert!(results[0].change().is_some_and(|c| c < 0.0), "Expected faster.");
+		assert!(results[1].change().is_some_and(|c| 0.0 < c), "Expected slower.");
+		assert!(results[2].change().is_none(), "Unexpected change.");
+		assert!(results[3].change().is_none(), "Unexpected change.");
+
+		// New and skipped benches shouldn't count.
+		assert_eq!(
+			tally_line(&results, false).as_deref(),
+			Some("1 faster \u{b7} 1 slower \u{b7} 1 unchanged \u{b7} 1 error"),
+		);
+		let summary = Summary {
+			results: results.clone(),
+			skipped: 1,
+			interrupted: false,
+			history_loaded: true,
+			history_saved: true,
+		};
+		assert_eq!(summary.faster(), 1);
+		assert_eq!(summary.slower(), 1);
+		assert_eq!(summary.unchanged(), 1);
+		assert_eq!(summary.errored(), 1);
+
+		// Empty buckets should be left out.
+		assert_eq!(tally_line(&results[2..4], false).as_deref(), Some("1 unchanged"));
+
+		// And without any history, the line should be left out entirely.
+		assert!(tally_line(&results[3..], false).is_none(), "Unexpected tally.");
+	}
+
+	#[test]
+	fn t_cold() {
+		// Cold benches take fewer samples by default.
+		assert_eq!(Bench::new("a()").samples(), DEFAULT_SAMPLES);
+		assert_eq!(Bench::new("a()").cold(true).samples(), DEFAULT_COLD_SAMPLES);
+		assert_eq!(Bench::new("a()").cold(true).with_samples(1000).samples().get(), 1000);
+
+		// The callback should run once per sample, outside the timer.
+		let chills = std::cell::Cell::new(0_u32);
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.push(
+			Bench::new("cold()")
+				.with_samples(100)
+				.cold_with(|| chills.set(chills.get() + 1))
+				.run(|| 1_u8.checked_add(1))
+		);
+		benches.exec(&mut History::disabled());
+		assert_eq!(chills.get(), 100, "Wrong number of chills.");
+		let stats = benches.set[0].stats.clone().and_then(Result::ok).expect("Missing stats.");
+		assert!(stats.mean().abs_diff(Duration::from_micros(1)) <= Duration::from_nanos(1), "Wrong mean.");
+		let b = &benches.set[0];
+		assert_eq!(b.config, Some(Config::new(100, b.timeout(), b.outliers, true)), "Wrong config.");
+
+		// And the table should say so.
+		assert!(benches.render_plain().contains("cold() (cold)"), "Missing cold marker.");
+
+		// Skipped benches shouldn't pick up a callback.
+		let b = Bench::new("a()").skip("Nope.").cold_with(|| {});
+		assert!(! b.cold && b.chill.is_none(), "Skipped benches shouldn't chill.");
+	}
+
+	#[test]
+	fn t_warmup() {
+		// Each call advances the mock clock by a microsecond; the warm-up
+		// should keep calling until a millisecond has passed, without
+		// recording anything.
+		let calls = std::cell::Cell::new(0_u32);
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]))
+			.with_verbose(true);
+		benches.push(
+			Bench::new("warm()")
+				.with_samples(100)
+				.with_warmup(Duration::from_millis(1))
+				.run(|| calls.set(calls.get() + 1))
+		);
+		benches.exec(&mut History::disabled());
+		assert!(100 < calls.get(), "The warm-up didn't run.");
+		let stats = benches.set[0].stats.clone().and_then(Result::ok).expect("Missing stats.");
+		assert_eq!(stats.samples(), (100, 100), "Warm-up calls shouldn't be sampled.");
+		assert!(stats.mean().abs_diff(Duration::from_micros(1)) <= Duration::from_nanos(1), "Wrong mean.");
+
+		// Verbose output should say so.
+		let note = benches.warmup_note().expect("Missing warm-up note.");
+		assert!(note.contains("warm() (1.00 ms)"), "{note}");
+
+		// Nothing to say otherwise.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.push(Bench::new("plain()").with_samples(100).run(|| 1_u8.checked_add(1)));
+		benches.exec(&mut History::disabled());
+		assert!(benches.warmup_note().is_none(), "Unexpected warm-up note.");
+
+		// With no time left to measure anything, there's no point warming up.
+		let calls = std::cell::Cell::new(0_u32);
+		let mut bench = Bench::new("cold()")
+			.with_samples(100)
+			.with_warmup(Duration::from_millis(1))
+			.run(|| calls.set(calls.get() + 1));
+		bench.timer = Some(Rc::new(crate::MockTimer::new([Duration::from_micros(1)])));
+		bench.timeout = Some(Duration::ZERO);
+		bench.exec(&mut Progress::new(1), true, None);
+		assert_eq!(calls.get(), 1, "Only the one sample should have been taken.");
+		assert!(bench.warmed.is_zero(), "The warm-up shouldn't have run.");
+		drop(bench);
+
+		// Nor should the warm-ups eat into the suite budget: with each taking
+		// a second, the last bench would otherwise be left with little more
+		// than a second.
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_millis(1)]))
+			.with_total_budget(Duration::from_secs(3))
+			.default_timeout(Duration::from_secs(30));
+		benches.extend((0..2).map(|i|
+			Bench::new(format!("warm({i})"))
+				.with_samples(100)
+				.with_warmup(Duration::from_secs(1))
+				.run(|| 1_u8.checked_add(1))
+		));
+		benches.exec(&mut History::disabled());
+		assert!(
+			benches.set.iter().all(|b| b.stats.as_ref().is_some_and(Result::is_ok)),
+			"Missing stats.",
+		);
+		assert!(
+			Duration::from_millis(2500) < benches.set[1].timeout(),
+			"The warm-up counted against the budget: {:?}",
+			benches.set[1].timeout(),
+		);
+	}
+
+	#[test]
+	fn t_bench() {
+		let setups = std::cell::Cell::new(0_u32);
+		let mut benches = Benches::default()
+			.with_timer(crate::MockTimer::new([Duration::from_micros(1)]));
+		benches.extend([
+			// The usual flavors should all work.
+			Bench::new("iter()")
+				.with_samples(100)
+				.bench(|b| b.iter(|| 1_u8.checked_add(1))),
+			Bench::new("iter_batched()")
+				.with_samples(100)
+				.bench(|b| b.iter_batched(|| setups.set(setups.get() + 1), |()| 1_u8.checked_add(1))),
+			Bench::new("iter_custom()")
+				.with_samples(100)
+				.bench(|b| b.iter_custom(|_| Duration::from_micros(5))),
+			// But something has to be measured.
+			Bench::new("nothing()").with_samples(100).bench(|_| {}),
+		]);
+		benches.exec(&mut History::disabled());
+
+		for (b, mean) in benches.set.iter().take(3).zip([1, 1, 5]) {
+			let stats = b.stats.clone().and_then(Result::ok).expect("Missing stats.");
+			assert_eq!(stats.samples(), (100, 100), "Wrong sample count for {}.", b.name);
+			assert!(
+				stats.mean().abs_diff(Duration::from_micros(mean)) <= Duration::from_nanos(1),
+				"Wrong mean for {}.", b.name,
+			);
+		}
+		assert_eq!(setups.get(), 100, "Wrong number of setups.");
+		assert!(
+			matches!(benches.set[3].stats, Some(Err(BrunchError::Panicked(_)))),
+			"Expected Panicked: {:?}",
+			benches.set[3].stats,
+		);
+	}
+
+	#[test]
+	fn t_parallel() {
+		let stats = Stats::try_from(vec![Duration::from_micros(5); 200]).expect("Invalid stats.");
+		let render = |benches: &Benches<'_>| {
+			let mut out = String::new();
+			benches.table().write_to(&mut out, false).expect("Unable to write table.");
+			out
+		};
+
+		// Sequential by default.
+		let mut benches = Benches::default();
+		let mut bench = Bench::new("Foo");
+		bench.stats = Some(Ok(stats));
+		benches.push(bench);
+		assert_eq!(benches.jobs(), 1);
+		assert!(! render(&benches).contains("parallel run"), "Unexpected parallel marker.");
+
+		// Parallel runs are isolated, and marked as such.
+		benches.parallel = Some(3);
+		assert_eq!(benches.jobs(), 3);
+		assert!(benches.isolate(), "Parallel runs should be isolated.");
+		assert!(
+			render(&benches).contains("Method (parallel run \u{2014} treat numbers as approximate)"),
+			"Missing parallel marker.",
+		);
+
+		// Zero means one per core.
+		benches.parallel = Some(0);
+		assert_eq!(
+			benches.jobs(),
+			std::thread::available_parallelism().map_or(1, NonZeroUsize::get),
+		);
+
+		// The history should be left alone.
+		let path = std::env::temp_dir().join("__brunch-test-parallel.last");
+		let _res = std::fs::remove_file(&path);
+		let mut history = History::new(Some(&path));
+		history.freeze();
+		history.insert("Foo", stats, Metric::Mean, None, None, None);
+		assert_eq!(history.save(), 0);
+		assert!(! path.exists(), "Frozen history should not be saved.");
+		assert!(
+			benches.notes(&history, 0).iter().any(|n| n == "Parallel run (baseline not updated)."),
+			"Missing history note.",
+		);
+
+		// Launches and retries alike share the budget, if any.
+		let now = Tick::now();
+		assert_eq!(benches.parallel_slice(&now, 4, 2), None);
+		benches.budget = Some(Duration::from_secs(10));
+		let slice = benches.parallel_slice(&now, 4, 2).expect("Missing slice.");
+		assert!(Duration::from_millis(4900) < slice && slice <= Duration::from_secs(5), "Wrong slice.");
+		benches.budget = Some(Duration::ZERO);
+		assert_eq!(benches.parallel_slice(&now, 4, 2), Some(Duration::ZERO));
+	}
+
+	#[test]
+	fn t_expect_under() {
+		let stats = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(1000 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let cell = |b: &Bench<'_>| Column::Budget.cell(b, stats, Gate::default(), TimeUnit::Auto)
+			.map(|c| {
+				let mut out = String::new();
+				c.write_to(&mut out, false).expect("Unable to write cell.");
+				out
+			});
+
+		// No budget, no worries.
+		let mut bench = Bench::new("Foo");
+		bench.stats = Some(Ok(stats));
+		assert!(! bench.over_budget());
+		assert_eq!(cell(&bench), None);
+
+		// Under budget.
+		bench = bench.expect_under(Duration::from_micros(2));
+		assert!(! bench.over_budget());
+		assert_eq!(cell(&bench).as_deref(), Some("\u{2713}"));
+
+		// Over budget.
+		bench = bench.expect_under(Duration::from_nanos(800));
+		assert!(bench.over_budget());
+		let over = cell(&bench).expect("Missing budget.");
+		assert!(over.starts_with("BUDGET EXCEEDED (+25."), "Unexpected: {over}");
+
+		// Errors count against the budget too.
+		let mut broken = Bench::new("Bar").expect_under(Duration::from_secs(1));
+		broken.stats = Some(Err(BrunchError::Panicked("Oops.".to_owned())));
+		assert!(broken.over_budget());
+
+		// And the results should agree.
+		let mut benches = Benches::default();
+		benches.push(bench);
+		benches.push(broken);
+		benches.push(Bench::new("Baz"));
+		let res = benches.results();
+		assert_eq!(res[0].budget(), Some(Duration::from_nanos(800)));
+		assert!(res[0].over_budget());
+		assert!(res[1].over_budget());
+		assert!(res[2].budget().is_none());
+		assert!(! res[2].over_budget());
+	}
+
+	#[test]
+	fn t_show_ops() {
+		let stats = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_micros(1000 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let bench = |name: &str, ops: Option<bool>| {
+			let mut b = Bench::new(name);
+			b.ops = ops;
+			b.stats = Some(Ok(stats));
+			b
+		};
+
+		// Nobody asked.
+		let mut benches = Benches::default();
+		benches.push(bench("Foo", None));
+		assert!(! benches.render_plain().contains("Ops/s"));
+
+		// Opting in or out.
+		let mut benches = Benches::default();
+		benches.push(bench("Foo", None));
+		benches.push(bench("Bar", Some(true)));
+		let mut err = Bench::new("Baz").show_ops(true);
+		err.stats = Some(Err(BrunchError::NoRun));
+		benches.push(err);
+		let plain = benches.render_plain();
+		assert!(plain.contains("Ops/s"), "{plain}");
+		assert_eq!(plain.matches(" ops/s").count(), 1, "{plain}");
+		assert!(plain.lines().any(|l| l.starts_with("Foo") && l.contains(NO_CHANGE)), "{plain}");
+
+		// Suite-wide, with an override.
+		let mut benches = Benches::default().show_ops(true);
+		benches.push(bench("Foo", None));
+		benches.push(bench("Bar", Some(false)));
+		assert_eq!(benches.render_plain().matches(" ops/s").count(), 1);
+	}
+
+	#[test]
+	fn t_measured() {
+		let bench = |name: &str, nanos: u64| {
+			let mut b = Bench::new(name);
+			b.stats = Some(Stats::try_from(vec![Duration::from_nanos(nanos); 1000]));
+			b
+		};
+
+		let mut benches = Benches::default().with_verbose(false);
+		benches.push(bench("Long", 1_000_000));
+		benches.push(bench("Short", 1000));
+		for (res, secs) in benches.results().into_iter().zip([1.0, 0.001]) {
+			let measured = res.stats().expect("Missing stats.").measured().as_secs_f64();
+			assert!((measured - secs).abs() < 1e-9, "Wrong measured time: {measured}.");
+		}
+
+		// The column is verbose-only.
+		assert!(! benches.render_plain().contains("Total"));
+		let benches = benches.with_verbose(true);
+		let plain = benches.render_plain();
+		assert!(plain.contains("Total"), "{plain}");
+		assert!(plain.lines().any(|l| l.starts_with("Short") && l.contains("1.00 ms")), "{plain}");
+
+		// Only the short one needs a note.
+		let note = benches.measured_note().expect("Missing measured note.");
+		assert!(note.contains("Short") && ! note.contains("Long"), "{note}");
+	}
+
+	#[test]
+	fn t_distribution() {
+		let mut benches = Benches::default().with_verbose(false);
+		let mut b = Bench::new("Lumpy");
+		b.stats = Some(Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_micros(if i % 5 < 3 { 10 } else { 30 })).collect::<Vec<_>>()
+		));
+		benches.push(b);
+
+		// The columns are verbose-only.
+		let plain = benches.render_plain();
+		assert!(! plain.contains("Median") && ! plain.contains("p95"), "{plain}");
+
+		let benches = benches.with_verbose(true);
+		let plain = benches.render_plain();
+		assert!(plain.contains("Median") && plain.contains("p95") && plain.contains("Range"), "{plain}");
+		assert!(plain.contains("[10.00, 30.00]"), "{plain}");
+	}
+
+	#[test]
+	fn t_new_in() {
+		let bench = Bench::new_in(" dactyl::NiceU8 ", "from(\t0 )");
+		assert_eq!(bench.name, "dactyl::NiceU8::from( 0 )");
+		assert_eq!(bench.namespace(), Some("dactyl::NiceU8"));
+		assert!(Bench::new("dactyl::NiceU8::from(0)").namespace().is_none());
+
+		// The combined name is the key.
+		let mut benches = Benches::default();
+		benches.push(Bench::new_in("a", "b()").run(|| 1_u8.checked_add(1)));
+		benches.push(Bench::new("a::b()").run(|| 1_u8.checked_add(1)));
+		assert!(matches!(benches.set[1].stats, Some(Err(BrunchError::DupeName))));
+
+		// The namespace is dimmed, no guessing required.
+		let stats = || Stats::try_from(vec![Duration::from_micros(1); 1000]);
+		let mut benches = Benches::default().with_geomean(true);
+		for (ns, name) in [("json::parse", "a()"), ("json::parse", "ab()")] {
+			let mut b = Bench::new_in(ns, name);
+			b.stats = Some(stats());
+			benches.push(b);
+		}
+		let table = benches.table();
+		let TableRow::Normal(name, _) = &table.rows[2] else { panic!("Missing row."); };
+		assert_eq!(
+			name.0,
+			[(STYLE_NAME_DIM, "json::parse::".to_owned()), (STYLE_NAME, "a()".to_owned())],
+		);
+
+		// And used for the group summary.
+		let plain = benches.render_plain();
+		assert!(plain.contains("\u{2b91} json::parse geomean"), "{plain}");
+	}
+
+	#[test]
+	fn t_name_style() {
+		/// # Split.
+		///
+		/// Return the dimmed and highlighted portions of the name.
+		fn split(style: NameStyle, name: &str, names: &[&str]) -> (String, String) {
+			let names: Vec<Vec<char>> = names.iter().map(|n| n.chars().collect()).collect();
+			let cell = style.format(name, None, &names);
+
+			// The text should never change.
+			assert_eq!(cell.0.iter().map(|(_, t)| t.as_str()).collect::<String>(), name);
+			assert_eq!(cell.width(), util::width(name));
+
+			let mut out = (String::new(), String::new());
+			for (style, text) in cell.0 {
+				if style == STYLE_NAME_DIM { out.0.push_str(&text); }
+				else { out.1.push_str(&text); }
+			}
+			out
+		}
+
+		let names = ["foo::bar(10)", "foo::bar(100)", "foo::baz()", "solo", "\u{e9}t\u{e9}::caf\u{e9}(\u{2615})"];
+		let split_auto = |name: &str| split(NameStyle::Auto, name, &names);
+		assert_eq!(split_auto("foo::bar(100)"), ("foo::bar".to_owned(), "(100)".to_owned()));
+		// "foo::bar(10)" and "foo::bar(100)" part ways at the closing paren,
+		// so the marker rewinds to the last "(", same as ever.
+		assert_eq!(split_auto("foo::bar(10)"), ("foo::bar".to_owned(), "(10)".to_owned()));
+		assert_eq!(split_auto("foo::baz()"), ("foo".to_owned(), "::baz()".to_owned()));
+		assert_eq!(split_auto("solo"), (String::new(), "solo".to_owned()));
+
+		// Plain is plain.
+		for n in names {
+			assert_eq!(split(NameStyle::Plain, n, &names), (String::new(), n.to_owned()));
+		}
+
+		// Splits are at the last delimiter (run).
+		let split_at = |delim: char, name: &str| split(NameStyle::SplitAt(delim), name, &names);
+		assert_eq!(split_at(':', "foo::bar(10)"), ("foo".to_owned(), "::bar(10)".to_owned()));
+		assert_eq!(split_at(':', "a::b::c"), ("a::b".to_owned(), "::c".to_owned()));
+		assert_eq!(split_at('(', "foo::bar(10)"), ("foo::bar".to_owned(), "(10)".to_owned()));
+		assert_eq!(split_at('(', "f((x))"), ("f".to_owned(), "((x))".to_owned()));
+		assert_eq!(split_at(':', "solo"), (String::new(), "solo".to_owned()));
+		assert_eq!(split_at(':', "::solo"), (String::new(), "::solo".to_owned()));
+		assert_eq!(
+			split_at('(', "\u{e9}t\u{e9}::caf\u{e9}(\u{2615})"),
+			("\u{e9}t\u{e9}::caf\u{e9}".to_owned(), "(\u{2615})".to_owned()),
+		);
+		assert_eq!(
+			split_at('\u{e9}', "\u{e9}t\u{e9}::caf\u{e9}(\u{2615})"),
+			("\u{e9}t\u{e9}::caf".to_owned(), "\u{e9}(\u{2615})".to_owned()),
+		);
+
+		// The plain-text rendering is unaffected.
+		let bench = |name: &str| {
+			let mut b = Bench::new(name);
+			b.stats = Some(Stats::try_from(vec![Duration::from_micros(1); 1000]));
+			b
+		};
+		let mut benches = Benches::default();
+		for n in &names[..3] { benches.push(bench(n)); }
+		let plain = benches.render_plain();
+		let color = benches.table().to_string();
+		let benches = benches.name_style(NameStyle::SplitAt(':'));
+		assert_eq!(benches.render_plain(), plain);
+		assert_ne!(benches.table().to_string(), color);
+	}
+
+	#[test]
+	fn t_geomean() {
+		assert_eq!(geomean([1.0, 4.0]), Some(2.0));
+		assert!(geomean([2.0, 8.0, 4.0]).is_some_and(|g| (g - 4.0).abs() < 0.000_001));
+		assert_eq!(geomean([1.0, 0.0]), None);
+		assert_eq!(geomean([]), None);
+
+		assert_eq!(group_prefix(["json::parse(1)", "json::parse(10)"]), Some("json"));
+		assert_eq!(group_prefix(["a::b::c()", "a::b::d()", "a::e()"]), Some("a"));
+		assert_eq!(group_prefix(["a::b()", "c::d()"]), None);
+		assert_eq!(group_prefix(["\u{e9}::a()", "\u{e8}::b()"]), None);
+
+		let stats = |n: u64| Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(n + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let bench = |name: &str, n: u64, baseline: Option<u64>| {
+			let mut b = Bench::new(name);
+			b.stats = Some(Ok(stats(n)));
+			b.baseline = baseline.map(stats);
+			b
+		};
+
+		// Off by default.
+		let mut benches = Benches::default();
+		benches.push(bench("json::a()", 1000, None));
+		benches.push(bench("json::b()", 4000, None));
+		assert!(! benches.render_plain().contains("geomean"));
+
+		// One group with history, one without, and one too small.
+		let mut benches = Benches::default().with_geomean(true);
+		benches.push(bench("json::a()", 1000, Some(1000)));
+		benches.push(bench("json::b()", 4000, Some(2000)));
+		benches.push(Bench::spacer());
+		benches.push(bench("xml::a()", 1000, None));
+		benches.push(bench("yaml::b()", 4000, Some(2000)));
+		benches.push(Bench::spacer());
+		benches.push(bench("toml::a()", 1000, None));
+		let plain = benches.render_plain();
+		let rows: Vec<&str> = plain.lines().filter(|l| l.contains("geomean")).collect();
+		assert_eq!(rows.len(), 2, "{plain}");
+		assert!(rows[0].trim_start().starts_with("\u{2b91} json geomean"), "{plain}");
+		assert!(rows[0].contains("+41.3"), "{plain}");
+		assert!(rows[1].trim_start().starts_with("\u{2b91} geomean"), "{plain}");
+		assert!(! rows[1].contains('%'), "{plain}");
+
+		// Not real benches!
+		assert_eq!(benches.results().len(), 5);
+	}
+
+	#[test]
+	fn t_pruning() {
+		// Clean, light, and heavy pruning, respectively.
+		let bench = |name: &str, outliers: u64| {
+			let samples: Vec<Duration> = (0..1000_u64)
+				.map(|i| Duration::from_nanos(if i < outliers { 1_000_000 } else { 1000 + i % 10 }))
+				.collect();
+			let mut b = Bench::new(name);
+			b.stats = Some(Stats::from_samples(samples, OutlierPolicy::MAD, Metric::Mean, MIN_SAMPLES));
+			b
+		};
+		let mut benches = Benches::default();
+		benches.push(bench("a()", 0));
+		benches.push(bench("b()", 150));
+		benches.push(bench("c()", 300));
+
+		let results = benches.results();
+		assert_eq!(results[0].pruned(), Some(0.0));
+		assert!(results[1].pruned().is_some_and(|p| (p - 0.15).abs() < 0.001));
+		assert!(results[2].pruned().is_some_and(|p| (p - 0.3).abs() < 0.001));
+
+		// Only the heavy ones should be highlighted.
+		let table = benches.table();
+		let styles: Vec<Option<&str>> = table.rows.iter()
+			.skip(1) // Header.
+			.filter_map(|r| match r {
+				TableRow::Normal(_, cells) => Some(cells),
+				_ => None,
+			})
+			.map(|cells| {
+				let idx = table.cols.iter().position(|c| *c == Column::Samples)?;
+				let cell = cells[idx].as_ref()?;
+				cell.0.iter().find_map(|seg|
+					if seg.0 == STYLE_PRUNED_WARN || seg.0 == STYLE_PRUNED_ALERT { Some(seg.0) }
+					else { None }
+				)
+			})
+			.collect();
+		assert_eq!(styles, [None, Some(STYLE_PRUNED_WARN), Some(STYLE_PRUNED_ALERT)]);
+
+		// And mentioned.
+		let note = benches.pruning_note().expect("Missing pruning note.");
+		assert!(! note.contains("a()"), "{note}");
+		assert!(note.contains("b()") && note.contains("c()"), "{note}");
+
+		// Unless we say otherwise.
+		let benches = benches.outlier_thresholds(0.2, 0.5);
+		let note = benches.pruning_note().expect("Missing pruning note.");
+		assert!(! note.contains("b()") && note.contains("c()"), "{note}");
+		let benches = benches.without_outlier_warnings();
+		assert!(benches.pruning_note().is_none());
+		assert!(benches.table().rows.iter().all(|r| match r {
+			TableRow::Normal(_, cells) => cells.iter().flatten().all(|c| c.0.iter().all(|seg|
+				seg.0 != STYLE_PRUNED_WARN && seg.0 != STYLE_PRUNED_ALERT
+			)),
+			_ => true,
+		}));
+	}
+
+	#[test]
+	fn t_slow_thresholds() {
+		let bench = |name: &str, nanos: u64| {
+			let mut b = Bench::new(name);
+			b.stats = Some(Stats::try_from(vec![Duration::from_nanos(nanos); 1000]));
+			b
+		};
+		let mut benches = Benches::default();
+		benches.push(bench("fast()", 500));
+		benches.push(bench("normal()", 20_000));
+		benches.push(bench("slow()", 200_000));
+		benches.push(bench("very_slow()", 2_000_000));
+
+		// Nothing special by default.
+		let mean_styles = |benches: &Benches<'_>| -> Vec<&'static str> {
+			let table = benches.table();
+			let idx = table.cols.iter().position(|c| *c == Column::Mean).expect("Missing mean.");
+			table.rows.iter()
+				.skip(1) // Header.
+				.filter_map(|r| match r {
+					TableRow::Normal(_, cells) => cells[idx].as_ref().map(|c| c.0[0].0),
+					_ => None,
+				})
+				.collect()
+		};
+		assert_eq!(mean_styles(&benches), [STYLE_MEAN; 4]);
+		assert!(! benches.render_plain().contains('!'), "Unexpected markers.");
+
+		// Now with bands.
+		let benches = benches.slow_thresholds(Duration::from_micros(100), Duration::from_millis(1));
+		assert_eq!(
+			mean_styles(&benches),
+			[STYLE_MEAN_FAST, STYLE_MEAN, STYLE_MEAN_SLOW, STYLE_MEAN_VERY_SLOW],
+		);
+
+		// Plain text gets markers instead…
+		let plain = benches.render_plain();
+		let marked: Vec<&str> = plain.lines().filter(|l| l.contains('!')).collect();
+		assert_eq!(marked.len(), 2, "{plain}");
+		assert!(marked[0].starts_with("slow() ! "), "{plain}");
+		assert!(marked[1].starts_with("very_slow() !! "), "{plain}");
+
+		// …which color output leaves out, without upsetting the alignment.
+		let color = benches.table().to_string();
+		assert!(! color.contains('!'), "{color}");
+		assert_eq!(util::strip_ansi(&color).len(), plain.len());
+	}
+
+	#[test]
+	fn t_panic() {
+		let mut benches = Benches::default();
+		let mut n = 0_u32;
+		benches.extend([
+			Bench::new("Panicky").run(move || {
+				n += 1;
+				assert!(n < 10, "Ten is too many!\nSecond line.");
+			}),
+			Bench::new("Fine").with_timeout(Duration::from_millis(500)).run(|| 1_u8),
+		]);
+
+		// The panic shouldn't stop the show.
+		let mut history = History::disabled();
+		benches.exec(&mut history);
+		let res = benches.results();
+		assert!(
+			matches!(res[0].stats(), Err(BrunchError::Panicked(ref m)) if m == "Ten is too many!"),
+			"Expected Panicked.",
+		);
+		assert!(res[1].stats().is_ok(), "The second bench should have run.");
+
+		// Only the successful bench should be recorded.
+		assert!(history.latest("Panicky").is_none(), "Panics should not be recorded.");
+		assert!(history.latest("Fine").is_some(), "Missing history.");
+
+		// And make it to the table.
+		assert!(benches.render_plain().contains("Panicked: Ten is too many!"), "Missing panic.");
+
+		// Non-string payloads are nameless.
+		assert_eq!(catch_panic(|| std::panic::panic_any(5_u8)), Err(String::new()));
+		assert_eq!(catch_panic(|| 5_u8), Ok(5));
+	}
+
+	#[test]
+	fn t_measure_cpu() {
+		let mut benches = Benches::default().with_verbose(true);
+		benches.extend([
+			Bench::new("Sleepy")
+				.with_samples(300)
+				.measure_cpu(true)
+				.run(|| std::thread::sleep(Duration::from_millis(1))),
+			Bench::new("Unmeasured").with_samples(100).run(|| 1_u8),
+		]);
+		benches.exec(&mut History::disabled());
+
+		let res = benches.results();
+		assert!(res[1].cpu().is_none(), "CPU time wasn't requested.");
+		if clock::thread_cpu_time().is_some() {
+			let wall = res[0].stats().expect("Missing stats.");
+			let cpu = res[0].cpu().expect("Missing CPU time.");
+			assert!(cpu.mean() < wall.mean(), "Sleeping shouldn't take CPU time.");
+			assert!(benches.render_plain().contains("CPU Time"), "Missing CPU column.");
+		}
+	}
+
+	#[test]
+	fn t_total_budget() {
+		let mut benches = Benches::default()
+			.with_total_budget(Duration::from_millis(60));
+		benches.extend([
+			Bench::new("Sleepy").run(|| std::thread::sleep(Duration::from_millis(1))),
+			Bench::new("Sleepier").run(|| std::thread::sleep(Duration::from_millis(1))),
+		]);
+		benches.exec(&mut History::disabled());
+
+		// Both should have been limited by the budget.
+		for b in &benches.set {
+			assert!(b.budgeted, "Timeout should have been limited by the budget.");
+			assert!(b.timeout() <= Duration::from_millis(60), "Timeout exceeds budget.");
+			assert!(matches!(b.stats, Some(Err(BrunchError::TimedOut { .. }))), "Expected TimedOut.");
+		}
+
+		// The hint should make it to the table.
+		let plain = benches.render_plain();
+		assert!(plain.contains("suite budget"), "Missing budget hint.");
+	}
+
+	#[test]
+	fn t_table_plain() {
+		let names: Vec<Vec<char>> = ["foo::bar(10)", "foo::baz(10)"].iter()
+			.map(|n| n.chars().collect())
+			.collect();
+
+		let mut table = Table::new(vec![Column::Mean, Column::Samples, Column::Change]);
+		table.rows.push(TableRow::Normal(
+			format_name(names[0].clone(), &names),
+			vec![
+				Some(Cell::new(STYLE_MEAN, "2.22 ms")),
+				Some(Cell(vec![
+					(STYLE_DIM, "2,408".to_owned()),
+					(STYLE_SLASH, "/".to_owned()),
+					(STYLE_DIM, "2,500".to_owned()),
+				])),
+				Some(format_change(0.0102)),
+			],
+		));
+		table.rows.push(TableRow::Spacer);
+		table.rows.push(TableRow::Normal(
+			format_name(names[1].clone(), &names),
+			vec![
+				Some(Cell::new(STYLE_MEAN, "56.17 ns")),
+				Some(Cell(vec![
+					(STYLE_DIM, "99".to_owned()),
+					(STYLE_SLASH, "/".to_owned()),
+					(STYLE_DIM, "100".to_owned()),
+				])),
+				None,
+			],
+		));
+		table.rows.push(TableRow::Error(
+			Cell::new(STYLE_NAME, "Ab\u{301}c"),
+			Cell::new(STYLE_ERROR, BrunchError::NoRun.to_string()),
+		));
+
+		let mut plain = String::new();
+		table.write_to(&mut plain, false).expect("Write failed.");
+		assert_eq!(
+			plain,
+			"Method              Mean        Samples    Change
+-------------------------------------------------
+foo::bar(10)     2.22 ms    2,408/2,500    +1.02%
+-------------------------------------------------
+foo::baz(10)    56.17 ns         99/100       ---
+Ab\u{301}c             Missing Bench::run.
+",
+		);
+
+		// The colored version should have the same content.
+		let color = table.to_string();
+		assert!(color.contains('\x1b'), "Missing ANSI.");
+		assert_eq!(util::strip_ansi(&color), plain, "Colored content mismatch.");
+	}
+
+	#[test]
+	fn t_table_decimal() {
+		let mut table = Table::new(vec![Column::Mean, Column::Samples]);
+		for (name, mean) in [
+			("pico", "0.00005 ms"),
+			("nano", "56.17 ns"),
+			("milli", "2.22 ms"),
+			("many", "1,204.50 ms"),
+			("secs", "12.5 s "),
+		] {
+			table.rows.push(TableRow::Normal(
+				Cell::new(STYLE_NAME, name),
+				vec![
+					Some(Cell::new(STYLE_MEAN, mean)),
+					Some(Cell::new(STYLE_DIM, "100/100")),
+				],
+			));
+		}
+
+		// The integers should be right-aligned, the fractions left-aligned,
+		// and the units right-aligned again.
+		let mut plain = String::new();
+		table.write_to(&mut plain, false).expect("Write failed.");
+		assert_eq!(
+			plain,
+			"Method              Mean    Samples
+-----------------------------------
+pico          0.00005 ms    100/100
+nano         56.17    ns    100/100
+milli         2.22    ms    100/100
+many      1,204.50    ms    100/100
+secs         12.5     s     100/100
+",
+		);
+
+		// Non-numeric cells are left alone.
+		assert!(Cell::new(STYLE_DIM, NO_CHANGE).aligned((5, 5)).is_none());
+		assert!(Cell::new(STYLE_HEADER, "Mean").aligned((5, 5)).is_none());
+		assert_eq!(
+			util::split_decimal("1,204.50 ms"),
+			Some(("1,204", ".50", " ms")),
+		);
+		assert_eq!(util::split_decimal("7 B"), Some(("7", "", " B")));
+
+		// Same for Markdown, which doesn't need to line anything up.
+		let mut md = String::new();
+		table.write_markdown(&mut md).expect("Write failed.");
+		assert!(md.contains("| nano | 56.17 ns | 100/100 |"), "{md}");
+	}
+
+	#[test]
+	fn t_table_fit() {
+		let names: Vec<Vec<char>> = [
+			"json::parser::streaming::deserialize::parse_huge(1_000_000)",
+			"json::parser::streaming::deserialize::parse_tiny(10)",
+		].iter()
+			.map(|n| n.chars().collect())
+			.collect();
+
+		let mut table = Table::new(vec![Column::Mean, Column::Samples, Column::Change]);
+		for n in &names {
+			table.rows.push(TableRow::Normal(
+				format_name(n.clone(), &names),
+				vec![
+					Some(Cell::new(STYLE_MEAN, "2.22 ms")),
+					Some(Cell::new(STYLE_DIM, "2,408/2,500")),
+					Some(format_change(0.0102)),
+				],
+			));
+		}
+		assert_eq!(table.width(), 59 + 4 + 7 + 4 + 11 + 4 + 6);
+
+		// Plenty of room.
+		assert_eq!(table.fitted(200).width(), table.width());
+
+		// Names first.
+		let fit = table.fitted(80);
+		assert_eq!(fit.width(), 80);
+		assert_eq!(fit.cols, [Column::Mean, Column::Samples, Column::Change]);
+		let mut plain = String::new();
+		fit.write_to(&mut plain, false).expect("Write failed.");
+		assert!(plain.contains("\njson::parser::stream\u{2026}::parse_huge(1_000_000)    2.22 ms"), "{plain}");
+		assert!(plain.contains("\njson::parser::streamin\u{2026}alize::parse_tiny(10)    2.22 ms"), "{plain}");
+
+		// Then Samples.
+		let fit = table.fitted(59);
+		assert_eq!(fit.cols, [Column::Mean, Column::Change]);
+		assert_eq!(fit.width(), MIN_NAME_WIDTH + 4 + 7 + 4 + 6);
+
+		// Then Change.
+		let fit = table.fitted(40);
+		assert_eq!(fit.cols, [Column::Mean]);
+		assert_eq!(fit.width(), MIN_NAME_WIDTH + 4 + 7);
+
+		// Truncation should respect the printable width.
+		let mut cell = Cell::new(STYLE_NAME, "\u{4e2d}\u{6587}e\u{301}abcdefghijklmnopqrstuvwxyz");
+		cell.truncate_middle(12);
+		assert!(cell.width() <= 12);
+		let mut s = String::new();
+		cell.write_to(&mut s, false).expect("Write failed.");
+		assert!(s.starts_with("\u{4e2d}\u{6587}e\u{301}"), "{s}");
+		assert!(s.ends_with("\u{2026}vwxyz"), "{s}");
 	}
 }
@@ -0,0 +1,200 @@
+/*!
+# Brunch: Bencher
+*/
+
+use crate::clock::{
+	Lap,
+	Stopwatch,
+};
+use std::{
+	hint::black_box,
+	time::Duration,
+};
+
+
+
+#[derive(Debug)]
+/// # Bencher.
+///
+/// This is handed to the callback of [`Bench::bench`](crate::Bench::bench)
+/// once per sample, giving full control over what does — and doesn't —
+/// happen inside the timed region.
+///
+/// Each call to the callback must call exactly one of [`Bencher::iter`],
+/// [`Bencher::iter_batched`], or [`Bencher::iter_custom`]; calling none, or
+/// more than one, is reported as a panic.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use brunch::Bench;
+///
+/// brunch::benches!(
+///     Bench::new("Vec::sort")
+///         .bench(|b| b.iter_batched(
+///             || vec![5_u8, 3, 1, 4, 2],
+///             |mut v| { v.sort(); v },
+///         ))
+/// );
+/// ```
+#[expect(missing_copy_implementations, reason = "Measurements should not be duplicated.")]
+pub struct Bencher {
+	/// # Measure CPU Time?
+	cpu: bool,
+
+	/// # Measurements.
+	lap: Option<Lap>,
+}
+
+impl Bencher {
+	/// # New.
+	pub(crate) const fn new(cpu: bool) -> Self {
+		Self { cpu, lap: None }
+	}
+
+	/// # Iterate.
+	///
+	/// Time a single call to the routine. The output is dropped only after
+	/// the clock has stopped.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("u64::checked_mul(3)")
+	///         .bench(|b| b.iter(|| 7_u64.checked_mul(3)))
+	/// );
+	/// ```
+	#[expect(clippy::iter_not_returning_iterator, reason = "Criterion compatibility.")]
+	pub fn iter<F, O>(&mut self, routine: F)
+	where F: FnOnce() -> O {
+		let watch = Stopwatch::start(self.cpu);
+		let out = black_box(routine());
+		let lap = watch.stop();
+		drop(out);
+		self.record(lap);
+	}
+
+	/// # Iterate (Batched).
+	///
+	/// Run the setup — untimed — then time a single call to the routine with
+	/// its output, e.g. to sort a fresh copy of some data each sample. The
+	/// routine's output is dropped only after the clock has stopped.
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	///
+	/// brunch::benches!(
+	///     Bench::new("String::push_str")
+	///         .bench(|b| b.iter_batched(
+	///             || String::with_capacity(64),
+	///             |mut s| { s.push_str("Hello World"); s },
+	///         ))
+	/// );
+	/// ```
+	pub fn iter_batched<S, I, F, O>(&mut self, setup: S, routine: F)
+	where S: FnOnce() -> I, F: FnOnce(I) -> O {
+		let input = setup();
+		let watch = Stopwatch::start(self.cpu);
+		let out = black_box(routine(input));
+		let lap = watch.stop();
+		drop(out);
+		self.record(lap);
+	}
+
+	/// # Iterate (Self-Timed).
+	///
+	/// Call the routine with the number of iterations to run — always `1`, as
+	/// each sample is a single iteration — and record whatever duration it
+	/// returns, as with [`Bench::run_timed`](crate::Bench::run_timed).
+	///
+	/// ## Examples
+	///
+	/// ```no_run
+	/// use brunch::Bench;
+	/// use std::time::Instant;
+	///
+	/// brunch::benches!(
+	///     Bench::new("Vec::with_capacity(1024)")
+	///         .bench(|b| b.iter_custom(|iters| {
+	///             let now = Instant::now();
+	///             for _ in 0..iters {
+	///                 std::hint::black_box(Vec::<u8>::with_capacity(1024));
+	///             }
+	///             now.elapsed()
+	///         }))
+	/// );
+	/// ```
+	pub fn iter_custom<F>(&mut self, routine: F)
+	where F: FnOnce(u64) -> Duration {
+		let watch = Stopwatch::start(self.cpu);
+		let reported = black_box(routine(1));
+		self.record(watch.stop().with_reported(reported));
+	}
+
+	/// # Finish.
+	///
+	/// Return the measurements for the sample.
+	///
+	/// ## Panics
+	///
+	/// This will panic if none of the `iter` methods were called.
+	pub(crate) const fn finish(self) -> Lap {
+		self.lap.expect("Bencher callbacks must call iter, iter_batched, or iter_custom.")
+	}
+
+	/// # Record.
+	///
+	/// ## Panics
+	///
+	/// This will panic if a lap was already recorded.
+	fn record(&mut self, lap: Lap) {
+		assert!(
+			self.lap.replace(lap).is_none(),
+			"Bencher callbacks must call iter, iter_batched, or iter_custom only once.",
+		);
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_bencher() {
+		// Each flavor should record a lap.
+		let mut b = Bencher::new(false);
+		b.iter(|| 1_u8.checked_add(1));
+		let _lap = b.finish();
+
+		let mut b = Bencher::new(false);
+		let mut setup = false;
+		b.iter_batched(|| { setup = true; 5_u8 }, |v| v.checked_add(1));
+		assert!(setup, "Setup should have run.");
+		let _lap = b.finish();
+
+		let mut b = Bencher::new(false);
+		b.iter_custom(|iters| {
+			assert_eq!(iters, 1, "Wrong iteration count.");
+			Duration::from_micros(3)
+		});
+		assert_eq!(b.finish().wall(), Duration::from_micros(3), "Wrong reported time.");
+
+		// Doing nothing — or too much — should panic.
+		assert!(std::panic::catch_unwind(|| Bencher::new(false).finish()).is_err(), "Expected panic.");
+		assert!(
+			std::panic::catch_unwind(|| {
+				let mut b = Bencher::new(false);
+				b.iter(|| 1_u8);
+				b.iter(|| 2_u8);
+			}).is_err(),
+			"Expected panic.",
+		);
+	}
+}
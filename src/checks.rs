@@ -0,0 +1,217 @@
+/*!
+# Brunch: Environment Checks
+*/
+
+use std::{
+	collections::BTreeSet,
+	path::Path,
+};
+
+
+
+/// # Environment Probe.
+///
+/// This abstracts away the system queries used by the sanity checks so that
+/// the logic can be tested without any particular hardware. Each method
+/// returns `None` if the answer is unknown.
+pub(crate) trait Probe {
+	/// # CPU Frequency Governors.
+	///
+	/// Return the scaling governor for each CPU.
+	fn governors(&self) -> Option<Vec<String>>;
+
+	/// # Turbo Boost Enabled?
+	fn turbo(&self) -> Option<bool>;
+
+	/// # Running on Battery?
+	fn on_battery(&self) -> Option<bool>;
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # System Probe.
+///
+/// This queries the real system. Only Linux is currently supported; the
+/// answers are always unknown elsewhere.
+pub(crate) struct SysProbe;
+
+impl Probe for SysProbe {
+	fn governors(&self) -> Option<Vec<String>> {
+		if ! cfg!(target_os = "linux") { return None; }
+
+		let mut out: Vec<String> = std::fs::read_dir("/sys/devices/system/cpu").ok()?
+			.filter_map(|e| {
+				let e = e.ok()?;
+				let name = e.file_name();
+				let num = name.to_str()?.strip_prefix("cpu")?;
+				if num.is_empty() || ! num.bytes().all(|b| b.is_ascii_digit()) { return None; }
+				read_trimmed(&e.path().join("cpufreq/scaling_governor"))
+			})
+			.collect();
+		if out.is_empty() { None }
+		else {
+			out.sort_unstable();
+			Some(out)
+		}
+	}
+
+	fn turbo(&self) -> Option<bool> {
+		if ! cfg!(target_os = "linux") { return None; }
+
+		// Intel's driver has it backwards…
+		read_trimmed(Path::new("/sys/devices/system/cpu/intel_pstate/no_turbo"))
+			.map(|s| s == "0")
+			// …compared to everybody else.
+			.or_else(|| read_trimmed(Path::new("/sys/devices/system/cpu/cpufreq/boost")).map(|s| s == "1"))
+	}
+
+	fn on_battery(&self) -> Option<bool> {
+		if ! cfg!(target_os = "linux") { return None; }
+
+		let mut found = false;
+		for e in std::fs::read_dir("/sys/class/power_supply").ok()?.flatten() {
+			let path = e.path();
+			if read_trimmed(&path.join("type")).is_some_and(|t| t == "Battery") {
+				found = true;
+				if read_trimmed(&path.join("status")).is_some_and(|s| s == "Discharging") {
+					return Some(true);
+				}
+			}
+		}
+
+		if found { Some(false) }
+		else { None }
+	}
+}
+
+
+
+/// # Print Warnings.
+///
+/// Run the sanity checks against the system and print a compact warning
+/// block listing anything suspicious, unless `BRUNCH_NO_CHECKS=1`.
+///
+/// These are only ever advisory; nothing here can fail the run.
+pub(crate) fn print() {
+	if std::env::var("BRUNCH_NO_CHECKS").is_ok_and(|s| s.trim() == "1") { return; }
+
+	let warnings = warnings(&SysProbe);
+	if warnings.is_empty() { return; }
+
+	let mut out = String::from("\x1b[1;93mWarning:\x1b[0m The environment may skew the results:\n");
+	for w in warnings {
+		out.push_str("\x1b[2m  \u{2022} ");
+		out.push_str(&w);
+		out.push_str("\x1b[0m\n");
+	}
+	eprint!("{out}");
+}
+
+/// # Warnings.
+///
+/// Return a list of potential problems, if any.
+pub(crate) fn warnings<P: Probe>(probe: &P) -> Vec<String> {
+	let mut out = Vec::new();
+
+	// Anything other than "performance" will likely scale the frequency up
+	// and down mid-run.
+	if let Some(governors) = probe.governors() {
+		let odd: BTreeSet<String> = governors.into_iter()
+			.filter(|g| g != "performance")
+			.collect();
+		if ! odd.is_empty() {
+			let odd: Vec<String> = odd.into_iter().collect();
+			out.push(format!("CPU governor is {}.", odd.join("/")));
+		}
+	}
+
+	if probe.turbo() == Some(true) {
+		out.push("Turbo boost is enabled; results may drift.".to_owned());
+	}
+
+	if probe.on_battery() == Some(true) {
+		out.push("Running on battery power.".to_owned());
+	}
+
+	out
+}
+
+/// # Read and Trim.
+///
+/// Read a (small) file into a string, returning `None` if it is missing or
+/// empty.
+fn read_trimmed(path: &Path) -> Option<String> {
+	let s = std::fs::read_to_string(path).ok()?;
+	let s = s.trim();
+	if s.is_empty() { None }
+	else { Some(s.to_owned()) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[derive(Default)]
+	/// # Fake Probe.
+	struct Mock {
+		governors: Option<Vec<&'static str>>,
+		turbo: Option<bool>,
+		battery: Option<bool>,
+	}
+
+	impl Probe for Mock {
+		fn governors(&self) -> Option<Vec<String>> {
+			self.governors.as_ref().map(|v| v.iter().map(|&s| s.to_owned()).collect())
+		}
+		fn turbo(&self) -> Option<bool> { self.turbo }
+		fn on_battery(&self) -> Option<bool> { self.battery }
+	}
+
+	#[test]
+	fn t_warnings() {
+		// Nothing known, nothing to say.
+		assert!(warnings(&Mock::default()).is_empty());
+
+		// A perfectly good setup.
+		let good = Mock {
+			governors: Some(vec!["performance", "performance"]),
+			turbo: Some(false),
+			battery: Some(false),
+		};
+		assert!(warnings(&good).is_empty());
+
+		// Governors.
+		let mock = Mock { governors: Some(vec!["powersave", "powersave"]), ..Mock::default() };
+		assert_eq!(warnings(&mock), ["CPU governor is powersave."]);
+		let mock = Mock {
+			governors: Some(vec!["performance", "schedutil", "powersave"]),
+			..Mock::default()
+		};
+		assert_eq!(warnings(&mock), ["CPU governor is powersave/schedutil."]);
+
+		// Turbo.
+		let mock = Mock { turbo: Some(true), ..Mock::default() };
+		assert_eq!(warnings(&mock), ["Turbo boost is enabled; results may drift."]);
+
+		// Battery.
+		let mock = Mock { battery: Some(true), ..Mock::default() };
+		assert_eq!(warnings(&mock), ["Running on battery power."]);
+
+		// All together now.
+		let bad = Mock {
+			governors: Some(vec!["powersave"]),
+			turbo: Some(true),
+			battery: Some(true),
+		};
+		assert_eq!(warnings(&bad).len(), 3);
+	}
+
+	#[test]
+	fn t_sys_probe() {
+		// Whatever the answers, asking shouldn't blow up.
+		let _res = warnings(&SysProbe);
+	}
+}
@@ -0,0 +1,300 @@
+/*!
+# Brunch: Clocks
+*/
+
+use crate::{
+	alloc::Snapshot,
+	perf,
+	timer::Tick,
+};
+use std::time::Duration;
+
+
+
+#[derive(Debug, Clone)]
+/// # Stopwatch.
+///
+/// This times a single sample, always by the wall clock — or whatever
+/// [`Timer`](crate::Timer) is active — and optionally by the thread's CPU
+/// clock too. If the [`TrackingAllocator`](crate::TrackingAllocator) is
+/// installed, the allocations are counted as well, and if hardware
+/// performance counters are available, so are the instructions and cycles.
+pub(crate) struct Stopwatch {
+	/// # Wall Start.
+	wall: Tick,
+
+	/// # CPU Start.
+	cpu: Option<Duration>,
+
+	/// # Allocation Counts.
+	allocs: Option<Snapshot>,
+
+	/// # Performance Counters.
+	perf: Option<perf::Snapshot>,
+}
+
+impl Stopwatch {
+	/// # Start.
+	///
+	/// The CPU clock and other counters, if any, are read first so that
+	/// their overhead isn't included in the wall time.
+	pub(crate) fn start(cpu: bool) -> Self {
+		let cpu = if cpu { thread_cpu_time() } else { None };
+		let allocs = Snapshot::now();
+		let perf = perf::Snapshot::now();
+		Self { wall: Tick::now(), cpu, allocs, perf }
+	}
+
+	/// # Stop.
+	///
+	/// Return the elapsed wall time, along with the CPU time and other
+	/// counts if requested (and available).
+	pub(crate) fn stop(self) -> Lap {
+		let wall = self.wall.elapsed();
+		let perf = self.perf.and_then(|start|
+			perf::Snapshot::now().map(|end| end.since(start))
+		);
+		let allocs = self.allocs.and_then(|start|
+			Snapshot::now().map(|end| end.since(start))
+		);
+		let cpu = self.cpu.and_then(|start|
+			thread_cpu_time().map(|end| end.saturating_sub(start))
+		);
+		Lap { wall, outer: None, cpu, allocs, perf, err: false }
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Lap.
+///
+/// The measurements for a single sample, as returned by [`Stopwatch::stop`].
+pub(crate) struct Lap {
+	/// # Wall Time.
+	///
+	/// For self-timed benchmarks, this is the time reported by the callback.
+	wall: Duration,
+
+	/// # Outer Wall Time.
+	///
+	/// For self-timed benchmarks, this is the wall time around the call.
+	outer: Option<Duration>,
+
+	/// # CPU Time.
+	cpu: Option<Duration>,
+
+	/// # Allocation Counts.
+	allocs: Option<Snapshot>,
+
+	/// # Performance Counters.
+	perf: Option<perf::Snapshot>,
+
+	/// # Callback Returned `Err`?
+	err: bool,
+}
+
+impl Lap {
+	#[cfg(test)]
+	/// # Fake.
+	///
+	/// Return a lap with the given wall time and nothing else, for testing.
+	pub(crate) const fn fake(wall: Duration) -> Self {
+		Self { wall, outer: None, cpu: None, allocs: None, perf: None, err: false }
+	}
+
+	/// # Wall Time.
+	pub(crate) const fn wall(&self) -> Duration { self.wall }
+
+	/// # Time Spent.
+	///
+	/// Return the wall time actually spent on the sample, which is what the
+	/// timeouts are measured against. This is usually the same as the wall
+	/// time, but not for self-timed benchmarks.
+	pub(crate) const fn spent(&self) -> Duration {
+		match self.outer {
+			Some(d) => d,
+			None => self.wall,
+		}
+	}
+
+	/// # CPU Time.
+	pub(crate) const fn cpu(&self) -> Option<Duration> { self.cpu }
+
+	/// # Allocation Counts.
+	pub(crate) const fn allocs(&self) -> Option<Snapshot> { self.allocs }
+
+	/// # Performance Counters.
+	pub(crate) const fn perf(&self) -> Option<perf::Snapshot> { self.perf }
+
+	/// # Callback Returned `Err`?
+	pub(crate) const fn is_err(&self) -> bool { self.err }
+
+	#[must_use]
+	/// # With Error Flag.
+	///
+	/// Note whether or not the (fallible) callback returned an `Err`.
+	pub(crate) const fn with_err(mut self, err: bool) -> Self {
+		self.err = err;
+		self
+	}
+
+	#[must_use]
+	/// # With Reported Time.
+	///
+	/// Swap in the time reported by a self-timed callback, keeping the
+	/// measured wall time for the timeout.
+	pub(crate) const fn with_reported(mut self, reported: Duration) -> Self {
+		self.outer = Some(self.wall);
+		self.wall = reported;
+		self
+	}
+}
+
+
+
+/// # Thread CPU Time.
+///
+/// Return the total CPU time consumed by the current thread so far, or `None`
+/// if the platform doesn't provide a usable clock.
+pub(crate) fn thread_cpu_time() -> Option<Duration> { sys::thread_cpu_time() }
+
+
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+/// # Unix.
+mod sys {
+	use std::{
+		ffi::{
+			c_int,
+			c_long,
+		},
+		time::Duration,
+	};
+
+	#[cfg(any(target_os = "linux", target_os = "android"))]
+	/// # Thread CPU Clock ID.
+	const CLOCK_THREAD_CPUTIME_ID: c_int = 3;
+
+	#[cfg(target_os = "macos")]
+	/// # Thread CPU Clock ID.
+	const CLOCK_THREAD_CPUTIME_ID: c_int = 16;
+
+	#[repr(C)]
+	/// # Time Spec.
+	struct Timespec {
+		/// # Seconds.
+		tv_sec: c_long,
+
+		/// # Nanoseconds.
+		tv_nsec: c_long,
+	}
+
+	extern "C" {
+		/// # Get Time.
+		fn clock_gettime(clock: c_int, ts: *mut Timespec) -> c_int;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Thread CPU Time.
+	pub(super) fn thread_cpu_time() -> Option<Duration> {
+		let mut ts = Timespec { tv_sec: 0, tv_nsec: 0 };
+
+		// Safety: the struct matches the C layout.
+		if 0 == unsafe { clock_gettime(CLOCK_THREAD_CPUTIME_ID, &raw mut ts) } {
+			let secs = u64::try_from(ts.tv_sec).ok()?;
+			let nanos = u32::try_from(ts.tv_nsec).ok()?;
+			Some(Duration::new(secs, nanos))
+		}
+		else { None }
+	}
+}
+
+#[cfg(windows)]
+/// # Windows.
+mod sys {
+	use std::{
+		ffi::c_void,
+		time::Duration,
+	};
+
+	#[link(name = "kernel32")]
+	extern "system" {
+		/// # Current Thread (Pseudo) Handle.
+		fn GetCurrentThread() -> *mut c_void;
+
+		/// # Thread Times.
+		///
+		/// Each value is a `FILETIME`, i.e. a 64-bit count of 100-nanosecond
+		/// intervals split into two 32-bit halves; `u64` has the same layout
+		/// on little-endian machines.
+		fn GetThreadTimes(
+			thread: *mut c_void,
+			creation: *mut u64,
+			exit: *mut u64,
+			kernel: *mut u64,
+			user: *mut u64,
+		) -> i32;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Thread CPU Time.
+	///
+	/// This is the sum of the kernel and user time.
+	pub(super) fn thread_cpu_time() -> Option<Duration> {
+		let mut creation = 0_u64;
+		let mut exit = 0_u64;
+		let mut kernel = 0_u64;
+		let mut user = 0_u64;
+
+		// Safety: the pseudo handle is always valid for the calling thread,
+		// and the pointers are all valid.
+		let res = unsafe {
+			GetThreadTimes(GetCurrentThread(), &raw mut creation, &raw mut exit, &raw mut kernel, &raw mut user)
+		};
+		if res == 0 { None }
+		else { Some(Duration::from_nanos(kernel.saturating_add(user).saturating_mul(100))) }
+	}
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", windows)))]
+/// # Other.
+mod sys {
+	use std::time::Duration;
+
+	/// # Thread CPU Time (Unsupported).
+	pub(super) const fn thread_cpu_time() -> Option<Duration> { None }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	use std::time::Instant;
+
+	#[test]
+	fn t_stopwatch() {
+		let lap = Stopwatch::start(false).stop();
+		assert!(lap.cpu().is_none(), "CPU time wasn't requested.");
+		assert!(lap.wall() < Duration::from_secs(1));
+
+		if thread_cpu_time().is_some() {
+			// Sleeping takes time, but not CPU time.
+			let watch = Stopwatch::start(true);
+			std::thread::sleep(Duration::from_millis(50));
+			let lap = watch.stop();
+			let cpu = lap.cpu().expect("Missing CPU time.");
+			assert!(Duration::from_millis(50) <= lap.wall(), "Wall time too short.");
+			assert!(cpu < Duration::from_millis(25), "CPU time too long: {cpu:?}");
+
+			// Spinning takes both.
+			let watch = Stopwatch::start(true);
+			let now = Instant::now();
+			while now.elapsed() < Duration::from_millis(50) { std::hint::spin_loop(); }
+			let cpu = watch.stop().cpu().expect("Missing CPU time.");
+			assert!(Duration::from_millis(10) <= cpu, "CPU time too short: {cpu:?}");
+		}
+	}
+}
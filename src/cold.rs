@@ -0,0 +1,64 @@
+/*!
+# Brunch: Cold Caches
+*/
+
+use std::{
+	cell::RefCell,
+	hint::black_box,
+};
+
+
+
+/// # Scratch Size.
+///
+/// This should comfortably exceed the last-level cache of most machines.
+const SCRATCH: usize = 64 * 1024 * 1024;
+
+/// # Stride.
+///
+/// Touching one byte per (typical) cache line is enough to pull the whole
+/// line in.
+const STRIDE: usize = 64;
+
+thread_local! {
+	/// # Scratch Buffer.
+	///
+	/// This is allocated on first use and kept for the life of the thread.
+	static SCRATCH_BUF: RefCell<Vec<u8>> = const { RefCell::new(Vec::new()) };
+}
+
+
+
+/// # Chill.
+///
+/// Evict (most of) the data cache by dirtying every line of a large scratch
+/// buffer, leaving the benchmark's own data cold for the next sample.
+///
+/// This is slow — milliseconds rather than nanoseconds — so must only ever
+/// be called outside the timed region.
+pub(crate) fn chill() {
+	SCRATCH_BUF.with_borrow_mut(|buf| {
+		if buf.is_empty() { buf.resize(SCRATCH, 0); }
+		for b in buf.iter_mut().step_by(STRIDE) { *b = b.wrapping_add(1); }
+		black_box(buf.as_slice());
+	});
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_chill() {
+		chill();
+		chill();
+		SCRATCH_BUF.with_borrow(|buf| {
+			assert_eq!(buf.len(), SCRATCH, "Wrong scratch size.");
+			assert_eq!(buf[0], 2, "The buffer should have been touched twice.");
+			assert_eq!(buf[STRIDE], 2, "The buffer should have been touched twice.");
+			assert_eq!(buf[1], 0, "Only one byte per line should be touched.");
+		});
+	}
+}
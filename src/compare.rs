@@ -0,0 +1,242 @@
+/*!
+# Brunch: Compare
+*/
+
+use crate::{
+	bench::{
+		Column,
+		Table,
+		format_name,
+	},
+	BrunchError,
+	History,
+	OutputTarget,
+};
+use std::{
+	collections::BTreeSet,
+	io::Write,
+	path::Path,
+};
+
+
+
+/// # Compare Two History Files.
+///
+/// Load two previously-saved history files — e.g. recorded on different
+/// branches — and print a table comparing the most recent mean of each
+/// benchmark, along with the relative difference from `A` to `B`. Entries
+/// present in only one of the files are flagged as such.
+///
+/// No benchmarks are run. The table is printed to `STDERR`, or `STDOUT` if
+/// the `BRUNCH_STDOUT=1` environmental variable is set.
+///
+/// This can also be triggered from any [`benches`](crate::benches)-generated
+/// `main` by setting the `BRUNCH_COMPARE` environmental variable to the path
+/// of another history file, which will be compared against the current one.
+///
+/// To compare a named baseline instead of the default one, use
+/// [`compare_baseline`].
+///
+/// ## Examples
+///
+/// ```no_run
+/// brunch::compare("main.hist", "feature.hist").unwrap();
+/// ```
+///
+/// ## Errors
+///
+/// This will return an error if either file is missing or cannot be parsed.
+pub fn compare<A, B>(a: A, b: B) -> Result<(), BrunchError>
+where A: AsRef<Path>, B: AsRef<Path> {
+	compare_paths(a.as_ref(), b.as_ref(), None)
+}
+
+/// # Compare Two History Files (Named Baseline).
+///
+/// This works just like [`compare`], except the entries are pulled from the
+/// named baseline — see [`Benches::save_baseline`](crate::Benches::save_baseline)
+/// — of each file rather than the default one.
+///
+/// When triggered via `BRUNCH_COMPARE`, the `BRUNCH_BASELINE` environmental
+/// variable, if set, has the same effect.
+///
+/// ## Examples
+///
+/// ```no_run
+/// brunch::compare_baseline("main.hist", "feature.hist", "main").unwrap();
+/// ```
+///
+/// ## Errors
+///
+/// This will return an error if either file is missing or cannot be parsed.
+pub fn compare_baseline<A, B, S>(a: A, b: B, name: S) -> Result<(), BrunchError>
+where A: AsRef<Path>, B: AsRef<Path>, S: AsRef<str> {
+	compare_paths(a.as_ref(), b.as_ref(), Some(name.as_ref()))
+}
+
+#[doc(hidden)]
+#[must_use]
+/// # Compare From Environment.
+///
+/// If the `BRUNCH_COMPARE` environmental variable is set, compare that
+/// history file against the current one and return `true`.
+///
+/// This is used by the [`benches`](crate::benches) macro to short-circuit the
+/// generated `main`; it is not intended to be called manually.
+pub fn compare_env() -> bool {
+	let Some(b) = std::env::var_os("BRUNCH_COMPARE").filter(|v| ! v.is_empty())
+	else { return false; };
+
+	let baseline = std::env::var("BRUNCH_BASELINE").ok();
+	let res = History::default_path()
+		.ok_or(BrunchError::NoHistory)
+		.and_then(|a| compare_paths(&a, b.as_ref(), baseline.as_deref()));
+	if let Err(e) = res { eprintln!("\x1b[1;91mError:\x1b[0m {e}"); }
+
+	true
+}
+
+/// # Compare Paths.
+///
+/// This is the business end of [`compare`] and [`compare_baseline`].
+fn compare_paths(a: &Path, b: &Path, baseline: Option<&str>) -> Result<(), BrunchError> {
+	let table = table(a, b, baseline)?;
+
+	let target = OutputTarget::from_env();
+	let color = target.color();
+	let mut out = String::new();
+	let _res = table.write_to(&mut out, color);
+
+	// Add a legend so it's clear which is which.
+	let mut legend = format!("A: {}\nB: {}", a.display(), b.display());
+	if let Some(name) = baseline.map(str::trim).filter(|n| ! n.is_empty()) {
+		legend.push_str("\nBaseline: ");
+		legend.push_str(name);
+	}
+	let out =
+		if color { format!("{out}\x1b[2m{legend}\x1b[0m\n\n") }
+		else { format!("{out}{legend}\n\n") };
+
+	let _res = match target {
+		OutputTarget::Stderr => std::io::stderr().lock().write_all(out.as_bytes()),
+		OutputTarget::Stdout => std::io::stdout().lock().write_all(out.as_bytes()),
+	};
+
+	Ok(())
+}
+
+/// # Build Table.
+fn table(a: &Path, b: &Path, baseline: Option<&str>) -> Result<Table, BrunchError> {
+	let mut a = History::open(a).ok_or(BrunchError::NoHistory)?;
+	let mut b = History::open(b).ok_or(BrunchError::NoHistory)?;
+	if let Some(name) = baseline {
+		a.select(name);
+		b.select(name);
+	}
+
+	// Merge and sort the keys.
+	let keys: BTreeSet<&str> = a.keys().chain(b.keys()).collect();
+	let names: Vec<Vec<char>> = keys.iter().map(|k| k.chars().collect()).collect();
+
+	let mut table = Table::new(vec![Column::MeanA, Column::MeanB, Column::Change]);
+	for (key, name) in keys.iter().zip(&names) {
+		table.push_compare(
+			format_name(name.clone(), &names),
+			a.latest(key),
+			b.latest(key),
+		);
+	}
+
+	Ok(table)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		Metric,
+		Stats,
+	};
+	use std::time::Duration;
+
+	/// # Test Stats.
+	fn stats(micros: u64) -> Stats {
+		Stats::try_from(vec![Duration::from_micros(micros); 200]).expect("Invalid stats.")
+	}
+
+	#[test]
+	fn t_compare() {
+		let dir = std::env::temp_dir();
+		let path_a = dir.join("__brunch-test-compare-a.last");
+		let path_b = dir.join("__brunch-test-compare-b.last");
+
+		let mut hist = History::new(Some(&path_a));
+		hist.insert("Both", stats(2), Metric::Mean, None, None, None);
+		hist.insert("Only A", stats(2), Metric::Mean, None, None, None);
+		hist.save();
+
+		let mut hist = History::new(Some(&path_b));
+		hist.insert("Both", stats(3), Metric::Mean, None, None, None);
+		hist.insert("Only B", stats(2), Metric::Mean, None, None, None);
+		hist.save();
+
+		let plain = {
+			let table = table(&path_a, &path_b, None).expect("Comparison failed.");
+			let mut out = String::new();
+			table.write_to(&mut out, false).expect("Write failed.");
+			out
+		};
+
+		let _res = std::fs::remove_file(&path_a);
+		let _res = std::fs::remove_file(&path_b);
+
+		assert!(plain.contains("Mean (A)"), "Missing column.");
+		assert!(plain.contains("+50.00%"), "Missing difference.");
+		assert!(plain.contains("Missing from B."), "Missing flag.");
+		assert!(plain.contains("Missing from A."), "Missing flag.");
+
+		// A missing file is an error.
+		assert!(matches!(
+			table(&path_a, &path_b, None),
+			Err(BrunchError::NoHistory),
+		));
+	}
+
+	#[test]
+	fn t_compare_baseline() {
+		let dir = std::env::temp_dir();
+		let path_a = dir.join("__brunch-test-compare-baseline-a.last");
+		let path_b = dir.join("__brunch-test-compare-baseline-b.last");
+
+		// Put something different in the default and main baselines.
+		for (path, micros) in [(&path_a, 2), (&path_b, 3)] {
+			let mut hist = History::new(Some(path));
+			hist.insert("Default", stats(micros), Metric::Mean, None, None, None);
+			hist.save();
+			let mut hist = History::new(Some(path));
+			hist.save_to("main");
+			hist.insert("Main", stats(micros * 2), Metric::Mean, None, None, None);
+			hist.save();
+		}
+
+		let render = |baseline: Option<&str>| {
+			let table = table(&path_a, &path_b, baseline).expect("Comparison failed.");
+			let mut out = String::new();
+			table.write_to(&mut out, false).expect("Write failed.");
+			out
+		};
+		let default = render(None);
+		let main = render(Some("main"));
+
+		let _res = std::fs::remove_file(&path_a);
+		let _res = std::fs::remove_file(&path_b);
+
+		assert!(default.contains("Default"), "Missing default entry.");
+		assert!(! default.contains("Main"), "Unexpected main entry.");
+		assert!(main.contains("Main"), "Missing main entry.");
+		assert!(! main.contains("Default"), "Unexpected default entry.");
+		assert!(main.contains("+50.00%"), "Missing difference.");
+	}
+}
@@ -0,0 +1,228 @@
+/*!
+# Brunch: Shared Context
+*/
+
+use std::{
+	cell::{
+		Cell,
+		RefCell,
+	},
+	fmt,
+	rc::Rc,
+};
+
+
+
+/// # Initializer.
+type Init<'a, T> = Box<dyn FnOnce() -> T + 'a>;
+
+
+
+/// # Shared Context.
+///
+/// This is a handle to some (expensive) setup shared by any number of
+/// benchmarks, like a large parsed dataset, for use with
+/// [`Bench::run_with_context`](crate::Bench::run_with_context).
+///
+/// The value is built lazily — once — right before the first benchmark that
+/// needs it is run, so the construction time is never attributed to any
+/// benchmark's samples or timeout. If nothing needs it, it is never built
+/// at all.
+///
+/// Handles are cheap to clone; they all point to the same value.
+///
+/// Contexts created with [`Benches::with_context`](crate::Benches::with_context)
+/// are dropped as soon as the benchmarks have finished running; otherwise
+/// the value lives until the last handle is dropped.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use brunch::{Bench, Context};
+///
+/// fn main() {
+///     let data = Context::new(|| (0..1_000_000_u32).collect::<Vec<_>>());
+///
+///     brunch::benches!(
+///         inline:
+///
+///         Bench::new("Vec::iter().sum()")
+///             .run_with_context(&data, |v| v.iter().copied().map(u64::from).sum::<u64>()),
+///         Bench::new("Vec::iter().max()")
+///             .run_with_context(&data, |v| v.iter().max().copied()),
+///     );
+/// }
+/// ```
+pub struct Context<'a, T> {
+	/// # Shared State.
+	inner: Rc<Inner<'a, T>>,
+}
+
+impl<T> Clone for Context<'_, T> {
+	fn clone(&self) -> Self {
+		Self { inner: Rc::clone(&self.inner) }
+	}
+}
+
+impl<T: fmt::Debug> fmt::Debug for Context<'_, T> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_struct("Context")
+			.field("value", &self.inner.value)
+			.finish_non_exhaustive()
+	}
+}
+
+impl<'a, T: 'a> Context<'a, T> {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new context from the initializer, which will be called — at
+	/// most once — the first time the value is needed.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use brunch::Context;
+	///
+	/// let ctx = Context::new(|| vec![1_u8, 2, 3]);
+	/// ```
+	pub fn new<F>(init: F) -> Self
+	where F: FnOnce() -> T + 'a {
+		Self {
+			inner: Rc::new(Inner {
+				init: Cell::new(Some(Box::new(init))),
+				value: RefCell::new(None),
+			}),
+		}
+	}
+
+	/// # Initialize.
+	///
+	/// Build the value if it hasn't been built yet.
+	pub(crate) fn init(&self) { self.inner.init(); }
+
+	/// # With Value.
+	///
+	/// Pass a reference to the value to the callback, building it first if
+	/// needed.
+	///
+	/// ## Panics
+	///
+	/// This will panic if the value is no longer available, i.e. the
+	/// initializer panicked or the context has been dropped.
+	pub(crate) fn with<F, O>(&self, cb: F) -> O
+	where F: FnOnce(&T) -> O {
+		self.inner.init();
+		let value = self.inner.value.borrow();
+		let value = value.as_ref().expect("The shared context is unavailable.");
+		cb(value)
+	}
+}
+
+
+
+#[derive(Default)]
+/// # Registered Contexts.
+///
+/// This holds type-erased handles to the contexts created by a suite so they
+/// can be dropped once it has finished.
+pub(crate) struct Contexts<'a>(Vec<Rc<dyn Reset + 'a>>);
+
+impl fmt::Debug for Contexts<'_> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.debug_tuple("Contexts").field(&self.0.len()).finish()
+	}
+}
+
+impl<'a> Contexts<'a> {
+	/// # Push.
+	pub(crate) fn push<T: 'a>(&mut self, ctx: &Context<'a, T>) {
+		let reset: Rc<dyn Reset + 'a> = Rc::<Inner<'a, T>>::clone(&ctx.inner);
+		self.0.push(reset);
+	}
+
+	/// # Reset.
+	///
+	/// Drop the values of all registered contexts.
+	pub(crate) fn reset(&mut self) {
+		for ctx in self.0.drain(..) { ctx.reset(); }
+	}
+}
+
+
+
+/// # Shared State.
+struct Inner<'a, T> {
+	/// # Initializer.
+	///
+	/// This is taken the first time the value is needed.
+	init: Cell<Option<Init<'a, T>>>,
+
+	/// # Value.
+	value: RefCell<Option<T>>,
+}
+
+impl<T> Inner<'_, T> {
+	/// # Initialize.
+	fn init(&self) {
+		if self.value.borrow().is_some() { return; }
+		if let Some(init) = self.init.take() {
+			let value = init();
+			self.value.replace(Some(value));
+		}
+	}
+}
+
+
+
+/// # Reset.
+///
+/// This lets the suite drop contexts of any type once it has finished.
+trait Reset {
+	/// # Reset.
+	///
+	/// Drop the value — and initializer — if still present.
+	fn reset(&self);
+}
+
+impl<T> Reset for Inner<'_, T> {
+	fn reset(&self) {
+		drop(self.init.take());
+		drop(self.value.take());
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_context() {
+		let calls = Cell::new(0_u8);
+		let ctx = Context::new(|| {
+			calls.set(calls.get() + 1);
+			vec![1_u8, 2, 3]
+		});
+		let ctx2 = ctx.clone();
+
+		// Nothing until needed.
+		assert_eq!(calls.get(), 0);
+
+		// Built once, shared by all.
+		assert_eq!(ctx.with(Vec::len), 3);
+		assert_eq!(ctx2.with(|v| v[2]), 3);
+		ctx.init();
+		assert_eq!(calls.get(), 1);
+
+		// Reset drops it for good.
+		let mut set = Contexts::default();
+		set.push(&ctx);
+		set.reset();
+		assert!(ctx2.inner.value.borrow().is_none(), "Value should have been dropped.");
+		let res = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| ctx2.with(Vec::len)));
+		assert!(res.is_err(), "Dropped contexts should be unavailable.");
+		assert_eq!(calls.get(), 1);
+	}
+}
@@ -0,0 +1,198 @@
+/*!
+# Brunch: Criterion Compatibility
+*/
+
+use crate::Stats;
+use std::{
+	fmt::Write,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+
+
+/// # Maximum Directory Name Length.
+///
+/// This matches Criterion's own limit.
+const MAX_DIR_LEN: usize = 64;
+
+/// # Nanoseconds Per Second.
+const NANOS: f64 = 1_000_000_000.0;
+
+
+
+/// # Enabled?
+///
+/// Criterion-compatible output is only written when
+/// `BRUNCH_CRITERION_COMPAT=1`.
+pub(crate) fn enabled() -> bool {
+	std::env::var("BRUNCH_CRITERION_COMPAT").is_ok_and(|s| s.trim() == "1")
+}
+
+/// # Output Directory.
+///
+/// Return the `criterion` directory under Cargo's target directory.
+pub(crate) fn root() -> PathBuf {
+	std::env::var_os("CARGO_TARGET_DIR")
+		.filter(|p| ! p.is_empty())
+		.map_or_else(|| PathBuf::from("target"), PathBuf::from)
+		.join("criterion")
+}
+
+/// # Write Bench.
+///
+/// Write a minimal Criterion-style `benchmark.json` and `estimates.json` for
+/// the benchmark to `<root>/<name>/new/`, which is where tools like
+/// `critcmp` expect to find them.
+///
+/// ## Errors
+///
+/// Any I/O errors are passed through.
+pub(crate) fn write(root: &Path, name: &str, stats: Stats) -> std::io::Result<()> {
+	let dir = root.join(dir_name(name)).join("new");
+	std::fs::create_dir_all(&dir)?;
+	std::fs::write(dir.join("benchmark.json"), benchmark_json(name))?;
+	std::fs::write(dir.join("estimates.json"), estimates_json(stats))
+}
+
+/// # Benchmark JSON.
+///
+/// Criterion benchmarks are identified by group, function, and value; brunch
+/// benchmarks just have a name, so that serves as the group.
+fn benchmark_json(name: &str) -> String {
+	let dir = json_str(&dir_name(name));
+	let name = json_str(name);
+	format!(
+		r#"{{"group_id":{name},"function_id":null,"value_str":null,"throughput":null,"full_id":{name},"directory_name":{dir},"title":{name}}}"#
+	)
+}
+
+/// # Estimates JSON.
+///
+/// The mean and standard deviation are filled in from the stats (in
+/// nanoseconds, like Criterion). Brunch doesn't calculate a median, so the
+/// mean stands in for it; the median absolute deviation is zeroed, and the
+/// slope is omitted.
+fn estimates_json(stats: Stats) -> String {
+	let (lo, hi) = stats.interval();
+	let (lo, hi) = (lo * NANOS, hi * NANOS);
+	let mean = stats.mean().as_secs_f64() * NANOS;
+	let dev = stats.deviation().as_secs_f64() * NANOS;
+	let se = dev / f64::from(stats.valid()).sqrt();
+
+	let mut out = String::with_capacity(512);
+	out.push('{');
+	for (idx, (key, point, lo, hi, se)) in [
+		("mean", mean, lo, hi, se),
+		("median", mean, lo, hi, se),
+		("median_abs_dev", 0.0, 0.0, 0.0, 0.0),
+		("std_dev", dev, dev, dev, 0.0),
+	].into_iter().enumerate() {
+		if idx != 0 { out.push(','); }
+		let _res = write!(
+			out,
+			r#""{key}":{{"confidence_interval":{{"confidence_level":0.95,"lower_bound":{},"upper_bound":{}}},"point_estimate":{},"standard_error":{}}}"#,
+			json_num(lo),
+			json_num(hi),
+			json_num(point),
+			json_num(se),
+		);
+	}
+	out.push_str(r#","slope":null}"#);
+	out
+}
+
+/// # Directory Name.
+///
+/// Make the name safe for use as a directory, the same way Criterion does:
+/// reserved characters are replaced with underscores, and the result is
+/// capped at 64 characters.
+fn dir_name(name: &str) -> String {
+	name.chars()
+		.map(|c| if matches!(c, '?' | '"' | '/' | '\\' | '*' | '<' | '>' | ':' | '|' | '^') { '_' } else { c })
+		.take(MAX_DIR_LEN)
+		.collect()
+}
+
+/// # JSON Number.
+///
+/// JSON has no room for NaN or infinity, so those become zero.
+pub(crate) fn json_num(n: f64) -> String {
+	if n.is_finite() { format!("{n:?}") }
+	else { "0.0".to_owned() }
+}
+
+/// # JSON String.
+///
+/// Quote and escape a string.
+pub(crate) fn json_str(src: &str) -> String {
+	let mut out = String::with_capacity(src.len() + 2);
+	out.push('"');
+	for c in src.chars() {
+		match c {
+			'"' => out.push_str("\\\""),
+			'\\' => out.push_str("\\\\"),
+			'\n' => out.push_str("\\n"),
+			'\r' => out.push_str("\\r"),
+			'\t' => out.push_str("\\t"),
+			c if c.is_control() => { let _res = write!(out, "\\u{:04x}", u32::from(c)); },
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	#[test]
+	fn t_write() {
+		let stats = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(1000 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+
+		let root = std::env::temp_dir().join("__brunch-test-criterion");
+		let _res = std::fs::remove_dir_all(&root);
+		write(&root, "foo::bar(\"baz\")", stats).expect("Write failed.");
+
+		// The name should be made safe for the file system.
+		let dir = root.join("foo__bar(_baz_)/new");
+		let benchmark = std::fs::read_to_string(dir.join("benchmark.json"))
+			.expect("Missing benchmark.json.");
+		let estimates = std::fs::read_to_string(dir.join("estimates.json"))
+			.expect("Missing estimates.json.");
+		let _res = std::fs::remove_dir_all(&root);
+
+		// The name should be escaped, but otherwise unchanged.
+		assert!(benchmark.contains(r#""full_id":"foo::bar(\"baz\")""#), "{benchmark}");
+		assert!(benchmark.contains(r#""directory_name":"foo__bar(_baz_)""#), "{benchmark}");
+
+		// The estimates should be in nanoseconds.
+		let mean = stats.mean().as_secs_f64() * NANOS;
+		assert!(estimates.starts_with(r#"{"mean":{"confidence_interval":{"confidence_level":0.95,"#));
+		assert!(estimates.contains(&format!(r#""point_estimate":{mean:?}"#)), "{estimates}");
+		for key in ["median", "median_abs_dev", "std_dev"] {
+			assert!(estimates.contains(&format!(r#""{key}":{{"#)), "Missing {key}.");
+		}
+		assert!(estimates.ends_with(r#","slope":null}"#));
+
+		// The braces should balance.
+		for json in [&benchmark, &estimates] {
+			assert_eq!(json.matches('{').count(), json.matches('}').count());
+		}
+	}
+
+	#[test]
+	fn t_json_str() {
+		assert_eq!(json_str("Hello"), r#""Hello""#);
+		assert_eq!(json_str("a\"b\\c\nd\u{1}"), r#""a\"b\\c\nd\u0001""#);
+		assert_eq!(dir_name(&"x".repeat(100)).len(), MAX_DIR_LEN);
+	}
+}
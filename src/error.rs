@@ -2,12 +2,16 @@
 # Brunch
 */
 
+use crate::util;
 use dactyl::NiceU32;
-use std::fmt;
+use std::{
+	fmt,
+	time::Duration,
+};
 
 
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 /// # Error.
 ///
 /// This enum serves as the custom error type for `Brunch`.
@@ -15,15 +19,56 @@ pub enum BrunchError {
 	/// # Duplicate name.
 	DupeName,
 
+	/// # A fallible callback returned `Err` on some of the samples.
+	Fallible {
+		/// # Samples That Failed.
+		failed: u32,
+
+		/// # Samples Taken.
+		total: u32,
+	},
+
+	/// # An isolated benchmark process died unexpectedly (with this exit
+	/// code, if any).
+	Crashed(Option<i32>),
+
 	/// # No benches were specified.
 	NoBench,
 
+	/// # A history file could not be loaded.
+	NoHistory,
+
+	/// # The run was interrupted (Ctrl+C).
+	Interrupted,
+
 	/// # A bench was missing a [`Bench::run`](crate::Bench::run)-type call.
 	NoRun,
 
 	/// # General math failure. (Floats aren't fun.)
 	Overflow,
 
+	/// # The benchmark panicked (with this message).
+	Panicked(String),
+
+	/// # Not enough samples were collected because the seed generation took
+	/// too long.
+	SlowSeed(u32),
+
+	/// # The timeout was reached before enough samples were collected.
+	TimedOut {
+		/// # Samples Collected.
+		collected: u32,
+
+		/// # Samples Needed.
+		needed: u32,
+
+		/// # Mean Time Per Iteration.
+		mean_iter: Duration,
+
+		/// # Timeout.
+		timeout: Duration,
+	},
+
 	/// # The benchmark completed too quickly to analyze.
 	TooFast,
 
@@ -32,23 +77,138 @@ pub enum BrunchError {
 
 	/// # The samples were too chaotic to analyze.
 	TooWild,
+
+	/// # The benchmark output failed verification.
+	Unverified,
 }
 
 impl std::error::Error for BrunchError {}
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for BrunchError {
+	fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+		use serde::ser::SerializeStruct;
+
+		let mut out = serializer.serialize_struct("BrunchError", 2)?;
+		out.serialize_field("kind", self.kind())?;
+		out.serialize_field("message", &util::strip_ansi(&self.to_string()))?;
+		out.end()
+	}
+}
+
 impl fmt::Display for BrunchError {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
+			Self::Crashed(Some(code)) => write!(f, "The benchmark process exited unexpectedly (code {code})."),
+			Self::Crashed(None) => f.write_str("The benchmark process died unexpectedly."),
 			Self::DupeName => f.write_str("Benchmark names must be unique."),
+			Self::Fallible { failed, total } => write!(
+				f, "The callback returned Err on {}/{} samples.",
+				NiceU32::from(*failed),
+				NiceU32::from(*total),
+			),
+			Self::Interrupted => f.write_str("Interrupted."),
 			Self::NoBench => f.write_str("At least one benchmark is required."),
+			Self::NoHistory => f.write_str("Unable to load the history file."),
 			Self::NoRun => f.write_str("Missing \x1b[1;96mBench::run\x1b[0m."),
 			Self::Overflow => f.write_str("Unable to crunch the numbers."),
+			Self::Panicked(msg) =>
+				if msg.is_empty() { f.write_str("Panicked!") }
+				else { write!(f, "Panicked: {msg}") },
+			Self::SlowSeed(n) => write!(
+				f, "Insufficient samples collected ({}); the seed generation is the bottleneck.",
+				NiceU32::from(*n),
+			),
+			Self::TimedOut { collected, needed, mean_iter, timeout } => {
+				let want = suggest_timeout(*collected, *needed, *mean_iter, *timeout);
+				write!(
+					f, "Collected {}/{} samples in {} (~{}/iter); raise the timeout to at least {}, e.g. Bench::with_timeout(Duration::from_secs({})).",
+					NiceU32::from(*collected),
+					NiceU32::from(*needed),
+					util::nice_time(timeout.as_secs_f64()).trim_end(),
+					util::nice_time(mean_iter.as_secs_f64()).trim_end(),
+					util::nice_time(want.as_secs_f64()).trim_end(),
+					want.as_secs(),
+				)
+			},
 			Self::TooFast => f.write_str("Too fast to benchmark!"),
 			Self::TooSmall(n) => write!(
 				f, "Insufficient samples collected ({}); try increasing the timeout.",
 				NiceU32::from(*n),
 			),
 			Self::TooWild => f.write_str("Samples too wild to analyze."),
+			Self::Unverified => f.write_str("Verification failed."),
 		}
 	}
 }
+
+impl BrunchError {
+	/// # Kind.
+	///
+	/// Return the variant name, e.g. `TooSmall`.
+	pub(crate) const fn kind(&self) -> &'static str {
+		match self {
+			Self::Crashed(_) => "Crashed",
+			Self::DupeName => "DupeName",
+			Self::Fallible { .. } => "Fallible",
+			Self::Interrupted => "Interrupted",
+			Self::NoBench => "NoBench",
+			Self::NoHistory => "NoHistory",
+			Self::NoRun => "NoRun",
+			Self::Overflow => "Overflow",
+			Self::Panicked(_) => "Panicked",
+			Self::SlowSeed(_) => "SlowSeed",
+			Self::TimedOut { .. } => "TimedOut",
+			Self::TooFast => "TooFast",
+			Self::TooSmall(_) => "TooSmall",
+			Self::TooWild => "TooWild",
+			Self::Unverified => "Unverified",
+		}
+	}
+}
+
+
+
+/// # Suggest Timeout.
+///
+/// Work out how long it would take to collect the needed samples at the
+/// observed pace, plus a little headroom, rounded up to the second. If the
+/// pace is unknown, the timeout is scaled up to cover the shortfall instead.
+fn suggest_timeout(collected: u32, needed: u32, mean_iter: Duration, timeout: Duration)
+-> Duration {
+	let want =
+		if mean_iter.is_zero() {
+			timeout.as_secs_f64() * f64::from(needed) / f64::from(collected.max(1))
+		}
+		else { mean_iter.as_secs_f64() * f64::from(needed) * 1.1 };
+	Duration::try_from_secs_f64(want.ceil()).unwrap_or(Duration::MAX)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_suggest_timeout() {
+		// A three-minute iteration needs hours, not the naive scaled-up
+		// timeout.
+		let err = BrunchError::TimedOut {
+			collected: 1,
+			needed: 100,
+			mean_iter: Duration::from_millis(187_430),
+			timeout: Duration::from_secs(10),
+		};
+		let msg = err.to_string();
+		assert!(msg.contains("in 10.00 s (~3 min 07 s/iter)"), "Wrong pace: {msg}");
+		assert!(msg.contains("at least 5 h 44 min"), "Wrong suggestion: {msg}");
+		assert!(msg.contains("Duration::from_secs(20618)"), "Wrong snippet: {msg}");
+
+		// Without a pace, the timeout is scaled instead.
+		assert_eq!(
+			suggest_timeout(25, 100, Duration::ZERO, Duration::from_secs(10)),
+			Duration::from_secs(40),
+		);
+	}
+}
@@ -0,0 +1,115 @@
+/*!
+# Brunch: GitHub Actions
+*/
+
+use std::{
+	fs::OpenOptions,
+	io::Write,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Annotation Level.
+pub(crate) enum Level {
+	/// # Warning.
+	Warning,
+
+	/// # Error.
+	Error,
+}
+
+impl Level {
+	/// # As Str.
+	const fn as_str(self) -> &'static str {
+		match self {
+			Self::Warning => "warning",
+			Self::Error => "error",
+		}
+	}
+}
+
+
+
+/// # Enabled?
+///
+/// GitHub mode is enabled automatically when running under GitHub Actions,
+/// or can be forced with `BRUNCH_GITHUB=1`.
+pub(crate) fn enabled() -> bool {
+	std::env::var("BRUNCH_GITHUB").is_ok_and(|s| s.trim() == "1") ||
+	std::env::var("GITHUB_ACTIONS").is_ok_and(|s| s.trim() == "true")
+}
+
+/// # Annotation.
+///
+/// Return a workflow command that will surface the message as an inline
+/// annotation.
+pub(crate) fn annotation(level: Level, title: &str, msg: &str) -> String {
+	format!(
+		"::{} title={}::{}",
+		level.as_str(),
+		escape_property(title),
+		escape_data(msg),
+	)
+}
+
+/// # Report.
+///
+/// Print the annotations to `STDOUT` — where the runner looks for them — and
+/// append the Markdown table to the step summary, if there is one.
+pub(crate) fn report(annotations: &[String], markdown: &str) {
+	if ! annotations.is_empty() {
+		let mut out = std::io::stdout().lock();
+		for a in annotations { let _res = writeln!(out, "{a}"); }
+		let _res = out.flush();
+	}
+
+	if let Some(path) = std::env::var_os("GITHUB_STEP_SUMMARY").filter(|p| ! p.is_empty()) {
+		let res = OpenOptions::new()
+			.create(true)
+			.append(true)
+			.open(path)
+			.and_then(|mut f| writeln!(f, "{markdown}"));
+		if let Err(e) = res {
+			eprintln!("\x1b[1;93mWarning:\x1b[0m Unable to write the GitHub step summary ({e}).");
+		}
+	}
+}
+
+/// # Escape Data.
+///
+/// Workflow command messages need their percent signs and line breaks
+/// encoded.
+fn escape_data(src: &str) -> String {
+	src.replace('%', "%25")
+		.replace('\r', "%0D")
+		.replace('\n', "%0A")
+}
+
+/// # Escape Property.
+///
+/// Property values need the same treatment as data, plus colons and commas.
+fn escape_property(src: &str) -> String {
+	escape_data(src)
+		.replace(':', "%3A")
+		.replace(',', "%2C")
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_annotation() {
+		assert_eq!(
+			annotation(Level::Warning, "Brunch", "foo::bar(): 1.00 ms \u{2192} 1.50 ms (+50.00%)"),
+			"::warning title=Brunch::foo::bar(): 1.00 ms \u{2192} 1.50 ms (+50.00%25)",
+		);
+		assert_eq!(
+			annotation(Level::Error, "a:b, c", "Line one.\nLine two."),
+			"::error title=a%3Ab%2C c::Line one.%0ALine two.",
+		);
+	}
+}
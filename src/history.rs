@@ -0,0 +1,217 @@
+/*!
+# Brunch: History
+
+This module provides read-only access to `Brunch` history files for external
+tooling — dashboards, CI scripts, etc. — without having to reverse-engineer
+the binary format. (Writing is left to [`Benches`](crate::Benches).)
+
+## Examples
+
+```no_run
+if let Some(path) = brunch::history::default_path() {
+    for e in brunch::history::read(path).unwrap() {
+        println!("{}: {:?} ± {:?}", e.name(), e.mean(), e.deviation());
+    }
+}
+```
+*/
+
+use crate::{
+	BrunchError,
+	History,
+};
+use std::{
+	path::{
+		Path,
+		PathBuf,
+	},
+	time::Duration,
+};
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// # History Entry.
+///
+/// This holds the baseline for a single benchmark — its stats pooled across
+/// all of the retained runs — i.e. what the next run will be compared
+/// against, as returned by [`read`].
+pub struct HistoryEntry {
+	/// # Benchmark Name.
+	name: String,
+
+	/// # Mean.
+	mean: Duration,
+
+	/// # Standard Deviation.
+	deviation: Duration,
+
+	/// # Valid Samples.
+	valid: u32,
+
+	/// # Total Samples.
+	total: u32,
+}
+
+impl HistoryEntry {
+	#[must_use]
+	/// # Benchmark Name.
+	pub fn name(&self) -> &str { &self.name }
+
+	#[must_use]
+	/// # Mean.
+	pub const fn mean(&self) -> Duration { self.mean }
+
+	#[must_use]
+	/// # Standard Deviation.
+	pub const fn deviation(&self) -> Duration { self.deviation }
+
+	#[must_use]
+	/// # Valid Samples.
+	///
+	/// Return the number of samples that survived outlier pruning.
+	pub const fn valid(&self) -> u32 { self.valid }
+
+	#[must_use]
+	/// # Total Samples.
+	pub const fn total(&self) -> u32 { self.total }
+}
+
+
+
+/// # Read History.
+///
+/// Load the history file at `path`, returning the baseline of each
+/// benchmark, sorted by name.
+///
+/// ## Examples
+///
+/// ```no_run
+/// let entries = brunch::history::read("bench.hist").unwrap();
+/// for e in &entries {
+///     println!("{}: {:?}", e.name(), e.mean());
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// This will return an error if the file is missing or cannot be parsed.
+pub fn read<P: AsRef<Path>>(path: P) -> Result<Vec<HistoryEntry>, BrunchError> {
+	read_inner(path.as_ref(), None)
+}
+
+/// # Read History (Named Baseline).
+///
+/// This works just like [`read`], except the entries are pulled from the
+/// named baseline — see [`Benches::save_baseline`](crate::Benches::save_baseline)
+/// — rather than the default one.
+///
+/// ## Examples
+///
+/// ```no_run
+/// let entries = brunch::history::read_baseline("bench.hist", "main").unwrap();
+/// for e in &entries {
+///     println!("{}: {:?}", e.name(), e.mean());
+/// }
+/// ```
+///
+/// ## Errors
+///
+/// This will return an error if the file is missing or cannot be parsed.
+pub fn read_baseline<P, S>(path: P, name: S) -> Result<Vec<HistoryEntry>, BrunchError>
+where P: AsRef<Path>, S: AsRef<str> {
+	read_inner(path.as_ref(), Some(name.as_ref()))
+}
+
+#[must_use]
+/// # Default Path.
+///
+/// Return the history file path a [`Benches`](crate::Benches) run would use
+/// by default — i.e. the one specified by `BRUNCH_HISTORY`, or the profile-
+/// and binary-specific file in the temporary directory — or `None` if
+/// `NO_BRUNCH_HISTORY=1` is set or the location is unusable.
+///
+/// Note: the default file name depends on the current executable, so when
+/// called from outside the bench binary, you'll probably want to set
+/// `BRUNCH_HISTORY` or pass an explicit path to [`read`] instead.
+pub fn default_path() -> Option<PathBuf> { History::default_path() }
+
+/// # Read (Inner).
+///
+/// This is the business end of [`read`] and [`read_baseline`].
+fn read_inner(path: &Path, baseline: Option<&str>) -> Result<Vec<HistoryEntry>, BrunchError> {
+	let mut history = History::open(path).ok_or(BrunchError::NoHistory)?;
+	if let Some(name) = baseline { history.select(name); }
+	Ok(
+		history.keys()
+			.filter_map(|k| {
+				let s = history.get(k)?;
+				Some(HistoryEntry {
+					name: k.to_owned(),
+					mean: s.mean(),
+					deviation: s.deviation(),
+					valid: s.valid(),
+					total: s.total(),
+				})
+			})
+			.collect()
+	)
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		Metric,
+		Stats,
+	};
+
+	#[test]
+	fn t_read() {
+		let path = std::env::temp_dir().join("__brunch-test-read.last");
+		let _res = std::fs::remove_file(&path);
+
+		// Nothing to read yet.
+		assert!(matches!(read(&path), Err(BrunchError::NoHistory)), "Expected an error.");
+
+		// Save a couple entries.
+		let a = Stats::try_from(vec![Duration::from_micros(30); 200]).expect("Invalid stats.");
+		let b = Stats::try_from(vec![Duration::from_micros(10); 150]).expect("Invalid stats.");
+		let mut history = History::new(Some(&path));
+		history.insert("B", b, Metric::Mean, None, None, None);
+		history.insert("A", a, Metric::Mean, None, None, None);
+		history.save();
+
+		// They should come back sorted by name.
+		let entries = read(&path).expect("Unable to read history.");
+		assert_eq!(entries.len(), 2);
+		assert_eq!(entries[0].name(), "A");
+		assert!(entries[0].mean().abs_diff(Duration::from_micros(30)) <= Duration::from_nanos(1), "Wrong mean.");
+		assert!(entries[0].deviation() <= Duration::from_nanos(1), "Wrong deviation.");
+		assert_eq!(entries[0].valid(), 200);
+		assert_eq!(entries[0].total(), 200);
+		assert_eq!(entries[1].name(), "B");
+		assert!(entries[1].mean().abs_diff(Duration::from_micros(10)) <= Duration::from_nanos(1), "Wrong mean.");
+		assert_eq!(entries[1].total(), 150);
+
+		// Named baselines are kept separately.
+		let mut history = History::new(Some(&path));
+		history.save_to("main");
+		history.insert("C", a, Metric::Mean, None, None, None);
+		history.save();
+		assert_eq!(read(&path).expect("Unable to read history.").len(), 2);
+		let entries = read_baseline(&path, "main").expect("Unable to read history.");
+		assert_eq!(entries.len(), 1);
+		assert_eq!(entries[0].name(), "C");
+		assert!(read_baseline(&path, "other").expect("Unable to read history.").is_empty());
+
+		// Garbage is no good either.
+		std::fs::write(&path, b"Hello World").expect("Unable to write file.");
+		assert!(matches!(read(&path), Err(BrunchError::NoHistory)), "Expected an error.");
+
+		let _res = std::fs::remove_file(&path);
+	}
+}
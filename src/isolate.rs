@@ -0,0 +1,627 @@
+/*!
+# Brunch: Isolation
+*/
+
+use crate::{
+	alloc::Allocs,
+	BrunchError,
+	Distribution,
+	perf::Counters,
+	Progress,
+	rss::MaxRss,
+	signal,
+	Stats,
+	stats::history::{
+		Deserialize,
+		serialize_allocs,
+		serialize_perf,
+		serialize_stats,
+		serialize_str,
+	},
+};
+use dactyl::traits::SaturatingFrom;
+use std::{
+	num::NonZeroU32,
+	path::PathBuf,
+	process::{
+		self,
+		Command,
+		Stdio,
+	},
+	sync::atomic::{
+		AtomicU32,
+		Ordering::SeqCst,
+	},
+	time::{
+		Duration,
+		Instant,
+	},
+};
+
+
+
+/// # Child Env: Bench Name.
+const ENV_NAME: &str = "__BRUNCH_CHILD";
+
+/// # Child Env: Output Path.
+const ENV_OUT: &str = "__BRUNCH_CHILD_OUT";
+
+/// # Child Env: Sample Limit.
+const ENV_SAMPLES: &str = "__BRUNCH_CHILD_SAMPLES";
+
+/// # Child Env: Time Limit (Nanoseconds).
+const ENV_TIMEOUT: &str = "__BRUNCH_CHILD_TIMEOUT";
+
+/// # Child Env: Measure CPU Time?
+const ENV_CPU: &str = "__BRUNCH_CHILD_CPU";
+
+/// # Child Env: Measure Peak Memory?
+const ENV_MEM: &str = "__BRUNCH_CHILD_MEM";
+
+/// # Outcome Magic Header.
+const MAGIC: &[u8] = b"BRUNCHC0";
+
+/// # Outcome.
+///
+/// The result, CPU time stats, allocations, performance counters, peak
+/// memory, wall time, and `Err` sample count (and total) of a child run.
+pub(crate) type Outcome = (
+	Result<Stats, BrunchError>,
+	Option<Stats>,
+	Option<Allocs>,
+	Option<Counters>,
+	Option<MaxRss>,
+	Duration,
+	(u32, u32),
+);
+
+/// # Poll Interval.
+///
+/// How often the parent checks in on the child.
+pub(crate) const POLL: Duration = Duration::from_millis(25);
+
+
+
+#[derive(Debug)]
+/// # Child Process.
+///
+/// When running in isolation, the parent re-executes the current binary once
+/// for each benchmark, passing the details along via (internal)
+/// environmental variables. This holds those details on the child's end.
+pub(crate) struct Child {
+	/// # Bench Name.
+	name: String,
+
+	/// # Output Path.
+	out: PathBuf,
+
+	/// # Sample Limit.
+	samples: NonZeroU32,
+
+	/// # Time Limit.
+	timeout: Duration,
+
+	/// # Measure CPU Time?
+	measure_cpu: bool,
+
+	/// # Measure Peak Memory?
+	measure_memory: bool,
+}
+
+impl Child {
+	/// # From Environment.
+	///
+	/// Return the child details if this process is one.
+	pub(crate) fn from_env() -> Option<Self> {
+		let name = std::env::var(ENV_NAME).ok()?;
+		let out = PathBuf::from(std::env::var_os(ENV_OUT)?);
+		let samples = std::env::var(ENV_SAMPLES).ok()?.parse::<NonZeroU32>().ok()?;
+		let timeout = std::env::var(ENV_TIMEOUT).ok()?.parse::<u64>().ok()?;
+		Some(Self {
+			name,
+			out,
+			samples,
+			timeout: Duration::from_nanos(timeout),
+			measure_cpu: std::env::var(ENV_CPU).is_ok_and(|s| s == "1"),
+			measure_memory: std::env::var(ENV_MEM).is_ok_and(|s| s == "1"),
+		})
+	}
+
+	/// # Bench Name.
+	pub(crate) fn name(&self) -> &str { &self.name }
+
+	/// # Sample Limit.
+	pub(crate) const fn samples(&self) -> NonZeroU32 { self.samples }
+
+	/// # Time Limit.
+	pub(crate) const fn timeout(&self) -> Duration { self.timeout }
+
+	/// # Measure CPU Time?
+	pub(crate) const fn measure_cpu(&self) -> bool { self.measure_cpu }
+
+	/// # Measure Peak Memory?
+	pub(crate) const fn measure_memory(&self) -> bool { self.measure_memory }
+
+	/// # Finish.
+	///
+	/// Write the outcome for the parent to pick up, then exit.
+	#[expect(clippy::too_many_arguments, reason = "It is what it is.")]
+	pub(crate) fn finish(
+		self,
+		res: &Result<Stats, BrunchError>,
+		cpu: Option<Stats>,
+		allocs: Option<Allocs>,
+		perf: Option<Counters>,
+		rss: Option<MaxRss>,
+		elapsed: Duration,
+		errors: (u32, u32),
+	) -> ! {
+		let raw = serialize(&self.name, res, cpu, allocs, perf, rss, elapsed, errors);
+		let code = i32::from(std::fs::write(&self.out, raw).is_err());
+		std::process::exit(code);
+	}
+}
+
+
+
+/// # Spawn.
+///
+/// Re-execute the current binary to run the named benchmark in a fresh
+/// process, returning its outcome, CPU time stats, allocations, performance
+/// counters, and peak memory (if any), wall time, and `Err` sample count.
+///
+/// Because each child is a fresh process, its peak memory reflects that one
+/// benchmark alone.
+///
+/// The parent keeps an eye on the child in the meantime, updating the
+/// progress and killing it if the run is interrupted. If the child dies
+/// without reporting back, the result is a [`BrunchError::Crashed`].
+pub(crate) fn spawn(
+	name: &str,
+	samples: NonZeroU32,
+	timeout: Duration,
+	measure_cpu: bool,
+	measure_memory: bool,
+	progress: &mut Progress,
+) -> Outcome {
+	let mut running = launch(name, samples, timeout, measure_cpu, measure_memory);
+
+	// Wait for it to finish.
+	loop {
+		if let Some(res) = running.poll() { return res; }
+
+		// Take it down with us.
+		if signal::interrupted() { return running.kill(); }
+
+		progress.tick(running.elapsed());
+		std::thread::sleep(POLL);
+	}
+}
+
+/// # Launch.
+///
+/// Same as [`spawn`], except the child is left to run in the background; it
+/// is up to the caller to [`poll`](Running::poll) it. This is how
+/// [parallel](crate::Benches::parallel) runs are carried out.
+///
+/// If the child can't be started, the first poll will say so.
+pub(crate) fn launch(
+	name: &str,
+	samples: NonZeroU32,
+	timeout: Duration,
+	measure_cpu: bool,
+	measure_memory: bool,
+) -> Running {
+	/// # Spawn Counter.
+	static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+	let now = Instant::now();
+	let out = std::env::temp_dir().join(format!(
+		"__brunch-child-{}-{}.tmp",
+		std::process::id(),
+		COUNTER.fetch_add(1, SeqCst),
+	));
+	let _res = std::fs::remove_file(&out);
+
+	let child = std::env::current_exe().and_then(|exe|
+		Command::new(exe)
+			.args(std::env::args_os().skip(1))
+			.env(ENV_NAME, name)
+			.env(ENV_OUT, &out)
+			.env(ENV_SAMPLES, samples.to_string())
+			.env(ENV_TIMEOUT, u64::saturating_from(timeout.as_nanos()).to_string())
+			.env(ENV_CPU, if measure_cpu { "1" } else { "0" })
+			.env(ENV_MEM, if measure_memory { "1" } else { "0" })
+			.env_remove("BRUNCH_COMPARE")
+			.stdin(Stdio::null())
+			.stdout(Stdio::null())
+			.stderr(Stdio::null())
+			.spawn()
+	);
+	Running { name: name.to_owned(), child: child.ok(), out, now }
+}
+
+
+
+#[derive(Debug)]
+/// # Running Child.
+///
+/// This is a handle to a child process launched via [`launch`].
+pub(crate) struct Running {
+	/// # Bench Name.
+	name: String,
+
+	/// # Process.
+	///
+	/// This is `None` if the child couldn't be started.
+	child: Option<process::Child>,
+
+	/// # Output Path.
+	out: PathBuf,
+
+	/// # Start Time.
+	now: Instant,
+}
+
+impl Running {
+	/// # Elapsed Time.
+	pub(crate) fn elapsed(&self) -> Duration { self.now.elapsed() }
+
+	/// # Poll.
+	///
+	/// Return the outcome if the child has finished, or `None` if it's still
+	/// going.
+	pub(crate) fn poll(&mut self) -> Option<Outcome> {
+		let status = match self.child.as_mut().map(process::Child::try_wait) {
+			Some(Ok(Some(status))) => Some(status),
+			Some(Ok(None)) => return None,
+			Some(Err(_)) | None => None,
+		};
+
+		// Collect the results.
+		let res = std::fs::read(&self.out).ok()
+			.and_then(|raw| deserialize(&raw, &self.name))
+			.unwrap_or_else(|| (
+				Err(BrunchError::Crashed(status.and_then(|s| s.code()))),
+				None,
+				None,
+				None,
+				None,
+				self.now.elapsed(),
+				(0, 0),
+			));
+		let _res = std::fs::remove_file(&self.out);
+		Some(res)
+	}
+
+	/// # Kill.
+	///
+	/// Take the child down, e.g. because the run was interrupted.
+	pub(crate) fn kill(mut self) -> Outcome {
+		if let Some(child) = self.child.as_mut() {
+			let _res = child.kill();
+			let _res = child.wait();
+		}
+		let _res = std::fs::remove_file(&self.out);
+		(Err(BrunchError::Interrupted), None, None, None, None, self.now.elapsed(), (0, 0))
+	}
+}
+
+
+
+/// # Serialize Outcome.
+///
+/// The format is simple: the magic header, the bench name, the elapsed time
+/// in nanoseconds, and a `u8` flag — `0` for success, `1` for failure —
+/// followed by either the [`Stats`] — plus the exact sum of the valid
+/// samples and the drift, each as an `f64`, and a `u8` flag — `1` if the
+/// distribution is known, `0` if not — followed by its minimum, median, 95th
+/// percentile, and maximum, also as `f64`s — or the error.
+///
+/// Errors are written as a `u8` code, followed by any additional data.
+///
+/// Next comes another `u8` flag — `1` if there are CPU time [`Stats`], `0`
+/// if not — followed by the stats, if any.
+///
+/// Then come the allocations and performance counters, if any, in the same
+/// format as the history.
+///
+/// Then comes one more `u8` flag — `1` if there is a peak memory
+/// measurement, `0` if not — followed by the peak and growth as `u64`s.
+///
+/// Last come the number of samples for which a fallible callback returned
+/// `Err`, and the total number taken, as `u32`s.
+#[expect(clippy::too_many_arguments, reason = "It is what it is.")]
+fn serialize(
+	name: &str,
+	res: &Result<Stats, BrunchError>,
+	cpu: Option<Stats>,
+	allocs: Option<Allocs>,
+	perf: Option<Counters>,
+	rss: Option<MaxRss>,
+	elapsed: Duration,
+	errors: (u32, u32),
+) -> Vec<u8> {
+	let mut out = Vec::with_capacity(64);
+	out.extend_from_slice(MAGIC);
+	serialize_str(&mut out, name);
+	out.extend_from_slice(&u64::saturating_from(elapsed.as_nanos()).to_be_bytes());
+
+	match res {
+		Ok(s) => {
+			out.push(0);
+			serialize_stats(&mut out, *s);
+			out.extend_from_slice(&s.measured().as_secs_f64().to_be_bytes());
+			out.extend_from_slice(&s.drift().to_be_bytes());
+			if let Some(d) = s.distribution() {
+				out.push(1);
+				for n in [d.min, d.median, d.p95, d.max] {
+					out.extend_from_slice(&n.to_be_bytes());
+				}
+			}
+			else { out.push(0); }
+		},
+		Err(e) => {
+			out.push(1);
+			match e {
+				BrunchError::DupeName => out.push(0),
+				BrunchError::NoBench => out.push(1),
+				BrunchError::NoHistory => out.push(2),
+				BrunchError::Interrupted => out.push(3),
+				BrunchError::NoRun => out.push(4),
+				BrunchError::Overflow => out.push(5),
+				BrunchError::Panicked(msg) => {
+					out.push(6);
+					serialize_str(&mut out, msg);
+				},
+				BrunchError::TooFast => out.push(7),
+				BrunchError::TooSmall(n) => {
+					out.push(8);
+					out.extend_from_slice(&n.to_be_bytes());
+				},
+				BrunchError::TooWild => out.push(9),
+				BrunchError::Crashed(code) => {
+					out.push(10);
+					out.extend_from_slice(&code.unwrap_or(i32::MIN).to_be_bytes());
+				},
+				BrunchError::Unverified => out.push(11),
+				BrunchError::SlowSeed(n) => {
+					out.push(12);
+					out.extend_from_slice(&n.to_be_bytes());
+				},
+				BrunchError::TimedOut { collected, needed, mean_iter, timeout } => {
+					out.push(13);
+					out.extend_from_slice(&collected.to_be_bytes());
+					out.extend_from_slice(&needed.to_be_bytes());
+					out.extend_from_slice(&u64::saturating_from(mean_iter.as_nanos()).to_be_bytes());
+					out.extend_from_slice(&u64::saturating_from(timeout.as_nanos()).to_be_bytes());
+				},
+				BrunchError::Fallible { failed, total } => {
+					out.push(14);
+					out.extend_from_slice(&failed.to_be_bytes());
+					out.extend_from_slice(&total.to_be_bytes());
+				},
+			}
+		},
+	}
+
+	if let Some(cpu) = cpu {
+		out.push(1);
+		serialize_stats(&mut out, cpu);
+	}
+	else { out.push(0); }
+
+	serialize_allocs(&mut out, allocs);
+	serialize_perf(&mut out, perf);
+
+	if let Some(rss) = rss {
+		out.push(1);
+		out.extend_from_slice(&rss.peak().to_be_bytes());
+		out.extend_from_slice(&rss.delta().to_be_bytes());
+	}
+	else { out.push(0); }
+
+	out.extend_from_slice(&errors.0.to_be_bytes());
+	out.extend_from_slice(&errors.1.to_be_bytes());
+
+	out
+}
+
+/// # Deserialize Outcome.
+///
+/// Returns `None` if the data is invalid or belongs to a different bench.
+fn deserialize(raw: &[u8], name: &str) -> Option<Outcome> {
+	let raw = raw.strip_prefix(MAGIC)?;
+	let (lbl, raw) = <&str>::deserialize(raw)?;
+	if lbl != name.trim() { return None; }
+	let (elapsed, raw) = u64::deserialize(raw)?;
+	let elapsed = Duration::from_nanos(elapsed);
+
+	let (flag, raw) = u8::deserialize(raw)?;
+	let (res, raw) =
+		if flag == 0 {
+			let (s, raw) = Stats::deserialize(raw)?;
+			let (sum, raw) = f64::deserialize(raw)?;
+			let (drift, raw) = f64::deserialize(raw)?;
+			let s = s.with_sum(sum).with_drift(drift);
+			let (flag, raw) = u8::deserialize(raw)?;
+			if flag == 1 {
+				let (min, raw) = f64::deserialize(raw)?;
+				let (median, raw) = f64::deserialize(raw)?;
+				let (p95, raw) = f64::deserialize(raw)?;
+				let (max, raw) = f64::deserialize(raw)?;
+				(Ok(s.with_distribution(Distribution { min, median, p95, max })), raw)
+			}
+			else { (Ok(s), raw) }
+		}
+		else {
+			let (code, raw) = u8::deserialize(raw)?;
+			let (e, raw) = match code {
+				0 => (BrunchError::DupeName, raw),
+				1 => (BrunchError::NoBench, raw),
+				2 => (BrunchError::NoHistory, raw),
+				3 => (BrunchError::Interrupted, raw),
+				4 => (BrunchError::NoRun, raw),
+				5 => (BrunchError::Overflow, raw),
+				6 => {
+					let (msg, raw) = <&str>::deserialize(raw)?;
+					(BrunchError::Panicked(msg.to_owned()), raw)
+				},
+				7 => (BrunchError::TooFast, raw),
+				8 => {
+					let (n, raw) = u32::deserialize(raw)?;
+					(BrunchError::TooSmall(n), raw)
+				},
+				9 => (BrunchError::TooWild, raw),
+				10 => {
+					let (n, raw) = i32::deserialize(raw)?;
+					(BrunchError::Crashed(if n == i32::MIN { None } else { Some(n) }), raw)
+				},
+				11 => (BrunchError::Unverified, raw),
+				12 => {
+					let (n, raw) = u32::deserialize(raw)?;
+					(BrunchError::SlowSeed(n), raw)
+				},
+				13 => {
+					let (collected, raw) = u32::deserialize(raw)?;
+					let (needed, raw) = u32::deserialize(raw)?;
+					let (mean_iter, raw) = u64::deserialize(raw)?;
+					let (timeout, raw) = u64::deserialize(raw)?;
+					(
+						BrunchError::TimedOut {
+							collected,
+							needed,
+							mean_iter: Duration::from_nanos(mean_iter),
+							timeout: Duration::from_nanos(timeout),
+						},
+						raw,
+					)
+				},
+				14 => {
+					let (failed, raw) = u32::deserialize(raw)?;
+					let (total, raw) = u32::deserialize(raw)?;
+					(BrunchError::Fallible { failed, total }, raw)
+				},
+				_ => return None,
+			};
+			(Err(e), raw)
+		};
+
+	let (flag, raw) = u8::deserialize(raw)?;
+	let (cpu, raw) =
+		if flag == 0 { (None, raw) }
+		else {
+			let (s, raw) = Stats::deserialize(raw)?;
+			(Some(s), raw)
+		};
+
+	let (allocs, raw) = Option::<Allocs>::deserialize(raw)?;
+	let (perf, raw) = Option::<Counters>::deserialize(raw)?;
+
+	let (flag, raw) = u8::deserialize(raw)?;
+	let (rss, raw) =
+		if flag == 0 { (None, raw) }
+		else {
+			let (peak, raw) = u64::deserialize(raw)?;
+			let (delta, raw) = u64::deserialize(raw)?;
+			(Some(MaxRss::new(peak.saturating_sub(delta), peak)), raw)
+		};
+
+	let (failed, raw) = u32::deserialize(raw)?;
+	let (total, raw) = u32::deserialize(raw)?;
+
+	if raw.is_empty() { Some((res, cpu, allocs, perf, rss, elapsed, (failed, total))) }
+	else { None }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_outcome() {
+		let stats = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(1000 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let elapsed = Duration::from_millis(1234);
+
+		// Success.
+		let raw = serialize("foo::bar()", &Ok(stats), None, None, None, None, elapsed, (0, 0));
+		let (res, cpu, allocs, perf, rss, e, errors) = deserialize(&raw, "foo::bar()").expect("Deserialization failed.");
+		assert_eq!(e, elapsed);
+		assert_eq!(errors, (0, 0));
+		assert!(cpu.is_none(), "Unexpected CPU time.");
+		assert!(allocs.is_none(), "Unexpected allocations.");
+		assert!(perf.is_none(), "Unexpected performance counters.");
+		assert!(rss.is_none(), "Unexpected max RSS.");
+		let res = res.expect("Expected stats.");
+		assert_eq!(res.samples(), stats.samples());
+		assert_eq!(res.mean(), stats.mean());
+		assert_eq!(res.measured(), stats.measured());
+		assert_eq!(res.drift().to_bits(), stats.drift().to_bits(), "Wrong drift.");
+		assert_eq!(res.median(), stats.median(), "Wrong median.");
+		assert_eq!(res.p95(), stats.p95(), "Wrong p95.");
+		assert_eq!((res.min(), res.max()), (stats.min(), stats.max()), "Wrong range.");
+
+		// The name has to match.
+		assert!(deserialize(&raw, "foo::baz()").is_none(), "Name mismatch.");
+
+		// Failures.
+		for err in [
+			BrunchError::Interrupted,
+			BrunchError::NoRun,
+			BrunchError::Panicked("Oh no!".to_owned()),
+			BrunchError::TooSmall(55),
+			BrunchError::Crashed(None),
+			BrunchError::Crashed(Some(-3)),
+			BrunchError::Unverified,
+			BrunchError::SlowSeed(3),
+			BrunchError::TimedOut {
+				collected: 37,
+				needed: 100,
+				mean_iter: Duration::from_millis(270),
+				timeout: Duration::from_secs(10),
+			},
+			BrunchError::Fallible { failed: 312, total: 2500 },
+		] {
+			let raw = serialize("foo::bar()", &Err(err.clone()), None, None, None, None, elapsed, (0, 0));
+			let (res, _, _, _, _, _, _) = deserialize(&raw, "foo::bar()").expect("Deserialization failed.");
+			assert_eq!(res.expect_err("Expected error.").to_string(), err.to_string());
+		}
+
+		// With CPU time.
+		let raw = serialize("foo::bar()", &Ok(stats), Some(stats), None, None, None, elapsed, (0, 0));
+		let (_, cpu, _, _, _, _, _) = deserialize(&raw, "foo::bar()").expect("Deserialization failed.");
+		assert_eq!(cpu.map(Stats::mean), Some(stats.mean()));
+
+		// With allocations.
+		let raw = serialize("foo::bar()", &Ok(stats), None, Some(Allocs::new(2.0, 48.0)), None, None, elapsed, (0, 0));
+		let (_, _, allocs, _, _, _, _) = deserialize(&raw, "foo::bar()").expect("Deserialization failed.");
+		assert_eq!(allocs, Some(Allocs::new(2.0, 48.0)));
+
+		// With performance counters.
+		let raw = serialize("foo::bar()", &Ok(stats), None, None, Some(Counters::new(1200.0, 400.0)), None, elapsed, (0, 0));
+		let (_, _, _, perf, _, _, _) = deserialize(&raw, "foo::bar()").expect("Deserialization failed.");
+		assert_eq!(perf, Some(Counters::new(1200.0, 400.0)));
+
+		// With peak memory.
+		let raw = serialize("foo::bar()", &Ok(stats), None, None, None, Some(MaxRss::new(1000, 5000)), elapsed, (0, 0));
+		let (_, _, _, _, rss, _, _) = deserialize(&raw, "foo::bar()").expect("Deserialization failed.");
+		assert_eq!(rss, Some(MaxRss::new(1000, 5000)));
+
+		// With Err samples.
+		let raw = serialize("foo::bar()", &Ok(stats), None, None, None, None, elapsed, (3, 1003));
+		let (_, _, _, _, _, _, errors) = deserialize(&raw, "foo::bar()").expect("Deserialization failed.");
+		assert_eq!(errors, (3, 1003));
+
+		// Junk should be rejected.
+		let mut raw = serialize("foo::bar()", &Ok(stats), None, None, None, None, elapsed, (0, 0));
+		raw.pop();
+		assert!(deserialize(&raw, "foo::bar()").is_none(), "Truncated.");
+		raw.extend_from_slice(&[0, 0]);
+		assert!(deserialize(&raw, "foo::bar()").is_none(), "Trailing data.");
+		assert!(deserialize(b"BRUNCH04", "foo::bar()").is_none(), "Wrong magic.");
+	}
+}
@@ -11,7 +11,7 @@
 
 
 
-`Brunch` is a very simple Rust micro-benchmark runner inspired by [`easybench`](https://crates.io/crates/easybench). It has roughly a million times fewer dependencies than [`criterion`](https://crates.io/crates/criterion), does not require nightly, and maintains a (single) "last run" state for each benchmark, allowing it to show relative changes from run-to-run.
+`Brunch` is a very simple Rust micro-benchmark runner inspired by [`easybench`](https://crates.io/crates/easybench). It has roughly a million times fewer dependencies than [`criterion`](https://crates.io/crates/criterion), does not require nightly, and maintains a short history of recent runs for each benchmark, allowing it to show relative changes from run-to-run.
 
 (The formatting is also quite pretty.)
 
@@ -76,7 +76,48 @@ The following optional environmental variables are supported:
 | Variable | Value | Description | Default |
 | -------- | ----- | ----------- | ------- |
 | `NO_BRUNCH_HISTORY` | `1` | Disable run-to-run history. | |
-| `BRUNCH_HISTORY` | Path to history file. | Load/save run-to-run history from this specific path. | `std::env::temp_dir()/__brunch.last` |
+| `BRUNCH_HISTORY` | Path to history file. | Load/save run-to-run history from this specific path. | `std::env::temp_dir()/__brunch-{profile}.{binary}.last` |
+| `BRUNCH_COMPARE` | Path to history file. | Compare this history file against the current one instead of running the benchmarks. (Generated `main` only.) | |
+| `BRUNCH_COMPARE_BEST` | `1` | Measure changes against the fastest run ever recorded rather than the recent runs. | |
+| `BRUNCH_HISTORY_RO` | `1` | Compare against the history as usual, but never update it, so repeated experiments are all measured against the same baseline. | |
+| `BRUNCH_BASELINE` | Name, e.g. `main`. | Compare against this named baseline instead of the default one, leaving it as-is. (Also applies to `BRUNCH_COMPARE`.) | |
+| `BRUNCH_SAVE_BASELINE` | Name, e.g. `main`. | Save to — and unless `BRUNCH_BASELINE` says otherwise, compare against — this named baseline instead of the default one. Any number of baselines can share a history file. | |
+| `BRUNCH_HISTORY_DEPTH` | `1`–`255` | The number of runs to retain per benchmark. | `5` |
+| `BRUNCH_RUNS` | `1`–`100` | Run the whole suite this many times, pooling the samples from each round. (Not supported in isolated mode.) | `1` |
+| `BRUNCH_SAMPLES` | Number of samples. | Override the sample limit of every benchmark, taking precedence over `Bench::with_samples`. (Min: 100; zero for unlimited.) | |
+| `BRUNCH_TIMEOUT` | Seconds. | Override the time limit of every benchmark, taking precedence over `Bench::with_timeout`. (Min: 0.5.) | |
+| `BRUNCH_STDOUT` | `1` | Print the results summary to `STDOUT` instead of `STDERR`. | |
+| `BRUNCH_BENCHFMT` | `libtest` | Also print a libtest-style `test … bench: … ns/iter` line to `STDOUT` for each benchmark. | |
+| `BRUNCH_CRITERION_COMPAT` | `1` | Also write Criterion-style `benchmark.json` and `estimates.json` files to `target/criterion/<name>/new/` for tools like `critcmp`. | |
+| `BRUNCH_GITHUB` | `1` | Emit GitHub Actions annotations for regressions and failures, and append a Markdown summary to `GITHUB_STEP_SUMMARY`. (Automatic when `GITHUB_ACTIONS=true`.) | |
+| `BRUNCH_INTERLEAVE` | `1` | Collect the samples in slices, rotating through the benchmarks, to spread any thermal or background drift evenly across the suite. (Not supported in isolated mode.) | |
+| `BRUNCH_ISOLATE` | `1` | Run each benchmark in its own process. | |
+| `BRUNCH_LOG` | Path to file. | Append a record for every successful benchmark — timestamp, name, mean, deviation, and sample counts — to this file, as CSV if it ends in `.csv`, or JSON Lines otherwise. | |
+| `BRUNCH_NO_CHECKS` | `1` | Skip the pre-run environment checks (CPU governor, turbo boost, battery). | |
+| `BRUNCH_PARALLEL` | Job count. | Run this many benchmarks at once, each in its own isolated process — not a thread, as benchmark callbacks needn't be `Send` — or one per CPU core if `0`. Handy for quick smoke tests of large suites, but the timings are skewed by the competition, so the history is left untouched. | `1` |
+| `BRUNCH_PIN` | Core index. | Pin the benchmarking thread to this (zero-indexed) CPU core. | |
+| `BRUNCH_PLOTS` | Path to directory. | Write an SVG plot of every benchmark's sample distribution — after outlier pruning, with the previous mean, if any, marked for reference — to a file in this directory. | |
+| `BRUNCH_PRIORITY` | `high`, `realtime` | Raise the benchmarking thread's scheduling priority while sampling. (Usually requires elevated privileges.) | |
+| `BRUNCH_PRUNE` | `1` | Drop stale history entries after a fully successful run. | |
+| `BRUNCH_QUIET` | `1` | Suppress the decorative output — banner, progress, notes — leaving only the results summary, or only the libtest lines if `BRUNCH_BENCHFMT=libtest` is also set. | |
+| `BRUNCH_RANK` | `1` | Follow the results summary with a table ranking the benchmarks in each group from fastest to slowest. | |
+| `BRUNCH_RAW` | Path to directory. | Write every benchmark's raw samples — before outlier pruning, in nanoseconds, one per line — to a file in this directory for external analysis. | |
+| `BRUNCH_SKIP_TAGS` | Comma-separated tags. | Leave out any benchmark carrying at least one of these tags. | |
+| `BRUNCH_TAG` | Text. | A label — a commit hash, say — to include with each `BRUNCH_LOG` record. | |
+| `BRUNCH_TAGS` | Comma-separated tags. | Only run the benchmarks carrying at least one of these tags. | |
+| `BRUNCH_THRESHOLD` | Fraction. | The minimum relative change worth reporting, e.g. `0.03` for 3%. | `0` |
+| `BRUNCH_VERBOSE` | `1` | Include additional columns — confidence intervals, medians, 95th percentiles, and ranges, total measured time, wall time, and CPU time (if measured) — in the results summary. | |
+| `BRUNCH_WIDTH` | Columns. | The width to squeeze the results summary into — by truncating long names, then dropping the _Samples_ and _Change_ columns — or `0` to never truncate. | Terminal width, `COLUMNS`, or `100`. |
+
+By default, history is kept separately for each build configuration — target triple, Cargo profile, and optimization level — so that, for example, `release` and custom `bench-lto` runs never compare against one another — and for each bench binary, so that identically-named benchmarks in different `[[bench]]` targets don't either. Setting an explicit history path opts out of this separation.
+
+Debug builds are the exception: unless [`Benches::allow_debug`] is set, they print a loud warning and skip the history altogether, so an accidental unoptimized run can never poison the baselines.
+
+Each run's sample limit, timeout, outlier policy, and metric (e.g. `Metric::Min`) are saved alongside it, too. If any of them change, the old runs are discarded rather than compared against — the _Change_ column reads `--- (config changed)` — and a warning spells out what differed.
+
+To inspect a history file from other tooling — a dashboard, a quick "what's my baseline?" script — use [`history::read`], which returns the name, mean, deviation, and sample counts of each benchmark's baseline. [`history::default_path`] resolves the location the same way the benchmarks do. (For a named baseline, use [`history::read_baseline`] instead.)
+
+For long-term tracking, `Benches::with_run_log` (or `BRUNCH_LOG`) appends a record for each successful benchmark to a JSON Lines or CSV file on every run, optionally tagged via `BRUNCH_TAG`, e.g. with a commit hash. Nothing is ever dropped from it, so months of data can be plotted without any extra infrastructure.
 
 
 
@@ -87,14 +128,30 @@ The heart of `Brunch` is the [`Bench`] struct, which defines a single benchmark.
 | Data | Description | Default |
 | ---- | ----------- | ------- |
 | Name | A unique identifier, ideally a string representation of the call itself, like `foo::bar(10)` | |
-| Samples | The number of samples to collect. | 2500 |
-| Timeout | A cutoff time to keep it from running forever. | 10 seconds |
+| Samples | The number of samples to collect, or zero for as many as fit within the timeout. | 2500 |
+| Timeout | A cutoff time — measured, excluding seed generation — to keep it from running forever. | 10 seconds |
 | Method | A method to run over and over again! | |
 
-The struct uses builder-style methods to allow everything to be set in a single chain. You always need to start with [`Bench::new`] and end with one of the runner methods — [`Bench::run`], [`Bench::run_seeded`], or [`Bench::run_seeded_with`]. If you want to change the sample or timeout limits, you can add [`Bench::with_samples`] or [`Bench::with_timeout`] in between.
+The struct uses builder-style methods to allow everything to be set in a single chain. You always need to start with [`Bench::new`] — or [`Bench::new_in`], to keep a namespace separate from the name — and end with one of the runner methods — [`Bench::run`], [`Bench::run_seeded`], [`Bench::run_seeded_with`], [`Bench::run_indexed`], or [`Bench::run_seeded_indexed`]. If you want to change the sample or timeout limits, you can add [`Bench::with_samples`] or [`Bench::with_timeout`] in between. (Benchmarks that take seconds per iteration can also lower the usual 100-sample minimum — to as few as 10 — with [`Bench::with_min_samples`], at the cost of some confidence.) And to make sure you're benchmarking the right answer, add [`Bench::verify`] right before the runner.
+
+For callbacks that return a `Result`, use [`Bench::run_fallible`] (or one of its seeded variants) instead. Each sample's outcome is checked, and if any come back `Err`, the benchmark is reported as an error — e.g. "The callback returned Err on 312/2,500 samples." — and its history left alone, so a bug that bails early can't pass for a speedup. To keep the `Ok` samples instead, add [`Bench::discard_errors`]. Either way, the count is available via [`BenchResult::errors`].
+
+By default, samples are taken back to back, so caches stay warm. For code that never runs that way in practice — startup paths, rarely-taken branches — add [`Bench::cold`] to evict the data cache (untimed) before each sample, or [`Bench::cold_with`] to also run your own cooling-off callback. Cold benches collect 250 samples by default, are marked "(cold)" in the table, and never share a baseline with warm runs.
+
+Going the other way, [`Bench::with_warmup`] runs the callback — untimed — for a set duration before sampling begins, so first-run cache and allocator noise doesn't skew the stats. The warm-up doesn't count toward the timeout, and is noted beneath the table in verbose mode.
+
+And for work that can't be timed from the outside — GPU kernels timed by device events, say — use [`Bench::run_timed`], whose callback returns its own `Duration` for each sample. (The timeout still goes by the wall time around the calls.)
+
+If none of those shapes fit, [`Bench::bench`] hands each sample a [`Bencher`] — much like `criterion`'s — leaving it to the callback to measure exactly what it wants with [`Bencher::iter`], [`Bencher::iter_batched`] (untimed setup), or [`Bencher::iter_custom`] (self-timed).
+
+For cross-cutting selections — only the slow ones, only the serde-related ones — benchmarks can be labeled with [`Bench::tag`] (as many times as needed), then filtered with [`Benches::filter_tags`] or [`Benches::skip_tags`] (or `BRUNCH_TAGS` and `BRUNCH_SKIP_TAGS`). To run just a few benchmarks by name instead, pass one or more filters on the command line, e.g. `cargo bench -- nice_u8`; only those whose names contain one of them are run. (Cargo's own harness flags, like `--bench`, are ignored, and a warning is printed if nothing matches.) (This can also be set in code with [`Benches::filter_names`].) Anything left out is dropped from the run and the results, but its history is left alone. To see what a given selection would run — each name followed by its tags, e.g. `name [slow, serde]` — without running anything, pass `--list`, e.g. `cargo bench -- --list`. (This can also be set in code with [`Benches::list_only`].)
 
 There is also a special [`Bench::spacer`] method that can be used to inject a linebreak into the results. See below for an example.
 
+Benchmarks that can't run in the current environment can be marked with [`Bench::skip`], in which case they show up in the results with the reason instead, and leave their history alone.
+
+Expensive setup shared by several benchmarks — a large parsed dataset, say — can be built once with [`Benches::with_context`] (or [`Context::new`]) and handed to each via [`Bench::run_with_context`]. The value is built right before the first benchmark that needs it, is never counted against any benchmark's samples or timeout, and is dropped once the suite has finished.
+
 ### Examples
 
 The [`benches`] macro is the easiest way to run `Brunch` benchmarks.
@@ -148,7 +205,49 @@ fn main() {
 }
 ```
 
-For even more control over the flow, skip the macro and just use [`Benches`](crate::Benches) directly.
+Common suite-wide settings can be given up front in an optional `config` block — after "inline:", if present — rather than hand-rolling `main()` just to call a builder method or two. Each key maps to the [`Benches`] method of the same purpose; see the [`benches`] macro for the full list. Unknown keys are a compile error.
+
+```no_run
+use brunch::{Bench, benches};
+
+benches!(
+    config: {
+        samples: 10_000,
+        timeout_secs: 5,
+        history: "target/brunch.hist",
+        color: false,
+    },
+
+    Bench::new("usize::checked_add(2)")
+        .run(|| 2_usize.checked_add(2)),
+);
+```
+
+For even more control over the flow, skip the macro and just use [`Benches`](crate::Benches) directly. Its [`Benches::finish`] method returns a [`Summary`] of the run — passes, failures, and whether the history was saved — leaving it up to you what to do about any problems. Its [`Summary::faster`], [`Summary::slower`], and [`Summary::unchanged`] methods give the same counts as the one-line tally printed after the table — e.g. `2 faster · 1 slower · 34 unchanged · 1 error` — so CI scripts can act on regressions without parsing any text.
+
+For programmatic interchange — dashboards, CI artifacts, etc. — [`Benches::report`] and [`Summary::report`] return an owned [`RunReport`] with the name, stats (or error), and baseline of each benchmark. Enable the optional `serde` crate feature to derive `Serialize` and `Deserialize` for it — and [`Stats`] — so it can be written out in whatever format you like.
+
+Samples are timed with `std::time::Instant` by default, but any other time source — `CLOCK_MONOTONIC_RAW`, calibrated TSC reads, etc. — can be swapped in by implementing the [`Timer`] trait and passing it to `Benches::with_timer`. A scripted [`MockTimer`] is provided too, for deterministic testing.
+
+### Attributes
+
+If listing every benchmark in one giant macro call gets unwieldy, enable the optional `macros` crate feature to register them individually — from any module — with the `#[brunch::bench]` attribute instead, then use `brunch::main!()` to generate a `main()` that runs them all.
+
+Zero-argument functions returning a [`Bench`] are registered as-is; anything else is treated as the benchmark callback itself, named after the function unless a `name` is given. Either way, the benchmarks are run in order of name so the output is stable from build to build.
+
+```ignore
+use brunch::Bench;
+
+#[brunch::bench]
+fn checked_add() -> Bench<'static> {
+    Bench::new("usize::checked_add(2)").run(|| 2_usize.checked_add(2))
+}
+
+#[brunch::bench(name = "usize::checked_mul(2)", samples = 1000)]
+fn checked_mul() -> Option<usize> { 2_usize.checked_mul(2) }
+
+brunch::main!();
+```
 
 
 
@@ -168,8 +267,45 @@ The _Method_ column speaks for itself, but the numbers deserve a little explanat
 | Column | Description |
 | ------ | ----------- |
 | Mean | The adjusted, average execution time for a _single_ run, scaled to the most appropriate time unit to keep the output tidy. |
-| Change | The relative difference between this run and the last run, if more than two standard deviations. |
+| Change | The relative difference between this run and the (pooled) previous runs, if statistically significant (Welch's t-test, _p_ < 0.05) and at least half a percent. |
+| Trend | The direction the mean has been heading over the retained runs: ↗ slower, ↘ faster, or → stable. |
 | Samples | The number of valid/total samples, the difference being outliers (5th and 95th quantiles) excluded from consideration. |
+
+If more than 10% of a benchmark's samples are pruned as outliers, its _Samples_ cell is colored yellow (red above 25%), and it is listed beneath the table; the numbers may not be trustworthy. The thresholds can be changed with `Benches::outlier_thresholds`, or the warnings disabled with `Benches::without_outlier_warnings`.
+
+Steady drift is another matter: if the CPU starts throttling partway through a benchmark, the mean lands somewhere between the before and after, and nothing looks out of place. To catch that, the means of the first and second halves of each benchmark's samples — in the order collected — are compared, and if they differ by more than 5%, the benchmark is flagged with a `⚠` and a footnote, and any reported change has to outstrip the drift. The threshold can be changed with [`Benches::drift_threshold`], or the check disabled with [`Benches::without_drift_warnings`].
+
+By default, the portion of each name shared with its neighbors is dimmed, and the rest highlighted. If the guesswork doesn't suit your naming scheme, [`Benches::name_style`] can switch to uniform highlighting, or an explicit split at the last occurrence of a given delimiter.
+
+To make slow benchmarks stand out at a glance, set a pair of thresholds with [`Benches::slow_thresholds`]; means above the first are colored yellow, and above the second, red. (Without color, they're flagged with a `!` or `!!` instead.) This is purely cosmetic.
+
+If you'd rather every time in the table use the same unit — to make them easier to compare at a glance — set one with `Benches::with_unit` (or `Bench::with_unit` for a single row).
+
+In verbose mode, a _95% CI_ column is added with the confidence interval for each mean (in the same unit), and the _Change_ column only reports differences when the intervals of the current and previous runs do not overlap.
+
+Since the mean alone can hide a skewed or bimodal distribution, verbose mode also adds _Median_, _p95_, and _Range_ columns, with the median, 95th percentile, and fastest/slowest of the valid samples. (These are also available programmatically via [`Stats::median`] and friends, but aren't known for streamed benchmarks, and aren't saved to history.)
+
+Suites or benchmarks that opt into `Benches::show_ops` (or `Bench::show_ops`) get an _Ops/s_ column too, with the reciprocal of the mean, e.g. `14.20 M ops/s`.
+
+With `Benches::with_geomean`, each group of benchmarks — separated by spacers — ends with a summary row holding the geometric mean of its members' means and, if they all have history, of their changes.
+
+With `Benches::show_ranking` (or `BRUNCH_RANK=1`), the summary is followed by a compact table ranking the successful benchmarks in each group from fastest to slowest, with each mean relative to the fastest, e.g. `1.00x`, `2.30x`. Benchmarks whose ±2σ ranges overlap are too close to call, so share a rank, marked with an `=`.
+
+To get a feel for how something scales, `Bench::scaling` builds one benchmark per input size — named like `sort(n=10000)`, so history works per size — seeding each sample with a freshly-generated input. Once the series has run, its means are fit against `n`, `n log n`, and `n²`, and the best-fitting class and its R² are reported in a summary row beneath the per-size rows. Poor fits (R² below `0.9`) are reported as inconclusive rather than confidently wrong.
+
+When the suite is repeated with `Benches::repeat` (or `BRUNCH_RUNS`), verbose mode adds a _Spread_ column too, showing how much the per-round means differed.
+
+Verbose mode also adds a _Total_ column with the sum of the valid samples, i.e. how much time was actually measured. Regardless of mode, any benchmark measured for less than 5 ms in total is noted beneath the table, as its numbers may be little more than scheduler noise.
+
+Benchmarks that opt into `Bench::measure_cpu` additionally get a _CPU Time_ column in verbose mode, with the mean CPU time of the sampling thread. (Only the wall time is saved to history.)
+
+If the [`TrackingAllocator`] is installed as the `#[global_allocator]`, _Allocs_ and _Bytes_ columns are added too, with the mean number of (re)allocations and bytes allocated per iteration. These are saved to history, and any change in the allocation count is noted in the _Change_ column, e.g. `allocs 3 → 5`.
+
+On Linux, enabling the optional `perf` crate feature adds _Instr._ and _IPC_ columns as well, with the mean number of (user-space) instructions retired per iteration and the instructions per cycle, read straight from the hardware performance counters. Instruction counts are far steadier than timings, so they're saved to history too, and any change of at least 0.5% is noted in the _Change_ column, e.g. `+1.80% instructions`. If the counters are unavailable — usually because of `/proc/sys/kernel/perf_event_paranoid` — a single warning is printed and the benchmarks carry on with plain timing.
+
+Likewise, benchmarks that opt into `Bench::measure_memory` get a _Max RSS_ column with the process's peak memory after the run, and how much it grew during it. (The peak only ever goes up, so for accurate per-bench numbers, run them in isolation with `Benches::isolated`.)
+
+Benchmarks given an absolute time budget with `Bench::expect_under` get a _Budget_ column, showing a check mark if the mean came in under it, or _BUDGET EXCEEDED_ and by how much if not. Any overage — or failure — causes the process to exit with a non-zero code once the summary has been printed.
 */
 
 #![deny(
@@ -224,27 +360,110 @@ The _Method_ column speaks for itself, but the numbers deserve a little explanat
 #![expect(clippy::needless_doctest_main, reason = "False positive.")]
 #![expect(clippy::redundant_pub_crate, reason = "Unresolvable.")]
 
+mod affinity;
+mod alloc;
 mod bench;
+mod bencher;
+mod checks;
+mod clock;
+mod cold;
+mod compare;
+mod context;
+mod criterion;
 mod error;
+mod github;
+pub mod history;
+mod isolate;
 #[macro_use] mod macros;
 mod math;
+mod output;
+mod perf;
+mod plot;
+mod priority;
+mod progress;
+mod raw;
+#[cfg(feature = "macros")] mod registry;
+mod report;
+mod rss;
+mod runlog;
+mod scaling;
+mod signal;
 mod stats;
+mod stream;
+mod term;
+mod timer;
+mod unit;
 pub(crate) mod util;
 
 
 
+pub use alloc::TrackingAllocator;
 pub use bench::{
 	Bench,
+	BenchEntry,
+	BenchResult,
 	Benches,
+	DupePolicy,
+	NameStyle,
+	Summary,
+	Verified,
 };
+pub use bencher::Bencher;
+#[cfg(feature = "macros")] pub use brunch_macros::bench;
+pub use compare::{
+	compare,
+	compare_baseline,
+};
+#[doc(hidden)] pub use compare::compare_env;
+pub use context::Context;
 pub use error::BrunchError;
 pub(crate) use math::Abacus;
-pub(crate) use stats::{
-	history::History,
+pub use output::OutputTarget;
+pub use priority::Priority;
+pub(crate) use progress::Progress;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use registry::{
+	register_bench,
+	registered_benches,
+};
+pub use report::{
+	BenchOutcome,
+	BenchReport,
+	RunReport,
+};
+pub(crate) use stats::history::{
+	Config,
+	History,
+};
+pub use stats::{
+	Metric,
+	OutlierPolicy,
 	Stats,
 };
+pub(crate) use stats::{
+	Distribution,
+	Pruning,
+	Trend,
+};
+pub(crate) use stream::Stream;
+pub use timer::{
+	MockTimer,
+	SystemTimer,
+	Timer,
+};
+pub use unit::TimeUnit;
+
+// Only used by the integration tests.
+#[cfg(test)] use trybuild as _;
 
 
 
 /// # Minimum Number of Samples.
 pub(crate) const MIN_SAMPLES: u32 = 100;
+
+/// # Minimum Number of Samples (Floor).
+///
+/// The lowest minimum a benchmark can opt into via
+/// [`Bench::with_min_samples`].
+pub(crate) const MIN_SAMPLES_FLOOR: u32 = 10;
@@ -61,37 +61,289 @@
 /// }
 /// ```
 ///
+/// Entries may also be `Option<Bench>`, making it easy to include benchmarks
+/// conditionally; `None` is simply skipped, along with any spacers left
+/// dangling as a result.
+///
+/// Likewise, an entry can be anything else that iterates over [`Bench`](crate::Bench)es,
+/// like a `Vec<Bench>` generated from a list of fixtures, in which case each
+/// of them is added in turn.
+///
+/// ```no_run
+/// use brunch::{Bench, benches};
+///
+/// benches!(
+///     Bench::new("usize::checked_add(2)")
+///         .run(|| 2_usize.checked_add(2)),
+///
+///     Bench::spacer(),
+///
+///     cfg!(target_os = "linux").then(||
+///         Bench::new("usize::checked_mul(2)")
+///             .run(|| 2_usize.checked_mul(2))
+///     ),
+///
+///     Bench::spacer(),
+///
+///     (1..=3_u8).map(|n|
+///         Bench::new(format!("u8::checked_pow({n})"))
+///             .run_seeded(n, |n| 2_u8.checked_pow(u32::from(n)))
+///     ).collect::<Vec<_>>(),
+/// );
+/// ```
+///
+/// Suite-wide settings can be specified in an optional `config: { … }` block
+/// ahead of the benchmarks (and after "inline:", if present). Each key maps
+/// to the corresponding [`Benches`](crate::Benches) builder method; all are
+/// optional:
+///
+/// | Key | Value | Method |
+/// | --- | ----- | ------ |
+/// | `budget_secs` | Seconds. | [`with_total_budget`](crate::Benches::with_total_budget) |
+/// | `color` | `bool` | [`with_color`](crate::Benches::with_color) |
+/// | `history` | Path. | [`with_history_path`](crate::Benches::with_history_path) |
+/// | `history_readonly` | `bool` | [`history_readonly`](crate::Benches::history_readonly) |
+/// | `isolated` | `bool` | [`isolated`](crate::Benches::isolated) |
+/// | `no_history` | `bool` | [`without_history`](crate::Benches::without_history) |
+/// | `output` | [`OutputTarget`](crate::OutputTarget) | [`with_output`](crate::Benches::with_output) |
+/// | `parallel` | `usize` | [`parallel`](crate::Benches::parallel) |
+/// | `quiet` | `bool` | [`quiet`](crate::Benches::quiet) |
+/// | `repeat` | `u32` | [`repeat`](crate::Benches::repeat) |
+/// | `retries` | `u8` | [`retries`](crate::Benches::retries) |
+/// | `samples` | `u32` | [`default_samples`](crate::Benches::default_samples) |
+/// | `skip_tags` | `[&str; N]` | [`skip_tags`](crate::Benches::skip_tags) |
+/// | `tags` | `[&str; N]` | [`filter_tags`](crate::Benches::filter_tags) |
+/// | `timeout` | [`Duration`](std::time::Duration) | [`default_timeout`](crate::Benches::default_timeout) |
+/// | `timeout_secs` | Seconds. | [`default_timeout`](crate::Benches::default_timeout) |
+/// | `unit` | [`TimeUnit`](crate::TimeUnit) | [`with_unit`](crate::Benches::with_unit) |
+/// | `verbose` | `bool` | [`with_verbose`](crate::Benches::with_verbose) |
+///
+/// ```no_run
+/// use brunch::{Bench, benches};
+///
+/// benches!(
+///     config: {
+///         samples: 10_000,
+///         timeout_secs: 5,
+///         history: "target/brunch.hist",
+///         color: false,
+///         tags: ["fast"],
+///     },
+///
+///     Bench::new("usize::checked_add(2)")
+///         .tag("fast")
+///         .run(|| 2_usize.checked_add(2)),
+/// );
+/// ```
+///
+/// Unknown keys are a compile error:
+///
+/// ```compile_fail
+/// use brunch::{Bench, benches};
+///
+/// benches!(
+///     config: { colour: false },
+///     Bench::new("usize::checked_add(2)").run(|| 2_usize.checked_add(2)),
+/// );
+/// ```
+///
 /// For even more control over the flow, skip the macro and just use [`Benches`](crate::Benches)
 /// directly.
 macro_rules! benches {
+	// Config: done!
+	(@config $b:ident,) => {};
+
+	// Config: known keys.
+	(@config $b:ident, budget_secs: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.with_total_budget(::std::time::Duration::from_secs_f64(::core::primitive::f64::from($v)));
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, color: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.with_color($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, history: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.with_history_path($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, history_readonly: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.history_readonly($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, isolated: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.isolated($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, no_history: $v:expr $(, $($rest:tt)*)?) => {
+		if $v { $b = $b.without_history(); }
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, output: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.with_output($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, parallel: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.parallel($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, quiet: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.quiet($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, repeat: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.repeat($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, retries: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.retries($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, samples: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.default_samples($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, skip_tags: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.skip_tags(&$v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, tags: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.filter_tags(&$v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, timeout: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.default_timeout($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, timeout_secs: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.default_timeout(::std::time::Duration::from_secs_f64(::core::primitive::f64::from($v)));
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, unit: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.with_unit($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+	(@config $b:ident, verbose: $v:expr $(, $($rest:tt)*)?) => {
+		$b = $b.with_verbose($v);
+		benches!(@config $b, $($($rest)*)?);
+	};
+
+	// Config: anything else.
+	(@config $b:ident, $key:ident : $($rest:tt)*) => {
+		::core::compile_error!(::core::concat!(
+			"Unknown benches! config key `",
+			::core::stringify!($key),
+			"`; expected one of: budget_secs, color, history, history_readonly, isolated, no_history, output, parallel, quiet, repeat, retries, samples, skip_tags, tags, timeout, timeout_secs, unit, verbose.",
+		));
+	};
+	(@config $b:ident, $($rest:tt)+) => {
+		::core::compile_error!("Malformed benches! config; expected `key: value` pairs separated by commas.");
+	};
+
+	(inline: config: { $($config:tt)* }, $($benches:expr),+ $(,)?) => {{
+		let mut benches = $crate::Benches::default();
+		benches!(@config benches, $($config)*);
+		$(
+			$crate::BenchEntry::push_to($benches, &mut benches);
+		)+
+		benches.finish_or_print();
+	}};
+
 	(inline: $($benches:expr),+ $(,)?) => {{
 		let mut benches = $crate::Benches::default();
 		$(
-			benches.push($benches);
+			$crate::BenchEntry::push_to($benches, &mut benches);
 		)+
-		benches.finish();
+		benches.finish_or_print();
 	}};
 
-	($($benches:expr),+ $(,)?) => {
+	(config: { $($config:tt)* }, $($benches:expr),+ $(,)?) => {
 		/// # Benchmarks!
 		fn main() {
-			// Announce that we've started.
-			::std::eprint!("\x1b[1;38;5;199mStarting:\x1b[0m Running benchmark(s). Stand by!\n\n");
+			// Compare saved histories instead?
+			if $crate::compare_env() { return; }
 
-			// Run the benches.
+			// Announce that we've started.
 			let mut benches = $crate::Benches::default();
+			benches!(@config benches, $($config)*);
+			benches.announce();
+
+			// Queue up the benches.
 			$(
-				// Print a dot to show some progress.
-				::std::eprint!("\x1b[1;34m•\x1b[0m");
+				$crate::BenchEntry::push_to($benches, &mut benches);
+			)+
+
+			// Run them and print the results!
+			benches.finish_or_print();
+		}
+	};
+
+	($($benches:expr),+ $(,)?) => {
+		/// # Benchmarks!
+		fn main() {
+			// Compare saved histories instead?
+			if $crate::compare_env() { return; }
+
+			// Announce that we've started.
+			let mut benches = $crate::Benches::default();
+			benches.announce();
 
-				benches.push($benches);
+			// Queue up the benches.
+			$(
+				$crate::BenchEntry::push_to($benches, &mut benches);
 			)+
 
-			// Give some space.
-			::std::eprint!("\n\n");
+			// Run them and print the results!
+			benches.finish_or_print();
+		}
+	};
+}
+
+#[cfg(feature = "macros")]
+#[macro_export(local_inner_macros)]
+/// # Helper: Main (Registered Benchmarks)
+///
+/// This macro generates a `main()` entrypoint that runs every benchmark
+/// registered with the [`bench`](crate::bench) attribute, no matter which
+/// module they live in.
+///
+/// The benchmarks are run in order of name — rather than declaration — so
+/// the output and history keys are stable from build to build.
+///
+/// This requires the `macros` crate feature.
+///
+/// ## Examples
+///
+/// ```no_run
+/// use brunch::Bench;
+///
+/// // Functions returning a Bench are registered as-is.
+/// #[brunch::bench]
+/// fn checked_add() -> Bench<'static> {
+///     Bench::new("usize::checked_add(2)").run(|| 2_usize.checked_add(2))
+/// }
+///
+/// // Anything else is benchmarked directly.
+/// #[brunch::bench(name = "usize::checked_mul(2)", samples = 1000)]
+/// fn checked_mul() -> Option<usize> { 2_usize.checked_mul(2) }
+///
+/// brunch::main!();
+/// ```
+macro_rules! main {
+	() => {
+		/// # Benchmarks!
+		fn main() {
+			// Compare saved histories instead?
+			if $crate::compare_env() { return; }
+
+			// Announce that we've started.
+			let mut benches = $crate::Benches::default();
+			benches.announce();
+
+			// Queue up the benches.
+			benches.extend($crate::registered_benches());
 
-			// Finish!
-			benches.finish();
+			// Run them and print the results!
+			benches.finish_or_print();
 		}
 	};
 }
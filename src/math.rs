@@ -2,9 +2,16 @@
 # Brunch: Math
 */
 
+use crate::{
+	OutlierPolicy,
+	Pruning,
+};
 use dactyl::{
 	total_cmp,
-	traits::IntDivFloat,
+	traits::{
+		IntDivFloat,
+		SaturatingFrom,
+	},
 };
 use std::{
 	cmp::Ordering,
@@ -36,9 +43,15 @@ pub(crate) struct Abacus {
 }
 
 impl From<Vec<Duration>> for Abacus {
+	#[expect(clippy::cast_precision_loss, reason = "It is what it is.")]
 	fn from(src: Vec<Duration>) -> Self {
-		let set: Vec<f64> = src.iter().map(Duration::as_secs_f64).collect();
-		Self::from(set)
+		// Tally the total in integer nanoseconds first; adding up seconds as
+		// floats drifts as the values (or sample counts) grow.
+		let nanos: u128 = src.iter().map(Duration::as_nanos).sum();
+		let set: Vec<f64> = src.into_iter().map(|d| d.as_secs_f64()).collect();
+		let mut out = Self::from(set);
+		out.total = (nanos / 1_000_000_000) as f64 + (nanos % 1_000_000_000) as f64 / 1_000_000_000.0;
+		out
 	}
 }
 
@@ -56,7 +69,7 @@ impl From<Vec<f64>> for Abacus {
 
 		// Everything from here on out requires a sorted set, so let's take
 		// care of that now.
-		set.sort_by(f64::total_cmp);
+		set.sort_unstable_by(f64::total_cmp);
 
 		// Pre-calculate some useful totals.
 		let len = set.len();
@@ -75,6 +88,11 @@ impl Abacus {
 	/// # Length.
 	pub(crate) const fn len(&self) -> usize { self.len }
 
+	/// # Into Inner.
+	///
+	/// Return the (sorted) set.
+	pub(crate) fn into_inner(self) -> Vec<f64> { self.set }
+
 	#[expect(clippy::cast_precision_loss, reason = "It is what it is.")]
 	/// # Float Length.
 	const fn f_len(&self) -> f64 { self.len as f64 }
@@ -87,10 +105,9 @@ impl Abacus {
 	pub(crate) fn deviation(&self) -> f64 {
 		if self.is_empty() || self.unique == 1 { return 0.0; }
 		let mean = self.mean();
-		let squares: Vec<f64> = self.set.iter()
+		let sum: f64 = self.set.iter()
 			.map(|n| (mean - *n).powi(2))
-			.collect();
-		let sum: f64 = squares.iter().sum();
+			.sum();
 		(sum / self.f_len()).sqrt()
 	}
 
@@ -107,29 +124,75 @@ impl Abacus {
 		else { self.total / self.f_len() }
 	}
 
+	/// # Median Absolute Deviation.
+	///
+	/// Return the median of the absolute differences between each entry and
+	/// the (pre-calculated) median.
+	fn mad(&self, median: f64) -> f64 {
+		let mut diffs: Vec<f64> = self.set.iter().map(|n| (n - median).abs()).collect();
+		median_unsorted(&mut diffs)
+	}
+
+	/// # Median.
+	///
+	/// Unlike `Abacus::quantile`, this returns the true median, averaging the
+	/// middle two entries for even-sized sets.
+	pub(crate) fn median(&self) -> f64 { median_sorted(&self.set) }
+
+	/// # Percentile.
+	///
+	/// Return the entry at the given percentile using the nearest-rank
+	/// method, i.e. the smallest entry with at least `phi` of the set at or
+	/// below it.
+	///
+	/// Unlike `Abacus::quantile`, this never strays from the requested rank
+	/// — even when the set is clumpy — so is better suited for reporting.
+	pub(crate) fn percentile(&self, phi: f64) -> f64 {
+		if self.is_empty() { 0.0 }
+		else {
+			let rank = usize::saturating_from((phi.clamp(0.0, 1.0) * self.f_len()).ceil());
+			self.set[rank.clamp(1, self.len) - 1]
+		}
+	}
+
 	/// # Minimum Value.
 	pub(crate) fn min(&self) -> f64 {
 		if self.is_empty() { 0.0 }
 		else { self.set[0] }
 	}
+
+	/// # Sum.
+	pub(crate) const fn sum(&self) -> f64 { self.total }
+
+	/// # Trimmed Mean.
+	///
+	/// Return the mean of the set after dropping the lowest and highest
+	/// `frac` of its entries (rounded down). If that leaves nothing, the
+	/// median is returned instead.
+	pub(crate) fn trimmed_mean(&self, frac: f64) -> f64 {
+		let trim = usize::saturating_from(self.f_len() * frac.clamp(0.0, 0.5));
+		let set = &self.set[trim.min(self.len)..self.len.saturating_sub(trim)];
+		if set.is_empty() { self.median() }
+		else {
+			compensated_sum(set) / f64::from(u32::saturating_from(set.len()))
+		}
+	}
 }
 
 impl Abacus {
 	/// # Prune Outliers.
 	///
-	/// This calculates an IQR using the 5th and 95th quantiles (fuzzily), and
-	/// removes entries below the lower boundary or above the upper one, using
-	/// a multiplier of `1.5`.
-	pub(crate) fn prune_outliers(&mut self) {
-		if 1 < self.unique && 0.0 < self.deviation() {
-			let q1 = self.ideal_quantile(0.05);
-			let q3 = self.ideal_quantile(0.95);
-			let iqr = q3 - q1;
-
-			// Low and high boundaries.
-			let lo = iqr.mul_add(-1.5, q1);
-			let hi = iqr.mul_add(1.5, q3);
-
+	/// This removes entries below the lower boundary or above the upper one,
+	/// as determined by the policy.
+	///
+	/// For IQR policies, that range is calculated from the policy's low and
+	/// high quantiles (fuzzily) — by default the 5th and 95th — stretched by
+	/// the policy's multiplier (by default `1.5`).
+	///
+	/// For MAD policies, it is the median plus or minus `k` median absolute
+	/// deviations.
+	pub(crate) fn prune_outliers(&mut self, policy: OutlierPolicy) {
+		if let Some((lo, hi)) = self.outlier_bounds(policy) {
 			// Remove outliers.
 			self.set.retain(|&s| total_cmp!(lo <= s) && total_cmp!(s <= hi));
 
@@ -138,10 +201,70 @@ impl Abacus {
 			if len != self.len {
 				self.len = len;
 				self.unique = count_unique(&self.set);
-				self.total = self.set.iter().sum();
+				self.total = compensated_sum(&self.set);
 			}
 		}
 	}
+
+	/// # Outlier Boundaries.
+	///
+	/// Return the lower and upper boundaries used by
+	/// [`Abacus::prune_outliers`], or `None` if pruning is disabled or the
+	/// set is too uniform to have any outliers.
+	pub(crate) fn outlier_bounds(&self, policy: OutlierPolicy) -> Option<(f64, f64)> {
+		let pruning = policy.pruning()?;
+		if 1 < self.unique && 0.0 < self.deviation() {
+			match pruning {
+				Pruning::Iqr { low, high, mult } => {
+					let q1 = self.ideal_quantile(low);
+					let q3 = self.ideal_quantile(high);
+					let iqr = q3 - q1;
+
+					// Low and high boundaries.
+					Some((iqr.mul_add(-mult, q1), iqr.mul_add(mult, q3)))
+				},
+				Pruning::Mad { k } => {
+					let median = self.median();
+					let mad = self.mad(median);
+
+					// A zero MAD would prune everything but the median.
+					if 0.0 < mad { Some((mad.mul_add(-k, median), mad.mul_add(k, median))) }
+					else { None }
+				},
+			}
+		}
+		else { None }
+	}
+}
+
+impl Abacus {
+	/// # Drift.
+	///
+	/// Return the relative difference between the means of the second and
+	/// first halves of `ordered` — the same samples, in the order they were
+	/// collected — ignoring anything [`Abacus::prune_outliers`] would drop.
+	///
+	/// Returns zero if either half comes up empty.
+	pub(crate) fn drift(&self, ordered: &[f64], policy: OutlierPolicy) -> f64 {
+		let (lo, hi) = self.outlier_bounds(policy).unwrap_or((f64::NEG_INFINITY, f64::INFINITY));
+		let mean = |set: &[f64]| {
+			let set: Vec<f64> = set.iter()
+				.copied()
+				.filter(|&s| total_cmp!(lo <= s) && total_cmp!(s <= hi))
+				.collect();
+			if set.is_empty() { None }
+			else { Some(compensated_sum(&set) / f64::from(u32::saturating_from(set.len()))) }
+		};
+
+		let (first, last) = ordered.split_at(ordered.len() / 2);
+		match (mean(first), mean(last)) {
+			(Some(a), Some(b)) if 0.0 < a => {
+				let diff = (b - a) / a;
+				if diff.is_finite() { diff } else { 0.0 }
+			},
+			_ => 0.0,
+		}
+	}
 }
 
 impl Abacus {
@@ -149,19 +272,14 @@ impl Abacus {
 	///
 	/// Return the total number of entries with values larger than the target.
 	fn count_above(&self, num: f64) -> usize {
-		self.set.iter()
-			.rev()
-			.take_while(|&&n| total_cmp!(n > num))
-			.count()
+		self.len - self.set.partition_point(|&n| total_cmp!(n <= num))
 	}
 
 	/// # Count Below.
 	///
 	/// Return the total number of entries with values lower than the target.
 	fn count_below(&self, num: f64) -> usize {
-		self.set.iter()
-			.take_while(|&&n| total_cmp!(n < num))
-			.count()
+		self.set.partition_point(|&n| total_cmp!(n < num))
 	}
 
 	#[expect(
@@ -283,8 +401,9 @@ impl Abacus {
 	/// Return the largest entry in the set with a value lower than the target,
 	/// if any.
 	fn step_down(&self, num: f64) -> Option<f64> {
-		let pos = self.set.iter().position(|&n| total_cmp!(n == num))?;
-		if 0 < pos { Some(self.set[pos - 1]) }
+		let pos = self.count_below(num);
+		let found = self.set.get(pos)?;
+		if 0 < pos && total_cmp!(found == num) { Some(self.set[pos - 1]) }
 		else { None }
 	}
 
@@ -293,30 +412,166 @@ impl Abacus {
 	/// Return the smallest entry in the set with a value larger than the
 	/// target, if any.
 	fn step_up(&self, num: f64) -> Option<f64> {
-		let pos = self.set.iter().rposition(|&n| total_cmp!(n == num))?;
-		if pos + 1 < self.len { Some(self.set[pos + 1]) }
+		let end = self.len - self.count_above(num);
+		let found = self.set.get(end.checked_sub(1)?)?;
+		if end < self.len && total_cmp!(found == num) { Some(self.set[end]) }
 		else { None }
 	}
 }
 
 
 
+/// # Student's T P-Value.
+///
+/// Return the two-tailed p-value for the t statistic with the given degrees
+/// of freedom.
+pub(crate) fn student_t_p(t: f64, df: f64) -> f64 {
+	if ! t.is_finite() { 0.0 }
+	else if ! df.is_finite() || df <= 0.0 { 1.0 }
+	else { inc_beta(df / t.mul_add(t, df), df / 2.0, 0.5) }
+}
+
+/// # Regularized Incomplete Beta Function.
+///
+/// Return `I_x(a, b)`, calculated via continued fraction, as described in
+/// _Numerical Recipes_.
+fn inc_beta(x: f64, a: f64, b: f64) -> f64 {
+	if x <= 0.0 { return 0.0; }
+	if x >= 1.0 { return 1.0; }
+
+	let front = (
+		ln_gamma(a + b) - ln_gamma(a) - ln_gamma(b) +
+		a.mul_add(x.ln(), b * (1.0 - x).ln())
+	).exp();
+
+	// The fraction converges quickly on one side of the mean or the other;
+	// use the symmetry relation as needed.
+	if x < (a + 1.0) / (a + b + 2.0) { front * beta_cf(x, a, b) / a }
+	else { 1.0 - front * beta_cf(1.0 - x, b, a) / b }
+}
+
+/// # Incomplete Beta Continued Fraction.
+///
+/// This evaluates the continued fraction for [`inc_beta`] using the modified
+/// Lentz method.
+fn beta_cf(x: f64, a: f64, b: f64) -> f64 {
+	/// # Convergence Threshold.
+	const EPSILON: f64 = 1e-14;
+
+	/// # Smallest Divisor.
+	const TINY: f64 = 1e-300;
+
+	/// # Keep Divisors Non-Zero.
+	fn nonzero(v: f64) -> f64 { if v.abs() < TINY { TINY } else { v } }
+
+	let (qab, qap, qam) = (a + b, a + 1.0, a - 1.0);
+	let mut cf = 1.0;
+	let mut df = 1.0 / nonzero(1.0 - qab * x / qap);
+	let mut out = df;
+
+	for m in 1..=1000_u16 {
+		let m = f64::from(m);
+		let m2 = 2.0 * m;
+
+		// Even step.
+		let num = m * (b - m) * x / ((qam + m2) * (a + m2));
+		df = 1.0 / nonzero(num.mul_add(df, 1.0));
+		cf = nonzero(1.0 + num / cf);
+		out *= df * cf;
+
+		// Odd step.
+		let num = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+		df = 1.0 / nonzero(num.mul_add(df, 1.0));
+		cf = nonzero(1.0 + num / cf);
+		let delta = df * cf;
+		out *= delta;
+
+		if (delta - 1.0).abs() < EPSILON { break; }
+	}
+
+	out
+}
+
+/// # Log Gamma.
+///
+/// Return `ln(Γ(x))` for positive `x`, using the Lanczos approximation.
+fn ln_gamma(x: f64) -> f64 {
+	/// # Lanczos Coefficients (g = 7).
+	const COEFFICIENTS: [f64; 8] = [
+		676.520_368_121_885_1,
+		-1_259.139_216_722_402_8,
+		771.323_428_777_653_1,
+		-176.615_029_162_140_6,
+		12.507_343_278_686_905,
+		-0.138_571_095_265_720_12,
+		9.984_369_578_019_572e-6,
+		1.505_632_735_149_311_6e-7,
+	];
+
+	let x = x - 1.0;
+	let t = x + 7.5;
+	let sum = COEFFICIENTS.iter()
+		.zip(1_u8..)
+		.fold(0.999_999_999_999_809_9, |acc, (c, i)| acc + c / (x + f64::from(i)));
+
+	(std::f64::consts::TAU.ln() / 2.0) + (x + 0.5).mul_add(t.ln(), -t) + sum.ln()
+}
+
+/// # Compensated Sum.
+///
+/// Add up the values using Neumaier's variant of Kahan summation, which
+/// keeps a running correction for the low-order bits lost along the way.
+/// The naive approach can drift noticeably for long runs of small values,
+/// or a few very large ones.
+fn compensated_sum(src: &[f64]) -> f64 {
+	let mut sum = 0.0_f64;
+	let mut comp = 0.0_f64;
+	for &v in src {
+		let t = sum + v;
+		if sum.abs() >= v.abs() { comp += (sum - t) + v; }
+		else { comp += (v - t) + sum; }
+		sum = t;
+	}
+	sum + comp
+}
+
 /// # Count Unique.
 ///
-/// This returns the number of unique entries in a set. It isn't particularly
-/// efficient, but won't run more than twice per benchmark, so should be fine.
+/// This returns the number of unique entries in a set, in a single pass.
 ///
 /// Note: values must be pre-sorted.
 fn count_unique(src: &[f64]) -> usize {
-	let mut count = 0;
-	let mut last = f64::INFINITY;
-	for num in src {
-		if last.total_cmp(num).is_ne() {
-			count += 1;
-			last = *num;
-		}
+	if src.is_empty() { 0 }
+	else {
+		1 + src.windows(2)
+			.filter(|w| w[0].total_cmp(&w[1]).is_ne())
+			.count()
 	}
-	count
+}
+
+/// # Median (of Sorted Set).
+fn median_sorted(src: &[f64]) -> f64 {
+	let len = src.len();
+	if len == 0 { 0.0 }
+	else if len % 2 == 0 { (src[len / 2 - 1] + src[len / 2]) / 2.0 }
+	else { src[len / 2] }
+}
+
+/// # Median (of Unsorted Set).
+///
+/// This partially reorders the set to find the middle without having to sort
+/// the whole thing, which adds up for very large sets.
+fn median_unsorted(src: &mut [f64]) -> f64 {
+	let len = src.len();
+	if len == 0 { return 0.0; }
+
+	let (below, mid, _) = src.select_nth_unstable_by(len / 2, f64::total_cmp);
+	let mid = *mid;
+	if len % 2 == 0 {
+		let below = below.iter().copied().max_by(f64::total_cmp).unwrap_or(mid);
+		(below + mid) / 2.0
+	}
+	else { mid }
 }
 
 /// # Distance Above and Below.
@@ -362,6 +617,156 @@ mod tests {
 			5.0,
 		];
 		assert_eq!(count_unique(set), 5);
+		assert_eq!(count_unique(&[]), 0);
+		assert_eq!(count_unique(&[1.0]), 1);
+	}
+
+	#[test]
+	#[expect(clippy::float_cmp, reason = "It is what it is.")]
+	fn t_median() {
+		assert_eq!(median_sorted(&[]), 0.0);
+		assert_eq!(median_sorted(&[1.0, 2.0, 9.0]), 2.0);
+		assert_eq!(median_sorted(&[1.0, 2.0, 4.0, 9.0]), 3.0);
+		assert_eq!(median_unsorted(&mut []), 0.0);
+		assert_eq!(median_unsorted(&mut [9.0, 1.0, 2.0]), 2.0);
+		assert_eq!(median_unsorted(&mut [4.0, 9.0, 2.0, 1.0]), 3.0);
+
+		// The MAD of 1, 1, 2, 2, 4, 6, 9 is 1.
+		let nanos = Abacus::from(vec![1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0]);
+		assert_eq!(nanos.median(), 2.0);
+		assert_eq!(nanos.mad(2.0), 1.0);
+	}
+
+	#[test]
+	#[expect(clippy::float_cmp, reason = "It is what it is.")]
+	/// # MAD Pruning.
+	///
+	/// A tight cluster with a long, one-sided tail of stalls.
+	fn t_prune_mad() {
+		let mut set: Vec<f64> = (0..200).map(|i| f64::from(100 + i % 10)).collect();
+		set.extend_from_slice(&[130.0, 150.0, 200.0, 500.0, 1000.0, 5000.0]);
+
+		// The median is 105 and the MAD 3, so at k=5 everything from the
+		// cluster is kept and every stall dropped.
+		let mut nanos = Abacus::from(set.clone());
+		assert_eq!(nanos.outlier_bounds(OutlierPolicy::MAD), Some((90.0, 120.0)));
+		nanos.prune_outliers(OutlierPolicy::MAD);
+		assert_eq!(nanos.len(), 200);
+		assert_eq!(nanos.max(), 109.0);
+
+		// At k=12, the first stall (130) squeaks in.
+		let mut nanos = Abacus::from(set.clone());
+		nanos.prune_outliers(OutlierPolicy::Mad { k: 12.0 });
+		assert_eq!(nanos.len(), 201);
+		assert_eq!(nanos.max(), 130.0);
+
+		// The default IQR policy, by comparison, keeps some of the stalls.
+		let mut nanos = Abacus::from(set);
+		nanos.prune_outliers(OutlierPolicy::Default);
+		assert!(200 < nanos.len());
+
+		// Invalid multipliers fall back to 5.
+		let nanos = Abacus::from((0..200).map(|i| f64::from(100 + i % 10)).collect::<Vec<_>>());
+		assert_eq!(
+			nanos.outlier_bounds(OutlierPolicy::Mad { k: -1.0 }),
+			nanos.outlier_bounds(OutlierPolicy::MAD),
+		);
+
+		// A zero MAD disables pruning.
+		let mut set = vec![100.0; 200];
+		set.push(5000.0);
+		let nanos = Abacus::from(set);
+		assert_eq!(nanos.outlier_bounds(OutlierPolicy::MAD), None);
+	}
+
+	#[test]
+	fn t_drift() {
+		// A steady slowdown.
+		let ordered: Vec<f64> = (0..200).map(|i| if i < 100 { 100.0 } else { 110.0 }).collect();
+		let nanos = Abacus::from(ordered.clone());
+		assert!((nanos.drift(&ordered, OutlierPolicy::None) - 0.1).abs() < 1e-9, "Wrong drift.");
+
+		// And a speedup.
+		let ordered: Vec<f64> = ordered.into_iter().rev().collect();
+		let nanos = Abacus::from(ordered.clone());
+		assert!((nanos.drift(&ordered, OutlierPolicy::None) + 0.1 / 1.1).abs() < 1e-9, "Wrong drift.");
+
+		// A single stall isn't drift, so long as it's pruned.
+		let mut ordered: Vec<f64> = (0..200).map(|i| f64::from(100 + i % 10)).collect();
+		ordered.push(5000.0);
+		let nanos = Abacus::from(ordered.clone());
+		assert!(nanos.drift(&ordered, OutlierPolicy::MAD).abs() < 1e-9, "Stall counted as drift.");
+		assert!(0.4 < nanos.drift(&ordered, OutlierPolicy::None), "Stall not counted.");
+
+		// Nothing, nothing.
+		assert!(Abacus::from(Vec::<f64>::new()).drift(&[], OutlierPolicy::None).abs() < f64::EPSILON);
+	}
+
+	#[test]
+	#[expect(clippy::float_cmp, reason = "It is what it is.")]
+	fn t_trimmed_mean() {
+		let nanos = Abacus::from((1..=10).map(f64::from).collect::<Vec<_>>());
+		assert_eq!(nanos.trimmed_mean(0.0), 5.5);
+		assert_eq!(nanos.trimmed_mean(0.1), 5.5);
+		assert_eq!(nanos.trimmed_mean(0.15), 5.5); // Still just one.
+		assert_eq!(nanos.trimmed_mean(0.5), 5.5); // Median.
+
+		// The stall is ignored.
+		let nanos = Abacus::from(vec![1.0, 2.0, 3.0, 4.0, 100.0]);
+		assert_eq!(nanos.trimmed_mean(0.0), 22.0);
+		assert_eq!(nanos.trimmed_mean(0.2), 3.0);
+		assert_eq!(nanos.trimmed_mean(0.4), 3.0);
+
+		// Asymmetry matters.
+		let nanos = Abacus::from(vec![1.0, 1.0, 2.0, 2.0, 4.0, 6.0, 9.0, 13.0, 20.0, 50.0]);
+		assert_eq!(nanos.trimmed_mean(0.1), 7.125);
+		assert_eq!(nanos.trimmed_mean(0.2), 6.0);
+	}
+
+	#[test]
+	fn t_student_t_p() {
+		// Γ(5) = 24; Γ(0.5) = √π.
+		assert!((ln_gamma(5.0) - 24_f64.ln()).abs() < 1e-12);
+		assert!((ln_gamma(0.5) - std::f64::consts::PI.sqrt().ln()).abs() < 1e-12);
+
+		// I_0.5(a, a) = 0.5.
+		for a in [0.5, 2.0, 50.0, 1000.0] {
+			assert!((inc_beta(0.5, a, a) - 0.5).abs() < 1e-10, "I_0.5({a}, {a})");
+		}
+
+		// Reference values.
+		for (t, df, p) in [
+			(0.0, 100.0, 1.0),
+			(1.984_0, 100.0, 0.050_01),
+			(2.0, 100.0, 0.048_23),
+			(2.0, 10.0, 0.073_39),
+			(3.0, 2000.0, 0.002_73),
+			(-3.0, 2000.0, 0.002_73),
+			(10.0, 200.0, 0.0),
+		] {
+			let res = student_t_p(t, df);
+			assert!((res - p).abs() < 0.000_1, "p({t}, {df}) = {res}, expected {p}.");
+		}
+	}
+
+	#[test]
+	/// # Binary Searches.
+	///
+	/// Make sure the binary-search helpers agree with naive linear scans.
+	fn t_search() {
+		let nanos = Abacus::from(t_set());
+		for num in [0.5, 1.0, 1.5, 1.8, 1.9, 2.0, 2.05, 2.4, 3.0, 3.5] {
+			let below = t_set().iter().filter(|&&n| n < num).count();
+			let above = t_set().iter().filter(|&&n| n > num).count();
+			assert_eq!(nanos.count_below(num), below, "Below {num}.");
+			assert_eq!(nanos.count_above(num), above, "Above {num}.");
+
+			let down = t_set().iter().rev().copied().find(|&n| n < num);
+			let up = t_set().iter().copied().find(|&n| n > num);
+			let present = t_set().contains(&num);
+			assert_eq!(nanos.step_down(num), down.filter(|_| present), "Step down {num}.");
+			assert_eq!(nanos.step_up(num), up.filter(|_| present), "Step up {num}.");
+		}
 	}
 
 	#[test]
@@ -0,0 +1,52 @@
+/*!
+# Brunch: Output
+*/
+
+use std::io::IsTerminal;
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Output Target.
+///
+/// This enum is used to specify where the results summary should be printed,
+/// via [`Benches::with_output`](crate::Benches::with_output).
+///
+/// When unspecified, the target is determined by the `BRUNCH_STDOUT`
+/// environmental variable, defaulting to [`OutputTarget::Stderr`].
+///
+/// Note: progress and other incidental messages are always sent to `STDERR`.
+pub enum OutputTarget {
+	#[default]
+	/// # `STDERR`.
+	Stderr,
+
+	/// # `STDOUT`.
+	///
+	/// If `STDOUT` is not a terminal (i.e. it is being piped or redirected),
+	/// the summary will be printed without ANSI formatting.
+	Stdout,
+}
+
+impl OutputTarget {
+	/// # From Environment.
+	///
+	/// Return the target specified by the `BRUNCH_STDOUT` environmental
+	/// variable, if any, or the default.
+	pub(crate) fn from_env() -> Self {
+		if std::env::var("BRUNCH_STDOUT").is_ok_and(|s| s.trim() == "1") {
+			Self::Stdout
+		}
+		else { Self::Stderr }
+	}
+
+	/// # Color?
+	///
+	/// Returns true if the output should include ANSI formatting.
+	pub(crate) fn color(self) -> bool {
+		match self {
+			Self::Stderr => true,
+			Self::Stdout => std::io::stdout().is_terminal(),
+		}
+	}
+}
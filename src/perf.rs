@@ -0,0 +1,425 @@
+/*!
+# Brunch: Hardware Performance Counters
+*/
+
+use dactyl::total_cmp;
+
+
+
+/// # Minimum Instruction Change.
+///
+/// Instruction counts are far steadier than timings, but not perfectly so;
+/// smaller relative changes than this go unmentioned.
+const MIN_CHANGE: f64 = 0.005;
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Counter Snapshot.
+///
+/// This holds the values of the instruction and cycle counters at a given
+/// moment, or the difference between two such moments.
+pub(crate) struct Snapshot {
+	/// # Instructions Retired.
+	instructions: u64,
+
+	/// # CPU Cycles.
+	cycles: u64,
+}
+
+impl Snapshot {
+	/// # Now.
+	///
+	/// Return the current counts for the calling thread, or `None` if the
+	/// counters are unsupported or unavailable.
+	pub(crate) fn now() -> Option<Self> {
+		sys::read().map(|(instructions, cycles)| Self { instructions, cycles })
+	}
+
+	/// # Since.
+	///
+	/// Return the difference between this snapshot and an earlier one.
+	pub(crate) const fn since(self, start: Self) -> Self {
+		Self {
+			instructions: self.instructions.saturating_sub(start.instructions),
+			cycles: self.cycles.saturating_sub(start.cycles),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default)]
+/// # Counter Tally.
+///
+/// This adds up the per-sample snapshots collected over the course of a
+/// benchmark.
+pub(crate) struct Tally {
+	/// # Samples.
+	samples: u64,
+
+	/// # Totals.
+	total: Snapshot,
+}
+
+impl Tally {
+	/// # Push Sample.
+	pub(crate) const fn push(&mut self, v: Snapshot) {
+		self.samples = self.samples.saturating_add(1);
+		self.total.instructions = self.total.instructions.saturating_add(v.instructions);
+		self.total.cycles = self.total.cycles.saturating_add(v.cycles);
+	}
+
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	/// # Finish.
+	///
+	/// Return the per-sample averages, or `None` if nothing was tallied.
+	pub(crate) fn finish(self) -> Option<Counters> {
+		if self.samples == 0 { return None; }
+		let samples = self.samples as f64;
+		Some(Counters::new(
+			self.total.instructions as f64 / samples,
+			self.total.cycles as f64 / samples,
+		))
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// # Counters Per Iteration.
+///
+/// This holds the mean number of instructions retired and CPU cycles spent
+/// per benchmark iteration.
+pub(crate) struct Counters {
+	/// # Instructions.
+	instructions: f64,
+
+	/// # Cycles.
+	cycles: f64,
+}
+
+impl Counters {
+	/// # New.
+	pub(crate) const fn new(instructions: f64, cycles: f64) -> Self {
+		Self { instructions, cycles }
+	}
+
+	/// # Instructions.
+	pub(crate) const fn instructions(self) -> f64 { self.instructions }
+
+	/// # Cycles.
+	pub(crate) const fn cycles(self) -> f64 { self.cycles }
+
+	/// # Instructions Per Cycle.
+	pub(crate) fn ipc(self) -> Option<f64> {
+		let out = self.instructions / self.cycles;
+		if out.is_finite() && total_cmp!(out >= 0.0) { Some(out) }
+		else { None }
+	}
+
+	/// # Instruction Change.
+	///
+	/// Return the relative change in instructions from an older run, if
+	/// large enough to mention.
+	pub(crate) fn change(self, old: Self) -> Option<f64> {
+		let out = self.instructions / old.instructions - 1.0;
+		if out.is_finite() && total_cmp!((out.abs()) >= MIN_CHANGE) { Some(out) }
+		else { None }
+	}
+
+	/// # Nice Instructions.
+	pub(crate) fn nice_instructions(self) -> String {
+		crate::util::nice_count(self.instructions.round())
+	}
+
+	/// # Nice IPC.
+	pub(crate) fn nice_ipc(self) -> Option<String> {
+		self.ipc().map(|n| format!("{n:.2}"))
+	}
+}
+
+
+
+#[cfg(all(
+	feature = "perf",
+	any(target_os = "linux", target_os = "android"),
+	any(
+		target_arch = "aarch64",
+		target_arch = "arm",
+		target_arch = "loongarch64",
+		target_arch = "riscv64",
+		target_arch = "x86",
+		target_arch = "x86_64",
+	),
+))]
+/// # Linux.
+mod sys {
+	use std::{
+		cell::OnceCell,
+		ffi::{
+			c_int,
+			c_long,
+			c_ulong,
+		},
+		fs::File,
+		io::{
+			Error,
+			Read,
+		},
+		os::fd::{
+			AsRawFd,
+			FromRawFd,
+		},
+		sync::Once,
+	};
+
+	#[cfg(target_arch = "x86_64")]
+	/// # Syscall Number.
+	const SYS_PERF_EVENT_OPEN: c_long = 298;
+
+	#[cfg(target_arch = "x86")]
+	/// # Syscall Number.
+	const SYS_PERF_EVENT_OPEN: c_long = 336;
+
+	#[cfg(target_arch = "arm")]
+	/// # Syscall Number.
+	const SYS_PERF_EVENT_OPEN: c_long = 364;
+
+	#[cfg(any(target_arch = "aarch64", target_arch = "loongarch64", target_arch = "riscv64"))]
+	/// # Syscall Number.
+	const SYS_PERF_EVENT_OPEN: c_long = 241;
+
+	/// # Event Type: Hardware.
+	const PERF_TYPE_HARDWARE: u32 = 0;
+
+	/// # Hardware Event: CPU Cycles.
+	const PERF_COUNT_HW_CPU_CYCLES: u64 = 0;
+
+	/// # Hardware Event: Instructions.
+	const PERF_COUNT_HW_INSTRUCTIONS: u64 = 1;
+
+	/// # Read Format: Group.
+	///
+	/// Read every counter in the group at once.
+	const PERF_FORMAT_GROUP: u64 = 1 << 3;
+
+	/// # Flags: Exclude Kernel and Hypervisor.
+	///
+	/// Only the benchmark's own (user-space) work is counted, which is also
+	/// what unprivileged users are allowed to count under the default
+	/// `perf_event_paranoid` setting.
+	const EXCLUDE_KERNEL_HV: u64 = (1 << 5) | (1 << 6);
+
+	/// # Open Flag: Close on Exec.
+	const PERF_FLAG_FD_CLOEXEC: c_ulong = 1 << 3;
+
+	/// # Attribute Size.
+	///
+	/// This is `PERF_ATTR_SIZE_VER5`, which every kernel since 4.1
+	/// understands.
+	const ATTR_SIZE: u32 = 112;
+
+	/// # Sanity Check.
+	const _: () = assert!(size_of::<Attr>() == ATTR_SIZE as usize, "Bug: bad attribute size.");
+
+	#[repr(C)]
+	/// # Event Attributes.
+	///
+	/// This is the fixed-size prefix of `perf_event_attr` we care about,
+	/// padded out to `PERF_ATTR_SIZE_VER5`; the remaining fields are zeroed.
+	struct Attr {
+		/// # Type.
+		kind: u32,
+
+		/// # Struct Size.
+		size: u32,
+
+		/// # Config.
+		config: u64,
+
+		/// # Sample Period.
+		sample_period: u64,
+
+		/// # Sample Type.
+		sample_type: u64,
+
+		/// # Read Format.
+		read_format: u64,
+
+		/// # Flags (Bitfield).
+		flags: u64,
+
+		/// # Everything Else.
+		rest: [u64; 8],
+	}
+
+	extern "C" {
+		/// # Syscall.
+		fn syscall(num: c_long, ...) -> c_long;
+	}
+
+	/// # Counter Group.
+	///
+	/// The instruction counter leads, the cycle counter follows, and both
+	/// are read together. They start counting as soon as they're opened,
+	/// which is fine since only the differences matter.
+	struct Group {
+		/// # Instructions (Leader).
+		leader: File,
+
+		/// # Cycles.
+		_cycles: File,
+	}
+
+	impl Group {
+		/// # Open.
+		fn open() -> Result<Self, Error> {
+			let leader = open(PERF_COUNT_HW_INSTRUCTIONS, -1)?;
+			let cycles = open(PERF_COUNT_HW_CPU_CYCLES, leader.as_raw_fd())?;
+			Ok(Self { leader, _cycles: cycles })
+		}
+
+		/// # Read.
+		///
+		/// Return the current instruction and cycle counts.
+		fn read(&self) -> Option<(u64, u64)> {
+			// The group format is the number of counters, followed by each
+			// value.
+			let mut buf = [0_u8; 24];
+			(&self.leader).read_exact(&mut buf).ok()?;
+			let (nr, rest) = buf.split_first_chunk::<8>()?;
+			let (a, rest) = rest.split_first_chunk::<8>()?;
+			let (b, _) = rest.split_first_chunk::<8>()?;
+			if u64::from_ne_bytes(*nr) == 2 {
+				Some((u64::from_ne_bytes(*a), u64::from_ne_bytes(*b)))
+			}
+			else { None }
+		}
+	}
+
+	thread_local! {
+		/// # Counters.
+		///
+		/// These are opened the first time they're needed, per thread.
+		static GROUP: OnceCell<Option<Group>> = const { OnceCell::new() };
+	}
+
+	/// # Read.
+	///
+	/// Return the calling thread's instruction and cycle counts, opening the
+	/// counters if this is the first go. If that fails — usually because of
+	/// `perf_event_paranoid` — a single warning is printed and `None` is
+	/// returned from then on.
+	pub(super) fn read() -> Option<(u64, u64)> {
+		/// # Once.
+		static WARN: Once = Once::new();
+
+		GROUP.with(|g| g.get_or_init(|| match Group::open() {
+			Ok(g) => Some(g),
+			Err(e) => {
+				WARN.call_once(|| eprintln!(
+					"\x1b[1;93mWarning:\x1b[0m Hardware performance counters are unavailable ({e}); check \x1b[1m/proc/sys/kernel/perf_event_paranoid\x1b[0m. Falling back to plain timing.",
+				));
+				None
+			},
+		}).as_ref().and_then(Group::read))
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Open Counter.
+	///
+	/// Open a counter for the calling thread, on any CPU, joining the given
+	/// group (or starting a new one if `-1`).
+	fn open(config: u64, group: c_int) -> Result<File, Error> {
+		let attr = Attr {
+			kind: PERF_TYPE_HARDWARE,
+			size: ATTR_SIZE,
+			config,
+			sample_period: 0,
+			sample_type: 0,
+			read_format: PERF_FORMAT_GROUP,
+			flags: EXCLUDE_KERNEL_HV,
+			rest: [0; 8],
+		};
+
+		// A PID of zero means the calling thread, and a CPU of -1 means any.
+		let (pid, cpu): (c_int, c_int) = (0, -1);
+
+		// Safety: the attributes match the C layout, and the other arguments
+		// are plain integers.
+		let fd = unsafe {
+			syscall(SYS_PERF_EVENT_OPEN, &raw const attr, pid, cpu, group, PERF_FLAG_FD_CLOEXEC)
+		};
+		let fd = c_int::try_from(fd).map_err(|_| Error::other("Invalid file descriptor."))?;
+		if fd < 0 { Err(Error::last_os_error()) }
+		// Safety: the descriptor is valid and owned by nobody else.
+		else { Ok(unsafe { File::from_raw_fd(fd) }) }
+	}
+}
+
+#[cfg(not(all(
+	feature = "perf",
+	any(target_os = "linux", target_os = "android"),
+	any(
+		target_arch = "aarch64",
+		target_arch = "arm",
+		target_arch = "loongarch64",
+		target_arch = "riscv64",
+		target_arch = "x86",
+		target_arch = "x86_64",
+	),
+)))]
+/// # Other.
+mod sys {
+	/// # Read (Unsupported).
+	pub(super) const fn read() -> Option<(u64, u64)> { None }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_tally() {
+		let mut tally = Tally::default();
+		assert!(tally.finish().is_none(), "Nothing tallied.");
+
+		tally.push(Snapshot { instructions: 1000, cycles: 500 });
+		tally.push(Snapshot { instructions: 1200, cycles: 700 });
+		let perf = tally.finish().expect("Missing counters.");
+		assert_eq!(perf, Counters::new(1100.0, 600.0));
+		assert_eq!(perf.nice_instructions(), "1,100");
+		assert_eq!(perf.nice_ipc().as_deref(), Some("1.83"));
+		assert!(Counters::new(1.0, 0.0).ipc().is_none(), "Division by zero.");
+
+		// Differences.
+		let a = Snapshot { instructions: 5, cycles: 10 };
+		let b = Snapshot { instructions: 8, cycles: 12 };
+		assert_eq!(b.since(a), Snapshot { instructions: 3, cycles: 2 });
+	}
+
+	#[test]
+	fn t_change() {
+		let old = Counters::new(1000.0, 500.0);
+		assert!(Counters::new(1002.0, 900.0).change(old).is_none(), "Too small.");
+		let diff = Counters::new(1018.0, 500.0).change(old).expect("Missing change.");
+		assert!((diff - 0.018).abs() < 0.000_001, "Wrong change: {diff}");
+		let diff = Counters::new(900.0, 500.0).change(old).expect("Missing change.");
+		assert!((diff + 0.1).abs() < 0.000_001, "Wrong change: {diff}");
+		assert!(Counters::new(1.0, 1.0).change(Counters::default()).is_none(), "Infinity.");
+	}
+
+	#[test]
+	fn t_snapshot() {
+		// Whether or not the counters are available, this shouldn't crash.
+		if let Some(start) = Snapshot::now() {
+			let mut n = 0_u64;
+			for i in 0..10_000_u64 { n = std::hint::black_box(n.wrapping_add(i)); }
+			let end = Snapshot::now().expect("Counters disappeared.");
+			assert!(10_000 <= end.since(start).instructions, "Too few instructions.");
+		}
+	}
+}
@@ -0,0 +1,298 @@
+/*!
+# Brunch: Distribution Plots
+*/
+
+use crate::{
+	raw,
+	Stats,
+	util,
+};
+use dactyl::NiceU32;
+use std::{
+	fmt::Write,
+	path::{
+		Path,
+		PathBuf,
+	},
+};
+
+
+
+/// # Number of Histogram Bins.
+const BINS: u32 = 48;
+
+/// # Image Width.
+const WIDTH: f64 = 640.0;
+
+/// # Image Height.
+const HEIGHT: f64 = 320.0;
+
+/// # Horizontal Margin.
+const MARGIN_X: f64 = 24.0;
+
+/// # Top Margin.
+const MARGIN_TOP: f64 = 56.0;
+
+/// # Bottom Margin.
+const MARGIN_BOTTOM: f64 = 40.0;
+
+/// # Histogram Color.
+const COLOR_FILL: &str = "#3b82f6";
+
+/// # Mean Color.
+const COLOR_MEAN: &str = "#111827";
+
+/// # Baseline Color.
+const COLOR_BASELINE: &str = "#dc2626";
+
+
+
+/// # Directory (From Environment).
+///
+/// Return the directory plots should be written to, per the `BRUNCH_PLOTS`
+/// environmental variable, if any.
+pub(crate) fn dir() -> Option<PathBuf> {
+	std::env::var_os("BRUNCH_PLOTS")
+		.filter(|p| ! p.is_empty())
+		.map(PathBuf::from)
+}
+
+/// # Write Plot.
+///
+/// Write a self-contained SVG plot of the (pruned, sorted) samples — in
+/// seconds — to `<dir>/<name>.svg`. Any existing file is overwritten.
+///
+/// ## Errors
+///
+/// Any I/O errors are passed through.
+pub(crate) fn write(
+	dir: &Path,
+	name: &str,
+	samples: &[f64],
+	stats: Stats,
+	baseline: Option<Stats>,
+) -> std::io::Result<()> {
+	std::fs::create_dir_all(dir)?;
+	let mut file = raw::file_stem(name);
+	file.push_str(".svg");
+	std::fs::write(dir.join(file), render(name, samples, stats, baseline))
+}
+
+/// # Render.
+///
+/// Draw a histogram of the samples as a filled polygon, with a solid line at
+/// the mean, dotted lines at ±σ, and — if there's a baseline — a dashed line
+/// at the previous mean.
+fn render(name: &str, samples: &[f64], stats: Stats, baseline: Option<Stats>) -> String {
+	let mean = stats.mean().as_secs_f64();
+	let sigma = stats.deviation().as_secs_f64();
+	let old = baseline.map(|b| b.mean().as_secs_f64());
+
+	// The range needs to cover the markers as well as the samples.
+	let mut lo = (mean - sigma).max(0.0);
+	let mut hi = mean + sigma;
+	for v in samples.first().into_iter().chain(samples.last()).copied().chain(old) {
+		lo = lo.min(v);
+		hi = hi.max(v);
+	}
+	let pad = (hi - lo).max(mean * 0.01).max(f64::EPSILON) * 0.05;
+	lo = (lo - pad).max(0.0);
+	hi += pad;
+
+	let bins = histogram(samples, lo, hi);
+	let peak = bins.iter().copied().max().unwrap_or(0).max(1);
+
+	// Coordinate helpers.
+	let plot_w = MARGIN_X.mul_add(-2.0, WIDTH);
+	let plot_h = HEIGHT - MARGIN_TOP - MARGIN_BOTTOM;
+	let bottom = HEIGHT - MARGIN_BOTTOM;
+	let x = |v: f64| ((v - lo) / (hi - lo)).mul_add(plot_w, MARGIN_X);
+	let y = |n: u32| (-f64::from(n) / f64::from(peak)).mul_add(plot_h, bottom);
+	let bin_w = plot_w / f64::from(BINS);
+
+	let mut out = String::with_capacity(4096);
+	let _res = writeln!(
+		out,
+		r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}" font-family="sans-serif" font-size="12">"#,
+	);
+	let _res = writeln!(out, r##"<rect width="100%" height="100%" fill="#ffffff"/>"##);
+
+	// Title and summary.
+	let _res = writeln!(
+		out,
+		r#"<text x="{MARGIN_X}" y="20" font-size="14" font-weight="bold">{}</text>"#,
+		escape(name),
+	);
+	let mut summary = format!(
+		"mean {} \u{b1} {} ({} samples)",
+		util::nice_time(mean),
+		util::nice_time(sigma),
+		NiceU32::from(stats.valid()),
+	);
+	if let Some(old) = old {
+		summary.push_str("; previous ");
+		summary.push_str(&util::nice_time(old));
+	}
+	let _res = writeln!(
+		out,
+		r##"<text x="{MARGIN_X}" y="38" fill="#6b7280">{}</text>"##,
+		escape(&summary),
+	);
+
+	// The histogram.
+	out.push_str("<polygon fill=\"");
+	out.push_str(COLOR_FILL);
+	out.push_str("\" fill-opacity=\"0.35\" stroke=\"");
+	out.push_str(COLOR_FILL);
+	out.push_str("\" points=\"");
+	let _res = write!(out, "{MARGIN_X:.1},{bottom:.1}");
+	let mut left = MARGIN_X;
+	for n in bins {
+		let top = y(n);
+		let right = left + bin_w;
+		let _res = write!(out, " {left:.1},{top:.1} {right:.1},{top:.1}");
+		left = right;
+	}
+	let _res = writeln!(out, " {left:.1},{bottom:.1}\"/>");
+
+	// The axis.
+	let _res = writeln!(
+		out,
+		r##"<line x1="{MARGIN_X}" y1="{bottom}" x2="{}" y2="{bottom}" stroke="#9ca3af"/>"##,
+		WIDTH - MARGIN_X,
+	);
+	let _res = writeln!(
+		out,
+		r##"<text x="{MARGIN_X}" y="{}" fill="#6b7280">{}</text>"##,
+		bottom + 18.0,
+		escape(&util::nice_time(lo)),
+	);
+	let _res = writeln!(
+		out,
+		r##"<text x="{}" y="{}" fill="#6b7280" text-anchor="end">{}</text>"##,
+		WIDTH - MARGIN_X,
+		bottom + 18.0,
+		escape(&util::nice_time(hi)),
+	);
+
+	// The markers.
+	let marker = |out: &mut String, v: f64, color: &str, dash: Option<&str>| {
+		let v = x(v);
+		let _res = write!(
+			out,
+			r#"<line x1="{v:.1}" y1="{MARGIN_TOP}" x2="{v:.1}" y2="{bottom}" stroke="{color}" stroke-width="1.5""#,
+		);
+		if let Some(dash) = dash {
+			let _res = write!(out, r#" stroke-dasharray="{dash}""#);
+		}
+		out.push_str("/>\n");
+	};
+	if 0.0 < sigma {
+		marker(&mut out, mean - sigma, COLOR_MEAN, Some("2,3"));
+		marker(&mut out, mean + sigma, COLOR_MEAN, Some("2,3"));
+	}
+	marker(&mut out, mean, COLOR_MEAN, None);
+	if let Some(old) = old { marker(&mut out, old, COLOR_BASELINE, Some("6,4")); }
+
+	out.push_str("</svg>\n");
+	out
+}
+
+#[expect(
+	clippy::cast_possible_truncation,
+	clippy::cast_sign_loss,
+	reason = "The index is clamped.",
+)]
+/// # Histogram.
+///
+/// Count the samples falling into each of `BINS` equal-width bins spanning
+/// `lo..=hi`.
+fn histogram(samples: &[f64], lo: f64, hi: f64) -> Vec<u32> {
+	let mut out = vec![0_u32; BINS as usize];
+	let span = hi - lo;
+	if span <= 0.0 { return out; }
+	let max = f64::from(BINS - 1);
+	for v in samples {
+		let idx = ((v - lo) / span * f64::from(BINS)).floor().clamp(0.0, max);
+		out[idx as usize] += 1;
+	}
+	out
+}
+
+/// # Escape.
+///
+/// Escape text for inclusion in the SVG.
+fn escape(src: &str) -> String {
+	let mut out = String::with_capacity(src.len());
+	for c in src.chars() {
+		match c {
+			'&' => out.push_str("&amp;"),
+			'<' => out.push_str("&lt;"),
+			'>' => out.push_str("&gt;"),
+			'"' => out.push_str("&quot;"),
+			c => out.push(c),
+		}
+	}
+	out
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	#[test]
+	fn t_render() {
+		let samples: Vec<f64> = (0..1000_u32)
+			.map(|i| f64::from(1000 + i % 50) / 1_000_000_000.0)
+			.collect();
+		let stats = Stats::try_from(
+			samples.iter().map(|s| Duration::from_secs_f64(*s)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+
+		// Without history.
+		let svg = render("foo::<u8>()", &samples, stats, None);
+		assert!(svg.starts_with("<svg "), "Not an SVG.");
+		assert!(svg.ends_with("</svg>\n"), "Incomplete SVG.");
+		assert!(svg.contains("foo::&lt;u8&gt;()"), "Name not escaped.");
+		assert!(svg.contains("<polygon "), "Missing histogram.");
+		assert!(! svg.contains(COLOR_BASELINE), "Unexpected baseline.");
+
+		// With history.
+		let old = Stats::try_from(vec![Duration::from_nanos(900); 1000])
+			.expect("Invalid stats.");
+		let svg = render("foo::<u8>()", &samples, stats, Some(old));
+		assert!(svg.contains(COLOR_BASELINE), "Missing baseline.");
+		assert!(svg.contains("stroke-dasharray=\"6,4\""), "Baseline should be dashed.");
+		assert!(svg.contains("previous 900"), "Missing previous mean.");
+	}
+
+	#[test]
+	fn t_histogram() {
+		let bins = histogram(&[0.0, 0.5, 1.0, 1.0], 0.0, 1.0);
+		assert_eq!(bins.len(), BINS as usize);
+		assert_eq!(bins.iter().sum::<u32>(), 4);
+		assert_eq!(bins[0], 1);
+		assert_eq!(bins[BINS as usize / 2], 1);
+		assert_eq!(bins[BINS as usize - 1], 2);
+
+		// Degenerate ranges are left empty.
+		assert!(histogram(&[1.0], 1.0, 1.0).iter().all(|n| *n == 0));
+	}
+
+	#[test]
+	fn t_write() {
+		let dir = std::env::temp_dir().join("__brunch-test-plot");
+		let _res = std::fs::remove_dir_all(&dir);
+
+		let stats = Stats::try_from(vec![Duration::from_micros(1); 1000])
+			.expect("Invalid stats.");
+		write(&dir, "foo::bar(10)", &[0.000_001; 1000], stats, None).expect("Write failed.");
+		let svg = std::fs::read_to_string(dir.join("foo__bar_10_.svg"))
+			.expect("Missing plot.");
+		let _res = std::fs::remove_dir_all(&dir);
+		assert!(svg.contains("foo::bar(10)"), "Missing name.");
+	}
+}
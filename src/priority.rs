@@ -0,0 +1,382 @@
+/*!
+# Brunch: Thread Priority
+*/
+
+use std::{
+	fmt,
+	io::Error,
+};
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Thread Priority.
+///
+/// This controls the scheduling priority of the benchmarking thread while
+/// sampling. See [`Benches::with_priority`](crate::Benches::with_priority)
+/// for usage and requirements.
+pub enum Priority {
+	#[default]
+	/// # Normal.
+	///
+	/// Leave the priority alone.
+	Normal,
+
+	/// # High.
+	///
+	/// The lowest niceness (`-20`) on Linux, or `THREAD_PRIORITY_HIGHEST`
+	/// on Windows.
+	High,
+
+	/// # Realtime.
+	///
+	/// `SCHED_FIFO` scheduling on Linux, or `THREAD_PRIORITY_TIME_CRITICAL`
+	/// on Windows.
+	Realtime,
+}
+
+impl fmt::Display for Priority {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(match self {
+			Self::Normal => "normal priority",
+			Self::High => "high priority",
+			Self::Realtime => "realtime scheduling",
+		})
+	}
+}
+
+impl Priority {
+	/// # From Environment.
+	///
+	/// Return the priority specified by the `BRUNCH_PRIORITY` environmental
+	/// variable, if any.
+	pub(crate) fn from_env() -> Option<Self> {
+		std::env::var("BRUNCH_PRIORITY").ok().and_then(|s| Self::parse(&s))
+	}
+
+	/// # Parse.
+	fn parse(src: &str) -> Option<Self> {
+		match src.trim().to_ascii_lowercase().as_str() {
+			"normal" => Some(Self::Normal),
+			"high" => Some(Self::High),
+			"realtime" | "rt" => Some(Self::Realtime),
+			_ => None,
+		}
+	}
+}
+
+
+
+#[derive(Debug)]
+/// # Priority Guard.
+///
+/// The original priority is restored when this is dropped — including
+/// during unwinding — so a panicking benchmark can't leave the thread
+/// hogging the CPU.
+pub(crate) struct Guard(sys::Saved);
+
+impl Drop for Guard {
+	fn drop(&mut self) { sys::restore(&self.0); }
+}
+
+/// # Raise Priority.
+///
+/// Raise the current thread's priority to the given level, returning a guard
+/// that restores the original when dropped.
+///
+/// ## Errors
+///
+/// This will return an error if the OS refuses — usually for want of
+/// permission — or changing priority isn't supported on the platform.
+pub(crate) fn raise(priority: Priority) -> Result<Guard, Error> {
+	sys::raise(priority).map(Guard)
+}
+
+
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+/// # Linux.
+mod sys {
+	use std::ffi::{
+		c_int,
+		c_long,
+		c_uint,
+	};
+	use super::{
+		Error,
+		Priority,
+	};
+
+	/// # Process Priority (i.e. `PRIO_PROCESS`).
+	///
+	/// On Linux, this targets the calling thread when paired with an ID of
+	/// zero.
+	const PRIO_PROCESS: c_int = 0;
+
+	/// # Highest Niceness.
+	const NICE_HIGH: c_int = -20;
+
+	/// # FIFO Scheduling Policy.
+	const SCHED_FIFO: c_int = 1;
+
+	#[repr(C)]
+	#[derive(Debug, Clone, Copy, Default)]
+	/// # Scheduling Parameters.
+	///
+	/// Only the priority matters, but some libcs pad the struct out, so we
+	/// leave plenty of room.
+	pub(super) struct SchedParam {
+		/// # Priority.
+		priority: c_int,
+
+		/// # Reserved.
+		reserved: [c_long; 8],
+	}
+
+	#[derive(Debug)]
+	/// # Saved State.
+	pub(super) enum Saved {
+		/// # Nothing Changed.
+		Unchanged,
+
+		/// # Original Niceness.
+		Nice(c_int),
+
+		/// # Original Policy and Parameters.
+		Sched(c_int, SchedParam),
+	}
+
+	extern "C" {
+		/// # Get Niceness.
+		fn getpriority(which: c_int, who: c_uint) -> c_int;
+
+		/// # Set Niceness.
+		fn setpriority(which: c_int, who: c_uint, prio: c_int) -> c_int;
+
+		/// # Get Scheduling Policy.
+		fn sched_getscheduler(pid: c_int) -> c_int;
+
+		/// # Get Scheduling Parameters.
+		fn sched_getparam(pid: c_int, param: *mut SchedParam) -> c_int;
+
+		/// # Set Scheduling Policy and Parameters.
+		fn sched_setscheduler(pid: c_int, policy: c_int, param: *const SchedParam) -> c_int;
+
+		/// # Maximum Priority for Policy.
+		fn sched_get_priority_max(policy: c_int) -> c_int;
+
+		/// # Minimum Priority for Policy.
+		fn sched_get_priority_min(policy: c_int) -> c_int;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Raise.
+	pub(super) fn raise(priority: Priority) -> Result<Saved, Error> {
+		match priority {
+			Priority::Normal => Ok(Saved::Unchanged),
+			Priority::High => {
+				// Safety: an ID of zero means the calling thread.
+				let old = unsafe { getpriority(PRIO_PROCESS, 0) };
+
+				// Safety: as above.
+				if 0 == unsafe { setpriority(PRIO_PROCESS, 0, NICE_HIGH) } {
+					Ok(Saved::Nice(old))
+				}
+				else { Err(Error::last_os_error()) }
+			},
+			Priority::Realtime => {
+				// Safety: a PID of zero means the calling thread.
+				let policy = unsafe { sched_getscheduler(0) };
+				if policy < 0 { return Err(Error::last_os_error()); }
+
+				let mut old = SchedParam::default();
+				// Safety: the struct is at least as large as the C one.
+				if 0 != unsafe { sched_getparam(0, &raw mut old) } {
+					return Err(Error::last_os_error());
+				}
+
+				// Split the difference; the very top is best left to the
+				// kernel's own threads.
+				// Safety: this just returns a number.
+				let max = unsafe { sched_get_priority_max(SCHED_FIFO) };
+				// Safety: this just returns a number.
+				let min = unsafe { sched_get_priority_min(SCHED_FIFO) };
+				if max < 0 || min < 0 { return Err(Error::last_os_error()); }
+				let new = SchedParam { priority: min + (max - min) / 2, ..SchedParam::default() };
+
+				// Safety: as above.
+				if 0 == unsafe { sched_setscheduler(0, SCHED_FIFO, &raw const new) } {
+					Ok(Saved::Sched(policy, old))
+				}
+				else { Err(Error::last_os_error()) }
+			},
+		}
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Restore.
+	///
+	/// Lowering the priority is always allowed, so errors are ignored.
+	pub(super) fn restore(saved: &Saved) {
+		match saved {
+			Saved::Unchanged => {},
+			Saved::Nice(old) => {
+				// Safety: an ID of zero means the calling thread.
+				unsafe { setpriority(PRIO_PROCESS, 0, *old); }
+			},
+			Saved::Sched(policy, old) => {
+				// Safety: a PID of zero means the calling thread, and the
+				// struct came from the C side in the first place.
+				unsafe { sched_setscheduler(0, *policy, old); }
+			},
+		}
+	}
+
+	#[cfg(test)]
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Current Niceness.
+	pub(super) fn nice() -> c_int {
+		// Safety: an ID of zero means the calling thread.
+		unsafe { getpriority(PRIO_PROCESS, 0) }
+	}
+
+	#[cfg(test)]
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Current Policy.
+	pub(super) fn policy() -> Option<c_int> {
+		// Safety: a PID of zero means the calling thread.
+		let policy = unsafe { sched_getscheduler(0) };
+		if policy < 0 { None } else { Some(policy) }
+	}
+}
+
+#[cfg(windows)]
+/// # Windows.
+mod sys {
+	use std::ffi::c_void;
+	use super::{
+		Error,
+		Priority,
+	};
+
+	/// # Highest (Normal) Priority.
+	const THREAD_PRIORITY_HIGHEST: i32 = 2;
+
+	/// # Time-Critical Priority.
+	const THREAD_PRIORITY_TIME_CRITICAL: i32 = 15;
+
+	/// # Error Value.
+	const THREAD_PRIORITY_ERROR_RETURN: i32 = i32::MAX;
+
+	#[derive(Debug)]
+	/// # Saved State.
+	///
+	/// The original priority, if changed.
+	pub(super) struct Saved(Option<i32>);
+
+	#[link(name = "kernel32")]
+	extern "system" {
+		/// # Current Thread (Pseudo) Handle.
+		fn GetCurrentThread() -> *mut c_void;
+
+		/// # Get Priority.
+		fn GetThreadPriority(thread: *mut c_void) -> i32;
+
+		/// # Set Priority.
+		fn SetThreadPriority(thread: *mut c_void, priority: i32) -> i32;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Raise.
+	pub(super) fn raise(priority: Priority) -> Result<Saved, Error> {
+		let new = match priority {
+			Priority::Normal => return Ok(Saved(None)),
+			Priority::High => THREAD_PRIORITY_HIGHEST,
+			Priority::Realtime => THREAD_PRIORITY_TIME_CRITICAL,
+		};
+
+		// Safety: the pseudo handle is always valid for the calling thread.
+		let old = unsafe { GetThreadPriority(GetCurrentThread()) };
+		if old == THREAD_PRIORITY_ERROR_RETURN { return Err(Error::last_os_error()); }
+
+		// Safety: as above.
+		if 0 == unsafe { SetThreadPriority(GetCurrentThread(), new) } {
+			Err(Error::last_os_error())
+		}
+		else { Ok(Saved(Some(old))) }
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Restore.
+	pub(super) fn restore(saved: &Saved) {
+		if let Some(old) = saved.0 {
+			// Safety: the pseudo handle is always valid for the calling
+			// thread.
+			unsafe { SetThreadPriority(GetCurrentThread(), old); }
+		}
+	}
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", windows)))]
+/// # Other.
+mod sys {
+	use std::io::ErrorKind;
+	use super::{
+		Error,
+		Priority,
+	};
+
+	#[derive(Debug)]
+	/// # Saved State (Nothing).
+	pub(super) struct Saved;
+
+	/// # Raise (Unsupported).
+	pub(super) fn raise(priority: Priority) -> Result<Saved, Error> {
+		if matches!(priority, Priority::Normal) { Ok(Saved) }
+		else { Err(ErrorKind::Unsupported.into()) }
+	}
+
+	/// # Restore (Nothing).
+	pub(super) const fn restore(_saved: &Saved) {}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_parse() {
+		assert_eq!(Priority::parse("high"), Some(Priority::High));
+		assert_eq!(Priority::parse(" Realtime\n"), Some(Priority::Realtime));
+		assert_eq!(Priority::parse("rt"), Some(Priority::Realtime));
+		assert_eq!(Priority::parse("normal"), Some(Priority::Normal));
+		assert_eq!(Priority::parse("ludicrous"), None);
+	}
+
+	#[test]
+	fn t_raise() {
+		// Do this in a separate thread so as not to affect anything else.
+		std::thread::spawn(|| {
+			#[cfg(any(target_os = "linux", target_os = "android"))]
+			let (nice, policy) = (sys::nice(), sys::policy());
+
+			for p in [Priority::Normal, Priority::High, Priority::Realtime] {
+				// Raising may or may not be permitted, but either way, it
+				// shouldn't crash.
+				match raise(p) {
+					Ok(guard) => drop(guard),
+					Err(e) => assert_ne!(p, Priority::Normal, "Normal should always work: {e}"),
+				}
+
+				// Whatever happened should be undone.
+				#[cfg(any(target_os = "linux", target_os = "android"))]
+				{
+					assert_eq!(sys::nice(), nice, "Niceness not restored.");
+					assert_eq!(sys::policy(), policy, "Policy not restored.");
+				}
+			}
+		}).join().expect("Thread panicked.");
+	}
+}
@@ -0,0 +1,172 @@
+/*!
+# Brunch: Progress
+*/
+
+use dactyl::{
+	NiceU32,
+	traits::SaturatingFrom,
+};
+use std::{
+	io::{
+		IsTerminal,
+		Write,
+	},
+	time::{
+		Duration,
+		Instant,
+	},
+};
+
+
+
+/// # Redraw Interval.
+///
+/// When printing to a terminal, the status line is redrawn (at most) this
+/// often to keep the elapsed time current.
+const REDRAW: Duration = Duration::from_millis(100);
+
+
+
+#[derive(Debug)]
+/// # Progress.
+///
+/// This prints a status line to `STDERR` as each benchmark is run, like
+/// `[3/14] foo::bar(10) … 7.2s`.
+///
+/// When `STDERR` is a terminal, a single line is updated in place; otherwise
+/// a plain line is printed for each benchmark as it begins.
+pub(crate) struct Progress {
+	/// # Total Benchmarks.
+	total: u32,
+
+	/// # Current Benchmark (Index).
+	done: u32,
+
+	/// # Current Benchmark (Name).
+	name: String,
+
+	/// # Round (Current, Total).
+	///
+	/// This is only set when the suite is repeated.
+	round: Option<(u32, u32)>,
+
+	/// # Last Redraw.
+	last: Instant,
+
+	/// # Print to a Terminal?
+	tty: bool,
+
+	/// # Quiet.
+	///
+	/// When `true`, nothing is printed at all.
+	quiet: bool,
+}
+
+impl Progress {
+	/// # New.
+	pub(crate) fn new(total: usize) -> Self {
+		Self {
+			total: u32::saturating_from(total),
+			done: 0,
+			name: String::new(),
+			round: None,
+			last: Instant::now(),
+			tty: std::io::stderr().is_terminal(),
+			quiet: false,
+		}
+	}
+
+	/// # Quiet.
+	///
+	/// Keep the counts, but don't print anything.
+	pub(crate) const fn with_quiet(mut self, quiet: bool) -> Self {
+		self.quiet = quiet;
+		self
+	}
+
+	/// # Start Round.
+	///
+	/// Reset the count for a new round. This has no visible effect unless
+	/// there is more than one.
+	pub(crate) const fn round(&mut self, round: u32, total: u32) {
+		self.done = 0;
+		self.round = if 1 < total { Some((round, total)) } else { None };
+	}
+
+	/// # Start Benchmark.
+	///
+	/// Bump the count and print the name of the benchmark about to be run.
+	pub(crate) fn start(&mut self, name: &str) {
+		self.done += 1;
+		name.clone_into(&mut self.name);
+		if self.quiet { return; }
+
+		if self.tty { self.draw(Duration::ZERO); }
+		else {
+			eprintln!(
+				"[{}/{}] {}{}",
+				NiceU32::from(self.done),
+				NiceU32::from(self.total),
+				self.name,
+				self.round_label(),
+			);
+		}
+	}
+
+	/// # Resume Benchmark.
+	///
+	/// Switch back to a benchmark that was already started — i.e. when
+	/// interleaving — without bumping the count. Outside of terminals, this
+	/// is silent.
+	pub(crate) fn resume(&mut self, name: &str) {
+		name.clone_into(&mut self.name);
+		if self.tty && ! self.quiet { self.draw(Duration::ZERO); }
+	}
+
+	/// # Tick.
+	///
+	/// Redraw the status line with the current benchmark's elapsed time, if
+	/// printing to a terminal and enough time has passed since the last go.
+	pub(crate) fn tick(&mut self, elapsed: Duration) {
+		if self.tty && ! self.quiet && REDRAW <= self.last.elapsed() { self.draw(elapsed); }
+	}
+
+	/// # Finish.
+	///
+	/// Clear the status line, or in non-terminal contexts, add a line break
+	/// to separate the progress from whatever comes next.
+	pub(crate) fn finish(&self) {
+		if self.done == 0 || self.quiet { return; }
+		let mut handle = std::io::stderr().lock();
+		let _res =
+			if self.tty { handle.write_all(b"\r\x1b[2K") }
+			else { handle.write_all(b"\n") };
+		let _res = handle.flush();
+	}
+
+	/// # Draw.
+	fn draw(&mut self, elapsed: Duration) {
+		let mut handle = std::io::stderr().lock();
+		let _res = write!(
+			handle,
+			"\r\x1b[2K\x1b[2m[\x1b[0;1m{}\x1b[0;2m/{}]\x1b[0m \x1b[94m{}\x1b[0m\x1b[2m{} \u{2026} {:.1}s\x1b[0m",
+			NiceU32::from(self.done),
+			NiceU32::from(self.total),
+			self.name,
+			self.round_label(),
+			elapsed.as_secs_f64(),
+		).and_then(|()| handle.flush());
+		self.last = Instant::now();
+	}
+
+	/// # Round Label.
+	///
+	/// Return e.g. ` (round 2/3)`, or nothing if there's only one.
+	fn round_label(&self) -> String {
+		self.round.map_or_else(String::new, |(r, t)| format!(
+			" (round {}/{})",
+			NiceU32::from(r),
+			NiceU32::from(t),
+		))
+	}
+}
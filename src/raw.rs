@@ -0,0 +1,102 @@
+/*!
+# Brunch: Raw Samples
+*/
+
+use std::{
+	fmt::Write,
+	path::{
+		Path,
+		PathBuf,
+	},
+	time::Duration,
+};
+
+
+
+/// # Maximum File Stem Length.
+const MAX_STEM_LEN: usize = 64;
+
+
+
+/// # Directory (From Environment).
+///
+/// Return the directory raw samples should be written to, per the
+/// `BRUNCH_RAW` environmental variable, if any.
+pub(crate) fn dir() -> Option<PathBuf> {
+	std::env::var_os("BRUNCH_RAW")
+		.filter(|p| ! p.is_empty())
+		.map(PathBuf::from)
+}
+
+/// # Write Samples.
+///
+/// Write every sample — in whole nanoseconds, one per line — to
+/// `<dir>/<name>.txt`, preceded by a header line with the benchmark name and
+/// sample count. Any existing file is overwritten.
+///
+/// ## Errors
+///
+/// Any I/O errors are passed through.
+pub(crate) fn write(dir: &Path, name: &str, samples: &[Duration]) -> std::io::Result<()> {
+	std::fs::create_dir_all(dir)?;
+	std::fs::write(dir.join(file_name(name)), render(name, samples))
+}
+
+/// # File Name.
+fn file_name(name: &str) -> String {
+	let mut out = file_stem(name);
+	out.push_str(".txt");
+	out
+}
+
+/// # File Stem.
+///
+/// Make the name safe for use as a file name: anything other than ASCII
+/// alphanumerics, dashes, dots, and underscores is replaced with an
+/// underscore, and the stem is capped at 64 characters.
+pub(crate) fn file_stem(name: &str) -> String {
+	name.chars()
+		.map(|c| if c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_') { c } else { '_' })
+		.take(MAX_STEM_LEN)
+		.collect()
+}
+
+/// # Render.
+fn render(name: &str, samples: &[Duration]) -> String {
+	let mut out = String::with_capacity(samples.len() * 8 + name.len() + 32);
+	let _res = writeln!(out, "# {} ({} samples, ns)", name.replace('\n', " "), samples.len());
+	for s in samples {
+		let _res = writeln!(out, "{}", s.as_nanos());
+	}
+	out
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_write() {
+		let dir = std::env::temp_dir().join("__brunch-test-raw");
+		let _res = std::fs::remove_dir_all(&dir);
+
+		// Existing files should be overwritten.
+		let samples = [Duration::from_nanos(1200), Duration::from_micros(3)];
+		write(&dir, "foo::bar(\"baz\")", &samples[..1]).expect("Write failed.");
+		write(&dir, "foo::bar(\"baz\")", &samples).expect("Write failed.");
+
+		let raw = std::fs::read_to_string(dir.join("foo__bar__baz__.txt"))
+			.expect("Missing raw samples.");
+		let _res = std::fs::remove_dir_all(&dir);
+		assert_eq!(raw, "# foo::bar(\"baz\") (2 samples, ns)\n1200\n3000\n");
+	}
+
+	#[test]
+	fn t_file_name() {
+		assert_eq!(file_name("Vec::<u8>::new()"), "Vec___u8___new__.txt");
+		assert_eq!(file_name("a/b c.d-e_f"), "a_b_c.d-e_f.txt");
+		assert_eq!(file_name(&"x".repeat(100)).len(), MAX_STEM_LEN + 4);
+	}
+}
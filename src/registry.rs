@@ -0,0 +1,60 @@
+/*!
+# Brunch: Registry
+*/
+
+use crate::Bench;
+use std::sync::Mutex;
+
+
+
+/// # Bench Factory.
+type Maker = fn() -> Bench<'static>;
+
+/// # Registered Benches.
+///
+/// This is populated before `main` by the code generated by
+/// [`bench`](crate::bench).
+static REGISTRY: Mutex<Vec<Maker>> = Mutex::new(Vec::new());
+
+
+
+#[doc(hidden)]
+/// # Register Bench.
+///
+/// This is called by the code generated by [`bench`](crate::bench); it isn't
+/// meant to be called directly.
+pub fn register_bench(maker: Maker) {
+	if let Ok(mut set) = REGISTRY.lock() { set.push(maker); }
+}
+
+#[doc(hidden)]
+#[must_use]
+/// # Registered Benches.
+///
+/// Build and return all registered benches, sorted by name so the order
+/// doesn't depend on the whims of the linker.
+pub fn registered_benches() -> Vec<Bench<'static>> {
+	let makers: Vec<Maker> = REGISTRY.lock().map(|set| set.clone()).unwrap_or_default();
+	let mut out: Vec<Bench<'static>> = makers.into_iter().map(|m| m()).collect();
+	out.sort_by(|a, b| a.name().cmp(b.name()));
+	out
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_registered() {
+		register_bench(|| Bench::new("b::two()").run(|| 2_u8));
+		register_bench(|| Bench::new("a::one()").run(|| 1_u8));
+		register_bench(|| Bench::new("c::three()").run(|| 3_u8));
+
+		let names: Vec<String> = registered_benches().iter()
+			.map(|b| b.name().to_owned())
+			.collect();
+		assert_eq!(names, ["a::one()", "b::two()", "c::three()"]);
+	}
+}
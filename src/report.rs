@@ -0,0 +1,197 @@
+/*!
+# Brunch: Report
+*/
+
+use crate::{
+	BenchResult,
+	BrunchError,
+	Stats,
+	util,
+};
+
+
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// # Run Report.
+///
+/// This is an owned, data-only snapshot of a run's results — the name,
+/// outcome, and baseline of each benchmark — suitable for programmatic
+/// interchange. With the `serde` crate feature enabled, it can be serialized
+/// to (and deserialized from) any `serde`-compatible format.
+///
+/// It can be obtained via [`Benches::report`](crate::Benches::report) or
+/// [`Summary::report`](crate::Summary::report).
+///
+/// Note: this is not a replacement for the history file, which remains the
+/// canonical on-disk record.
+pub struct RunReport {
+	/// # Benchmarks.
+	benches: Vec<BenchReport>,
+}
+
+impl From<&[BenchResult]> for RunReport {
+	fn from(src: &[BenchResult]) -> Self {
+		Self {
+			benches: src.iter().map(BenchReport::from).collect(),
+		}
+	}
+}
+
+impl RunReport {
+	#[must_use]
+	/// # Benchmarks.
+	///
+	/// Return the report for each benchmark, in order.
+	pub fn benches(&self) -> &[BenchReport] { &self.benches }
+}
+
+
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// # Benchmark Report.
+///
+/// This holds the name, outcome, and baseline of a single benchmark, as part
+/// of a [`RunReport`].
+pub struct BenchReport {
+	/// # Benchmark Name.
+	name: String,
+
+	/// # Outcome.
+	outcome: BenchOutcome,
+
+	/// # Previous Stats.
+	baseline: Option<Stats>,
+
+	/// # `Err` Samples.
+	#[cfg_attr(feature = "serde", serde(default))]
+	errors: u32,
+}
+
+impl From<&BenchResult> for BenchReport {
+	fn from(src: &BenchResult) -> Self {
+		Self {
+			name: src.name().to_owned(),
+			outcome: src.skipped().map_or_else(
+				|| BenchOutcome::from(src.stats()),
+				|reason| BenchOutcome::Skipped(reason.to_owned()),
+			),
+			baseline: src.baseline(),
+			errors: src.errors(),
+		}
+	}
+}
+
+impl BenchReport {
+	#[must_use]
+	/// # Name.
+	///
+	/// Return the benchmark name.
+	pub fn name(&self) -> &str { &self.name }
+
+	#[must_use]
+	/// # Outcome.
+	///
+	/// Return the stats collected for the benchmark, the reason it was
+	/// skipped, or the error that prevented the stats' collection.
+	pub const fn outcome(&self) -> &BenchOutcome { &self.outcome }
+
+	#[must_use]
+	/// # Baseline.
+	///
+	/// Return the pooled stats from the previous runs, if history was
+	/// available.
+	pub const fn baseline(&self) -> Option<Stats> { self.baseline }
+
+	#[must_use]
+	/// # `Err` Samples.
+	///
+	/// Return the number of samples for which a fallible callback returned
+	/// `Err`. See [`BenchResult::errors`](crate::BenchResult::errors).
+	pub const fn errors(&self) -> u32 { self.errors }
+}
+
+
+
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// # Benchmark Outcome.
+///
+/// This mirrors the `Result<Stats, BrunchError>` of a
+/// [`BenchResult`](crate::BenchResult), except skips are called out
+/// separately, and errors are reduced to their variant name and (plain-text)
+/// message so that they can be stored and passed around freely.
+pub enum BenchOutcome {
+	/// # Success.
+	Ok(Stats),
+
+	/// # Skipped.
+	///
+	/// The benchmark was skipped for the given reason. See
+	/// [`Bench::skip`](crate::Bench::skip).
+	Skipped(String),
+
+	/// # Failure.
+	Err {
+		/// # Kind.
+		///
+		/// The [`BrunchError`] variant name, e.g. `TooSmall`.
+		kind: String,
+
+		/// # Message.
+		message: String,
+	},
+}
+
+impl From<Result<Stats, BrunchError>> for BenchOutcome {
+	fn from(src: Result<Stats, BrunchError>) -> Self {
+		match src {
+			Ok(s) => Self::Ok(s),
+			Err(e) => Self::Err {
+				kind: e.kind().to_owned(),
+				message: util::strip_ansi(&e.to_string()),
+			},
+		}
+	}
+}
+
+impl BenchOutcome {
+	#[must_use]
+	/// # Stats.
+	///
+	/// Return the stats, if successful.
+	pub const fn stats(&self) -> Option<Stats> {
+		if let Self::Ok(s) = self { Some(*s) }
+		else { None }
+	}
+
+	#[must_use]
+	/// # Is Ok?
+	pub const fn is_ok(&self) -> bool { matches!(self, Self::Ok(_)) }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	#[test]
+	fn t_outcome() {
+		let stats = Stats::try_from(vec![Duration::from_micros(1); 1000])
+			.expect("Invalid stats.");
+		let out = BenchOutcome::from(Ok(stats));
+		assert!(out.is_ok());
+		assert_eq!(out.stats().map(Stats::mean), Some(stats.mean()));
+
+		// Errors should be reduced to plain text.
+		let out = BenchOutcome::from(Err(BrunchError::NoRun));
+		assert!(! out.is_ok());
+		assert!(out.stats().is_none());
+		let BenchOutcome::Err { kind, message } = out else { panic!("Expected error."); };
+		assert_eq!(kind, "NoRun");
+		assert_eq!(message, "Missing Bench::run.");
+	}
+}
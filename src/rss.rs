@@ -0,0 +1,190 @@
+/*!
+# Brunch: Peak Memory
+*/
+
+use crate::util;
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Max RSS.
+///
+/// This holds the process's peak resident set size (in bytes) at the end of a
+/// benchmark, along with how much it grew while the benchmark was running.
+pub(crate) struct MaxRss {
+	/// # High-Water Mark.
+	peak: u64,
+
+	/// # Growth.
+	delta: u64,
+}
+
+impl MaxRss {
+	/// # New.
+	///
+	/// Build from the peak before and after the benchmark.
+	pub(crate) const fn new(before: u64, after: u64) -> Self {
+		Self { peak: after, delta: after.saturating_sub(before) }
+	}
+
+	/// # High-Water Mark.
+	pub(crate) const fn peak(self) -> u64 { self.peak }
+
+	/// # Growth.
+	pub(crate) const fn delta(self) -> u64 { self.delta }
+
+	#[expect(clippy::cast_precision_loss, reason = "False positive.")]
+	/// # Nice Value.
+	///
+	/// Return the high-water mark, followed by the growth, if any, e.g.
+	/// `12.50 MiB (+3.00 MiB)`.
+	pub(crate) fn nice(self) -> String {
+		let peak = util::nice_bytes(self.peak as f64);
+		if self.delta == 0 { peak }
+		else { format!("{peak} (+{})", util::nice_bytes(self.delta as f64)) }
+	}
+}
+
+
+
+/// # Peak Memory.
+///
+/// Return the process's peak resident set size so far, in bytes, or `None`
+/// if the platform doesn't provide a usable measurement.
+pub(crate) fn max_rss() -> Option<u64> { sys::max_rss() }
+
+
+
+#[cfg(any(target_os = "linux", target_os = "android", target_os = "macos"))]
+/// # Unix.
+mod sys {
+	use std::ffi::{
+		c_int,
+		c_long,
+	};
+
+	/// # Resource Usage (Self).
+	const RUSAGE_SELF: c_int = 0;
+
+	/// # Resource Usage Size (in Longs).
+	///
+	/// The struct begins with two `timeval`s — each the size of two longs on
+	/// the supported platforms — followed by fourteen longs.
+	const WORDS: usize = 2 * 2 + 14;
+
+	/// # Max RSS Index.
+	///
+	/// The peak is the first long after the `timeval`s.
+	const MAXRSS: usize = 4;
+
+	#[cfg(any(target_os = "linux", target_os = "android"))]
+	/// # Max RSS Unit.
+	///
+	/// Linux reports kilobytes…
+	const UNIT: u64 = 1024;
+
+	#[cfg(target_os = "macos")]
+	/// # Max RSS Unit.
+	///
+	/// …while macOS reports bytes.
+	const UNIT: u64 = 1;
+
+	extern "C" {
+		/// # Resource Usage.
+		fn getrusage(who: c_int, usage: *mut c_long) -> c_int;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Peak Memory.
+	pub(super) fn max_rss() -> Option<u64> {
+		let mut usage: [c_long; WORDS] = [0; WORDS];
+
+		// Safety: the buffer is as large as the C struct.
+		if 0 == unsafe { getrusage(RUSAGE_SELF, usage.as_mut_ptr()) } {
+			u64::try_from(usage[MAXRSS]).ok()?.checked_mul(UNIT)
+		}
+		else { None }
+	}
+}
+
+#[cfg(windows)]
+/// # Windows.
+mod sys {
+	use std::ffi::c_void;
+
+	#[repr(C)]
+	#[derive(Default)]
+	#[expect(dead_code, reason = "Needed for the C layout.")]
+	/// # Process Memory Counters.
+	struct Counters {
+		/// # Struct Size.
+		cb: u32,
+
+		/// # Page Faults.
+		page_faults: u32,
+
+		/// # Peak Working Set.
+		peak_working_set: usize,
+
+		/// # The Rest.
+		///
+		/// Current working set, pool and page file usage; we don't need
+		/// them.
+		rest: [usize; 7],
+	}
+
+	#[link(name = "kernel32")]
+	extern "system" {
+		/// # Current Process (Pseudo) Handle.
+		fn GetCurrentProcess() -> *mut c_void;
+
+		/// # Process Memory Info.
+		fn K32GetProcessMemoryInfo(process: *mut c_void, counters: *mut Counters, cb: u32) -> i32;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Peak Memory.
+	pub(super) fn max_rss() -> Option<u64> {
+		let mut counters = Counters::default();
+		let cb = u32::try_from(size_of::<Counters>()).ok()?;
+		counters.cb = cb;
+
+		// Safety: the pseudo handle is always valid for the calling process,
+		// and the struct matches the C layout.
+		let res = unsafe { K32GetProcessMemoryInfo(GetCurrentProcess(), &raw mut counters, cb) };
+		if res == 0 { None }
+		else { u64::try_from(counters.peak_working_set).ok() }
+	}
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android", target_os = "macos", windows)))]
+/// # Other.
+mod sys {
+	/// # Peak Memory (Unsupported).
+	pub(super) const fn max_rss() -> Option<u64> { None }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_max_rss() {
+		let m = MaxRss::new(1024, 3072);
+		assert_eq!(m.peak(), 3072);
+		assert_eq!(m.delta(), 2048);
+		assert_eq!(m.nice(), "3.00 KiB (+2.00 KiB)");
+		assert_eq!(MaxRss::new(1024, 1024).nice(), "1.00 KiB");
+
+		// The peak can only go up.
+		if let Some(before) = max_rss() {
+			assert!(0 < before, "The process is using no memory?");
+			let v = vec![1_u8; 16 * 1024 * 1024];
+			std::hint::black_box(&v);
+			let after = max_rss().expect("Max RSS failed.");
+			assert!(before <= after, "Max RSS went down.");
+		}
+	}
+}
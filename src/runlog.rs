@@ -0,0 +1,241 @@
+/*!
+# Brunch: Run Log
+*/
+
+use crate::{
+	criterion::{
+		json_num,
+		json_str,
+	},
+	Stats,
+};
+use std::{
+	fmt::Write as _,
+	fs::OpenOptions,
+	io::Write,
+	path::{
+		Path,
+		PathBuf,
+	},
+	time::SystemTime,
+};
+
+
+
+/// # Nanoseconds Per Second.
+const NANOS: f64 = 1_000_000_000.0;
+
+/// # CSV Header.
+const CSV_HEADER: &str = "time,name,mean_ns,deviation_ns,valid,total,tag\n";
+
+
+
+/// # Path (From Environment).
+///
+/// Return the run log path, per the `BRUNCH_LOG` environmental variable, if
+/// any.
+pub(crate) fn path() -> Option<PathBuf> {
+	std::env::var_os("BRUNCH_LOG")
+		.filter(|p| ! p.is_empty())
+		.map(PathBuf::from)
+}
+
+/// # Tag (From Environment).
+///
+/// Return the user-supplied tag, per the `BRUNCH_TAG` environmental
+/// variable, if any.
+pub(crate) fn tag() -> Option<String> {
+	std::env::var("BRUNCH_TAG").ok()
+		.map(|s| s.trim().to_owned())
+		.filter(|s| ! s.is_empty())
+}
+
+/// # Append Records.
+///
+/// Append one line per record to the log, creating it if needed. Files with
+/// a `.csv` extension get CSV — with a header line if the file was empty —
+/// while everything else gets JSON Lines.
+///
+/// Each line is written with a single `write_all` call to a file opened in
+/// append mode, so concurrent runs won't interleave partial lines.
+///
+/// ## Errors
+///
+/// Any I/O errors are passed through.
+pub(crate) fn append<'a, I>(path: &Path, records: I) -> std::io::Result<()>
+where I: IntoIterator<Item=(&'a str, Stats)> {
+	let csv = path.extension().is_some_and(|e| e.eq_ignore_ascii_case("csv"));
+	let tag = tag();
+	let time = SystemTime::now()
+		.duration_since(SystemTime::UNIX_EPOCH)
+		.map_or(0, |d| d.as_secs());
+
+	if let Some(dir) = path.parent().filter(|d| ! d.as_os_str().is_empty()) {
+		std::fs::create_dir_all(dir)?;
+	}
+	let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+	if csv && file.metadata()?.len() == 0 {
+		file.write_all(CSV_HEADER.as_bytes())?;
+	}
+
+	for (name, stats) in records {
+		let mut line =
+			if csv { csv_line(time, name, stats, tag.as_deref()) }
+			else { json_line(time, name, stats, tag.as_deref()) };
+		line.push('\n');
+		file.write_all(line.as_bytes())?;
+	}
+
+	file.flush()
+}
+
+/// # JSON Line.
+///
+/// Format a record as a single-line JSON object — sans line break — e.g.
+/// `{"time":1700000000,"name":"foo()","mean_ns":1234.5,"deviation_ns":12.3,"valid":2480,"total":2500,"tag":null}`.
+fn json_line(time: u64, name: &str, stats: Stats, tag: Option<&str>) -> String {
+	let (valid, total) = stats.samples();
+	let mut out = format!(
+		r#"{{"time":{time},"name":{},"mean_ns":{},"deviation_ns":{},"valid":{valid},"total":{total},"tag":"#,
+		json_str(name),
+		json_num(stats.mean().as_secs_f64() * NANOS),
+		json_num(stats.deviation().as_secs_f64() * NANOS),
+	);
+	match tag {
+		Some(tag) => out.push_str(&json_str(tag)),
+		None => out.push_str("null"),
+	}
+	out.push('}');
+	out
+}
+
+/// # CSV Line.
+///
+/// Format a record as a CSV row, matching `CSV_HEADER`, sans line break.
+fn csv_line(time: u64, name: &str, stats: Stats, tag: Option<&str>) -> String {
+	let (valid, total) = stats.samples();
+	let mut out = String::with_capacity(name.len() + 64);
+	let _res = write!(
+		out,
+		"{time},{},{},{},{valid},{total},",
+		csv_str(name),
+		json_num(stats.mean().as_secs_f64() * NANOS),
+		json_num(stats.deviation().as_secs_f64() * NANOS),
+	);
+	if let Some(tag) = tag { out.push_str(&csv_str(tag)); }
+	out
+}
+
+/// # CSV String.
+///
+/// Quote the value, doubling any quotes within, and flattening line breaks
+/// so each record stays on one line.
+fn csv_str(src: &str) -> String {
+	let mut out = String::with_capacity(src.len() + 2);
+	out.push('"');
+	for c in src.chars() {
+		match c {
+			'"' => out.push_str("\"\""),
+			'\n' | '\r' => out.push(' '),
+			c => out.push(c),
+		}
+	}
+	out.push('"');
+	out
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use std::time::Duration;
+
+	/// # Parse JSON Line.
+	///
+	/// Split a flat JSON object into key/value pairs, unquoting string
+	/// values.
+	fn parse_json(line: &str) -> Vec<(String, String)> {
+		let inner = line.trim().strip_prefix('{').and_then(|l| l.strip_suffix('}'))
+			.expect("Not an object.");
+		let mut out = Vec::new();
+		let mut chars = inner.chars().peekable();
+		loop {
+			// Key.
+			assert_eq!(chars.next(), Some('"'), "Expected key.");
+			let key: String = chars.by_ref().take_while(|c| *c != '"').collect();
+			assert_eq!(chars.next(), Some(':'), "Expected colon.");
+
+			// Value.
+			let mut value = String::new();
+			if chars.peek() == Some(&'"') {
+				chars.next();
+				while let Some(c) = chars.next() {
+					match c {
+						'\\' => match chars.next() {
+							Some('n') => value.push('\n'),
+							Some(c) => value.push(c),
+							None => break,
+						},
+						'"' => break,
+						c => value.push(c),
+					}
+				}
+			}
+			else {
+				while let Some(c) = chars.next_if(|c| *c != ',') { value.push(c); }
+			}
+			out.push((key, value));
+
+			if chars.next().is_none() { break; }
+		}
+		out
+	}
+
+	#[test]
+	fn t_append() {
+		let stats = Stats::try_from(
+			(0..1000_u64).map(|i| Duration::from_nanos(1000 + i % 10)).collect::<Vec<_>>()
+		).expect("Invalid stats.");
+		let dir = std::env::temp_dir().join("__brunch-test-runlog");
+		let _res = std::fs::remove_dir_all(&dir);
+
+		// JSON Lines.
+		let path = dir.join("log.jsonl");
+		append(&path, [("foo::bar(\"baz\")", stats)]).expect("Append failed.");
+		append(&path, [("a", stats), ("b", stats)]).expect("Append failed.");
+		let raw = std::fs::read_to_string(&path).expect("Missing log.");
+		let lines: Vec<&str> = raw.lines().collect();
+		assert_eq!(lines.len(), 3, "Records should be appended.");
+
+		// Parse it back.
+		let record = parse_json(lines[0]);
+		let keys: Vec<&str> = record.iter().map(|(k, _)| k.as_str()).collect();
+		assert_eq!(keys, ["time", "name", "mean_ns", "deviation_ns", "valid", "total", "tag"]);
+		assert!(record[0].1.parse::<u64>().is_ok_and(|t| 0 < t), "Invalid time.");
+		assert_eq!(record[1].1, "foo::bar(\"baz\")");
+		let mean = record[2].1.parse::<f64>().expect("Invalid mean.");
+		assert!(stats.mean().as_secs_f64().mul_add(-NANOS, mean).abs() < 0.001, "Wrong mean.");
+		assert!(record[3].1.parse::<f64>().is_ok(), "Invalid deviation.");
+		assert_eq!(record[4].1.parse::<u32>().ok(), Some(stats.samples().0));
+		assert_eq!(record[5].1.parse::<u32>().ok(), Some(stats.samples().1));
+		assert_eq!(parse_json(lines[2])[1].1, "b");
+
+		// Tags are strings or null.
+		assert!(json_line(1, "a", stats, None).ends_with(r#","tag":null}"#));
+		let record = parse_json(&json_line(1, "a", stats, Some("commit-abc123")));
+		assert_eq!(record[6].1, "commit-abc123");
+
+		// CSV gets a header, once.
+		let path = dir.join("log.csv");
+		append(&path, [("a,\"b\"", stats)]).expect("Append failed.");
+		append(&path, [("c", stats)]).expect("Append failed.");
+		let raw = std::fs::read_to_string(&path).expect("Missing log.");
+		let _res = std::fs::remove_dir_all(&dir);
+		let mut lines = raw.lines();
+		assert_eq!(lines.next(), Some(CSV_HEADER.trim_end()));
+		assert!(lines.next().is_some_and(|l| l.contains(r#","a,""b""","#)), "Name not quoted.");
+		assert!(lines.next().is_some_and(|l| l.contains(r#","c","#)));
+		assert!(lines.next().is_none(), "Too many lines.");
+	}
+}
@@ -0,0 +1,186 @@
+/*!
+# Brunch: Scaling
+*/
+
+use dactyl::total_cmp;
+use std::fmt;
+
+
+
+/// # Minimum R².
+///
+/// Fits any worse than this are reported as inconclusive.
+const MIN_R2: f64 = 0.9;
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Complexity Class.
+pub(crate) enum Complexity {
+	/// # Linear.
+	Linear,
+
+	/// # Linearithmic.
+	Linearithmic,
+
+	/// # Quadratic.
+	Quadratic,
+}
+
+impl fmt::Display for Complexity {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.as_str())
+	}
+}
+
+impl Complexity {
+	/// # All Classes.
+	const ALL: [Self; 3] = [Self::Linear, Self::Linearithmic, Self::Quadratic];
+
+	/// # As Str.
+	const fn as_str(self) -> &'static str {
+		match self {
+			Self::Linear => "O(n)",
+			Self::Linearithmic => "O(n log n)",
+			Self::Quadratic => "O(n\u{b2})",
+		}
+	}
+
+	/// # Transform.
+	///
+	/// Return the class's growth function evaluated at `n`.
+	fn x(self, n: f64) -> f64 {
+		match self {
+			Self::Linear => n,
+			Self::Linearithmic => n * n.max(1.0).ln(),
+			Self::Quadratic => n * n,
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # Complexity Fit.
+///
+/// This holds the best least-squares fit of a series of means against their
+/// input sizes, i.e. `mean ≈ a + b·f(n)`.
+pub(crate) struct Fit {
+	/// # Best Class.
+	class: Complexity,
+
+	/// # Coefficient of Determination.
+	r2: f64,
+}
+
+impl fmt::Display for Fit {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_conclusive() {
+			write!(f, "{} (R\u{b2} = {:.3})", self.class, self.r2)
+		}
+		else {
+			write!(f, "inconclusive (best guess {}, R\u{b2} = {:.3})", self.class, self.r2)
+		}
+	}
+}
+
+impl Fit {
+	/// # New.
+	///
+	/// Fit the `(n, mean)` points against each complexity class, returning
+	/// the one with the highest R², or `None` if there are fewer than three
+	/// points — two will fit anything — or the means don't grow at all.
+	pub(crate) fn new(points: &[(f64, f64)]) -> Option<Self> {
+		if points.len() < 3 { return None; }
+		Complexity::ALL.into_iter()
+			.filter_map(|class| {
+				let r2 = r_squared(points.iter().map(|(n, y)| (class.x(*n), *y)))?;
+				Some(Self { class, r2 })
+			})
+			.max_by(|a, b| a.r2.total_cmp(&b.r2))
+	}
+
+	/// # Conclusive?
+	///
+	/// Returns `true` if the fit is good enough to be believed.
+	pub(crate) fn is_conclusive(self) -> bool { total_cmp!((self.r2) >= MIN_R2) }
+}
+
+
+
+#[expect(clippy::cast_precision_loss, reason = "It is what it is.")]
+/// # R².
+///
+/// Perform a simple linear regression of the points, returning the
+/// coefficient of determination, or `None` if the slope isn't positive or
+/// the figures are otherwise unusable.
+fn r_squared<I>(points: I) -> Option<f64>
+where I: IntoIterator<Item=(f64, f64)> {
+	let points: Vec<(f64, f64)> = points.into_iter().collect();
+	if points.iter().any(|(x, y)| ! x.is_finite() || ! y.is_finite()) { return None; }
+
+	let len = points.len() as f64;
+	let mx = points.iter().map(|(x, _)| x).sum::<f64>() / len;
+	let my = points.iter().map(|(_, y)| y).sum::<f64>() / len;
+	let (sxx, sxy, syy) = points.iter().fold((0.0, 0.0, 0.0), |(sxx, sxy, syy), (x, y)| {
+		let (dx, dy) = (x - mx, y - my);
+		(dx.mul_add(dx, sxx), dx.mul_add(dy, sxy), dy.mul_add(dy, syy))
+	});
+	if ! sxx.is_normal() || ! syy.is_normal() { return None; }
+
+	let slope = sxy / sxx;
+	if ! total_cmp!(slope > 0.0) { return None; }
+	let intercept = slope.mul_add(-mx, my);
+	let res: f64 = points.iter()
+		.map(|(x, y)| {
+			let diff = y - slope.mul_add(*x, intercept);
+			diff * diff
+		})
+		.sum();
+
+	let r2 = 1.0 - res / syy;
+	if r2.is_finite() { Some(r2) } else { None }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Points.
+	fn points<F: Fn(f64) -> f64>(cb: F) -> Vec<(f64, f64)> {
+		[1_000.0, 10_000.0, 50_000.0, 100_000.0, 500_000.0].into_iter()
+			.map(|n| (n, cb(n)))
+			.collect()
+	}
+
+	#[test]
+	fn t_fit() {
+		/// # Curve.
+		type Curve = fn(f64) -> f64;
+
+		let cases: [(Complexity, Curve); 3] = [
+			(Complexity::Linear, |n| n.mul_add(3.0, 500.0)),
+			(Complexity::Linearithmic, |n| (2.0 * n).mul_add(n.ln(), 100.0)),
+			(Complexity::Quadratic, |n| (0.5 * n).mul_add(n, 10.0)),
+		];
+		for (class, cb) in cases {
+			let fit = Fit::new(&points(cb)).expect("Missing fit.");
+			assert_eq!(fit.class, class);
+			assert!(fit.is_conclusive(), "{class} should be conclusive.");
+			assert!(fit.to_string().starts_with(class.as_str()), "{fit}");
+		}
+
+		// Noise is inconclusive.
+		let noisy = [(1_000.0, 5.0), (10_000.0, 1.0), (50_000.0, 6.0), (100_000.0, 2.0), (500_000.0, 4.0)];
+		let fit = Fit::new(&noisy).expect("Missing fit.");
+		assert!(! fit.is_conclusive(), "Noise shouldn't fit.");
+		assert!(fit.to_string().starts_with("inconclusive"), "{fit}");
+
+		// Too few points, or no growth.
+		assert!(Fit::new(&[(1.0, 1.0), (2.0, 2.0)]).is_none());
+		assert!(Fit::new(&points(|_| 5.0)).is_none());
+		assert!(Fit::new(&points(|n| 1.0 / n)).is_none());
+	}
+}
@@ -0,0 +1,131 @@
+/*!
+# Brunch: Signals
+*/
+
+use std::sync::{
+	atomic::{
+		AtomicBool,
+		Ordering::SeqCst,
+	},
+	Once,
+};
+
+
+
+/// # Interrupted?
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+/// # Exit Code.
+///
+/// The conventional exit code for processes terminated by `SIGINT`.
+pub(crate) const EXIT_INTERRUPTED: i32 = 130;
+
+
+
+/// # Install Handler.
+///
+/// Install a minimal Ctrl+C handler (once) so that the first interrupt merely
+/// sets a flag — checked between samples and benches — giving us the chance
+/// to print and save what we have. A second interrupt aborts immediately.
+pub(crate) fn install() {
+	/// # Once.
+	static ONCE: Once = Once::new();
+	ONCE.call_once(sys::install);
+}
+
+/// # Interrupted?
+///
+/// Returns true if Ctrl+C has been pressed.
+pub(crate) fn interrupted() -> bool { INTERRUPTED.load(SeqCst) }
+
+/// # Handle Interrupt.
+///
+/// Set the flag the first time; bail the second.
+fn on_interrupt() {
+	if INTERRUPTED.swap(true, SeqCst) { sys::abort(); }
+}
+
+
+
+#[cfg(unix)]
+/// # Unix.
+mod sys {
+	use std::ffi::c_int;
+
+	/// # Interrupt Signal.
+	const SIGINT: c_int = 2;
+
+	extern "C" {
+		/// # Set Signal Handler.
+		fn signal(signum: c_int, handler: extern "C" fn(c_int)) -> usize;
+
+		/// # Exit Immediately.
+		fn _exit(status: c_int) -> !;
+	}
+
+	/// # Handler.
+	extern "C" fn handler(_: c_int) { super::on_interrupt(); }
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Install Handler.
+	pub(super) fn install() {
+		// Safety: the handler only touches an atomic, or exits.
+		unsafe { signal(SIGINT, handler); }
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Abort.
+	///
+	/// Exit right away, using the async-signal-safe `_exit`.
+	pub(super) fn abort() -> ! {
+		// Safety: this is always safe to call.
+		unsafe { _exit(super::EXIT_INTERRUPTED) }
+	}
+}
+
+#[cfg(windows)]
+/// # Windows.
+mod sys {
+	/// # Ctrl+C Event.
+	const CTRL_C_EVENT: u32 = 0;
+
+	#[link(name = "kernel32")]
+	extern "system" {
+		/// # Set Console Control Handler.
+		fn SetConsoleCtrlHandler(
+			handler: Option<unsafe extern "system" fn(u32) -> i32>,
+			add: i32,
+		) -> i32;
+	}
+
+	/// # Handler.
+	unsafe extern "system" fn handler(ctrl: u32) -> i32 {
+		if ctrl == CTRL_C_EVENT {
+			super::on_interrupt();
+			1
+		}
+		else { 0 }
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Install Handler.
+	pub(super) fn install() {
+		// Safety: the handler only touches an atomic, or exits.
+		unsafe { SetConsoleCtrlHandler(Some(handler), 1); }
+	}
+
+	/// # Abort.
+	///
+	/// Handlers run on their own thread, so a regular exit is fine.
+	pub(super) fn abort() -> ! { std::process::exit(super::EXIT_INTERRUPTED) }
+}
+
+#[cfg(not(any(unix, windows)))]
+/// # Other.
+mod sys {
+	/// # Install Handler (No-Op).
+	pub(super) const fn install() {}
+
+	/// # Abort.
+	pub(super) fn abort() -> ! { std::process::exit(super::EXIT_INTERRUPTED) }
+}
@@ -2,33 +2,107 @@
 # Brunch: History
 */
 
-use crate::Stats;
+use crate::{
+	alloc::Allocs,
+	Metric,
+	MIN_SAMPLES_FLOOR,
+	OutlierPolicy,
+	perf::Counters,
+	Pruning,
+	Stats,
+	util,
+};
+use dactyl::{
+	NiceU32,
+	traits::SaturatingFrom,
+};
 use std::{
-	collections::BTreeMap,
+	borrow::Cow,
+	collections::{
+		BTreeMap,
+		BTreeSet,
+	},
 	ffi::OsStr,
-	fs::File,
-	io::Write,
+	fs::{
+		File,
+		OpenOptions,
+	},
+	io::{
+		ErrorKind,
+		Write,
+	},
 	path::{
 		Path,
 		PathBuf,
 	},
+	time::{
+		Duration,
+		Instant,
+		SystemTime,
+	},
 };
 
 
 
 /// # History Inner Data.
-type HistoryData = BTreeMap<String, Stats>;
+///
+/// Each benchmark maps to its most recent runs and best-ever run.
+type HistoryData = BTreeMap<String, Entry>;
+
+/// # Baseline Separator.
+///
+/// Entries belonging to a named baseline are keyed by the baseline name and
+/// benchmark name, joined by this (unit separator) character.
+const BASELINE_SEP: char = '\u{1f}';
+
+/// # Default Depth.
+///
+/// The default number of runs retained for each benchmark.
+const DEFAULT_DEPTH: u8 = 5;
 
 /// # History Default File Name.
+///
+/// This is used when an explicit history path lacks a file name.
 const HISTORY_FILE: &str = "__brunch.last";
 
+/// # Build Profile.
+///
+/// This identifies the build configuration — target triple, Cargo profile,
+/// and optimization level — and is used to key the default history file so
+/// that different configurations never compare against one another.
+const PROFILE: &str = env!("BRUNCH_PROFILE");
+
+/// # Lock Timeout.
+///
+/// The maximum amount of time to wait for another process to release its lock
+/// on the history file before giving up and proceeding anyway.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// # Lock Staleness.
+///
+/// Lock files older than this are assumed to have been left behind by a
+/// crashed process, and are removed straightaway.
+const LOCK_STALE: Duration = Duration::from_secs(60);
+
 /// # History Magic Header.
 ///
 /// This provides a quick way to know whether or not a given file might be a
 /// `Brunch` history. The trailing digits act like a format version; they'll
 /// get bumped any time the data format changes, to prevent compatibility
 /// issues between releases.
-const MAGIC: &[u8] = b"BRUNCH00";
+const MAGIC: &[u8] = b"BRUNCH07";
+
+/// # History Magic Prefix.
+///
+/// This is the version-agnostic part of `MAGIC`, used to distinguish
+/// unsupported history files from corrupt ones.
+const MAGIC_PREFIX: &[u8] = b"BRUNCH";
+
+/// # History Format Version.
+///
+/// This must match the trailing digits of `MAGIC`. Files using this version
+/// or any earlier one can be read.
+const VERSION: u8 = 7;
 
 
 
@@ -38,30 +112,733 @@ const MAGIC: &[u8] = b"BRUNCH00";
 ///
 /// This is triggered automatically when using the [`benches`] macro; it is
 /// not intended to be called manually.
-pub(crate) struct History(HistoryData);
+#[expect(clippy::struct_excessive_bools, reason = "It is what it is.")]
+pub(crate) struct History {
+	/// # Entries.
+	data: HistoryData,
+
+	/// # Saved Entries.
+	///
+	/// This holds the entries as loaded — or last saved — without any of the
+	/// fresh ones, in case the file can't be re-read when saving.
+	saved: HistoryData,
+
+	/// # Fresh Entries.
+	///
+	/// This holds the entries inserted since the last save, which are merged
+	/// into the on-disk history when saving.
+	fresh: BTreeMap<String, Run>,
+
+	/// # Seen Keys.
+	///
+	/// This holds the keys of all entries inserted during this run, saved or
+	/// not.
+	seen: BTreeSet<String>,
+
+	/// # File Path.
+	path: Option<PathBuf>,
+
+	/// # Corrupt?
+	///
+	/// This is set if an existing history file could not be parsed.
+	corrupt: bool,
+
+	/// # Loaded?
+	///
+	/// This is set if an existing history file was successfully parsed.
+	loaded: bool,
+
+	/// # Save Error.
+	///
+	/// This holds the reason the most recent save failed, if it did.
+	save_error: Option<String>,
+
+	/// # Prune?
+	///
+	/// If set, entries not updated during this run are dropped when saving.
+	prune: bool,
+
+	/// # Depth.
+	///
+	/// The maximum number of runs to retain for each benchmark.
+	depth: u8,
+
+	/// # Keyed by Profile?
+	///
+	/// This is set when using the default, profile-specific history file.
+	keyed: bool,
+
+	/// # Frozen?
+	///
+	/// If set, the history is used for comparison only, and never saved.
+	frozen: bool,
+
+	/// # Named Baseline.
+	///
+	/// If set, entries are read from this baseline rather than the default
+	/// one.
+	baseline: Option<String>,
+
+	/// # Saved Baseline.
+	///
+	/// If set, entries are written to this baseline rather than the default
+	/// one.
+	target: Option<String>,
+
+	/// # Split From Shared?
+	///
+	/// This is set when the default, per-binary history file doesn't exist
+	/// yet but the old shared one does.
+	split: bool,
+
+	/// # Current Metadata.
+	///
+	/// This is attached to each entry inserted during this run.
+	meta: Meta,
+}
 
 impl Default for History {
-	fn default() -> Self {
-		Self(load_history().unwrap_or_default())
-	}
+	fn default() -> Self { Self::new(None) }
 }
 
 impl History {
+	/// # New.
+	///
+	/// Load the history from the given path, or if `None`, the path specified
+	/// by the environment (or the default temporary location).
+	pub(crate) fn new(path: Option<&Path>) -> Self {
+		let keyed = path.is_none() && std::env::var_os("BRUNCH_HISTORY").is_none();
+		let mut out = Self::load(path.map_or_else(history_path, resolve_path));
+		out.depth = history_depth();
+		out.keyed = keyed && out.path.is_some();
+		out.split = out.keyed && out.path.as_deref().is_some_and(is_split);
+		out
+	}
+
+	/// # Open.
+	///
+	/// Load an existing history file for read-only purposes, returning `None`
+	/// if it is missing or cannot be parsed.
+	pub(crate) fn open(path: &Path) -> Option<Self> {
+		let out = Self::load(Some(path.to_path_buf()));
+		if out.corrupt || ! path.is_file() { None }
+		else { Some(out) }
+	}
+
+	/// # Default Path.
+	///
+	/// Return the history path specified by the environment, or the default
+	/// temporary location, if any.
+	pub(crate) fn default_path() -> Option<PathBuf> { history_path() }
+
+	/// # Disabled.
+	///
+	/// Return an empty history that will never be saved.
+	pub(crate) fn disabled() -> Self { Self::load(None) }
+
+	/// # Load.
+	///
+	/// Load the history from the given path, if any. If the file exists but
+	/// cannot be parsed, the history will start out empty and be marked as
+	/// corrupt. (Files from newer versions of `Brunch` are silently ignored.)
+	fn load(path: Option<PathBuf>) -> Self {
+		let mut corrupt = false;
+		let mut loaded = false;
+		let data = path.as_ref()
+			.and_then(|p| std::fs::read(p).ok())
+			.and_then(|raw| {
+				let out = deserialize(&raw);
+				corrupt = out.is_none() && ! is_unsupported(&raw);
+				loaded = out.is_some();
+				out
+			})
+			.unwrap_or_default();
+
+		Self {
+			saved: data.clone(),
+			data,
+			fresh: BTreeMap::new(),
+			seen: BTreeSet::new(),
+			path,
+			corrupt,
+			loaded,
+			save_error: None,
+			prune: false,
+			depth: DEFAULT_DEPTH,
+			keyed: false,
+			frozen: false,
+			baseline: None,
+			target: None,
+			split: false,
+			meta: Meta::current(),
+		}
+	}
+
 	/// # Get Entry.
+	///
+	/// Return the pooled stats of the retained runs, if any.
 	pub(crate) fn get(&self, key: &str) -> Option<Stats> {
-		self.0.get(key).copied()
+		Stats::pooled(&self.runs(key))
+	}
+
+	/// # Latest Entry.
+	///
+	/// Return the stats from the most recent run, if any.
+	pub(crate) fn latest(&self, key: &str) -> Option<Stats> {
+		self.entry(key).and_then(Entry::last).map(|r| r.stats)
+	}
+
+	/// # Best Entry.
+	///
+	/// Return the stats from the fastest (non-noisy) run ever recorded, if
+	/// any. Unlike the regular runs, this is retained indefinitely.
+	pub(crate) fn best(&self, key: &str) -> Option<Stats> {
+		self.entry(key).and_then(|e| e.best.as_ref()).map(|r| r.stats)
+	}
+
+	/// # Keys.
+	///
+	/// Return the (benchmark) keys belonging to the selected baseline.
+	pub(crate) fn keys(&self) -> impl Iterator<Item=&str> {
+		self.data.keys().filter_map(move |k| self.strip(k))
+	}
+
+	/// # Get Runs.
+	///
+	/// Return the stats for the retained runs, oldest first.
+	pub(crate) fn runs(&self, key: &str) -> Vec<Stats> {
+		self.entry(key)
+			.map(|e| e.runs.iter().map(|r| r.stats).collect())
+			.unwrap_or_default()
+	}
+
+	/// # Mismatched Metadata.
+	///
+	/// Return the metadata for the most recent run of the entry if it was
+	/// recorded under different conditions than the current run — another
+	/// compiler, target, or host — making comparisons dubious.
+	pub(crate) fn mismatch(&self, key: &str) -> Option<&Meta> {
+		self.entry(key)
+			.and_then(Entry::last)
+			.and_then(|r| r.meta.as_ref())
+			.filter(|m| ! m.same_env(&self.meta))
+	}
+
+	/// # Metric.
+	///
+	/// Return the metric used for the most recent run of the entry, if any.
+	pub(crate) fn metric(&self, key: &str) -> Option<Metric> {
+		self.entry(key).and_then(Entry::last).map(|r| r.metric)
+	}
+
+	/// # Allocations.
+	///
+	/// Return the allocations per iteration recorded for the most recent run
+	/// of the entry, if any.
+	pub(crate) fn allocs(&self, key: &str) -> Option<Allocs> {
+		self.entry(key).and_then(Entry::last).and_then(|r| r.allocs)
+	}
+
+	/// # Performance Counters.
+	///
+	/// Return the instructions and cycles per iteration recorded for the most
+	/// recent run of the entry, if any.
+	pub(crate) fn perf(&self, key: &str) -> Option<Counters> {
+		self.entry(key).and_then(Entry::last).and_then(|r| r.perf)
+	}
+
+	/// # Configuration.
+	///
+	/// Return the configuration used for the most recent run of the entry, if
+	/// known.
+	pub(crate) fn config(&self, key: &str) -> Option<Config> {
+		self.entry(key).and_then(Entry::last).and_then(|r| r.config)
 	}
 
 	/// # Insert.
-	pub(crate) fn insert(&mut self, key: &str, v: Stats) {
-		self.0.insert(key.to_owned(), v);
+	///
+	/// If the configuration differs from that of the previous runs, they are
+	/// dropped — best included — as they're no longer comparable.
+	pub(crate) fn insert(
+		&mut self,
+		key: &str,
+		v: Stats,
+		metric: Metric,
+		allocs: Option<Allocs>,
+		perf: Option<Counters>,
+		config: Option<Config>,
+	) {
+		let key = prefixed(self.target.as_deref(), key).into_owned();
+		let run = Run { stats: v, meta: Some(self.meta.clone()), metric, allocs, perf, config };
+		self.data.entry(key.clone()).or_default().push(run.clone(), self.depth);
+		self.fresh.insert(key.clone(), run);
+		self.seen.insert(key);
 	}
 
+	/// # Is Corrupt?
+	///
+	/// Returns true if an existing history file could not be parsed.
+	pub(crate) const fn is_corrupt(&self) -> bool { self.corrupt }
+
+	/// # Is Enabled?
+	///
+	/// Returns true if the history has somewhere to be saved.
+	pub(crate) const fn is_enabled(&self) -> bool { self.path.is_some() }
+
+	/// # Is Loaded?
+	///
+	/// Returns true if an existing history file was successfully parsed.
+	pub(crate) const fn is_loaded(&self) -> bool { self.loaded }
+
+	/// # Save Error.
+	///
+	/// Return the reason the most recent save failed, if it did.
+	pub(crate) fn save_error(&self) -> Option<&str> { self.save_error.as_deref() }
+
+	/// # Profile.
+	///
+	/// Return the build profile key if the history is being kept in the
+	/// default, profile-specific location.
+	pub(crate) const fn profile(&self) -> Option<&'static str> {
+		if self.keyed { Some(PROFILE) }
+		else { None }
+	}
+
+	/// # Freeze.
+	///
+	/// Keep the history as-is — still available for comparison — by turning
+	/// any subsequent saves into no-ops.
+	pub(crate) const fn freeze(&mut self) { self.frozen = true; }
+
+	/// # Is Frozen?
+	pub(crate) const fn is_frozen(&self) -> bool { self.frozen }
+
+	/// # Split From Shared?
+	///
+	/// Returns true if the old shared history file was passed over in favor
+	/// of a fresh per-binary one.
+	pub(crate) const fn is_split(&self) -> bool { self.split }
+
+	/// # Keep.
+	///
+	/// Mark an entry as seen — without inserting anything — so it survives
+	/// pruning.
+	pub(crate) fn keep(&mut self, key: &str) {
+		let key = prefixed(self.target.as_deref(), key).into_owned();
+		self.seen.insert(key);
+	}
+
+	/// # Select Baseline.
+	///
+	/// Read from the named baseline rather than the default one. Blank names
+	/// — and names containing the separator — are ignored.
+	///
+	/// This has no effect on where new entries are written; see
+	/// [`History::save_to`].
+	pub(crate) fn select(&mut self, name: &str) {
+		if let Some(name) = baseline_name(name) { self.baseline = Some(name); }
+	}
+
+	/// # Save To Baseline.
+	///
+	/// Write new entries to the named baseline rather than the default one,
+	/// leaving the others be. Blank names — and names containing the
+	/// separator — are ignored.
+	pub(crate) fn save_to(&mut self, name: &str) {
+		if let Some(name) = baseline_name(name) { self.target = Some(name); }
+	}
+
+	/// # Named Baseline.
+	///
+	/// Return the name of the selected baseline, if any.
+	pub(crate) fn baseline(&self) -> Option<&str> { self.baseline.as_deref() }
+
+	/// # Saved Baseline.
+	///
+	/// Return the name of the baseline new entries are written to, if any.
+	pub(crate) fn target(&self) -> Option<&str> { self.target.as_deref() }
+
+	/// # Entry.
+	///
+	/// Return the entry for the benchmark from the selected baseline, if any.
+	fn entry(&self, key: &str) -> Option<&Entry> {
+		self.data.get(&*prefixed(self.baseline.as_deref(), key))
+	}
+
+	/// # Strip Key.
+	///
+	/// Return the benchmark name from a storage key if it belongs to the
+	/// selected baseline.
+	fn strip<'k>(&self, key: &'k str) -> Option<&'k str> {
+		unprefixed(self.baseline.as_deref(), key)
+	}
+
+	/// # Prune.
+	///
+	/// Drop any entries not inserted during this run when saving.
+	pub(crate) const fn prune(&mut self) { self.prune = true; }
+
 	/// # Save.
-	pub(crate) fn save(&self) {
-		if let Some(mut f) = history_path().and_then(|f| File::create(f).ok()) {
-			let out = serialize(&self.0);
-			let _res = f.write_all(&out).and_then(|()| f.flush());
+	///
+	/// Other processes — e.g. separate bench targets — may have updated the
+	/// history since it was loaded, so to avoid clobbering their work, the
+	/// file is locked and re-read, and only the entries inserted since the
+	/// last save are merged in. As such, it is safe to call this repeatedly.
+	///
+	/// Returns the number of stale entries pruned, if any.
+	pub(crate) fn save(&mut self) -> usize {
+		let mut pruned = 0;
+		if self.frozen { return pruned; }
+		if let Some(path) = self.path.as_deref() {
+			let _lock = HistoryLock::acquire(path);
+
+			// Start with whatever's on disk, falling back to what we loaded
+			// originally. (The in-memory data already includes the fresh
+			// entries, so won't do.)
+			let mut data = std::fs::read(path).ok()
+				.and_then(|raw| deserialize(&raw))
+				.unwrap_or_else(|| self.saved.clone());
+
+			// Add in the fresh entries.
+			for (k, v) in std::mem::take(&mut self.fresh) {
+				data.entry(k).or_default().push(v, self.depth);
+			}
+
+			// Drop everything else? (Other baselines are left alone.)
+			if self.prune {
+				let before = data.len();
+				let target = self.target.as_deref();
+				data.retain(|k, _| self.seen.contains(k) || unprefixed(target, k).is_none());
+				pruned = before - data.len();
+			}
+
+			self.save_error = write_atomic(path, &serialize(&data)).err()
+				.map(|e| format!("{} ({e})", path.display()));
+			self.saved = data;
+		}
+		pruned
+	}
+}
+
+
+
+#[derive(Debug, Clone, Default)]
+/// # History Entry.
+///
+/// This holds the retained runs for a given benchmark, along with the best
+/// one ever recorded.
+struct Entry {
+	/// # Recent Runs (Oldest First).
+	runs: Vec<Run>,
+
+	/// # Best Run.
+	///
+	/// This is the run with the lowest mean, excluding noisy ones, and is
+	/// kept regardless of depth.
+	best: Option<Run>,
+}
+
+impl Entry {
+	/// # Last Run.
+	fn last(&self) -> Option<&Run> { self.runs.last() }
+
+	/// # Push Run.
+	///
+	/// Append a run to the set, dropping the oldest entries as needed to stay
+	/// within the depth, and crown it the best if it earns it.
+	///
+	/// If the run was configured — or averaged — differently than the last
+	/// one, everything else is dropped first.
+	fn push(&mut self, v: Run, depth: u8) {
+		if
+			v.config.zip(self.last().and_then(|r| r.config)).is_some_and(|(a, b)| a != b) ||
+			self.last().is_some_and(|r| r.metric != v.metric)
+		{
+			self.runs.clear();
+			self.best = None;
+		}
+		if v.beats(self.best.as_ref()) { self.best = Some(v.clone()); }
+		self.runs.push(v);
+		let depth = usize::from(depth.max(1));
+		if depth < self.runs.len() { self.runs.drain(..self.runs.len() - depth); }
+	}
+}
+
+
+
+#[derive(Debug, Clone)]
+/// # History Run.
+///
+/// This holds the stats for a single historical run, along with the
+/// conditions under which it was recorded, if known. (Older history formats
+/// did not track this.)
+struct Run {
+	/// # Stats.
+	stats: Stats,
+
+	/// # Metadata.
+	meta: Option<Meta>,
+
+	/// # Metric.
+	///
+	/// The metric used to calculate the mean. (Older history formats always
+	/// used a plain mean.)
+	metric: Metric,
+
+	/// # Allocations.
+	///
+	/// The allocations per iteration, if tracked.
+	allocs: Option<Allocs>,
+
+	/// # Performance Counters.
+	///
+	/// The instructions and cycles per iteration, if counted.
+	perf: Option<Counters>,
+
+	/// # Configuration.
+	///
+	/// The settings the run was sampled with. (Older history formats did not
+	/// track this.)
+	config: Option<Config>,
+}
+
+impl Run {
+	/// # Beats?
+	///
+	/// Returns true if this run should replace the current best, i.e. it
+	/// isn't noisy, and is either faster or the best was measured using a
+	/// different metric.
+	fn beats(&self, best: Option<&Self>) -> bool {
+		! self.stats.is_noisy() &&
+		best.is_none_or(|b| b.metric != self.metric || self.stats.mean < b.stats.mean)
+	}
+
+	/// # Deserialize.
+	///
+	/// This works like the `Deserialize` trait, but needs to know the format
+	/// version to know which fields to expect.
+	fn deserialize(raw: &[u8], version: u8) -> Option<(Self, &[u8])> {
+		let (stats, raw) = Stats::deserialize(raw)?;
+		let (meta, raw) =
+			if version < 2 { (None, raw) }
+			else {
+				let (meta, raw) = Meta::deserialize(raw)?;
+				(Some(meta), raw)
+			};
+		let (metric, raw) =
+			if version < 3 { (Metric::Mean, raw) }
+			else { Metric::deserialize(raw)? };
+		let (allocs, raw) =
+			if version < 5 { (None, raw) }
+			else { Option::<Allocs>::deserialize(raw)? };
+		let (perf, raw) =
+			if version < 6 { (None, raw) }
+			else { Option::<Counters>::deserialize(raw)? };
+		let (config, raw) =
+			if version < 7 { (None, raw) }
+			else { Option::<Config>::deserialize(raw)? };
+		Some((Self { stats, meta, metric, allocs, perf, config }, raw))
+	}
+
+	/// # Serialize.
+	///
+	/// Write the run to the buffer. See `serialize` for the format.
+	fn serialize(&self, out: &mut Vec<u8>) {
+		serialize_stats(out, self.stats);
+
+		let (time, rustc, target, host) = self.meta.as_ref().map_or(
+			(0, "", "", ""),
+			|m| (m.time, m.rustc.as_str(), m.target.as_str(), m.host.as_str()),
+		);
+		out.extend_from_slice(&time.to_be_bytes());
+		for v in [rustc, target, host] { serialize_str(out, v); }
+
+		let (kind, frac) = match self.metric {
+			Metric::Mean => (0_u8, 0.0_f64),
+			Metric::TrimmedMean(f) => (1, f),
+			Metric::Min => (2, 0.0),
+		};
+		out.push(kind);
+		out.extend_from_slice(&frac.to_be_bytes());
+
+		serialize_allocs(out, self.allocs);
+		serialize_perf(out, self.perf);
+		serialize_config(out, self.config);
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// # Run Configuration.
+///
+/// This holds the settings that affect how a benchmark is sampled; runs
+/// recorded with different settings aren't comparable.
+pub(crate) struct Config {
+	/// # Sample Limit.
+	samples: u32,
+
+	/// # Time Limit.
+	timeout: Duration,
+
+	/// # Outlier Pruning.
+	outliers: Option<Pruning>,
+
+	/// # Cold Caches?
+	cold: bool,
+}
+
+impl Config {
+	/// # New.
+	pub(crate) fn new(samples: u32, timeout: Duration, outliers: OutlierPolicy, cold: bool) -> Self {
+		Self { samples, timeout, outliers: outliers.pruning(), cold }
+	}
+
+	/// # Differences.
+	///
+	/// Return a description of each setting that differs from the other
+	/// (older) configuration, e.g. `samples 2,500 → 50,000`.
+	pub(crate) fn diff(&self, old: &Self) -> Vec<String> {
+		let mut out = Vec::new();
+		if self.samples != old.samples {
+			out.push(format!(
+				"samples {} \u{2192} {}",
+				NiceU32::from(old.samples),
+				NiceU32::from(self.samples),
+			));
+		}
+		if self.timeout != old.timeout {
+			out.push(format!(
+				"timeout {} \u{2192} {}",
+				util::nice_time(old.timeout.as_secs_f64()),
+				util::nice_time(self.timeout.as_secs_f64()),
+			));
+		}
+		if self.outliers != old.outliers {
+			out.push(format!(
+				"outliers {} \u{2192} {}",
+				nice_pruning(old.outliers),
+				nice_pruning(self.outliers),
+			));
+		}
+		if self.cold != old.cold {
+			out.push(format!(
+				"caches {} \u{2192} {}",
+				if old.cold { "cold" } else { "warm" },
+				if self.cold { "cold" } else { "warm" },
+			));
+		}
+		out
+	}
+}
+
+
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+/// # Run Metadata.
+///
+/// This holds some basic information about the environment a run was
+/// recorded in.
+pub(crate) struct Meta {
+	/// # Compiler Version.
+	rustc: String,
+
+	/// # Target Triple.
+	target: String,
+
+	/// # Hostname.
+	host: String,
+
+	/// # Timestamp (Unix Seconds).
+	time: u64,
+}
+
+impl Meta {
+	/// # Current.
+	///
+	/// Return the metadata for the current run.
+	fn current() -> Self {
+		Self {
+			rustc: env!("BRUNCH_RUSTC").to_owned(),
+			target: env!("BRUNCH_TARGET").to_owned(),
+			host: hostname(),
+			time: SystemTime::now()
+				.duration_since(SystemTime::UNIX_EPOCH)
+				.map_or(0, |d| d.as_secs()),
+		}
+	}
+
+	/// # Same Environment?
+	///
+	/// Returns true if the compiler, target, and host all match. The
+	/// timestamp is not considered.
+	fn same_env(&self, other: &Self) -> bool {
+		self.rustc == other.rustc &&
+		self.target == other.target &&
+		self.host == other.host
+	}
+
+	/// # Age.
+	///
+	/// Return the time elapsed since the run was recorded.
+	pub(crate) fn age(&self) -> Duration {
+		SystemTime::now()
+			.duration_since(SystemTime::UNIX_EPOCH)
+			.map_or(Duration::ZERO, |d| d.saturating_sub(Duration::from_secs(self.time)))
+	}
+
+	/// # Compiler Version.
+	pub(crate) fn rustc(&self) -> &str { &self.rustc }
+
+	/// # Target Triple.
+	pub(crate) fn target(&self) -> &str { &self.target }
+
+	/// # Hostname.
+	pub(crate) fn host(&self) -> &str { &self.host }
+}
+
+
+
+#[derive(Debug)]
+/// # History Lock.
+///
+/// This is a simple, portable advisory lock for the history file, used to
+/// keep concurrent processes from stepping on each other's toes. It works by
+/// exclusively creating a `.lock` file alongside the history, which is
+/// removed when the guard is dropped.
+///
+/// If the lock cannot be acquired within `LOCK_TIMEOUT`, the lock is taken
+/// over anyway so that a crashed process cannot wedge future runs forever.
+struct HistoryLock(PathBuf);
+
+impl Drop for HistoryLock {
+	fn drop(&mut self) { let _res = std::fs::remove_file(&self.0); }
+}
+
+impl HistoryLock {
+	/// # Acquire.
+	///
+	/// Returns `None` if locking isn't possible at all, e.g. because the
+	/// directory is read-only.
+	fn acquire(path: &Path) -> Option<Self> {
+		let mut lock = path.as_os_str().to_owned();
+		lock.push(".lock");
+		let lock = PathBuf::from(lock);
+
+		let now = Instant::now();
+		loop {
+			match OpenOptions::new().write(true).create_new(true).open(&lock) {
+				Ok(_) => return Some(Self(lock)),
+				Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+					// Take over the lock if it is stale or we've waited
+					// long enough.
+					if LOCK_TIMEOUT <= now.elapsed() || lock_is_stale(&lock) {
+						return Some(Self(lock));
+					}
+					std::thread::sleep(Duration::from_millis(25));
+				},
+				Err(_) => return None,
+			}
 		}
 	}
 }
@@ -69,7 +846,7 @@ impl History {
 
 
 /// # Deserialization.
-trait Deserialize<'a>: Sized {
+pub(crate) trait Deserialize<'a>: Sized {
 	/// # Deserialize.
 	///
 	/// This deserializes `Self` from some number of leading bytes, returning
@@ -89,7 +866,7 @@ macro_rules! deserialize {
 	)+);
 }
 
-deserialize!(2 u16, 4 u32, 8 f64);
+deserialize!(1 u8, 2 u16, 4 i32, 4 u32, 8 u64, 8 f64);
 
 impl<'a> Deserialize<'a> for &'a str {
 	fn deserialize(raw: &'a [u8]) -> Option<(Self, &'a [u8])> {
@@ -111,7 +888,80 @@ impl Deserialize<'_> for Stats {
 		let (deviation, raw) = f64::deserialize(raw)?;
 		let (mean, raw) = f64::deserialize(raw)?;
 
-		let out = Self { total, valid, deviation, mean };
+		// The sum isn't stored, but can be estimated.
+		let sum = mean * f64::from(valid);
+		let out = Self { total, valid, deviation, mean, sum, drift: 0.0, dist: None };
+		Some((out, raw))
+	}
+}
+
+impl Deserialize<'_> for Metric {
+	fn deserialize(raw: &[u8]) -> Option<(Self, &[u8])> {
+		let (kind, raw) = u8::deserialize(raw)?;
+		let (frac, raw) = f64::deserialize(raw)?;
+		match kind {
+			0 => Some((Self::Mean, raw)),
+			1 => Some((Self::TrimmedMean(frac), raw)),
+			2 => Some((Self::Min, raw)),
+			_ => None,
+		}
+	}
+}
+
+impl Deserialize<'_> for Option<Allocs> {
+	fn deserialize(raw: &[u8]) -> Option<(Self, &[u8])> {
+		let (flag, raw) = u8::deserialize(raw)?;
+		if flag == 0 { return Some((None, raw)); }
+		let (allocs, raw) = f64::deserialize(raw)?;
+		let (bytes, raw) = f64::deserialize(raw)?;
+		Some((Some(Allocs::new(allocs, bytes)), raw))
+	}
+}
+
+impl Deserialize<'_> for Option<Counters> {
+	fn deserialize(raw: &[u8]) -> Option<(Self, &[u8])> {
+		let (flag, raw) = u8::deserialize(raw)?;
+		if flag == 0 { return Some((None, raw)); }
+		let (instructions, raw) = f64::deserialize(raw)?;
+		let (cycles, raw) = f64::deserialize(raw)?;
+		Some((Some(Counters::new(instructions, cycles)), raw))
+	}
+}
+
+impl Deserialize<'_> for Option<Config> {
+	fn deserialize(raw: &[u8]) -> Option<(Self, &[u8])> {
+		let (flag, raw) = u8::deserialize(raw)?;
+		if flag == 0 { return Some((None, raw)); }
+		let cold = flag == 2;
+		let (samples, raw) = u32::deserialize(raw)?;
+		let (timeout, raw) = u64::deserialize(raw)?;
+		let (kind, raw) = u8::deserialize(raw)?;
+		let (a, raw) = f64::deserialize(raw)?;
+		let (b, raw) = f64::deserialize(raw)?;
+		let (c, raw) = f64::deserialize(raw)?;
+		let outliers = match kind {
+			0 => None,
+			1 => Some(Pruning::Iqr { low: a, high: b, mult: c }),
+			2 => Some(Pruning::Mad { k: a }),
+			_ => return None,
+		};
+		Some((Some(Config { samples, timeout: Duration::from_nanos(timeout), outliers, cold }), raw))
+	}
+}
+
+impl Deserialize<'_> for Meta {
+	fn deserialize(raw: &[u8]) -> Option<(Self, &[u8])> {
+		let (time, raw) = u64::deserialize(raw)?;
+		let (rustc, raw) = <&str>::deserialize(raw)?;
+		let (target, raw) = <&str>::deserialize(raw)?;
+		let (host, raw) = <&str>::deserialize(raw)?;
+
+		let out = Self {
+			rustc: rustc.to_owned(),
+			target: target.to_owned(),
+			host: host.to_owned(),
+			time,
+		};
 		Some((out, raw))
 	}
 }
@@ -124,18 +974,57 @@ impl Deserialize<'_> for Stats {
 /// an empty map if no benchmarks are present, but will return `None` if there
 /// are any structural issues, like a magic mismatch or invalid chunk lengths.
 ///
-/// See `serialize` for more details about the format.
+/// See `serialize` for more details about the format. Older versions are
+/// supported too, but lack some of the information:
+///
+/// * Version `0` entries hold a single run (and no run count).
+/// * Version `1` entries have no metadata.
+/// * Version `2` entries have no metric (i.e. a plain mean).
+/// * Version `3` entries have no best run; it is inferred from the retained
+///   runs instead.
+/// * Version `4` runs have no allocation counts.
+/// * Version `5` runs have no performance counters.
+/// * Version `6` runs have no configuration; they're assumed to match.
 fn deserialize(raw: &[u8]) -> Option<HistoryData> {
-	let mut raw = raw.strip_prefix(MAGIC)?;
+	let (version, mut raw) = header(raw)?;
+	if VERSION < version { return None; }
 	let mut out = HistoryData::default();
 
 	while ! raw.is_empty() {
 		let (lbl, rest) = <&str>::deserialize(raw)?;
-		let (stats, rest) = Stats::deserialize(rest)?;
+		let (count, mut rest) =
+			if version == 0 { (1, rest) }
+			else { u8::deserialize(rest)? };
+
+		// Pull the runs, keeping the valid ones.
+		let mut entry = Entry::default();
+		for _ in 0..count {
+			let (run, next) = Run::deserialize(rest, version)?;
+			if run.stats.is_valid(MIN_SAMPLES_FLOOR) {
+				// Older formats didn't track the best run, so make do with
+				// what we have.
+				if version < 4 && run.beats(entry.best.as_ref()) {
+					entry.best = Some(run.clone());
+				}
+				entry.runs.push(run);
+			}
+			rest = next;
+		}
+
+		// Pull the best run, if any.
+		if 4 <= version {
+			let (has_best, next) = u8::deserialize(rest)?;
+			rest = next;
+			if has_best == 1 {
+				let (run, next) = Run::deserialize(rest, version)?;
+				if run.stats.is_valid(MIN_SAMPLES_FLOOR) { entry.best = Some(run); }
+				rest = next;
+			}
+		}
 
 		// Push the result if it's valid.
-		if ! lbl.is_empty() && stats.is_valid() {
-			out.insert(lbl.to_owned(), stats);
+		if ! lbl.is_empty() && ! entry.runs.is_empty() {
+			out.insert(lbl.to_owned(), entry);
 		}
 
 		// Update the slice for the next go-round.
@@ -145,45 +1034,163 @@ fn deserialize(raw: &[u8]) -> Option<HistoryData> {
 	Some(out)
 }
 
+/// # Baseline Name.
+///
+/// Trim and validate a baseline name, returning `None` if it is blank or
+/// contains the separator.
+fn baseline_name(name: &str) -> Option<String> {
+	let name = name.trim();
+	if name.is_empty() || name.contains(BASELINE_SEP) { None }
+	else { Some(name.to_owned()) }
+}
+
+/// # Header.
+///
+/// Split off the magic header, returning the format version along with the
+/// rest of the data.
+fn header(raw: &[u8]) -> Option<(u8, &[u8])> {
+	let (head, rest) = raw.split_first_chunk::<8>()?;
+	let version = head.strip_prefix(MAGIC_PREFIX)?;
+	let version = std::str::from_utf8(version).ok()?.parse::<u8>().ok()?;
+	Some((version, rest))
+}
+
+/// # Hostname.
+///
+/// Return the name of the current machine, if it can be determined.
+fn hostname() -> String {
+	["/proc/sys/kernel/hostname", "/etc/hostname"].into_iter()
+		.find_map(|p| std::fs::read_to_string(p).ok())
+		.or_else(|| std::env::var("HOSTNAME").ok())
+		.or_else(|| std::env::var("COMPUTERNAME").ok())
+		.map(|s| s.trim().to_owned())
+		.unwrap_or_default()
+}
+
+/// # Default File Name.
+///
+/// Return the default history file name for the build profile and — if
+/// known — the bench binary, e.g. `__brunch-{profile}.{binary}.last`.
+fn default_file(binary: Option<&str>) -> String {
+	binary.map_or_else(
+		|| format!("__brunch-{PROFILE}.last"),
+		|binary| format!("__brunch-{PROFILE}.{binary}.last"),
+	)
+}
+
+/// # Executable Stem.
+///
+/// Return the file stem of the current executable, i.e. the bench binary,
+/// like `encode-abc123`.
+fn exe_stem() -> Option<String> {
+	let exe = std::env::current_exe().ok()?;
+	let stem = exe.file_stem()?.to_string_lossy();
+	if stem.is_empty() { None }
+	else { Some(stem.into_owned()) }
+}
+
 /// # History Path.
 ///
 /// Return the file path history should be written to or read from.
 fn history_path() -> Option<PathBuf> {
+	pick_path(
+		std::env::var("NO_BRUNCH_HISTORY").is_ok_and(|s| s.trim() == "1"),
+		std::env::var_os("BRUNCH_HISTORY").as_deref(),
+	)
+}
+
+/// # Pick Path.
+///
+/// This is the business end of `history_path`, with the environmental
+/// values — `NO_BRUNCH_HISTORY` and `BRUNCH_HISTORY` — passed in.
+fn pick_path(disabled: bool, custom: Option<&OsStr>) -> Option<PathBuf> {
 	// No history?
-	if std::env::var("NO_BRUNCH_HISTORY").is_ok_and(|s| s.trim() == "1") { None }
+	if disabled { None }
 	// To a specific file?
-	else if let Some(p) = std::env::var_os("BRUNCH_HISTORY") {
-		let p: &Path = p.as_ref();
+	else if let Some(p) = custom { resolve_path(p.as_ref()) }
+	// To the default temporary location?
+	else {
+		let p = try_dir(Some(std::env::temp_dir()))?;
+		Some(p.join(default_file(exe_stem().as_deref())))
+	}
+}
 
-		// If the path exists, it cannot be a directory.
-		if p.is_dir() { return None; }
+/// # Prefixed Key.
+///
+/// Return the storage key for the benchmark under the named baseline, if
+/// any.
+fn prefixed<'k>(baseline: Option<&str>, key: &'k str) -> Cow<'k, str> {
+	baseline.map_or(Cow::Borrowed(key), |b| Cow::Owned(format!("{b}{BASELINE_SEP}{key}")))
+}
 
-		// Tease out the parent.
-		let parent = try_dir(p.parent())
-			.or_else(|| try_dir(std::env::current_dir().ok()))?;
+/// # Is Split?
+///
+/// Returns true if the (default) history file doesn't exist yet, but the
+/// old shared one — from before binaries got their own — does. The old
+/// file is left alone rather than imported since its entries may well have
+/// come from some other binary.
+fn is_split(path: &Path) -> bool {
+	! path.exists() &&
+	path.parent().is_some_and(|dir| dir.join(default_file(None)).is_file())
+}
 
-		// Tease out the file name.
-		let name = match p.file_name() {
-			Some(n) if ! n.is_empty() => n,
-			_ => OsStr::new(HISTORY_FILE),
-		};
+/// # Lock Is Stale?
+///
+/// Returns true if the lock file was last modified more than `LOCK_STALE` ago.
+fn lock_is_stale(lock: &Path) -> bool {
+	std::fs::metadata(lock)
+		.and_then(|m| m.modified())
+		.ok()
+		.and_then(|m| m.elapsed().ok())
+		.is_some_and(|e| LOCK_STALE <= e)
+}
 
-		Some(parent.join(name))
-	}
-	// To the default temporary location?
-	else {
-		let p = try_dir(Some(std::env::temp_dir()))?;
-		Some(p.join(HISTORY_FILE))
+/// # Nice Pruning.
+///
+/// Describe the outlier pruning parameters, e.g. `MAD ×5`.
+fn nice_pruning(v: Option<Pruning>) -> String {
+	match v {
+		None => "none".to_owned(),
+		Some(Pruning::Iqr { low, high, mult }) => format!("IQR {low}\u{2013}{high} \u{d7}{mult}"),
+		Some(Pruning::Mad { k }) => format!("MAD \u{d7}{k}"),
 	}
 }
 
-/// # Read History.
+/// # Resolve Path.
 ///
-/// Load and return the history, if any.
-fn load_history() -> Option<HistoryData> {
-	let file = history_path()?;
-	let raw = std::fs::read(file).ok()?;
-	deserialize(&raw)
+/// Validate a user-supplied history file path, creating its parent
+/// directory if needed. Relative paths without a parent are placed in the
+/// current working directory.
+///
+/// Returns `None` if the path is an existing directory or the parent cannot
+/// be created.
+fn resolve_path(p: &Path) -> Option<PathBuf> {
+	// If the path exists, it cannot be a directory.
+	if p.is_dir() { return None; }
+
+	// Tease out the parent.
+	let parent = try_dir(p.parent())
+		.or_else(|| try_dir(std::env::current_dir().ok()))?;
+
+	// Tease out the file name.
+	let name = match p.file_name() {
+		Some(n) if ! n.is_empty() => n,
+		_ => OsStr::new(HISTORY_FILE),
+	};
+
+	Some(parent.join(name))
+}
+
+/// # Unprefixed Key.
+///
+/// Return the benchmark name from a storage key if it belongs to the named
+/// (or default) baseline.
+fn unprefixed<'k>(baseline: Option<&str>, key: &'k str) -> Option<&'k str> {
+	match baseline {
+		Some(b) => key.strip_prefix(b)?.strip_prefix(BASELINE_SEP),
+		None if key.contains(BASELINE_SEP) => None,
+		None => Some(key),
+	}
 }
 
 /// # Serialize.
@@ -200,19 +1207,49 @@ fn load_history() -> Option<HistoryData> {
 /// | ------ | ------ | ---- |
 /// | 2 | `u16` | Length of bench label. |
 /// | _n_ | UTF-8 | Bench label. |
+/// | 1 | `u8` | Number of runs. |
+///
+/// Each run then follows, oldest first:
+///
+/// | Length | Format | Data |
+/// | ------ | ------ | ---- |
 /// | 4 | `u32` | Total samples. |
 /// | 4 | `u32` | Valid samples. |
 /// | 8 | `f64` | Standard deviation. |
 /// | 8 | `f64` | Average time. |
+/// | 8 | `u64` | Timestamp (Unix seconds). |
+/// | 2 | `u16` | Length of compiler version. |
+/// | _n_ | UTF-8 | Compiler version. |
+/// | 2 | `u16` | Length of target triple. |
+/// | _n_ | UTF-8 | Target triple. |
+/// | 2 | `u16` | Length of hostname. |
+/// | _n_ | UTF-8 | Hostname. |
+/// | 1 | `u8` | Metric: `0` for mean, `1` for trimmed mean, `2` for minimum. |
+/// | 8 | `f64` | Metric trim fraction (or zero). |
+/// | 1 | `u8` | Allocations: `1` if tracked, `0` if not. |
+/// | 8 | `f64` | Allocations per iteration (if tracked). |
+/// | 8 | `f64` | Bytes allocated per iteration (if tracked). |
+/// | 1 | `u8` | Performance counters: `1` if counted, `0` if not. |
+/// | 8 | `f64` | Instructions per iteration (if counted). |
+/// | 8 | `f64` | Cycles per iteration (if counted). |
+/// | 1 | `u8` | Configuration: `1` if known (warm), `2` if known (cold), `0` if not. |
+/// | 4 | `u32` | Sample limit (if known). |
+/// | 8 | `u64` | Time limit in nanoseconds (if known). |
+/// | 1 | `u8` | Outlier pruning: `0` for none, `1` for IQR, `2` for MAD (if known). |
+/// | 24 | `f64` ×3 | IQR low, high, and multiplier, or MAD `k` and two zeroes (if known). |
+///
+/// Each entry then ends with a `u8` flag — `1` if there is a best run, `0`
+/// if not — followed by the best run, if any, in the same format.
 ///
-/// All number sequences use the Big Endian layout.
+/// All number sequences use the Big Endian layout. Runs without metadata are
+/// written with a zero timestamp and empty strings.
 fn serialize(history: &HistoryData) -> Vec<u8> {
 	// Start with the magic header.
 	let mut out = Vec::with_capacity(64 * history.len());
 	out.extend_from_slice(MAGIC);
 
 	// Write each benchmark entry.
-	for (lbl, s) in history {
+	for (lbl, entry) in history {
 		// We panic on long names so this should never fail, but just in case,
 		// let's check.
 		if let Ok(len) = u16::try_from(lbl.len()) {
@@ -220,17 +1257,140 @@ fn serialize(history: &HistoryData) -> Vec<u8> {
 			out.extend_from_slice(&len.to_be_bytes());
 			out.extend_from_slice(lbl.as_bytes());
 
-			// Total, valid, deviation, and mean follow, in that order.
-			out.extend_from_slice(&s.total.to_be_bytes());
-			out.extend_from_slice(&s.valid.to_be_bytes());
-			out.extend_from_slice(&s.deviation.to_be_bytes());
-			out.extend_from_slice(&s.mean.to_be_bytes());
+			// Next comes the number of runs, keeping the most recent if there
+			// are somehow too many.
+			let runs = &entry.runs;
+			let count = u8::saturating_from(runs.len());
+			out.push(count);
+
+			// Total, valid, deviation, and mean follow for each, in that
+			// order, then the metadata.
+			for r in &runs[runs.len() - usize::from(count)..] { r.serialize(&mut out); }
+
+			// And finally the best.
+			if let Some(best) = &entry.best {
+				out.push(1);
+				best.serialize(&mut out);
+			}
+			else { out.push(0); }
 		}
 	}
 
 	out
 }
 
+/// # Serialize Stats.
+///
+/// Write the total, valid, deviation, and mean to the buffer, in that order.
+pub(crate) fn serialize_stats(out: &mut Vec<u8>, v: Stats) {
+	out.extend_from_slice(&v.total.to_be_bytes());
+	out.extend_from_slice(&v.valid.to_be_bytes());
+	out.extend_from_slice(&v.deviation.to_be_bytes());
+	out.extend_from_slice(&v.mean.to_be_bytes());
+}
+
+/// # Serialize Allocations.
+///
+/// Write a `u8` flag — `1` if there are allocation counts, `0` if not —
+/// followed by the allocations and bytes per iteration, if any.
+pub(crate) fn serialize_allocs(out: &mut Vec<u8>, v: Option<Allocs>) {
+	if let Some(v) = v {
+		out.push(1);
+		out.extend_from_slice(&v.allocs().to_be_bytes());
+		out.extend_from_slice(&v.bytes().to_be_bytes());
+	}
+	else { out.push(0); }
+}
+
+/// # Serialize Performance Counters.
+///
+/// Write a `u8` flag — `1` if there are performance counts, `0` if not —
+/// followed by the instructions and cycles per iteration, if any.
+pub(crate) fn serialize_perf(out: &mut Vec<u8>, v: Option<Counters>) {
+	if let Some(v) = v {
+		out.push(1);
+		out.extend_from_slice(&v.instructions().to_be_bytes());
+		out.extend_from_slice(&v.cycles().to_be_bytes());
+	}
+	else { out.push(0); }
+}
+
+/// # Serialize Configuration.
+///
+/// Write a `u8` flag — `1` if there is a configuration, `0` if not —
+/// followed by the settings, if any.
+fn serialize_config(out: &mut Vec<u8>, v: Option<Config>) {
+	if let Some(v) = v {
+		out.push(if v.cold { 2 } else { 1 });
+		out.extend_from_slice(&v.samples.to_be_bytes());
+		out.extend_from_slice(&u64::saturating_from(v.timeout.as_nanos()).to_be_bytes());
+		let (kind, a, b, c) = match v.outliers {
+			None => (0_u8, 0.0_f64, 0.0_f64, 0.0_f64),
+			Some(Pruning::Iqr { low, high, mult }) => (1, low, high, mult),
+			Some(Pruning::Mad { k }) => (2, k, 0.0, 0.0),
+		};
+		out.push(kind);
+		for f in [a, b, c] { out.extend_from_slice(&f.to_be_bytes()); }
+	}
+	else { out.push(0); }
+}
+
+/// # Serialize String.
+///
+/// Write a length-prefixed string to the buffer. (Anything too long to
+/// measure is written as empty instead.)
+pub(crate) fn serialize_str(out: &mut Vec<u8>, v: &str) {
+	let (len, v) = u16::try_from(v.len()).map_or((0, ""), |len| (len, v));
+	out.extend_from_slice(&len.to_be_bytes());
+	out.extend_from_slice(v.as_bytes());
+}
+
+/// # Write Atomically.
+///
+/// Write the data to a temporary file alongside the destination, then move
+/// it into place, ensuring the destination is never left partially written.
+///
+/// If the rename fails for whatever reason, the data will be written to the
+/// destination directly instead.
+fn write_atomic(dst: &Path, data: &[u8]) -> std::io::Result<()> {
+	/// # Write File.
+	fn write(dst: &Path, data: &[u8]) -> std::io::Result<()> {
+		let mut f = File::create(dst)?;
+		f.write_all(data).and_then(|()| f.flush())
+	}
+
+	let mut tmp = dst.as_os_str().to_owned();
+	tmp.push(".tmp");
+	let tmp = PathBuf::from(tmp);
+
+	if write(&tmp, data).and_then(|()| std::fs::rename(&tmp, dst)).is_ok() {
+		return Ok(());
+	}
+
+	// Clean up and try again the old-fashioned way.
+	let _res = std::fs::remove_file(&tmp);
+	write(dst, data)
+}
+
+/// # History Depth.
+///
+/// Return the number of runs to retain per benchmark, per the
+/// `BRUNCH_HISTORY_DEPTH` environmental variable, or the default.
+fn history_depth() -> u8 {
+	std::env::var("BRUNCH_HISTORY_DEPTH").ok()
+		.and_then(|s| s.trim().parse::<u8>().ok())
+		.filter(|&n| n != 0)
+		.unwrap_or(DEFAULT_DEPTH)
+}
+
+/// # Is Unsupported?
+///
+/// Returns true if the raw data looks like a history file from a newer
+/// version of `Brunch`.
+fn is_unsupported(raw: &[u8]) -> bool {
+	header(raw).is_some_and(|(v, _)| VERSION < v)
+}
+
 /// # Try Dir.
 ///
 /// Test if the thing is a directory and return it.
@@ -266,6 +1426,9 @@ mod tests {
 					valid: 2496,
 					deviation: 0.000_000_123,
 					mean: 0.000_002_2,
+					sum: 0.0,
+					drift: 0.0,
+					dist: None,
 				},
 			),
 			(
@@ -275,12 +1438,15 @@ mod tests {
 					valid: 222,
 					deviation: 0.000_400_123,
 					mean: 0.000_012_2,
+					sum: 0.0,
+					drift: 0.0,
+					dist: None,
 				},
 			),
 		];
 
 		// Our reference.
-		let mut h = ENTRIES.into_iter().map(|(k, v)| (k.to_owned(), v)).collect::<HistoryData>();
+		let mut h = ENTRIES.into_iter().map(|(k, v)| (k.to_owned(), Entry { runs: vec![Run { stats: v, meta: None, metric: Metric::Mean, allocs: None, perf: None, config: None }], best: None })).collect::<HistoryData>();
 
 		// Serialize it.
 		let s = serialize(&h);
@@ -294,7 +1460,7 @@ mod tests {
 
 		// Make sure the entries are unchanged.
 		for (lbl, stat) in ENTRIES {
-			let tmp = d.get(lbl).expect("Missing entry!");
+			let tmp = d.get(lbl).and_then(Entry::last).map(|r| r.stats).expect("Missing entry!");
 			assert_eq!(stat.total, tmp.total, "Total changed.");
 			assert_eq!(stat.valid, tmp.valid, "Valid changed.");
 			assert!(total_cmp!((stat.deviation) == (tmp.deviation)), "Deviation changed.");
@@ -303,18 +1469,38 @@ mod tests {
 
 		// Let's add a logically-suspect entry to the history, and make sure
 		// it gets stripped out during deserialize.
-		h.insert("A Suspect One".to_owned(), Stats {
-			total: 200,
-			valid: 300,
-			deviation: 0.000_400_123,
-			mean: 0.000_012_2,
-		});
-		h.insert(String::new(), Stats {
-			total: 500,
-			valid: 300,
-			deviation: 0.000_400_123,
-			mean: 0.000_012_2,
-		});
+		h.insert("A Suspect One".to_owned(), Entry { runs: vec![Run {
+			stats: Stats {
+				total: 200,
+				valid: 300,
+				deviation: 0.000_400_123,
+				mean: 0.000_012_2,
+				sum: 0.0,
+				drift: 0.0,
+				dist: None,
+			},
+			meta: None,
+			metric: Metric::Mean,
+			allocs: None,
+			perf: None,
+			config: None,
+		}], best: None });
+		h.insert(String::new(), Entry { runs: vec![Run {
+			stats: Stats {
+				total: 500,
+				valid: 300,
+				deviation: 0.000_400_123,
+				mean: 0.000_012_2,
+				sum: 0.0,
+				drift: 0.0,
+				dist: None,
+			},
+			meta: None,
+			metric: Metric::Mean,
+			allocs: None,
+			perf: None,
+			config: None,
+		}], best: None });
 
 		// Make sure these exist in the reference struct.
 		assert!(h.contains_key("A Suspect One"));
@@ -332,7 +1518,7 @@ mod tests {
 		// To be extra safe, let's recheck the valid entries to make sure they
 		// didn't get screwed up in any way.
 		for (lbl, stat) in ENTRIES {
-			let tmp = d.get(lbl).expect("Missing entry!");
+			let tmp = d.get(lbl).and_then(Entry::last).map(|r| r.stats).expect("Missing entry!");
 			assert_eq!(stat.total, tmp.total, "Total changed.");
 			assert_eq!(stat.valid, tmp.valid, "Valid changed.");
 			assert!(total_cmp!((stat.deviation) == (tmp.deviation)), "Deviation changed.");
@@ -344,4 +1530,529 @@ mod tests {
 		assert!(deserialize(&s).is_none());
 		assert!(deserialize(&[]).is_none());
 	}
+
+	#[test]
+	fn t_depth() {
+		let path = std::env::temp_dir().join("__brunch-test-depth.last");
+		let _res = std::fs::remove_file(&path);
+
+		// Save more runs than we're allowed to keep.
+		for i in 1..=5_u32 {
+			let mut hist = History::load(Some(path.clone()));
+			hist.depth = 3;
+			hist.insert("Foo", Stats {
+				total: 2500,
+				valid: 2496,
+				deviation: 0.000_000_1,
+				mean: f64::from(i),
+				sum: 0.0,
+				drift: 0.0,
+				dist: None,
+			}, Metric::Mean, None, None, None);
+			hist.save();
+		}
+
+		// Only the three most recent should remain, oldest first.
+		let hist = History::load(Some(path.clone()));
+		let means: Vec<f64> = hist.runs("Foo").iter().map(|s| s.mean).collect();
+		assert_eq!(means, [3.0, 4.0, 5.0]);
+
+		// The baseline should be pooled.
+		let pooled = hist.get("Foo").expect("Missing entry.");
+		assert!(total_cmp!((pooled.mean) == 4.0), "Wrong pooled mean.");
+
+		// A file from a newer version should be ignored, but not considered
+		// corrupt.
+		std::fs::write(&path, b"BRUNCH99").expect("Unable to write history file.");
+		let hist = History::load(Some(path.clone()));
+		assert!(! hist.is_corrupt(), "Unsupported history is not corrupt.");
+		assert!(hist.runs("Foo").is_empty(), "Unsupported history should be ignored.");
+
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_best() {
+		let stat = |mean: f64, valid: u32| Stats {
+			total: 2500,
+			valid,
+			deviation: 0.000_000_1,
+			mean,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+
+		let mut hist = History::load(None);
+		hist.depth = 2;
+		assert!(hist.best("Foo").is_none(), "Unexpected best.");
+
+		// The first run is the best by default.
+		hist.insert("Foo", stat(3.0, 2500), Metric::Mean, None, None, None);
+		assert!(total_cmp!((hist.best("Foo").unwrap().mean) == 3.0));
+
+		// Slower runs shouldn't replace it.
+		hist.insert("Foo", stat(4.0, 2500), Metric::Mean, None, None, None);
+		assert!(total_cmp!((hist.best("Foo").unwrap().mean) == 3.0));
+
+		// Nor should noisy ones, however fast.
+		hist.insert("Foo", stat(1.0, 2000), Metric::Mean, None, None, None);
+		assert!(total_cmp!((hist.best("Foo").unwrap().mean) == 3.0));
+
+		// The best should outlive the depth, and survive the round trip.
+		hist.insert("Foo", stat(5.0, 2500), Metric::Mean, None, None, None);
+		let d = deserialize(&serialize(&hist.data)).expect("Deserialization failed.");
+		let entry = d.get("Foo").expect("Missing entry.");
+		assert_eq!(entry.runs.len(), 2);
+		assert!(total_cmp!((entry.best.as_ref().unwrap().stats.mean) == 3.0));
+
+		// Faster runs should take over.
+		hist.insert("Foo", stat(2.0, 2500), Metric::Mean, None, None, None);
+		assert!(total_cmp!((hist.best("Foo").unwrap().mean) == 2.0));
+
+		// As should runs using a different metric, which aren't comparable
+		// with any of the others.
+		hist.insert("Foo", stat(6.0, 2500), Metric::TrimmedMean(0.1), None, None, None);
+		assert!(total_cmp!((hist.best("Foo").unwrap().mean) == 6.0));
+		assert_eq!(hist.runs("Foo").len(), 1, "Old runs should be dropped.");
+		hist.insert("Foo", stat(7.0, 2500), Metric::Min, None, None, None);
+		assert!(total_cmp!((hist.best("Foo").unwrap().mean) == 7.0));
+		assert_eq!(hist.runs("Foo").len(), 1, "Old runs should be dropped.");
+	}
+
+	#[test]
+	fn t_allocs() {
+		let stat = Stats {
+			total: 2500,
+			valid: 2500,
+			deviation: 0.000_000_1,
+			mean: 1.0,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+
+		let mut hist = History::load(None);
+		hist.insert("Foo", stat, Metric::Mean, None, None, None);
+		hist.insert("Bar", stat, Metric::Mean, Some(Allocs::new(3.0, 96.0)), None, None);
+		assert!(hist.allocs("Foo").is_none(), "Unexpected allocs.");
+		assert_eq!(hist.allocs("Bar"), Some(Allocs::new(3.0, 96.0)));
+
+		// They should survive the round trip.
+		let d = deserialize(&serialize(&hist.data)).expect("Deserialization failed.");
+		assert!(d.get("Foo").and_then(Entry::last).and_then(|r| r.allocs).is_none());
+		assert_eq!(
+			d.get("Bar").and_then(Entry::last).and_then(|r| r.allocs),
+			Some(Allocs::new(3.0, 96.0)),
+		);
+	}
+
+	#[test]
+	fn t_config() {
+		let stat = |mean: f64| Stats {
+			total: 2500,
+			valid: 2500,
+			deviation: 0.000_000_1,
+			mean,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+		let one = Config::new(2500, Duration::from_secs(10), OutlierPolicy::Default, false);
+		let two = Config::new(50_000, Duration::from_secs(10), OutlierPolicy::MAD, false);
+
+		// Unknown configurations are assumed to match.
+		let mut hist = History::load(None);
+		hist.insert("Foo", stat(3.0), Metric::Mean, None, None, None);
+		hist.insert("Foo", stat(2.0), Metric::Mean, None, None, Some(one));
+		assert_eq!(hist.runs("Foo").len(), 2);
+		assert_eq!(hist.config("Foo"), Some(one));
+
+		// The same configuration just adds another run.
+		hist.insert("Foo", stat(4.0), Metric::Mean, None, None, Some(one));
+		assert_eq!(hist.runs("Foo").len(), 3);
+
+		// But a new one starts over.
+		hist.insert("Foo", stat(5.0), Metric::Mean, None, None, Some(two));
+		assert_eq!(hist.runs("Foo").len(), 1);
+		assert!(total_cmp!((hist.best("Foo").unwrap().mean) == 5.0), "Best should have been reset.");
+
+		// The differences should be spelled out.
+		assert_eq!(
+			two.diff(&one),
+			["samples 2,500 \u{2192} 50,000", "outliers IQR 0.05\u{2013}0.95 \u{d7}1.5 \u{2192} MAD \u{d7}5"],
+		);
+		assert!(one.diff(&one).is_empty());
+
+		// And it should survive the round trip.
+		let d = deserialize(&serialize(&hist.data)).expect("Deserialization failed.");
+		assert_eq!(d.get("Foo").and_then(Entry::last).and_then(|r| r.config), Some(two));
+
+		// Cold runs shouldn't mix with warm ones either.
+		let cold = Config::new(50_000, Duration::from_secs(10), OutlierPolicy::MAD, true);
+		assert_eq!(cold.diff(&two), ["caches warm \u{2192} cold"]);
+		hist.insert("Foo", stat(6.0), Metric::Mean, None, None, Some(cold));
+		assert_eq!(hist.runs("Foo").len(), 1);
+		let d = deserialize(&serialize(&hist.data)).expect("Deserialization failed.");
+		assert_eq!(d.get("Foo").and_then(Entry::last).and_then(|r| r.config), Some(cold));
+	}
+
+	#[test]
+	fn t_meta() {
+		let stat = Stats {
+			total: 2500,
+			valid: 2496,
+			deviation: 0.000_000_123,
+			mean: 0.000_002_2,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+
+		// Metadata should survive the round trip.
+		let mut hist = History::load(None);
+		hist.insert("Foo", stat, Metric::Mean, None, None, None);
+		let d = deserialize(&serialize(&hist.data)).expect("Deserialization failed.");
+		let meta = d.get("Foo")
+			.and_then(Entry::last)
+			.and_then(|r| r.meta.as_ref())
+			.expect("Missing metadata.");
+		assert_eq!(meta, &hist.meta);
+
+		// As should the metric.
+		hist.insert("Bar", stat, Metric::TrimmedMean(0.1), None, None, None);
+		let d = deserialize(&serialize(&hist.data)).expect("Deserialization failed.");
+		assert_eq!(d.get("Foo").and_then(Entry::last).map(|r| r.metric), Some(Metric::Mean));
+		assert_eq!(d.get("Bar").and_then(Entry::last).map(|r| r.metric), Some(Metric::TrimmedMean(0.1)));
+		assert_eq!(hist.metric("Bar"), Some(Metric::TrimmedMean(0.1)));
+		hist.insert("Baz", stat, Metric::Min, None, None, None);
+		let d = deserialize(&serialize(&hist.data)).expect("Deserialization failed.");
+		assert_eq!(d.get("Baz").and_then(Entry::last).map(|r| r.metric), Some(Metric::Min));
+
+		// Matching environments should not be flagged, even if the time
+		// differs.
+		hist.data.get_mut("Foo").unwrap().runs[0].meta.as_mut().unwrap().time = 0;
+		assert!(hist.mismatch("Foo").is_none(), "Unexpected mismatch.");
+
+		// But other differences should.
+		hist.data.get_mut("Foo").unwrap().runs[0].meta.as_mut().unwrap().rustc = "rustc 0.1.0".to_owned();
+		let meta = hist.mismatch("Foo").expect("Missing mismatch.");
+		assert_eq!(meta.rustc(), "rustc 0.1.0");
+
+		// Older formats should be readable, sans metadata.
+		let mut raw = b"BRUNCH01".to_vec();
+		raw.extend_from_slice(&3_u16.to_be_bytes());
+		raw.extend_from_slice(b"Foo");
+		raw.push(1);
+		raw.extend_from_slice(&stat.total.to_be_bytes());
+		raw.extend_from_slice(&stat.valid.to_be_bytes());
+		raw.extend_from_slice(&stat.deviation.to_be_bytes());
+		raw.extend_from_slice(&stat.mean.to_be_bytes());
+		let d = deserialize(&raw).expect("Deserialization failed.");
+		let run = d.get("Foo").and_then(Entry::last).expect("Missing entry.");
+		assert!(run.meta.is_none(), "Unexpected metadata.");
+		assert_eq!(run.metric, Metric::Mean);
+		assert_eq!(run.stats.total, stat.total);
+
+		// The best should be inferred from the runs.
+		let best = d.get("Foo").and_then(|e| e.best.as_ref()).expect("Missing best.");
+		assert_eq!(best.stats.total, stat.total);
+
+		// Same for the original format, which lacks the run count.
+		raw.drain(..8);
+		raw.remove(5);
+		raw.splice(..0, b"BRUNCH00".iter().copied());
+		let d = deserialize(&raw).expect("Deserialization failed.");
+		let run = d.get("Foo").and_then(Entry::last).expect("Missing entry.");
+		assert!(run.meta.is_none(), "Unexpected metadata.");
+		assert_eq!(run.stats.total, stat.total);
+	}
+
+	#[test]
+	fn t_resolve_path() {
+		let dir = std::env::temp_dir().join("__brunch-test-resolve");
+		let _res = std::fs::remove_dir_all(&dir);
+
+		// Missing parents should be created.
+		let path = resolve_path(&dir.join("nested/foo.hist")).expect("Path should resolve.");
+		assert!(path.ends_with("nested/foo.hist"), "Wrong file path.");
+		assert!(dir.join("nested").is_dir(), "Parent not created.");
+
+		// Existing directories are not valid file paths.
+		assert!(resolve_path(&dir).is_none(), "Directories are not files.");
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_pick_path() {
+		let dir = std::env::temp_dir().join("__brunch-test-pick");
+		let _res = std::fs::remove_dir_all(&dir);
+
+		// Disabled trumps everything.
+		assert!(pick_path(true, None).is_none(), "History should be disabled.");
+		assert!(pick_path(true, Some(dir.as_os_str())).is_none(), "History should be disabled.");
+
+		// The default lives in the temporary directory.
+		let path = pick_path(false, None).expect("Missing default path.");
+		let temp = std::fs::canonicalize(std::env::temp_dir()).expect("Missing temporary directory.");
+		assert_eq!(path.parent(), Some(temp.as_path()));
+		assert_eq!(
+			path.file_name().and_then(OsStr::to_str),
+			Some(default_file(exe_stem().as_deref()).as_str()),
+		);
+
+		// Custom paths should be used as-is, creating parents as needed.
+		let custom = dir.join("nested/custom.hist");
+		let path = pick_path(false, Some(custom.as_os_str())).expect("Missing custom path.");
+		assert!(path.ends_with("nested/custom.hist"), "Wrong file path.");
+		assert!(dir.join("nested").is_dir(), "Parent not created.");
+
+		// But directories are no good.
+		assert!(pick_path(false, Some(dir.as_os_str())).is_none(), "Directories are not files.");
+
+		// Bare and empty file names fall back to the working directory.
+		let cwd = std::fs::canonicalize(std::env::current_dir().expect("Missing working directory."))
+			.expect("Missing working directory.");
+		assert_eq!(pick_path(false, Some(OsStr::new("foo.hist"))), Some(cwd.join("foo.hist")));
+		assert_eq!(pick_path(false, Some(OsStr::new(""))), Some(cwd.join(HISTORY_FILE)));
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_split() {
+		let dir = std::env::temp_dir().join("__brunch-test-split");
+		let _res = std::fs::remove_dir_all(&dir);
+		std::fs::create_dir_all(&dir).expect("Unable to create directory.");
+
+		// Each binary should get its own file.
+		let path = dir.join(default_file(Some("encode-abc123")));
+		assert!(
+			path.to_string_lossy().ends_with(&format!("__brunch-{PROFILE}.encode-abc123.last")),
+			"Wrong file name: {}",
+			path.display(),
+		);
+		assert!(exe_stem().is_some(), "Missing executable stem.");
+
+		// Nothing to split from.
+		assert!(! is_split(&path), "Nothing should be split.");
+
+		// The old shared file exists, but the new one doesn't.
+		std::fs::write(dir.join(default_file(None)), MAGIC).expect("Unable to write history file.");
+		assert!(is_split(&path), "The shared history should be passed over.");
+
+		// Once the new one exists, it's business as usual.
+		std::fs::write(&path, MAGIC).expect("Unable to write history file.");
+		assert!(! is_split(&path), "The binary's own history should be used.");
+
+		let _res = std::fs::remove_dir_all(&dir);
+	}
+
+	#[test]
+	fn t_corrupt() {
+		let path = std::env::temp_dir().join("__brunch-test-corrupt.last");
+		let stat = Stats {
+			total: 2500,
+			valid: 2496,
+			deviation: 0.000_000_123,
+			mean: 0.000_002_2,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+
+		// Start with a good history.
+		let mut h = History::load(Some(path.clone()));
+		h.insert("Foo", stat, Metric::Mean, None, None, None);
+		h.save();
+		let h = History::load(Some(path.clone()));
+		assert!(! h.is_corrupt(), "History should not be corrupt.");
+		assert!(h.get("Foo").is_some(), "History entry missing.");
+
+		// Truncate it.
+		let mut raw = std::fs::read(&path).expect("Missing history file.");
+		raw.truncate(raw.len() - 5);
+		std::fs::write(&path, &raw).expect("Unable to write history file.");
+
+		// It should be flagged as corrupt, and empty.
+		let mut h = History::load(Some(path.clone()));
+		assert!(h.is_corrupt(), "History should be corrupt.");
+		assert!(h.get("Foo").is_none(), "History should be empty.");
+
+		// Saving should repair it.
+		h.insert("Bar", stat, Metric::Mean, None, None, None);
+		h.save();
+		let h = History::load(Some(path.clone()));
+		assert!(! h.is_corrupt(), "History should not be corrupt.");
+		assert!(h.get("Bar").is_some(), "History entry missing.");
+
+		// The temporary file should not have been left behind.
+		let mut tmp = path.as_os_str().to_owned();
+		tmp.push(".tmp");
+		assert!(! PathBuf::from(tmp).exists(), "Temporary file left behind.");
+
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_concurrent() {
+		let path = std::env::temp_dir().join("__brunch-test-concurrent.last");
+		let _res = std::fs::remove_file(&path);
+		let stat = Stats {
+			total: 2500,
+			valid: 2496,
+			deviation: 0.000_000_123,
+			mean: 0.000_002_2,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+
+		// Two processes load the same (empty) history.
+		let mut one = History::load(Some(path.clone()));
+		let mut two = History::load(Some(path.clone()));
+
+		// Each adds their own entries and saves.
+		one.insert("A", stat, Metric::Mean, None, None, None);
+		two.insert("B", stat, Metric::Mean, None, None, None);
+		one.save();
+		two.save();
+
+		// Both should be present.
+		let hist = History::load(Some(path.clone()));
+		assert!(hist.get("A").is_some(), "Missing entry A.");
+		assert!(hist.get("B").is_some(), "Missing entry B.");
+
+		// Simulate a stale lock left behind by a crashed process.
+		let mut lock = path.as_os_str().to_owned();
+		lock.push(".lock");
+		let lock = PathBuf::from(lock);
+		let file = File::create(&lock).expect("Unable to create lock.");
+		file.set_modified(std::time::SystemTime::now() - Duration::from_secs(3600))
+			.expect("Unable to set lock mtime.");
+		drop(file);
+
+		// Saving should barrel through it.
+		let now = Instant::now();
+		let mut three = History::load(Some(path.clone()));
+		three.insert("C", stat, Metric::Mean, None, None, None);
+		three.save();
+		assert!(now.elapsed() < LOCK_TIMEOUT, "Stale lock was not detected.");
+		assert!(! lock.exists(), "Lock file left behind.");
+
+		let hist = History::load(Some(path.clone()));
+		assert!(hist.get("A").is_some(), "Missing entry A.");
+		assert!(hist.get("B").is_some(), "Missing entry B.");
+		assert!(hist.get("C").is_some(), "Missing entry C.");
+
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_prune() {
+		let path = std::env::temp_dir().join("__brunch-test-prune.last");
+		let _res = std::fs::remove_file(&path);
+		let stat = Stats {
+			total: 2500,
+			valid: 2496,
+			deviation: 0.000_000_123,
+			mean: 0.000_002_2,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+
+		// Save a few entries.
+		let mut hist = History::load(Some(path.clone()));
+		hist.insert("A", stat, Metric::Mean, None, None, None);
+		hist.insert("B", stat, Metric::Mean, None, None, None);
+		hist.insert("C", stat, Metric::Mean, None, None, None);
+		assert_eq!(hist.save(), 0);
+
+		// Without pruning, nothing should be dropped.
+		let mut hist = History::load(Some(path.clone()));
+		hist.insert("A", stat, Metric::Mean, None, None, None);
+		assert_eq!(hist.save(), 0);
+		let hist = History::load(Some(path.clone()));
+		assert!(hist.get("C").is_some(), "Missing entry C.");
+
+		// Now prune.
+		let mut hist = History::load(Some(path.clone()));
+		hist.insert("A", stat, Metric::Mean, None, None, None);
+		hist.prune();
+		assert_eq!(hist.save(), 2);
+
+		let hist = History::load(Some(path.clone()));
+		assert!(hist.get("A").is_some(), "Missing entry A.");
+		assert!(hist.get("B").is_none(), "Entry B should have been pruned.");
+		assert!(hist.get("C").is_none(), "Entry C should have been pruned.");
+
+		let _res = std::fs::remove_file(&path);
+	}
+
+	#[test]
+	fn t_baseline() {
+		let path = std::env::temp_dir().join("__brunch-test-baseline.last");
+		let _res = std::fs::remove_file(&path);
+		let stat = |mean: f64| Stats {
+			total: 2500,
+			valid: 2496,
+			deviation: 0.000_000_123,
+			mean,
+			sum: 0.0,
+			drift: 0.0,
+			dist: None,
+		};
+
+		// Save the same bench to the default and a named baseline.
+		let mut hist = History::load(Some(path.clone()));
+		hist.insert("A", stat(1.0), Metric::Mean, None, None, None);
+		assert_eq!(hist.save(), 0);
+		let mut hist = History::load(Some(path.clone()));
+		hist.select(" main ");
+		hist.save_to(" main ");
+		assert_eq!(hist.baseline(), Some("main"));
+		assert_eq!(hist.target(), Some("main"));
+		assert!(hist.get("A").is_none(), "The main baseline should start empty.");
+		hist.insert("A", stat(2.0), Metric::Mean, None, None, None);
+		hist.insert("B", stat(3.0), Metric::Mean, None, None, None);
+		assert_eq!(hist.save(), 0);
+
+		// They should coexist.
+		let hist = History::load(Some(path.clone()));
+		assert!(hist.get("A").is_some_and(|s| total_cmp!((s.mean) == 1.0)), "Wrong default A.");
+		assert!(hist.get("B").is_none(), "B belongs to main.");
+		assert_eq!(hist.keys().collect::<Vec<_>>(), ["A"]);
+		let mut hist = History::load(Some(path.clone()));
+		hist.select("main");
+		assert!(hist.get("A").is_some_and(|s| total_cmp!((s.mean) == 2.0)), "Wrong main A.");
+		assert_eq!(hist.keys().collect::<Vec<_>>(), ["A", "B"]);
+
+		// Comparing against a baseline shouldn't write to it.
+		hist.insert("A", stat(4.0), Metric::Mean, None, None, None);
+		assert_eq!(hist.save(), 0);
+		let mut hist = History::load(Some(path.clone()));
+		assert_eq!(hist.runs("A").len(), 2, "The default baseline should have been updated.");
+		hist.select("main");
+		assert_eq!(hist.runs("A").len(), 1, "The main baseline should be untouched.");
+
+		// Pruning should only affect the saved-to baseline.
+		hist.save_to("main");
+		hist.insert("A", stat(2.0), Metric::Mean, None, None, None);
+		hist.prune();
+		assert_eq!(hist.save(), 1);
+		let hist = History::load(Some(path.clone()));
+		assert!(hist.get("A").is_some(), "The default baseline should be untouched.");
+
+		// Bad names are ignored.
+		let mut hist = History::load(Some(path.clone()));
+		hist.select("  ");
+		hist.select("a\u{1f}b");
+		hist.save_to("a\u{1f}b");
+		assert!(hist.baseline().is_none(), "Invalid baseline selected.");
+		assert!(hist.target().is_none(), "Invalid baseline selected.");
+
+		let _res = std::fs::remove_file(&path);
+	}
 }
@@ -8,23 +8,38 @@ use crate::{
 	Abacus,
 	BrunchError,
 	MIN_SAMPLES,
+	MIN_SAMPLES_FLOOR,
+	Stream,
+	TimeUnit,
+	util,
 };
 use dactyl::{
-	NiceFloat,
 	NicePercent,
 	total_cmp,
 	traits::SaturatingFrom,
 };
 use std::{
-	cmp::Ordering,
+	fmt,
 	time::Duration,
 };
 
 
 
+/// # Minimum Change.
+///
+/// Differences smaller than this (half a percent) are never reported, no
+/// matter how statistically significant.
+const MIN_CHANGE: f64 = 0.005;
+
+
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// # Runtime Stats!
-pub(crate) struct Stats {
+///
+/// This struct holds the crunched results for a single benchmark run, after
+/// outliers have been pruned. It can be obtained programmatically via
+/// [`Benches::results`](crate::Benches::results).
+pub struct Stats {
 	/// # Total Samples.
 	total: u32,
 
@@ -36,98 +51,772 @@ pub(crate) struct Stats {
 
 	/// # Mean Duration of Valid Samples.
 	mean: f64,
+
+	/// # Total Duration of Valid Samples.
+	sum: f64,
+
+	/// # Drift.
+	///
+	/// The relative change in the mean from the first half of the samples to
+	/// the second, in the order collected.
+	drift: f64,
+
+	/// # Distribution.
+	///
+	/// This is only known when the individual samples are, i.e. not for
+	/// streams or stats loaded from history.
+	dist: Option<Distribution>,
+}
+
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// # Distribution.
+///
+/// The shape of the valid samples, beyond their mean and deviation.
+pub(crate) struct Distribution {
+	/// # Fastest.
+	pub(crate) min: f64,
+
+	/// # Median.
+	pub(crate) median: f64,
+
+	/// # 95th Percentile.
+	pub(crate) p95: f64,
+
+	/// # Slowest.
+	pub(crate) max: f64,
+}
+
+impl From<&Abacus> for Distribution {
+	fn from(calc: &Abacus) -> Self {
+		Self {
+			min: calc.min(),
+			median: calc.median(),
+			p95: calc.percentile(0.95),
+			max: calc.max(),
+		}
+	}
+}
+
+impl Distribution {
+	/// # Is Valid?
+	///
+	/// Returns true if the figures are finite, non-negative, and in order.
+	fn is_valid(self) -> bool {
+		self.min.is_finite() &&
+		self.max.is_finite() &&
+		total_cmp!((self.min) >= 0.0) &&
+		total_cmp!((self.min) <= (self.median)) &&
+		total_cmp!((self.median) <= (self.max)) &&
+		total_cmp!((self.min) <= (self.p95)) &&
+		total_cmp!((self.p95) <= (self.max))
+	}
 }
 
 impl TryFrom<Vec<Duration>> for Stats {
 	type Error = BrunchError;
 	fn try_from(samples: Vec<Duration>) -> Result<Self, Self::Error> {
+		Self::from_samples(samples, OutlierPolicy::Default, Metric::Mean, MIN_SAMPLES)
+	}
+}
+
+impl Stats {
+	/// # From Samples.
+	///
+	/// Crunch the stats for a complete set of samples, pruning outliers
+	/// according to the policy, and averaging them according to the metric.
+	///
+	/// At least `min` samples are required, both before and after pruning.
+	pub(crate) fn from_samples(
+		samples: Vec<Duration>,
+		outliers: OutlierPolicy,
+		metric: Metric,
+		min: u32,
+	) -> Result<Self, BrunchError> {
 		let total = u32::saturating_from(samples.len());
-		if total < MIN_SAMPLES {
+		if total < min {
 			return Err(BrunchError::TooSmall(total));
 		}
 
+		// Hang onto the collection order long enough to check for drift.
+		let ordered: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+
 		// Crunch!
 		let mut calc = Abacus::from(samples);
-		calc.prune_outliers();
+		let drift = calc.drift(&ordered, outliers);
+		calc.prune_outliers(outliers);
+		let dist = Distribution::from(&calc);
 
 		let valid = u32::saturating_from(calc.len());
-		if valid < MIN_SAMPLES {
-			return Err(BrunchError::TooWild);
+		let mean = match metric.sanitize() {
+			Metric::Mean => calc.mean(),
+			Metric::TrimmedMean(f) => calc.trimmed_mean(f),
+			Metric::Min => calc.min(),
+		};
+		Self::from_crunched(total, valid, mean, calc.deviation(), calc.sum(), outliers, min)
+			.map(|s| s.with_drift(drift).with_distribution(dist))
+	}
+
+	/// # From Stream.
+	///
+	/// Crunch the stats for a [`Stream`], pruning outliers according to the
+	/// policy, and averaging them according to the metric.
+	///
+	/// Note: streams don't keep the collection order or individual samples,
+	/// so can't be checked for drift, or report a median, etc.
+	pub(crate) fn from_stream(
+		stream: Stream,
+		outliers: OutlierPolicy,
+		metric: Metric,
+		min: u32,
+	) -> Result<Self, BrunchError> {
+		let total = stream.total();
+		if total < min {
+			return Err(BrunchError::TooSmall(total));
 		}
 
-		let mean = calc.mean();
-		let deviation = calc.deviation();
+		// Crunch!
+		let (valid, mean, deviation, sum) = stream.crunch(outliers, metric);
+		Self::from_crunched(total, valid, mean, deviation, sum, outliers, min)
+	}
+
+	/// # From Crunched Figures.
+	///
+	/// Validate and return the final stats.
+	fn from_crunched(
+		total: u32,
+		valid: u32,
+		mean: f64,
+		deviation: f64,
+		sum: f64,
+		outliers: OutlierPolicy,
+		min: u32,
+	) -> Result<Self, BrunchError> {
+		if valid < min {
+			return Err(BrunchError::TooWild);
+		}
 
 		// Done!
-		let out = Self { total, valid, deviation, mean };
-		if out.is_valid() { Ok(out) }
+		let out = Self { total, valid, deviation, mean, sum, drift: 0.0, dist: None };
+		if out.is_valid(min) { Ok(out) }
+		// Without pruning, a deviation that blows up means the samples were
+		// just too wild.
+		else if matches!(outliers, OutlierPolicy::None) && ! deviation.is_finite() {
+			Err(BrunchError::TooWild)
+		}
 		else { Err(BrunchError::Overflow) }
 	}
 }
 
+impl Stats {
+	#[must_use]
+	/// # Mean Duration.
+	///
+	/// Return the average execution time of the valid samples.
+	pub fn mean(self) -> Duration { Duration::from_secs_f64(self.mean) }
+
+	#[must_use]
+	/// # Standard Deviation.
+	///
+	/// Return the standard deviation of the valid samples.
+	pub fn deviation(self) -> Duration { Duration::from_secs_f64(self.deviation) }
+
+	#[must_use]
+	/// # Measured Time.
+	///
+	/// Return the total time spent in the valid samples, i.e. the sum of
+	/// their durations.
+	///
+	/// Note: stats loaded from history only have an estimate — the mean
+	/// times the number of valid samples — as the sum itself isn't saved.
+	pub fn measured(self) -> Duration { Duration::from_secs_f64(self.sum) }
+
+	#[must_use]
+	/// # Total Samples.
+	///
+	/// Return the total number of samples collected, including outliers.
+	pub const fn total(self) -> u32 { self.total }
+
+	#[must_use]
+	/// # Valid Samples.
+	///
+	/// Return the number of samples used for the calculations, i.e. the total
+	/// minus any outliers.
+	pub const fn valid(self) -> u32 { self.valid }
+
+	#[must_use]
+	/// # Drift.
+	///
+	/// Return the relative change in the mean from the first half of the
+	/// samples to the second — in the order they were collected, outliers
+	/// aside — e.g. `0.08` if the latter ran 8% slower.
+	///
+	/// A steady drift like that usually means the CPU clocked up or down
+	/// partway through — thermal throttling, say — leaving the overall mean
+	/// somewhere in between. Neither outlier pruning nor the deviation will
+	/// catch it.
+	///
+	/// This is always zero for [streamed](crate::Bench::streaming) runs and
+	/// stats loaded from history.
+	pub const fn drift(self) -> f64 { self.drift }
+
+	#[must_use]
+	/// # Fastest Sample.
+	///
+	/// Return the fastest valid sample.
+	///
+	/// This, along with [`Stats::median`], [`Stats::p95`], and
+	/// [`Stats::max`], is only known when the individual samples are, so is
+	/// always `None` for [streamed](crate::Bench::streaming) runs and stats
+	/// loaded from history.
+	pub fn min(self) -> Option<Duration> {
+		self.dist.map(|d| Duration::from_secs_f64(d.min))
+	}
+
+	#[must_use]
+	/// # Median.
+	///
+	/// Return the median of the valid samples. When it strays far from the
+	/// mean, the distribution is skewed — or lumpy — and the mean alone is
+	/// probably misleading.
+	pub fn median(self) -> Option<Duration> {
+		self.dist.map(|d| Duration::from_secs_f64(d.median))
+	}
+
+	#[must_use]
+	/// # 95th Percentile.
+	///
+	/// Return the valid sample at the 95th percentile, i.e. the one faster
+	/// than (roughly) 5% of the rest.
+	pub fn p95(self) -> Option<Duration> {
+		self.dist.map(|d| Duration::from_secs_f64(d.p95))
+	}
+
+	#[must_use]
+	/// # Slowest Sample.
+	///
+	/// Return the slowest valid sample.
+	pub fn max(self) -> Option<Duration> {
+		self.dist.map(|d| Duration::from_secs_f64(d.max))
+	}
+
+	#[must_use]
+	/// # Pruned Fraction.
+	///
+	/// Return the fraction of samples discarded as outliers, e.g. `0.25` if
+	/// a quarter of them were dropped.
+	pub fn pruned(self) -> f64 {
+		if self.total == 0 { 0.0 }
+		else { f64::from(self.total.saturating_sub(self.valid)) / f64::from(self.total) }
+	}
+}
+
 impl Stats {
 	/// # Deviation?
 	///
-	/// This method is used to compare a past run with this (present) run to
-	/// see if it deviates in a meaningful way.
+	/// This method is used to compare past runs — pooled together via
+	/// [`Stats::pooled`] — with this (present) run to see if it deviates in a
+	/// meaningful way.
+	///
+	/// In practice, that means Welch's t-test says the difference is
+	/// significant — the p-value is below `alpha` — and the means differ by
+	/// at least half a percent.
+	///
+	/// If so, the relative difference is returned, positive if this run was
+	/// slower, negative if faster.
+	pub(crate) fn is_deviant(self, other: Self, alpha: f64) -> Option<f64> {
+		let diff = (self.mean - other.mean) / other.mean;
+		if
+			diff.is_finite() &&
+			total_cmp!((diff.abs()) >= MIN_CHANGE) &&
+			total_cmp!((self.welch_p(other)) < alpha)
+		{
+			Some(diff)
+		}
+		else { None }
+	}
+
+	/// # Welch's T-Test.
+	///
+	/// Return the two-tailed p-value for the difference between the means of
+	/// this run and another, without assuming equal variances.
+	fn welch_p(self, other: Self) -> f64 {
+		/// # Sample Variance of the Mean (and Sample Size).
+		fn var(s: Stats) -> (f64, f64) {
+			let n = f64::from(s.valid);
+			// Our deviation uses n; we need n-1 here.
+			(s.deviation * s.deviation / (n - 1.0).max(1.0), n)
+		}
+
+		let (se1, n1) = var(self);
+		let (se2, n2) = var(other);
+		let se = se1 + se2;
+
+		// Without any variance, it comes down to whether or not the means
+		// are identical.
+		if ! se.is_normal() {
+			return if total_cmp!((self.mean) == (other.mean)) { 1.0 } else { 0.0 };
+		}
+
+		let t = (self.mean - other.mean) / se.sqrt();
+		let df = se * se / (se1 * se1 / (n1 - 1.0).max(1.0) + se2 * se2 / (n2 - 1.0).max(1.0));
+		crate::math::student_t_p(t, df)
+	}
+
+	/// # Pooled Stats.
+	///
+	/// Combine the stats from multiple runs into one, as if all of their
+	/// valid samples had been collected together.
 	///
-	/// In practice, that means the absolute difference is greater than one
-	/// percent, and the old mean falls outside this run's valid range.
-	pub(crate) fn is_deviant(self, other: Self) -> Option<String> {
-		let lo = self.deviation.mul_add(-2.0, self.mean);
-		let hi = self.deviation.mul_add(2.0, self.mean);
-		if total_cmp!((other.mean) < lo) || total_cmp!((other.mean) > hi) {
-			let (color, sign, diff) = match self.mean.total_cmp(&other.mean) {
-				Ordering::Less => (92, "-", other.mean - self.mean),
-				Ordering::Equal => return None,
-				Ordering::Greater => (91, "+", self.mean - other.mean),
-			};
+	/// Returns `None` if the slice is empty.
+	pub(crate) fn pooled(runs: &[Self]) -> Option<Self> {
+		match runs {
+			[] => None,
+			[one] => Some(*one),
+			_ => {
+				let total = runs.iter().fold(0_u32, |acc, s| acc.saturating_add(s.total));
+				let valid = runs.iter().fold(0_u32, |acc, s| acc.saturating_add(s.valid));
+				let sum: f64 = runs.iter().map(|s| s.sum).sum();
+				let n: f64 = runs.iter().map(|s| f64::from(s.valid)).sum();
+
+				// The overall mean is weighted by sample count.
+				let mean = runs.iter().map(|s| f64::from(s.valid) * s.mean).sum::<f64>() / n;
+
+				// The overall variance includes both the variance within each
+				// run and the spread of the run means.
+				let variance = runs.iter()
+					.map(|s| {
+						let diff = s.mean - mean;
+						f64::from(s.valid) * s.deviation.mul_add(s.deviation, diff * diff)
+					})
+					.sum::<f64>() / n;
+
+				Some(Self { total, valid, deviation: variance.sqrt(), mean, sum, drift: 0.0, dist: None })
+			},
+		}
+	}
+
+	/// # Confidence Interval.
+	///
+	/// Return the lower and upper bounds of the 95% confidence interval for
+	/// the mean, i.e. `mean ± t·(σ/√n)`.
+	pub(crate) fn interval(self) -> (f64, f64) {
+		let margin = t_critical(self.valid) * self.deviation / f64::from(self.valid).sqrt();
+		(self.mean - margin, self.mean + margin)
+	}
 
-			return Some(format!(
-				"\x1b[{}m{}{}\x1b[0m",
-				color,
-				sign,
-				NicePercent::from(diff / other.mean),
-			));
+	/// # Significant Change?
+	///
+	/// This is a stricter supplement to [`Stats::is_deviant`] that only
+	/// considers the change significant if the two runs' 95% confidence
+	/// intervals do not overlap.
+	///
+	/// If so, the relative difference is returned, positive if this run was
+	/// slower, negative if faster.
+	pub(crate) fn is_significant(self, other: Self) -> Option<f64> {
+		let (lo, hi) = self.interval();
+		let (other_lo, other_hi) = other.interval();
+		if total_cmp!(hi < other_lo) || total_cmp!(other_hi < lo) {
+			Some((self.mean - other.mean) / other.mean)
 		}
+		else { None }
+	}
 
-		None
+	/// # Is Noisy?
+	///
+	/// Returns true if more than a tenth of the samples were discarded as
+	/// outliers, or the 95% confidence interval strays more than 5% from the
+	/// mean. Such runs are too unreliable to serve as the "best" baseline.
+	pub(crate) fn is_noisy(self) -> bool {
+		let (_, hi) = self.interval();
+		self.valid.saturating_mul(10) < self.total.saturating_mul(9) ||
+		! total_cmp!((hi - self.mean) <= (self.mean * 0.05))
 	}
 
+	/// # Nice Interval.
+	///
+	/// Return the confidence interval rescaled to the same unit as the mean.
+	pub(crate) fn nice_interval(self, unit: TimeUnit) -> String {
+		let (lo, hi) = self.interval();
+		util::nice_interval(self.mean, lo, hi, unit)
+	}
+
+	/// # Low Confidence?
+	///
+	/// Returns true if there are fewer valid samples than the usual minimum,
+	/// i.e. the benchmark opted into a lower one via
+	/// [`Bench::with_min_samples`](crate::Bench::with_min_samples).
+	pub(crate) const fn is_low_confidence(self) -> bool { self.valid < MIN_SAMPLES }
+
 	/// # Nice Mean.
 	///
 	/// Return the mean rescaled to the most appropriate unit.
-	pub(crate) fn nice_mean(self) -> String {
-		let (mean, unit) =
-			if total_cmp!((self.mean) < 0.000_001) {
-				(self.mean * 1_000_000_000.0, "ns")
-			}
-			else if total_cmp!((self.mean) < 0.001) {
-				(self.mean * 1_000_000.0, "\u{3bc}s")
-			}
-			else if total_cmp!((self.mean) < 1.0) {
-				(self.mean * 1_000.0, "ms")
-			}
-			else {
-				(self.mean, "s ")
-			};
-
-		format!("\x1b[0;1m{} {unit}\x1b[0m", NiceFloat::from(mean).precise_str(2))
+	pub(crate) fn nice_mean(self) -> String { util::nice_time(self.mean) }
+
+	/// # Nice Mean (In Unit).
+	///
+	/// Return the mean rescaled to the given unit.
+	pub(crate) fn nice_mean_in(self, unit: TimeUnit) -> String {
+		util::nice_time_in(self.mean, unit)
+	}
+
+	/// # With Sum.
+	///
+	/// Replace the (estimated) sum, e.g. with the exact figure passed along
+	/// by an isolated child process.
+	pub(crate) const fn with_sum(mut self, sum: f64) -> Self {
+		if sum.is_finite() && 0.0 <= sum { self.sum = sum; }
+		self
+	}
+
+	/// # With Drift.
+	///
+	/// Replace the drift, e.g. with the figure passed along by an isolated
+	/// child process.
+	pub(crate) const fn with_drift(mut self, drift: f64) -> Self {
+		if drift.is_finite() { self.drift = drift; }
+		self
 	}
 
+	/// # With Distribution.
+	///
+	/// Replace the distribution, e.g. with the figures passed along by an
+	/// isolated child process.
+	pub(crate) fn with_distribution(mut self, dist: Distribution) -> Self {
+		if dist.is_valid() { self.dist = Some(dist); }
+		self
+	}
+
+	/// # Distribution.
+	pub(crate) const fn distribution(self) -> Option<Distribution> { self.dist }
+
+	/// # Nice Median.
+	///
+	/// Return the median rescaled to the given unit, if known.
+	pub(crate) fn nice_median_in(self, unit: TimeUnit) -> Option<String> {
+		self.dist.map(|d| util::nice_time_in(d.median, unit))
+	}
+
+	/// # Nice 95th Percentile.
+	///
+	/// Return the 95th percentile rescaled to the given unit, if known.
+	pub(crate) fn nice_p95_in(self, unit: TimeUnit) -> Option<String> {
+		self.dist.map(|d| util::nice_time_in(d.p95, unit))
+	}
+
+	/// # Nice Range.
+	///
+	/// Return the fastest and slowest samples, if known, rescaled to the
+	/// same unit as the mean, like [`Stats::nice_interval`].
+	pub(crate) fn nice_range(self, unit: TimeUnit) -> Option<String> {
+		self.dist.map(|d| util::nice_interval(self.mean, d.min, d.max, unit))
+	}
+
+	/// # Is Drifting?
+	///
+	/// Returns true if the drift — in either direction — exceeds the
+	/// threshold.
+	pub(crate) fn is_drifting(self, threshold: f64) -> bool {
+		total_cmp!((self.drift.abs()) > threshold)
+	}
+
+	/// # Nice Measured Time.
+	///
+	/// Return the total measured time rescaled to the most appropriate unit.
+	pub(crate) fn nice_measured(self) -> String { util::nice_time(self.sum) }
+
 	/// # Samples.
 	///
 	/// Return the valid/total samples.
 	pub(crate) const fn samples(self) -> (u32, u32) { (self.valid, self.total) }
 
 	/// # Is Valid?
-	fn is_valid(self) -> bool {
-		MIN_SAMPLES <= self.valid &&
+	///
+	/// Returns true if the figures make sense, and there are at least `min`
+	/// valid samples. (The absolute floor applies regardless.)
+	fn is_valid(self, min: u32) -> bool {
+		MIN_SAMPLES_FLOOR.max(min) <= self.valid &&
 		self.valid <= self.total &&
 		self.deviation.is_finite() &&
 		total_cmp!((self.deviation) >= 0.0) &&
 		self.mean.is_finite() &&
-		total_cmp!((self.mean) >= 0.0)
+		total_cmp!((self.mean) >= 0.0) &&
+		self.sum.is_finite() &&
+		total_cmp!((self.sum) >= 0.0)
+	}
+}
+
+
+
+/// # Student's T Critical Value.
+///
+/// Return the (approximate) two-tailed 95% critical value of the
+/// t-distribution for a sample of size `n`, using the first couple terms of
+/// the Cornish-Fisher expansion around the normal `1.96`.
+///
+/// This is plenty accurate for the sample sizes we deal with — at least 100,
+/// where it differs from the exact value by less than `0.0001`.
+fn t_critical(n: u32) -> f64 {
+	/// # Normal Critical Value.
+	const Z: f64 = 1.959_963_984_540_054;
+
+	let df = f64::from(n.saturating_sub(1).max(1));
+	let z3 = Z * Z * Z;
+	let z5 = z3 * Z * Z;
+	let g1 = (z3 + Z) / 4.0;
+	let g2 = z5.mul_add(5.0, z3.mul_add(16.0, 3.0 * Z)) / 96.0;
+	(g2 / df + g1) / df + Z
+}
+
+
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// # Metric.
+///
+/// This controls how the (valid) samples are averaged to produce the "mean"
+/// reported for a benchmark. See
+/// [`Bench::with_metric`](crate::Bench::with_metric) for usage.
+pub enum Metric {
+	#[default]
+	/// # Mean.
+	///
+	/// The plain arithmetic mean.
+	Mean,
+
+	/// # Trimmed Mean.
+	///
+	/// The mean of the central `1 - 2f` fraction of the (sorted) samples,
+	/// i.e. ignoring the fastest and slowest `f` of them.
+	///
+	/// The fraction is clamped to `0.0..=0.49`; a fraction of zero (or NaN)
+	/// is just a plain mean.
+	TrimmedMean(f64),
+
+	/// # Minimum.
+	///
+	/// The fastest valid sample.
+	///
+	/// Since (almost) all noise is additive, this is the least noisy
+	/// estimator for short, CPU-bound benchmarks, but it says nothing about
+	/// the typical case, and is a poor fit for anything with real variation,
+	/// like I/O.
+	Min,
+}
+
+impl fmt::Display for Metric {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self.sanitize() {
+			Self::Mean => f.write_str("mean"),
+			Self::TrimmedMean(n) => write!(f, "{} trimmed mean", NicePercent::from(n)),
+			Self::Min => f.write_str("minimum"),
+		}
+	}
+}
+
+impl Metric {
+	/// # Sanitize.
+	///
+	/// Clamp the trim fraction, if any, collapsing no trim to a plain mean.
+	pub(crate) fn sanitize(self) -> Self {
+		match self {
+			Self::TrimmedMean(f) if total_cmp!(f > 0.0) => Self::TrimmedMean(f.min(0.49)),
+			Self::Min => Self::Min,
+			_ => Self::Mean,
+		}
+	}
+}
+
+
+
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+/// # Outlier Policy.
+///
+/// This controls how outliers are identified and pruned from a benchmark's
+/// samples before its stats are crunched. See
+/// [`Bench::with_outliers`](crate::Bench::with_outliers) for usage.
+///
+/// The outliers are reported in the Samples column as the difference
+/// between the valid and total counts.
+///
+/// ## Choosing a Policy.
+///
+/// The IQR-based policies — including the default — work well for roughly
+/// symmetrical distributions, and since the boundaries scale with the spread
+/// of the data, they rarely discard much. The flip side is that a long,
+/// one-sided tail of stalls (typical of syscalls, allocation, I/O) stretches
+/// the upper quantile, letting obvious stalls through.
+///
+/// The MAD-based policy is anchored to the median instead, so is much less
+/// affected by tails, making it the better choice for tight distributions
+/// with occasional stalls. It can, however, cut real data from wide or
+/// bimodal distributions, and does nothing at all when more than half of
+/// the samples are identical (as the MAD is then zero).
+pub enum OutlierPolicy {
+	#[default]
+	/// # Default.
+	///
+	/// Prune samples more than `1.5×` the range between the (fuzzy) 5th and
+	/// 95th quantiles below or above it.
+	Default,
+
+	/// # None.
+	///
+	/// Keep everything.
+	None,
+
+	/// # Custom IQR.
+	///
+	/// Prune samples more than `mult×` the range between the (fuzzy) `low`
+	/// and `high` quantiles below or above it.
+	///
+	/// The quantiles must satisfy `0.0 <= low < high <= 1.0`, and the
+	/// multiplier must be finite and non-negative; otherwise the default
+	/// policy is used instead.
+	Iqr {
+		/// # Low Quantile.
+		low: f64,
+
+		/// # High Quantile.
+		high: f64,
+
+		/// # IQR Multiplier.
+		mult: f64,
+	},
+
+	/// # Median Absolute Deviation.
+	///
+	/// Prune samples more than `k×` the median absolute deviation (MAD)
+	/// below or above the median.
+	///
+	/// Note that this uses the raw MAD; for normally-distributed data, `k`
+	/// is roughly two-thirds of the equivalent number of standard deviations.
+	///
+	/// The multiplier must be finite and positive; otherwise the suggested
+	/// [`OutlierPolicy::MAD`] value of `5.0` is used instead.
+	Mad {
+		/// # MAD Multiplier.
+		k: f64,
+	},
+}
+
+impl OutlierPolicy {
+	/// # MAD With Suggested Multiplier.
+	///
+	/// This is [`OutlierPolicy::Mad`] with a `k` of `5.0`, a reasonable
+	/// starting point for most workloads.
+	pub const MAD: Self = Self::Mad { k: 5.0 };
+
+	/// # Pruning Parameters.
+	///
+	/// Return the (sanitized) pruning parameters, or `None` if pruning is
+	/// disabled.
+	pub(crate) fn pruning(self) -> Option<Pruning> {
+		match self {
+			Self::None => None,
+			Self::Iqr { low, high, mult } if
+				total_cmp!(low >= 0.0) &&
+				total_cmp!(low < high) &&
+				total_cmp!(high <= 1.0) &&
+				mult.is_finite() &&
+				total_cmp!(mult >= 0.0)
+			=> Some(Pruning::Iqr { low, high, mult }),
+			Self::Default | Self::Iqr { .. } => Some(Pruning::Iqr { low: 0.05, high: 0.95, mult: 1.5 }),
+			Self::Mad { k } if k.is_finite() && total_cmp!(k > 0.0) => Some(Pruning::Mad { k }),
+			Self::Mad { .. } => Some(Pruning::Mad { k: 5.0 }),
+		}
+	}
+}
+
+
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+/// # Pruning Parameters.
+///
+/// This is the sanitized, internal counterpart to [`OutlierPolicy`].
+pub(crate) enum Pruning {
+	/// # Interquantile Range.
+	Iqr {
+		/// # Low Quantile.
+		low: f64,
+
+		/// # High Quantile.
+		high: f64,
+
+		/// # IQR Multiplier.
+		mult: f64,
+	},
+
+	/// # Median Absolute Deviation.
+	Mad {
+		/// # MAD Multiplier.
+		k: f64,
+	},
+}
+
+
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+/// # Trend.
+///
+/// This describes the direction benchmark means have been heading over the
+/// retained history runs.
+pub(crate) enum Trend {
+	/// # Getting Slower.
+	Slower,
+
+	/// # Getting Faster.
+	Faster,
+
+	/// # Holding Steady.
+	Stable,
+}
+
+impl Trend {
+	/// # Minimum Runs.
+	///
+	/// Trends aren't meaningful without at least this many runs.
+	const MIN_RUNS: usize = 3;
+
+	/// # Threshold.
+	///
+	/// The minimum relative drift — from the first to last run, per the
+	/// line of best fit — needed to call it a trend.
+	const THRESHOLD: f64 = 0.05;
+
+	/// # New.
+	///
+	/// Work out the trend from a chronological list of runs, oldest first.
+	pub(crate) fn new(runs: &[Stats]) -> Option<Self> {
+		if runs.len() < Self::MIN_RUNS { return None; }
+
+		// Fit a line through the means.
+		let n = f64::from(u32::saturating_from(runs.len()));
+		let x_mean = (n - 1.0) / 2.0;
+		let y_mean = runs.iter().map(|s| s.mean).sum::<f64>() / n;
+		if ! y_mean.is_normal() { return None; }
+
+		let (num, den) = runs.iter()
+			.enumerate()
+			.fold((0.0, 0.0), |(num, den), (x, s)| {
+				let dx = f64::from(u32::saturating_from(x)) - x_mean;
+				(dx.mul_add(s.mean - y_mean, num), dx.mul_add(dx, den))
+			});
+
+		// Total drift relative to the average.
+		let drift = num / den * (n - 1.0) / y_mean;
+		if drift.is_nan() { None }
+		else if Self::THRESHOLD <= drift { Some(Self::Slower) }
+		else if drift <= -Self::THRESHOLD { Some(Self::Faster) }
+		else { Some(Self::Stable) }
+	}
+
+	/// # Arrow.
+	pub(crate) const fn arrow(self) -> &'static str {
+		match self {
+			Self::Slower => "↗",
+			Self::Faster => "↘",
+			Self::Stable => "→",
+		}
 	}
 }
 
@@ -137,6 +826,233 @@ impl Stats {
 mod tests {
 	use super::*;
 
+	#[test]
+	fn t_interval() {
+		// Known critical values.
+		for (n, t) in [(100, 1.984_217), (101, 1.983_972), (1001, 1.962_339), (10_001, 1.960_201)] {
+			assert!((t_critical(n) - t).abs() < 0.000_1, "Wrong critical value for {n}.");
+		}
+
+		// Mean ± 1.984 * 0.000_001 / √100.
+		let a = Stats {
+			total: 100,
+			valid: 100,
+			deviation: 0.000_001,
+			mean: 0.000_010,
+			sum: 0.001,
+			drift: 0.0,
+			dist: None,
+		};
+		let (lo, hi) = a.interval();
+		assert!((a.mean - lo - 0.000_000_198_42).abs() < 1e-11, "Wrong lower bound.");
+		assert!((hi - a.mean - 0.000_000_198_42).abs() < 1e-11, "Wrong upper bound.");
+		assert_eq!(a.nice_interval(TimeUnit::Auto), "[9.80, 10.20]");
+
+		// Overlapping intervals aren't significant.
+		let mut b = a;
+		b.mean = 0.000_010_3;
+		assert!(b.is_significant(a).is_none(), "Overlap should not be significant.");
+
+		// But separate ones are.
+		b.mean = 0.000_010_5;
+		let diff = b.is_significant(a).expect("Change should be significant.");
+		assert!((diff - 0.05).abs() < 1e-9, "Wrong change.");
+	}
+
+	#[test]
+	fn t_welch() {
+		/// # Test Stats.
+		fn stat(valid: u32, mean: f64, deviation: f64) -> Stats {
+			Stats { total: valid, valid, deviation, mean, sum: mean * f64::from(valid), drift: 0.0, dist: None }
+		}
+
+		// Identical means are never deviant, regardless of variance.
+		let a = stat(2500, 0.000_001, 0.000_000_01);
+		let b = stat(500, 0.000_001, 0.000_000_5);
+		assert!(total_cmp!((a.welch_p(b)) == 1.0));
+		assert_eq!(a.is_deviant(b, 0.05), None);
+		assert_eq!(b.is_deviant(a, 0.05), None);
+
+		// A small-but-consistent shift is.
+		let b = stat(2500, 0.000_001_01, 0.000_000_01);
+		assert!(b.welch_p(a) < 0.000_001);
+		let diff = b.is_deviant(a, 0.05).expect("Shift should be significant.");
+		assert!((diff - 0.01).abs() < 1e-9);
+		let diff = a.is_deviant(b, 0.05).expect("Shift should be significant.");
+		assert!(diff < 0.0, "Should be faster.");
+
+		// A bigger shift drowned out by noise isn't.
+		let b = stat(100, 0.000_001_05, 0.000_001);
+		assert!(0.05 < b.welch_p(a));
+		assert_eq!(b.is_deviant(a, 0.05), None);
+
+		// But a laxer threshold might let it through.
+		assert!(b.is_deviant(a, 0.9).is_some());
+
+		// Significant shifts under half a percent are ignored.
+		let b = stat(2500, 0.000_001_004, 0.000_000_001);
+		assert!(b.welch_p(a) < 0.05);
+		assert_eq!(b.is_deviant(a, 0.05), None);
+
+		// Without variance, any (big enough) difference counts.
+		let a = stat(200, 0.000_002, 0.0);
+		let b = stat(200, 0.000_003, 0.0);
+		assert!(b.is_deviant(a, 0.05).is_some());
+		assert_eq!(a.is_deviant(a, 0.05), None);
+
+		// Same sizes and variance: t = 0.000_000_02 / √(2 × 0.000_000_1² / 199)
+		// ≈ 1.995 with 398 degrees of freedom, so p ≈ 0.0467.
+		let a = stat(200, 0.000_001, 0.000_000_1);
+		let b = stat(200, 0.000_001_02, 0.000_000_1);
+		assert!((b.welch_p(a) - 0.046_7).abs() < 0.000_5, "Wrong p-value.");
+	}
+
+	#[test]
+	fn t_outliers() {
+		// A thousand samples, give or take, with a few big spikes.
+		let samples: Vec<Duration> = (0..1000_u64)
+			.map(|i|
+				if i % 100 == 0 { Duration::from_millis(1) }
+				else { Duration::from_nanos(1000 + i % 97) }
+			)
+			.collect();
+
+		let default = Stats::from_samples(samples.clone(), OutlierPolicy::Default, Metric::Mean, MIN_SAMPLES)
+			.expect("Default failed.");
+		assert_eq!(default.samples(), (990, 1000), "The spikes should be pruned.");
+
+		let none = Stats::from_samples(samples.clone(), OutlierPolicy::None, Metric::Mean, MIN_SAMPLES)
+			.expect("None failed.");
+		assert_eq!(none.samples(), (1000, 1000), "Nothing should be pruned.");
+		assert!(default.mean < none.mean);
+
+		let loose = Stats::from_samples(
+			samples.clone(),
+			OutlierPolicy::Iqr { low: 0.05, high: 0.95, mult: 1_000_000.0 },
+			Metric::Mean,
+			MIN_SAMPLES,
+		).expect("Loose failed.");
+		assert_eq!(loose.samples(), (1000, 1000), "Nothing should be pruned.");
+
+		let tight = Stats::from_samples(
+			samples.clone(),
+			OutlierPolicy::Iqr { low: 0.25, high: 0.75, mult: 0.0 },
+			Metric::Mean,
+			MIN_SAMPLES,
+		).expect("Tight failed.");
+		assert!(tight.valid < default.valid, "More should be pruned.");
+
+		// Bad parameters fall back to the default.
+		let bad = Stats::from_samples(
+			samples,
+			OutlierPolicy::Iqr { low: 0.95, high: 0.05, mult: f64::NAN },
+			Metric::Mean,
+			MIN_SAMPLES,
+		).expect("Bad failed.");
+		assert_eq!(bad.samples(), default.samples());
+	}
+
+	#[test]
+	fn t_min() {
+		/// # Noisy Samples.
+		///
+		/// A thousand samples of the base time plus up to `noise` nanoseconds
+		/// of additive noise, and the occasional stall.
+		fn samples(base: u64, noise: u64) -> Stats {
+			let samples: Vec<Duration> = (0..1000_u64)
+				.map(|i|
+					if i % 100 == 50 { Duration::from_millis(1) }
+					else { Duration::from_nanos(base + (i * 37) % noise) }
+				)
+				.collect();
+			Stats::from_samples(samples, OutlierPolicy::Default, Metric::Min, MIN_SAMPLES)
+				.expect("Min failed.")
+		}
+
+		// The minimum should ignore the noise entirely.
+		let a = samples(1000, 101);
+		assert!((a.mean - 0.000_001).abs() < 1e-15, "Wrong minimum.");
+
+		// A real slowdown should be caught.
+		let b = samples(1100, 101);
+		let diff = b.is_deviant(a, 0.05).expect("Slowdown should be significant.");
+		assert!((diff - 0.1).abs() < 1e-9, "Wrong change.");
+		let diff = a.is_deviant(b, 0.05).expect("Speedup should be significant.");
+		assert!(diff < 0.0, "Should be faster.");
+
+		// But more noise shouldn't register as a change…
+		let c = samples(1000, 401);
+		assert_eq!(c.is_deviant(a, 0.05), None);
+
+		// …even though it would for the means.
+		let mean = |noise: u64| Stats::from_samples(
+			(0..1000_u64).map(|i| Duration::from_nanos(1000 + (i * 37) % noise)).collect(),
+			OutlierPolicy::Default,
+			Metric::Mean,
+			MIN_SAMPLES,
+		).expect("Mean failed.");
+		assert!(mean(401).is_deviant(mean(101), 0.05).is_some(), "Means should differ.");
+
+		// Minimums aren't means.
+		assert_eq!(Metric::Min.sanitize(), Metric::Min);
+		assert_eq!(Metric::Min.to_string(), "minimum");
+	}
+
+	#[test]
+	fn t_distribution() {
+		/// # Close Enough?
+		fn close(a: Option<Duration>, b: Duration) -> bool {
+			a.is_some_and(|a| a.abs_diff(b) <= Duration::from_nanos(1))
+		}
+
+		// A bimodal set: the mean falls between the humps, where no sample
+		// actually landed.
+		let samples: Vec<Duration> = (0..1000_u32)
+			.map(|i| Duration::from_micros(if i % 5 < 3 { 10 } else { 30 }))
+			.collect();
+		let stats = Stats::try_from(samples).expect("Invalid stats.");
+		assert!(stats.mean().abs_diff(Duration::from_micros(18)) <= Duration::from_nanos(1), "Wrong mean.");
+		assert!(close(stats.median(), Duration::from_micros(10)), "Wrong median.");
+		assert!(close(stats.p95(), Duration::from_micros(30)), "Wrong p95.");
+		assert!(close(stats.min(), Duration::from_micros(10)), "Wrong minimum.");
+		assert!(close(stats.max(), Duration::from_micros(30)), "Wrong maximum.");
+		assert_eq!(stats.nice_range(TimeUnit::Auto).as_deref(), Some("[10.00, 30.00]"));
+
+		// Outliers are excluded.
+		let samples: Vec<Duration> = (0..1000_u64)
+			.map(|i|
+				if i == 500 { Duration::from_millis(5) }
+				else { Duration::from_nanos(1000 + i % 100) }
+			)
+			.collect();
+		let stats = Stats::try_from(samples).expect("Invalid stats.");
+		assert_eq!(stats.samples(), (999, 1000), "The spike should be pruned.");
+		assert!(close(stats.min(), Duration::from_micros(1)), "Wrong minimum.");
+		assert!(close(stats.median(), Duration::from_nanos(1050)), "Wrong median.");
+		assert!(close(stats.p95(), Duration::from_nanos(1094)), "Wrong p95.");
+		assert!(close(stats.max(), Duration::from_nanos(1099)), "Wrong maximum.");
+
+		// Clumpy sets shouldn't throw the percentile off, even when a spike
+		// survives pruning.
+		let samples: Vec<Duration> = (0..1000_u64)
+			.map(|i|
+				if i == 500 { Duration::from_millis(5) }
+				else { Duration::from_nanos(1000 + i % 10) }
+			)
+			.collect();
+		let stats = Stats::try_from(samples).expect("Invalid stats.");
+		assert!(close(stats.p95(), Duration::from_nanos(1009)), "Wrong p95.");
+
+		// Pooled stats don't have the samples.
+		let pooled = Stats::pooled(&[stats, stats]).expect("Missing pooled stats.");
+		assert!(pooled.median().is_none(), "Unexpected median.");
+		assert!(pooled.nice_range(TimeUnit::Auto).is_none(), "Unexpected range.");
+
+		// Nonsense is ignored.
+		let weird = Distribution { min: 2.0, median: 1.0, p95: 3.0, max: 4.0 };
+		assert!(pooled.with_distribution(weird).median().is_none(), "Invalid distribution.");
+	}
+
 	#[test]
 	fn t_stats_valid() {
 		let mut stat = Stats {
@@ -144,33 +1060,81 @@ mod tests {
 			valid: 2496,
 			deviation: 0.000_000_123,
 			mean: 0.000_002_2,
+			sum: 0.005_491_2,
+			drift: 0.0,
+			dist: None,
 		};
 
-		assert!(stat.is_valid(), "Stat should be valid.");
+		assert!(stat.is_valid(MIN_SAMPLES), "Stat should be valid.");
 
 		stat.total = 100;
-		assert!(! stat.is_valid(), "Insufficient total.");
+		assert!(! stat.is_valid(MIN_SAMPLES), "Insufficient total.");
 
 		stat.valid = 100;
-		assert!(stat.is_valid(), "Stat should be valid.");
+		assert!(stat.is_valid(MIN_SAMPLES), "Stat should be valid.");
 
 		stat.valid = 30;
-		assert!(! stat.is_valid(), "Insufficient samples.");
+		assert!(! stat.is_valid(MIN_SAMPLES), "Insufficient samples.");
+		assert!((stat.pruned() - 0.7).abs() < f64::EPSILON, "Wrong pruned fraction.");
 
 		stat.valid = 100;
-		assert!(stat.is_valid(), "Stat should be valid.");
+		assert!(stat.is_valid(MIN_SAMPLES), "Stat should be valid.");
 
 		stat.deviation = f64::NAN;
-		assert!(! stat.is_valid(), "NaN deviation.");
+		assert!(! stat.is_valid(MIN_SAMPLES), "NaN deviation.");
 		stat.deviation = -0.003;
-		assert!(! stat.is_valid(), "Negative deviation.");
+		assert!(! stat.is_valid(MIN_SAMPLES), "Negative deviation.");
 
 		stat.deviation = 0.003;
-		assert!(stat.is_valid(), "Stat should be valid.");
+		assert!(stat.is_valid(MIN_SAMPLES), "Stat should be valid.");
 
 		stat.mean = f64::NAN;
-		assert!(! stat.is_valid(), "NaN mean.");
+		assert!(! stat.is_valid(MIN_SAMPLES), "NaN mean.");
 		stat.mean = -0.003;
-		assert!(! stat.is_valid(), "Negative mean.");
+		assert!(! stat.is_valid(MIN_SAMPLES), "Negative mean.");
+	}
+
+	#[test]
+	fn t_pooled() {
+		let one = Stats { total: 200, valid: 100, deviation: 1.0, mean: 10.0, sum: 1000.0, drift: 0.0, dist: None };
+		let two = Stats { total: 200, valid: 100, deviation: 1.0, mean: 12.0, sum: 1200.0, drift: 0.0, dist: None };
+
+		assert!(Stats::pooled(&[]).is_none(), "Nothing to pool.");
+
+		// A single run should pass through as-is.
+		let pooled = Stats::pooled(&[one]).expect("Missing pooled stats.");
+		assert!(total_cmp!((pooled.mean) == 10.0), "Mean changed.");
+		assert!(total_cmp!((pooled.deviation) == 1.0), "Deviation changed.");
+
+		// Multiple runs should be combined, with the spread of the means
+		// contributing to the deviation.
+		let pooled = Stats::pooled(&[one, two]).expect("Missing pooled stats.");
+		assert_eq!(pooled.samples(), (200, 400));
+		assert!(total_cmp!((pooled.mean) == 11.0), "Wrong pooled mean.");
+		assert!(total_cmp!((pooled.deviation) == (2.0_f64.sqrt())), "Wrong pooled deviation.");
+		assert_eq!(pooled.measured(), Duration::from_secs(2200), "Wrong pooled sum.");
+	}
+
+	#[test]
+	fn t_trend() {
+		let runs: Vec<Stats> = [10.0, 10.1, 9.9, 10.0, 10.05].into_iter()
+			.map(|mean| Stats { total: 200, valid: 100, deviation: 0.1, mean, sum: mean * 100.0, drift: 0.0, dist: None })
+			.collect();
+
+		// Too few runs.
+		assert_eq!(Trend::new(&runs[..2]), None);
+
+		// Noise.
+		assert_eq!(Trend::new(&runs), Some(Trend::Stable));
+
+		// Slower.
+		let mut slower = runs.clone();
+		for (s, i) in slower.iter_mut().zip(0_u8..) { s.mean += f64::from(i); }
+		assert_eq!(Trend::new(&slower), Some(Trend::Slower));
+
+		// Faster.
+		let mut faster = runs;
+		for (s, i) in faster.iter_mut().zip(0_u8..) { s.mean -= f64::from(i); }
+		assert_eq!(Trend::new(&faster), Some(Trend::Faster));
 	}
 }
@@ -0,0 +1,379 @@
+/*!
+# Brunch: Streaming Stats
+*/
+
+use crate::{
+	Abacus,
+	Metric,
+	OutlierPolicy,
+};
+use dactyl::{
+	total_cmp,
+	traits::SaturatingFrom,
+};
+use std::{
+	cmp::Ordering,
+	time::Duration,
+};
+
+
+
+/// # Smallest Bucket (as a power of two, in seconds).
+///
+/// This works out to about 58 picoseconds; anything smaller gets lumped in
+/// with it.
+const MIN_LOG2: f64 = -34.0;
+
+/// # Buckets Per Octave.
+///
+/// Each bucket spans about 2% of its value.
+const STEPS: f64 = 32.0;
+
+/// # Total Buckets.
+///
+/// Forty-eight octaves (topping out around four and a half hours), plus one
+/// extra for zeroes.
+const BUCKETS: usize = 48 * 32 + 1;
+
+/// # Reservoir Size.
+const RESERVOIR: usize = 10_000;
+
+
+
+#[derive(Debug)]
+/// # Streaming Stats.
+///
+/// This is a constant-memory alternative to collecting every sample into a
+/// `Vec` and crunching them with [`Abacus`] after the fact, used for benches
+/// with very large sample counts.
+///
+/// Each sample is folded into a log-scaled histogram bucket — each of which
+/// tracks its own count, mean, and variance — as well as a fixed-size random
+/// reservoir, which is used to estimate the outlier boundaries.
+///
+/// Once sampling is done, the buckets within those boundaries are merged to
+/// produce the final figures.
+pub(crate) struct Stream {
+	/// # Total Samples (Including Invalid Ones).
+	total: u32,
+
+	/// # Valid Samples.
+	seen: u32,
+
+	/// # Histogram.
+	buckets: Box<[Bucket]>,
+
+	/// # Reservoir.
+	reservoir: Vec<f64>,
+
+	/// # Reservoir RNG State.
+	rng: u64,
+}
+
+impl Default for Stream {
+	fn default() -> Self {
+		Self {
+			total: 0,
+			seen: 0,
+			buckets: vec![Bucket::default(); BUCKETS].into_boxed_slice(),
+			reservoir: Vec::with_capacity(RESERVOIR),
+			rng: 0x2545_F491_4F6C_DD1D,
+		}
+	}
+}
+
+impl Stream {
+	/// # Total Samples.
+	///
+	/// Return the total number of samples pushed, including any that were
+	/// invalid.
+	pub(crate) const fn total(&self) -> u32 { self.total }
+
+	/// # Push Sample.
+	pub(crate) fn push(&mut self, sample: Duration) {
+		self.total = self.total.saturating_add(1);
+
+		// As with Abacus, negative and abnormal values are ignored.
+		let x = sample.as_secs_f64();
+		match x.total_cmp(&0.0) {
+			Ordering::Equal => {},
+			Ordering::Greater if x.is_normal() => {},
+			_ => return,
+		}
+
+		self.buckets[bucket(x)].push(x);
+
+		// Algorithm R: fill the reservoir, then replace entries at random
+		// with decreasing probability.
+		if self.reservoir.len() < RESERVOIR { self.reservoir.push(x); }
+		else {
+			let idx = usize::saturating_from(self.next_rng() % (u64::from(self.seen) + 1));
+			if idx < RESERVOIR { self.reservoir[idx] = x; }
+		}
+
+		self.seen += 1;
+	}
+
+	/// # Crunch.
+	///
+	/// Prune the outliers and return the number of remaining samples, their
+	/// mean (or trimmed mean, or minimum), their standard deviation, and their
+	/// sum.
+	///
+	/// The outlier boundaries are worked out from the reservoir exactly as
+	/// [`Abacus::prune_outliers`] would for the full set; buckets are then
+	/// kept or discarded wholesale based on where their means fall.
+	///
+	/// Trimming is approximate too, working from the bucket means, while the
+	/// minimum is taken from the lowest bucket kept.
+	pub(crate) fn crunch(self, policy: OutlierPolicy, metric: Metric) -> (u32, f64, f64, f64) {
+		let bounds = Abacus::from(self.reservoir).outlier_bounds(policy);
+		let kept: Vec<&Bucket> = self.buckets.iter()
+			.filter(|b|
+				0 != b.count &&
+				bounds.is_none_or(|(lo, hi)| total_cmp!(lo <= (b.mean)) && total_cmp!((b.mean) <= hi))
+			)
+			.collect();
+
+		let mut out = Bucket::default();
+		for b in &kept { out.merge(b); }
+
+		let mean = match metric.sanitize() {
+			Metric::Mean => out.mean,
+			Metric::TrimmedMean(f) => trimmed_mean(&kept, out.count, f),
+			Metric::Min => kept.first().map_or(0.0, |b| b.min),
+		};
+
+		(out.count, mean, out.deviation(), out.mean * f64::from(out.count))
+	}
+
+	/// # Next Random Number.
+	///
+	/// This is a simple xorshift; it doesn't need to be good, just cheap.
+	const fn next_rng(&mut self) -> u64 {
+		self.rng ^= self.rng << 13;
+		self.rng ^= self.rng >> 7;
+		self.rng ^= self.rng << 17;
+		self.rng
+	}
+}
+
+
+
+#[derive(Debug, Default, Clone, Copy)]
+/// # Histogram Bucket.
+///
+/// This holds the running count, mean, and sum of squared differences for
+/// the samples falling within its range, calculated using Welford's method,
+/// as well as the smallest of them.
+struct Bucket {
+	/// # Count.
+	count: u32,
+
+	/// # Mean.
+	mean: f64,
+
+	/// # Minimum.
+	min: f64,
+
+	/// # Sum of Squared Differences.
+	m2: f64,
+}
+
+impl Bucket {
+	/// # Push Value.
+	fn push(&mut self, x: f64) {
+		self.min = if self.count == 0 { x } else { self.min.min(x) };
+		self.count += 1;
+		let delta = x - self.mean;
+		self.mean += delta / f64::from(self.count);
+		self.m2 = delta.mul_add(x - self.mean, self.m2);
+	}
+
+	/// # Merge.
+	///
+	/// Combine another bucket's figures into this one (Chan et al.).
+	fn merge(&mut self, other: &Self) {
+		if other.count == 0 { return; }
+		if self.count == 0 {
+			*self = *other;
+			return;
+		}
+
+		let a = f64::from(self.count);
+		let b = f64::from(other.count);
+		let n = a + b;
+		let delta = other.mean - self.mean;
+
+		self.min = self.min.min(other.min);
+		self.count += other.count;
+		self.mean = delta.mul_add(b / n, self.mean);
+		self.m2 = (delta * delta).mul_add(a * b / n, self.m2 + other.m2);
+	}
+
+	/// # Standard Deviation.
+	///
+	/// Note: this uses the _n_ rather than _n+1_ approach, same as Abacus.
+	fn deviation(&self) -> f64 {
+		if self.count == 0 || self.m2 <= 0.0 { 0.0 }
+		else { (self.m2 / f64::from(self.count)).sqrt() }
+	}
+}
+
+
+
+/// # Trimmed Mean.
+///
+/// Return the mean of the (ordered) buckets after dropping the lowest and
+/// highest `frac` of the `count` samples within, treating each bucket's
+/// samples as if they all equalled its mean.
+fn trimmed_mean(buckets: &[&Bucket], count: u32, frac: f64) -> f64 {
+	let trim = u32::saturating_from(f64::from(count) * frac);
+	let lo = trim;
+	let hi = count.saturating_sub(trim);
+	if hi <= lo { return 0.0; }
+
+	let mut pos = 0_u32;
+	let mut sum = 0.0;
+	for b in buckets {
+		let start = pos.max(lo);
+		pos += b.count;
+		let end = pos.min(hi);
+		if start < end { sum = f64::from(end - start).mul_add(b.mean, sum); }
+	}
+
+	sum / f64::from(hi - lo)
+}
+
+/// # Bucket Index.
+///
+/// Zeroes go in the first bucket; everything else is sorted logarithmically.
+fn bucket(x: f64) -> usize {
+	if x == 0.0 { 0 }
+	else {
+		let pos = (x.log2() - MIN_LOG2).max(0.0) * STEPS;
+		usize::saturating_from(pos).saturating_add(1).min(BUCKETS - 1)
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// # Uniform Noise.
+	///
+	/// A tiny LCG so the tests are deterministic.
+	struct Noise(u64);
+
+	impl Noise {
+		fn next(&mut self) -> f64 {
+			self.0 = self.0.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1_442_695_040_888_963_407);
+			f64::from(u32::saturating_from(self.0 >> 33)) / f64::from(1_u32 << 31)
+		}
+	}
+
+	/// # Compare Paths.
+	///
+	/// Crunch the same samples both ways and make sure they agree.
+	fn compare(name: &str, samples: &[f64]) {
+		let mut exact = Abacus::from(samples.to_vec());
+		exact.prune_outliers(OutlierPolicy::Default);
+
+		let mut stream = Stream::default();
+		for &s in samples { stream.push(Duration::from_secs_f64(s)); }
+		assert_eq!(stream.total() as usize, samples.len(), "{name}: wrong total.");
+
+		// Check the trimmed mean while we're here.
+		let mut stream2 = Stream::default();
+		for &s in samples { stream2.push(Duration::from_secs_f64(s)); }
+		let (_, trimmed, _, _) = stream2.crunch(OutlierPolicy::Default, Metric::TrimmedMean(0.1));
+		let e_trimmed = exact.trimmed_mean(0.1);
+		let diff = (trimmed - e_trimmed).abs() / e_trimmed;
+		assert!(diff < 0.01, "{name}: trimmed mean {trimmed} vs {e_trimmed}.");
+
+		// And the minimum, which can only be off by a bucket's width.
+		let mut stream3 = Stream::default();
+		for &s in samples { stream3.push(Duration::from_secs_f64(s)); }
+		let (_, min, _, _) = stream3.crunch(OutlierPolicy::Default, Metric::Min);
+		let e_min = exact.min();
+		let diff = (min - e_min).abs() / e_min;
+		assert!(diff < 0.03, "{name}: minimum {min} vs {e_min}.");
+
+		let (valid, mean, deviation, sum) = stream.crunch(OutlierPolicy::Default, Metric::Mean);
+
+		let e_valid = f64::from(u32::saturating_from(exact.len()));
+		let diff = (f64::from(valid) - e_valid).abs() / e_valid;
+		assert!(diff < 0.01, "{name}: valid {valid} vs {e_valid}.");
+
+		let diff = (mean - exact.mean()).abs() / exact.mean();
+		assert!(diff < 0.005, "{name}: mean {mean} vs {}.", exact.mean());
+
+		let diff = (sum - exact.sum()).abs() / exact.sum();
+		assert!(diff < 0.01, "{name}: sum {sum} vs {}.", exact.sum());
+
+		let e_deviation = exact.deviation();
+		if e_deviation == 0.0 { assert!(deviation < 1e-15, "{name}: deviation {deviation}."); }
+		else {
+			let diff = (deviation - e_deviation).abs() / e_deviation;
+			assert!(diff < 0.05, "{name}: deviation {deviation} vs {e_deviation}.");
+		}
+	}
+
+	#[test]
+	fn t_stream() {
+		for len in [500_usize, 50_000] {
+			let mut noise = Noise(len as u64);
+
+			// Constant.
+			let set = vec![0.000_000_5; len];
+			compare("Constant", &set);
+
+			// Roughly normal, with occasional spikes.
+			let set: Vec<f64> = (0..len).map(|i| {
+				let n: f64 = (0..12).map(|_| noise.next()).sum::<f64>() - 6.0;
+				let spike = if i % 97 == 0 { 0.000_02 } else { 0.0 };
+				n.mul_add(0.000_000_05, 0.000_001) + spike
+			}).collect();
+			compare("Normal", &set);
+
+			// Exponential.
+			let set: Vec<f64> = (0..len).map(|_|
+				(-(1.0 - noise.next()).ln()).mul_add(0.000_000_05, 0.000_000_2)
+			).collect();
+			compare("Exponential", &set);
+
+			// Uniform, in milliseconds.
+			let set: Vec<f64> = (0..len).map(|_|
+				noise.next().mul_add(0.001, 0.002)
+			).collect();
+			compare("Uniform", &set);
+		}
+	}
+
+	#[test]
+	fn t_bucket_merge() {
+		let mut noise = Noise(42);
+		let set: Vec<f64> = (0..1000).map(|_| noise.next()).collect();
+
+		let mut all = Bucket::default();
+		let mut a = Bucket::default();
+		let mut b = Bucket::default();
+		for (i, &x) in set.iter().enumerate() {
+			all.push(x);
+			if i % 3 == 0 { a.push(x); }
+			else { b.push(x); }
+		}
+		a.merge(&b);
+
+		let exact = Abacus::from(set);
+		assert_eq!(a.count, all.count);
+		assert!((a.mean - exact.mean()).abs() < 1e-12);
+		assert!((all.mean - exact.mean()).abs() < 1e-12);
+		assert!((a.deviation() - exact.deviation()).abs() < 1e-12);
+		assert!((all.deviation() - exact.deviation()).abs() < 1e-12);
+		assert!((a.min - exact.min()).abs() < 1e-12);
+		assert!((all.min - exact.min()).abs() < 1e-12);
+	}
+}
@@ -0,0 +1,181 @@
+/*!
+# Brunch: Terminal Width
+*/
+
+use crate::OutputTarget;
+
+
+
+/// # Default Width.
+///
+/// This is used when the output isn't a terminal and `COLUMNS` is unset.
+const DEFAULT_WIDTH: usize = 100;
+
+/// # Minimum Width.
+///
+/// Anything narrower than this is probably a mistake.
+const MIN_WIDTH: usize = 40;
+
+
+
+/// # Table Width.
+///
+/// Return the maximum width the results table should be squeezed into, or
+/// `None` if it should never be truncated.
+///
+/// The `BRUNCH_WIDTH` environmental variable takes priority — `0` disables
+/// truncation altogether — followed by the size of the terminal, `COLUMNS`,
+/// and finally `DEFAULT_WIDTH`.
+pub(crate) fn width(target: OutputTarget) -> Option<usize> {
+	if let Some(w) = env_width("BRUNCH_WIDTH") {
+		return if w == 0 { None } else { Some(w.max(MIN_WIDTH)) };
+	}
+
+	let w = sys::width(matches!(target, OutputTarget::Stdout))
+		.or_else(|| env_width("COLUMNS"))
+		.filter(|w| 0 != *w)
+		.unwrap_or(DEFAULT_WIDTH);
+	Some(w.max(MIN_WIDTH))
+}
+
+/// # Width From Environment.
+fn env_width(key: &str) -> Option<usize> {
+	std::env::var(key).ok()?.trim().parse::<usize>().ok()
+}
+
+
+
+#[cfg(any(target_os = "linux", target_os = "macos"))]
+/// # Unix.
+mod sys {
+	use std::ffi::{
+		c_int,
+		c_ulong,
+	};
+
+	#[cfg(target_os = "linux")]
+	/// # Get Window Size Request.
+	const TIOCGWINSZ: c_ulong = 0x5413;
+
+	#[cfg(target_os = "macos")]
+	/// # Get Window Size Request.
+	const TIOCGWINSZ: c_ulong = 0x4008_7468;
+
+	#[repr(C)]
+	#[derive(Default)]
+	/// # Window Size.
+	struct WinSize {
+		/// # Rows.
+		rows: u16,
+
+		/// # Columns.
+		cols: u16,
+
+		/// # Width (Pixels).
+		x: u16,
+
+		/// # Height (Pixels).
+		y: u16,
+	}
+
+	extern "C" {
+		/// # I/O Control.
+		fn ioctl(fd: c_int, request: c_ulong, ...) -> c_int;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Terminal Width.
+	pub(super) fn width(stdout: bool) -> Option<usize> {
+		let mut size = WinSize::default();
+		let fd: c_int = if stdout { 1 } else { 2 };
+
+		// Safety: the struct matches the C layout.
+		let res = unsafe { ioctl(fd, TIOCGWINSZ, &raw mut size) };
+		if res == 0 && size.cols != 0 { Some(usize::from(size.cols)) }
+		else { None }
+	}
+}
+
+#[cfg(windows)]
+/// # Windows.
+mod sys {
+	use std::ffi::c_void;
+
+	/// # Standard Output Handle.
+	///
+	/// This is `-11` as a `DWORD`.
+	const STD_OUTPUT_HANDLE: u32 = u32::MAX - 10;
+
+	/// # Standard Error Handle.
+	///
+	/// This is `-12` as a `DWORD`.
+	const STD_ERROR_HANDLE: u32 = u32::MAX - 11;
+
+	#[repr(C)]
+	#[derive(Default)]
+	#[expect(dead_code, reason = "Needed for the C layout.")]
+	/// # Console Screen Buffer Info.
+	struct BufferInfo {
+		/// # Buffer Size.
+		size: [i16; 2],
+
+		/// # Cursor Position.
+		cursor: [i16; 2],
+
+		/// # Attributes.
+		attributes: u16,
+
+		/// # Window (Left, Top, Right, Bottom).
+		window: [i16; 4],
+
+		/// # Maximum Window Size.
+		max_size: [i16; 2],
+	}
+
+	#[link(name = "kernel32")]
+	extern "system" {
+		/// # Standard Handle.
+		fn GetStdHandle(which: u32) -> *mut c_void;
+
+		/// # Console Screen Buffer Info.
+		fn GetConsoleScreenBufferInfo(handle: *mut c_void, info: *mut BufferInfo) -> i32;
+	}
+
+	#[expect(unsafe_code, reason = "Needed for FFI.")]
+	/// # Terminal Width.
+	pub(super) fn width(stdout: bool) -> Option<usize> {
+		let mut info = BufferInfo::default();
+		let which = if stdout { STD_OUTPUT_HANDLE } else { STD_ERROR_HANDLE };
+
+		// Safety: standard handles are owned by the process, and the struct
+		// matches the C layout.
+		let res = unsafe { GetConsoleScreenBufferInfo(GetStdHandle(which), &raw mut info) };
+		if res == 0 { None }
+		else {
+			let [left, _, right, _] = info.window;
+			usize::try_from(i32::from(right) - i32::from(left) + 1).ok()
+		}
+	}
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", windows)))]
+/// # Other.
+mod sys {
+	/// # Terminal Width (Unsupported).
+	pub(super) const fn width(_stdout: bool) -> Option<usize> { None }
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_width() {
+		// Whatever we're attached to, the answer should be sane.
+		if let Some(w) = width(OutputTarget::Stderr) {
+			assert!(MIN_WIDTH <= w, "Width too small: {w}");
+		}
+	}
+}
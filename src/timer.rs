@@ -0,0 +1,252 @@
+/*!
+# Brunch: Timers
+*/
+
+use dactyl::traits::SaturatingFrom;
+use std::{
+	cell::{
+		Cell,
+		RefCell,
+	},
+	fmt,
+	rc::Rc,
+	time::{
+		Duration,
+		Instant,
+	},
+};
+
+
+
+thread_local! {
+	/// # Active Timer.
+	///
+	/// This is set — via `TimerGuard` — while a benchmark with a custom
+	/// timer is running. When `None`, `Instant` is used directly.
+	static TIMER: RefCell<Option<Rc<dyn Timer>>> = const { RefCell::new(None) };
+}
+
+
+
+/// # Timer.
+///
+/// This trait abstracts the time source used to measure each sample, as well
+/// as to enforce the timeouts and budgets, allowing the default
+/// `Instant`-based [`SystemTimer`] to be swapped out for something more
+/// exotic — `CLOCK_MONOTONIC_RAW`, calibrated TSC reads, etc. — or a
+/// deterministic [`MockTimer`] for testing. See
+/// [`Benches::with_timer`](crate::Benches::with_timer) for usage.
+///
+/// Implementations need only return a monotonic reading in nanoseconds; the
+/// starting point is arbitrary, as only the differences matter.
+pub trait Timer {
+	/// # Now.
+	///
+	/// Return the current reading in nanoseconds.
+	fn now(&self) -> u64;
+}
+
+
+
+#[derive(Debug, Clone, Copy)]
+/// # System Timer.
+///
+/// This is the default [`Timer`], backed by [`Instant`], i.e. whatever
+/// monotonic clock the platform's standard library uses.
+///
+/// (Benchmarks without a custom timer use `Instant` directly, skipping the
+/// indirection.)
+pub struct SystemTimer {
+	/// # Epoch.
+	epoch: Instant,
+}
+
+impl Default for SystemTimer {
+	fn default() -> Self { Self::new() }
+}
+
+impl Timer for SystemTimer {
+	fn now(&self) -> u64 { u64::saturating_from(self.epoch.elapsed().as_nanos()) }
+}
+
+impl SystemTimer {
+	#[must_use]
+	/// # New.
+	pub fn new() -> Self { Self { epoch: Instant::now() } }
+}
+
+
+
+#[derive(Debug, Clone, Default)]
+/// # Mock Timer.
+///
+/// This is a deterministic [`Timer`] that advances by a scripted amount
+/// each time it is read, cycling through the steps in order. (With no steps,
+/// it never advances at all.)
+///
+/// Each sample takes two readings — one at the start, one at the end — so
+/// its measured time is whatever step the second lands on. The timeout
+/// checks take readings of their own, so script accordingly.
+///
+/// ## Examples
+///
+/// ```
+/// use brunch::{MockTimer, Timer};
+/// use std::time::Duration;
+///
+/// let timer = MockTimer::new([Duration::from_millis(1), Duration::from_millis(10)]);
+/// assert_eq!(timer.now(), 1_000_000);
+/// assert_eq!(timer.now(), 11_000_000);
+/// assert_eq!(timer.now(), 12_000_000);
+/// ```
+pub struct MockTimer {
+	/// # Steps (Nanoseconds).
+	steps: Vec<u64>,
+
+	/// # Next Step.
+	next: Cell<usize>,
+
+	/// # Current Reading.
+	now: Cell<u64>,
+}
+
+impl Timer for MockTimer {
+	fn now(&self) -> u64 {
+		if ! self.steps.is_empty() {
+			let idx = self.next.get() % self.steps.len();
+			self.next.set(idx + 1);
+			self.now.set(self.now.get().saturating_add(self.steps[idx]));
+		}
+		self.now.get()
+	}
+}
+
+impl MockTimer {
+	#[must_use]
+	/// # New.
+	///
+	/// Create a new mock timer, starting at zero, that advances by each of
+	/// the steps in turn.
+	pub fn new<I>(steps: I) -> Self
+	where I: IntoIterator<Item=Duration> {
+		Self {
+			steps: steps.into_iter().map(|d| u64::saturating_from(d.as_nanos())).collect(),
+			next: Cell::new(0),
+			now: Cell::new(0),
+		}
+	}
+}
+
+
+
+/// # Timer Guard.
+///
+/// This installs a custom timer — if any — for the current thread, restoring
+/// the previous one when dropped.
+pub(crate) struct TimerGuard(Option<Rc<dyn Timer>>);
+
+impl Drop for TimerGuard {
+	fn drop(&mut self) {
+		let prev = self.0.take();
+		TIMER.with_borrow_mut(|t| *t = prev);
+	}
+}
+
+impl TimerGuard {
+	/// # New.
+	pub(crate) fn new(timer: Option<Rc<dyn Timer>>) -> Self {
+		Self(TIMER.with_borrow_mut(|t| std::mem::replace(t, timer)))
+	}
+}
+
+
+
+#[derive(Clone)]
+/// # Tick.
+///
+/// A reading from the active timer, from which the elapsed time can be
+/// calculated later.
+pub(crate) enum Tick {
+	/// # Standard Instant.
+	Std(Instant),
+
+	/// # Custom Timer (and Reading).
+	Custom(Rc<dyn Timer>, u64),
+}
+
+impl fmt::Debug for Tick {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::Std(i) => f.debug_tuple("Std").field(i).finish(),
+			Self::Custom(_, n) => f.debug_tuple("Custom").field(n).finish(),
+		}
+	}
+}
+
+impl Tick {
+	/// # Now.
+	///
+	/// Take a reading from the active timer.
+	pub(crate) fn now() -> Self {
+		TIMER.with_borrow(|t| t.as_ref().map_or_else(
+			|| Self::Std(Instant::now()),
+			|t| Self::Custom(Rc::clone(t), t.now()),
+		))
+	}
+
+	/// # Elapsed.
+	///
+	/// Return the time elapsed since the reading, per the same timer.
+	pub(crate) fn elapsed(&self) -> Duration {
+		match self {
+			Self::Std(i) => i.elapsed(),
+			Self::Custom(t, start) => Duration::from_nanos(t.now().saturating_sub(*start)),
+		}
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_mock() {
+		let timer = MockTimer::new([Duration::from_nanos(5), Duration::from_nanos(10)]);
+		assert_eq!(timer.now(), 5);
+		assert_eq!(timer.now(), 15);
+		assert_eq!(timer.now(), 20);
+		assert_eq!(timer.now(), 30);
+
+		// No steps, no progress.
+		let timer = MockTimer::default();
+		assert_eq!(timer.now(), 0);
+		assert_eq!(timer.now(), 0);
+	}
+
+	#[test]
+	fn t_guard() {
+		assert!(matches!(Tick::now(), Tick::Std(_)), "Expected the standard timer.");
+		{
+			let _guard = TimerGuard::new(Some(Rc::new(MockTimer::new([Duration::from_millis(3)]))));
+			let tick = Tick::now();
+			assert!(matches!(tick, Tick::Custom(_, 3_000_000)), "Expected the mock timer.");
+			assert_eq!(tick.elapsed(), Duration::from_millis(3));
+
+			// Guards nest.
+			{
+				let _guard = TimerGuard::new(None);
+				assert!(matches!(Tick::now(), Tick::Std(_)), "Expected the standard timer.");
+			}
+			assert!(matches!(Tick::now(), Tick::Custom(..)), "Expected the mock timer.");
+		}
+		assert!(matches!(Tick::now(), Tick::Std(_)), "Expected the standard timer.");
+
+		// The system timer should move forward.
+		let timer = SystemTimer::new();
+		let a = timer.now();
+		std::thread::sleep(Duration::from_millis(1));
+		assert!(a < timer.now(), "The system timer should advance.");
+	}
+}
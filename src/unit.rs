@@ -0,0 +1,114 @@
+/*!
+# Brunch: Time Units
+*/
+
+use dactyl::total_cmp;
+
+
+
+/// # Maximum Precision.
+///
+/// This is as many decimal places as we'll ever print.
+const MAX_PRECISION: usize = 8;
+
+
+
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+/// # Time Unit.
+///
+/// This enum is used to force the time values in the results table — the
+/// means, confidence intervals, etc. — into a particular unit, via
+/// [`Benches::with_unit`](crate::Benches::with_unit) or
+/// [`Bench::with_unit`](crate::Bench::with_unit).
+///
+/// By default, each value is scaled to whatever unit suits it best, with
+/// durations of a minute or more broken down into minutes and seconds — e.g.
+/// `3 min 07 s` — or hours and minutes.
+pub enum TimeUnit {
+	#[default]
+	/// # Automatic.
+	Auto,
+
+	/// # Nanoseconds.
+	Nanos,
+
+	/// # Microseconds.
+	Micros,
+
+	/// # Milliseconds.
+	Millis,
+
+	/// # Seconds.
+	Secs,
+}
+
+impl TimeUnit {
+	/// # Scale and Label.
+	///
+	/// Return the multiplier needed to convert the value (in seconds) to this
+	/// unit, along with its label. For `Auto`, the unit is chosen based on
+	/// the value.
+	pub(crate) fn scale(self, secs: f64) -> (f64, &'static str) {
+		match self {
+			Self::Auto =>
+				if total_cmp!(secs < 0.000_001) { Self::Nanos.scale(secs) }
+				else if total_cmp!(secs < 0.001) { Self::Micros.scale(secs) }
+				else if total_cmp!(secs < 1.0) { Self::Millis.scale(secs) }
+				else { Self::Secs.scale(secs) },
+			Self::Nanos => (1_000_000_000.0, "ns"),
+			Self::Micros => (1_000_000.0, "\u{3bc}s"),
+			Self::Millis => (1_000.0, "ms"),
+			Self::Secs => (1.0, "s "),
+		}
+	}
+
+	/// # Precision.
+	///
+	/// Return the number of decimal places to use for a scaled value.
+	///
+	/// Two is plenty for automatically-scaled values, but forced units can
+	/// leave tiny values with no significant digits at all, so those get
+	/// as many extra places as needed to show a few.
+	pub(crate) fn precision(self, scaled: f64) -> usize {
+		if matches!(self, Self::Auto) || ! scaled.is_normal() || total_cmp!(scaled >= 1.0) {
+			return 2;
+		}
+
+		let mut precision = 2;
+		let mut v = scaled.abs();
+		while total_cmp!(v < 1.0) && precision < MAX_PRECISION {
+			v *= 10.0;
+			precision += 1;
+		}
+		precision
+	}
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_scale() {
+		// Automatic scaling.
+		assert_eq!(TimeUnit::Auto.scale(0.000_000_5).1, "ns");
+		assert_eq!(TimeUnit::Auto.scale(0.000_5).1, "\u{3bc}s");
+		assert_eq!(TimeUnit::Auto.scale(0.5).1, "ms");
+		assert_eq!(TimeUnit::Auto.scale(5.0).1, "s ");
+
+		// Forced.
+		for v in [0.000_000_5, 0.5, 5.0] {
+			assert_eq!(TimeUnit::Nanos.scale(v), (1_000_000_000.0, "ns"));
+			assert_eq!(TimeUnit::Millis.scale(v), (1_000.0, "ms"));
+		}
+
+		// Precision.
+		assert_eq!(TimeUnit::Auto.precision(0.001), 2);
+		assert_eq!(TimeUnit::Nanos.precision(1020.0), 2);
+		assert_eq!(TimeUnit::Micros.precision(0.98), 3);
+		assert_eq!(TimeUnit::Secs.precision(0.000_000_05), MAX_PRECISION);
+		assert_eq!(TimeUnit::Secs.precision(0.0), 2);
+	}
+}
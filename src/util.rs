@@ -2,31 +2,322 @@
 # Brunch: Utility Functions
 */
 
-use unicode_width::UnicodeWidthChar;
+use dactyl::{
+	NiceFloat,
+	total_cmp,
+};
+use crate::TimeUnit;
+use std::time::Duration;
+use unicode_width::UnicodeWidthStr;
 
 
 
+/// # Nice Interval.
+///
+/// Return a pair of durations (in seconds) as a bracketed range, rescaled to
+/// the same unit [`nice_time_in`] would use for the reference value, but
+/// without the unit itself, e.g. `[55.90, 56.60]`.
+///
+/// References of a minute or more are written as clock times instead, e.g.
+/// `[3:06.20, 3:08.10]`, since [`nice_time_in`] switches to minutes and
+/// hours for those.
+pub(crate) fn nice_interval(reference: f64, lo: f64, hi: f64, unit: TimeUnit) -> String {
+	if matches!(unit, TimeUnit::Auto) && total_cmp!(reference >= 60.0) {
+		if let Some((lo, hi)) = nice_clock(lo).zip(nice_clock(hi)) {
+			return format!("[{lo}, {hi}]");
+		}
+	}
+
+	let (scale, _) = unit.scale(reference);
+	let precision = unit.precision(reference * scale);
+	format!(
+		"[{}, {}]",
+		NiceFloat::from(round_to(lo * scale, precision)).precise_str(precision),
+		NiceFloat::from(round_to(hi * scale, precision)).precise_str(precision),
+	)
+}
+
+/// # Round To.
+///
+/// Round a value to the given number of decimal places. (`NiceFloat`
+/// truncates, which would make bounds like `10.198` look tighter than they
+/// are.)
+fn round_to(n: f64, precision: usize) -> f64 {
+	let m = 10_f64.powi(i32::try_from(precision).unwrap_or(i32::MAX));
+	let out = (n * m).round() / m;
+	if out.is_finite() { out } else { n }
+}
+
+/// # Nice Time.
+///
+/// Return a duration (in seconds) rescaled to the most appropriate unit.
+pub(crate) fn nice_time(secs: f64) -> String { nice_time_in(secs, TimeUnit::Auto) }
+
+/// # Nice Time (In Unit).
+///
+/// Return a duration (in seconds) rescaled to the given unit.
+///
+/// When scaled automatically, durations of a minute or more are broken down
+/// into minutes and seconds, e.g. `3 min 07 s`, or hours and minutes, e.g.
+/// `2 h 05 min`.
+pub(crate) fn nice_time_in(secs: f64, unit: TimeUnit) -> String {
+	if matches!(unit, TimeUnit::Auto) {
+		if let Some(out) = nice_long(secs) { return out; }
+	}
+
+	let (scale, label) = unit.scale(secs);
+	let scaled = secs * scale;
+	format!("{} {label}", NiceFloat::from(scaled).precise_str(unit.precision(scaled)))
+}
+
+/// # Nice Long Time.
+///
+/// Return a duration (in seconds) of a minute or more as minutes and
+/// seconds, or if an hour or more, hours and minutes. Shorter (or invalid)
+/// durations return `None`.
+fn nice_long(secs: f64) -> Option<String> {
+	if total_cmp!(secs < 60.0) { return None; }
+
+	// Round to the nearest second (or minute).
+	let whole = Duration::try_from_secs_f64(secs + 0.5).ok()?.as_secs();
+	if whole < 3600 {
+		return Some(format!("{} min {:02} s", whole / 60, whole % 60));
+	}
+
+	let mins = Duration::try_from_secs_f64(secs / 60.0 + 0.5).ok()?.as_secs();
+	Some(format!("{} h {:02} min", mins / 60, mins % 60))
+}
+
+/// # Nice Clock Time.
+///
+/// Return a duration (in seconds) as `m:ss.ss`, or if an hour or more,
+/// `h:mm:ss`. Invalid durations return `None`.
+fn nice_clock(secs: f64) -> Option<String> {
+	if secs.is_sign_negative() { return None; }
+
+	let centis = Duration::try_from_secs_f64(secs.mul_add(100.0, 0.5)).ok()?.as_secs();
+	if centis < 360_000 {
+		Some(format!("{}:{:02}.{:02}", centis / 6000, centis / 100 % 60, centis % 100))
+	}
+	else {
+		let whole = centis / 100;
+		Some(format!("{}:{:02}:{:02}", whole / 3600, whole / 60 % 60, whole % 60))
+	}
+}
+
+/// # Nice Count.
+///
+/// Return a (mean) count, with two decimal places if it isn't a whole
+/// number.
+pub(crate) fn nice_count(n: f64) -> String {
+	let whole = n.round();
+	let diff = (n - whole).abs();
+	if total_cmp!(diff < 0.005) { NiceFloat::from(whole).precise_str(0).to_owned() }
+	else { NiceFloat::from(n).precise_str(2).to_owned() }
+}
+
+/// # Nice Bytes.
+///
+/// Return a (mean) byte count rescaled to the most appropriate binary unit.
+pub(crate) fn nice_bytes(n: f64) -> String {
+	if total_cmp!(n < 1024.0) { return format!("{} B", nice_count(n)); }
+
+	let mut n = n / 1024.0;
+	for unit in ["KiB", "MiB", "GiB"] {
+		if total_cmp!(n < 1024.0) {
+			return format!("{} {unit}", NiceFloat::from(n).precise_str(2));
+		}
+		n /= 1024.0;
+	}
+	format!("{} TiB", NiceFloat::from(n).precise_str(2))
+}
+
+/// # Nice Operations Per Second.
+///
+/// Return the reciprocal of a (mean) duration in seconds, rescaled to the
+/// most appropriate SI prefix, e.g. `14.20 M ops/s`, or `None` if it can't
+/// be calculated.
+pub(crate) fn nice_ops(secs: f64) -> Option<String> {
+	if ! secs.is_normal() || secs.is_sign_negative() { return None; }
+
+	let mut n = secs.recip();
+	for prefix in ["", "K ", "M "] {
+		if total_cmp!(n < 1000.0) {
+			return Some(format!("{} {prefix}ops/s", NiceFloat::from(n).precise_str(2)));
+		}
+		n /= 1000.0;
+	}
+	Some(format!("{} G ops/s", NiceFloat::from(n).precise_str(2)))
+}
+
+/// # Strip ANSI.
+///
+/// Return a copy of the string with any terminal escape sequences — colors,
+/// hyperlinks, etc. — removed.
+pub(crate) fn strip_ansi(src: &str) -> String { Printable(src).collect() }
+
+/// # Split Decimal.
+///
+/// Split a formatted number-and-unit string like `2.22 ms` into its integer
+/// part, its fraction (including the separator), and whatever follows, so
+/// that a column of them can be aligned on the decimal point.
+///
+/// Strings that don't start with a digit — headers, placeholders, etc. —
+/// return `None`.
+pub(crate) fn split_decimal(src: &str) -> Option<(&str, &str, &str)> {
+	if ! src.starts_with(|c: char| c.is_ascii_digit()) { return None; }
+	let num = src.find(char::is_whitespace).unwrap_or(src.len());
+	let int = src[..num].find('.').unwrap_or(num);
+	Some((&src[..int], &src[int..num], &src[num..]))
+}
+
 /// # Width.
 ///
-/// Return the printable width of a string. This is somewhat naive, but gets
-/// closer than merely calling `String::len`.
+/// Return the printable width of a string, ignoring any terminal escape
+/// sequences, and measuring what's left with `unicode-width`, which takes
+/// care of combining marks, emoji sequences, and the like.
 pub(crate) fn width(src: &str) -> usize {
-	let mut in_ansi: bool = false;
-	src.chars()
-		.fold(0_usize, |w, c| {
-			// In ANSI.
-			if in_ansi {
-				if matches!(c, 'm' | 'A' | 'K') { in_ansi = false; }
-				w
-			}
-			// New ANSI.
-			else if c == '\x1b' {
-				in_ansi = true;
-				w
-			}
-			// Something else.
-			else {
-				UnicodeWidthChar::width(c).map_or(w, |w2| w2 + w)
+	Printable(src).map(UnicodeWidthStr::width).sum()
+}
+
+
+
+/// # Printable Text.
+///
+/// This iterator yields the printable stretches of a string, i.e. everything
+/// between its escape sequences.
+///
+/// CSI sequences (`ESC [`) run through any parameter and intermediate bytes
+/// to a final byte in `@`–`~`; OSC sequences (`ESC ]`), like hyperlinks, run
+/// to a `BEL` or string terminator; anything else is assumed to be a simple
+/// two-character escape.
+struct Printable<'a>(&'a str);
+
+impl<'a> Iterator for Printable<'a> {
+	type Item = &'a str;
+
+	fn next(&mut self) -> Option<Self::Item> {
+		while ! self.0.is_empty() {
+			// Return the text before the next escape, if any.
+			let end = self.0.find(['\x1b', '\u{9b}']).unwrap_or(self.0.len());
+			if end != 0 {
+				let (out, rest) = self.0.split_at(end);
+				self.0 = rest;
+				return Some(out);
 			}
-		})
+
+			// Otherwise skip the escape and try again.
+			self.0 = skip_escape(self.0);
+		}
+
+		None
+	}
+}
+
+/// # Skip Escape.
+///
+/// Return whatever follows the escape sequence at the start of the string.
+fn skip_escape(src: &str) -> &str {
+	let mut chars = src.chars();
+	let csi = match chars.next() {
+		Some('\u{9b}') => true,
+		_ => match chars.next() {
+			Some('[') => true,
+			Some(']') => false,
+			_ => return chars.as_str(),
+		},
+	};
+
+	// CSI: stop at the final byte, or anything that shouldn't be there.
+	if csi {
+		for c in chars.by_ref() {
+			if ! matches!(c, ' '..='?') { break; }
+		}
+		return chars.as_str();
+	}
+
+	// OSC: stop at BEL or ST.
+	let rest = chars.as_str();
+	for (idx, c) in rest.char_indices() {
+		match c {
+			'\x07' | '\u{9c}' => return &rest[idx + c.len_utf8()..],
+			'\x1b' => {
+				let rest = &rest[idx..];
+				return rest.strip_prefix("\x1b\\").unwrap_or(rest);
+			},
+			_ => {},
+		}
+	}
+	""
+}
+
+
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn t_nice_time() {
+		// Short durations are scaled as usual.
+		assert_eq!(nice_time(0.000_002_5), "2.50 \u{3bc}s");
+		assert_eq!(nice_time(59.994), "59.99 s ");
+
+		// Long ones are broken down.
+		assert_eq!(nice_time(60.0), "1 min 00 s");
+		assert_eq!(nice_time(187.43), "3 min 07 s");
+		assert_eq!(nice_time(3599.6), "1 h 00 min");
+		assert_eq!(nice_time(7512.0), "2 h 05 min");
+		assert_eq!(nice_time(86_400.0 * 2.0), "48 h 00 min");
+
+		// Unless a unit is forced.
+		assert_eq!(nice_time_in(187.43, TimeUnit::Secs), "187.43 s ");
+
+		// Intervals follow suit.
+		assert_eq!(nice_interval(187.43, 186.2, 188.1, TimeUnit::Auto), "[3:06.20, 3:08.10]");
+		assert_eq!(nice_interval(7512.0, 7500.4, 7523.0, TimeUnit::Auto), "[2:05:00, 2:05:23]");
+		assert_eq!(nice_interval(30.0, 29.5, 30.5, TimeUnit::Auto), "[29.50, 30.50]");
+	}
+
+	#[test]
+	fn t_nice_ops() {
+		assert_eq!(nice_ops(0.5).as_deref(), Some("2.00 ops/s"));
+		assert_eq!(nice_ops(0.000_1).as_deref(), Some("10.00 K ops/s"));
+		assert_eq!(nice_ops(0.000_000_070_42).as_deref(), Some("14.20 M ops/s"));
+		assert_eq!(nice_ops(0.000_000_000_1).as_deref(), Some("10.00 G ops/s"));
+		assert_eq!(nice_ops(0.0), None);
+		assert_eq!(nice_ops(f64::NAN), None);
+	}
+
+	#[test]
+	fn t_width() {
+		for (src, expected) in [
+			("Hello", 5),
+			("\x1b[1;38;5;208mHello\x1b[0m", 5),
+			("\x1b[2J\x1b[1;1HHello", 5),
+			("\u{9b}2mHello", 5),
+			// Hyperlinks, with ST and BEL terminators.
+			("\x1b]8;;https://blobfolio.com\x1b\\Blobfolio\x1b]8;;\x1b\\", 9),
+			("\x1b]8;;https://blobfolio.com\x07Blobfolio\x1b]8;;\x07", 9),
+			// An unterminated OSC swallows the rest.
+			("Hello\x1b]0;title", 5),
+			// Combining marks.
+			("e\u{301}", 1),
+			("Ab\u{301}c", 3),
+			// Emoji: ZWJ sequences, variation selectors, and modifiers.
+			("\u{1f468}\u{200d}\u{1f469}\u{200d}\u{1f467}", 2),
+			("\u{2764}\u{fe0f}", 2),
+			("\u{1f44d}\u{1f3fd}", 2),
+			// CJK.
+			("\u{4e2d}\u{6587}", 4),
+			("foo::\u{4e2d}\u{6587}()", 11),
+		] {
+			assert_eq!(width(src), expected, "Width mismatch: {src:?}");
+		}
+
+		assert_eq!(
+			strip_ansi("\x1b[1mHi\x1b[0m \x1b]8;;https://blobfolio.com\x1b\\there\x1b]8;;\x1b\\!"),
+			"Hi there!",
+		);
+	}
 }
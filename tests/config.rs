@@ -0,0 +1,14 @@
+/*!
+# Brunch: Config Grammar
+
+These lock down the `benches!` config block from the outside, making sure
+the keys are accepted, and that unknown keys and malformed blocks are
+rejected. (The key-by-key mapping is covered by the unit tests.)
+*/
+
+#[test]
+fn t_config() {
+	let t = trybuild::TestCases::new();
+	t.pass("tests/ui/config-pass/*.rs");
+	t.compile_fail("tests/ui/config-fail/*.rs");
+}
@@ -0,0 +1,9 @@
+use brunch::{Bench, benches};
+
+fn main() {
+	benches!(
+		inline:
+		config: { colour: false },
+		Bench::new("usize::checked_add(2)").run(|| 2_usize.checked_add(2)),
+	);
+}
@@ -0,0 +1,32 @@
+use brunch::{Bench, OutputTarget, TimeUnit, benches};
+use std::time::Duration;
+
+fn main() {
+	// Compile, don't run.
+	if false {
+		benches!(
+			inline:
+			config: {
+				budget_secs: 60,
+				color: false,
+				history: "target/brunch.hist",
+				history_readonly: true,
+				isolated: false,
+				no_history: true,
+				output: OutputTarget::Stdout,
+				parallel: 2,
+				quiet: true,
+				repeat: 3,
+				retries: 1,
+				samples: 10_000,
+				skip_tags: ["slow"],
+				tags: ["fast"],
+				timeout: Duration::from_secs(5),
+				timeout_secs: 5,
+				unit: TimeUnit::Micros,
+				verbose: true,
+			},
+			Bench::new("usize::checked_add(2)").run(|| 2_usize.checked_add(2)),
+		);
+	}
+}
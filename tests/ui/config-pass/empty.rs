@@ -0,0 +1,12 @@
+use brunch::{Bench, benches};
+
+fn main() {
+	// Compile, don't run.
+	if false {
+		benches!(
+			inline:
+			config: {},
+			Bench::new("usize::checked_add(2)").run(|| 2_usize.checked_add(2)),
+		);
+	}
+}
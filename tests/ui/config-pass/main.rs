@@ -0,0 +1,11 @@
+use brunch::{Bench, benches};
+
+benches!(
+	config: {
+		color: false,
+		no_history: true,
+		quiet: true,
+		samples: 100,
+	},
+	Bench::new("usize::checked_add(2)").run(|| 2_usize.checked_add(2)),
+);